@@ -0,0 +1,143 @@
+import { invalidRequest } from './errors.js';
+
+/**
+ * Validation of tool arguments against the tool's input schema before the handler runs.
+ *
+ * Every missing required argument and every top-level argument of the wrong type or outside
+ * its enum is collected, so one invalid_request error lists all of them (in `fields`)
+ * instead of the caller fixing them one round trip at a time. Handlers keep their own
+ * checks for the rules a schema can't express.
+ *
+ * Arguments the tool doesn't accept are usually copy-paste mistakes that would otherwise be
+ * silently ignored. LETTA_UNKNOWN_ARGUMENTS decides what happens to them: "reject" (the
+ * default) reports them with the other problems, "warn" logs them and "ignore" lets them
+ * through.
+ */
+
+export const UNKNOWN_ARGUMENT_POLICIES = ['reject', 'warn', 'ignore'];
+
+/**
+ * Read how unknown tool arguments are handled from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {string} One of UNKNOWN_ARGUMENT_POLICIES
+ */
+export function readUnknownArgumentPolicy(env = process.env) {
+    const value = env.LETTA_UNKNOWN_ARGUMENTS;
+    if (value === undefined || value === '') return 'reject';
+    if (!UNKNOWN_ARGUMENT_POLICIES.includes(value)) {
+        throw new Error(
+            `Invalid environment variable LETTA_UNKNOWN_ARGUMENTS: expected ${UNKNOWN_ARGUMENT_POLICIES.join(', ')}`,
+        );
+    }
+    return value;
+}
+
+const TYPE_CHECKS = {
+    string: (value) => typeof value === 'string',
+    integer: Number.isInteger,
+    number: (value) => typeof value === 'number' && Number.isFinite(value),
+    boolean: (value) => typeof value === 'boolean',
+    array: Array.isArray,
+    object: (value) => typeof value === 'object' && value !== null && !Array.isArray(value),
+    null: (value) => value === null,
+};
+
+const TYPE_NAMES = {
+    string: 'a string',
+    integer: 'an integer',
+    number: 'a number',
+    boolean: 'a boolean',
+    array: 'an array',
+    object: 'an object',
+    null: 'null',
+};
+
+/**
+ * Whether a required argument counts as not given
+ */
+const isMissing = (value) => value === undefined || value === null || value === '';
+
+/**
+ * Check one argument against its property schema
+ * @returns {string|null} What is wrong with the value, or null if it is valid
+ */
+function checkProperty(name, value, property) {
+    const types = [property.type].flat().filter((type) => TYPE_CHECKS[type]);
+    if (types.length > 0 && !types.some((type) => TYPE_CHECKS[type](value))) {
+        return `${name} must be ${types.map((type) => TYPE_NAMES[type]).join(' or ')}`;
+    }
+    if (Array.isArray(property.enum) && !property.enum.includes(value)) {
+        return `${name} must be one of ${property.enum.join(', ')}`;
+    }
+    return null;
+}
+
+/**
+ * Collect the problems with a tool call's arguments
+ * @param {Object} schema - The tool's inputSchema
+ * @param {Object} [args] - Tool arguments
+ * @returns {{missing: string[], invalid: Object[], unknown: string[]}} Missing required
+ *     argument names, invalid arguments as `{ field, message }`, and arguments the schema
+ *     doesn't declare
+ */
+export function findArgumentProblems(schema, args = {}) {
+    const values = args ?? {};
+    const properties = schema?.properties ?? {};
+    const missing = (schema?.required ?? []).filter((name) => isMissing(values[name]));
+
+    const invalid = [];
+    const unknown = [];
+    for (const [name, value] of Object.entries(values)) {
+        if (!properties[name]) {
+            if (schema?.additionalProperties !== true) unknown.push(name);
+            continue;
+        }
+        if (missing.includes(name)) continue;
+        // Explicit nulls mean "not set" for optional arguments
+        if (value === null) continue;
+        const message = checkProperty(name, value, properties[name]);
+        if (message) invalid.push({ field: name, message });
+    }
+    return { missing, invalid, unknown };
+}
+
+/**
+ * Validate a tool call's arguments against its input schema
+ * @param {Object} schema - The tool's inputSchema
+ * @param {Object} [args] - Tool arguments
+ * @param {Object} [options]
+ * @param {string} [options.toolName] - Tool name, for messages
+ * @param {string} [options.unknownArguments] - Policy for undeclared arguments (default: reject)
+ * @param {Object} [options.logger] - Logger for the "warn" policy
+ * @throws {Error} One invalid_request error listing every problem, with the offending
+ *     argument names in `fields`
+ */
+export function validateArguments(schema, args, options = {}) {
+    const { toolName = 'this tool', unknownArguments = 'reject', logger } = options;
+    const problems = findArgumentProblems(schema, args);
+    const { missing, invalid } = problems;
+    let unknown = problems.unknown;
+    if (unknown.length > 0 && unknownArguments !== 'reject') {
+        if (unknownArguments === 'warn') {
+            logger?.warn(`Ignoring arguments not accepted by ${toolName}: ${unknown.join(', ')}`);
+        }
+        unknown = [];
+    }
+    if (missing.length === 0 && invalid.length === 0 && unknown.length === 0) return;
+
+    const parts = [];
+    if (missing.length > 0) {
+        const noun = missing.length === 1 ? 'argument' : 'arguments';
+        parts.push(`Missing required ${noun}: ${missing.join(', ')}`);
+    }
+    if (invalid.length > 0) {
+        const noun = invalid.length === 1 ? 'argument' : 'arguments';
+        parts.push(`Invalid ${noun}: ${invalid.map(({ message }) => message).join('; ')}`);
+    }
+    if (unknown.length > 0) {
+        const noun = unknown.length === 1 ? 'argument' : 'arguments';
+        parts.push(`Unknown ${noun} for ${toolName}: ${unknown.join(', ')}`);
+    }
+    const fields = [...missing, ...invalid.map(({ field }) => field), ...unknown];
+    throw Object.assign(invalidRequest(fields[0], parts.join('. ')), { fields });
+}
@@ -0,0 +1,19 @@
+/**
+ * Letta API base URL.
+ *
+ * LETTA_BASE_URL is the server root, which may sit under a reverse proxy subpath
+ * (https://host/letta). The /v1 API prefix is added here; a URL that already ends in /v1
+ * (https://host/letta/v1) is accepted as well, and trailing slashes are ignored.
+ */
+
+/**
+ * Read the API base URL from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {string|null} Base URL ending in /v1, or null when LETTA_BASE_URL is unset
+ */
+export function readApiBase(env = process.env) {
+    const value = env.LETTA_BASE_URL?.trim();
+    if (!value) return null;
+    const root = value.replace(/\/+$/, '').replace(/\/v1$/, '');
+    return `${root}/v1`;
+}
@@ -0,0 +1,140 @@
+import { scopeHeaders } from './scope.js';
+
+/**
+ * Opt-in TTL cache for list tool responses.
+ *
+ * LETTA_CACHE_TTL_SECS enables caching of list_agents and list_tools results for that many
+ * seconds (default 0 = disabled). Entries are keyed by tool, arguments and project scope.
+ * A call can pass `bust_cache: true` to skip the cache, and any tool that changes agents or
+ * tools drops the cached listings for that resource once it succeeds.
+ */
+
+/**
+ * Cached tools and the resource their results list
+ */
+export const CACHED_TOOLS = {
+    list_agents: 'agents',
+    list_tools: 'tools',
+};
+
+/**
+ * Mutating tools and the cached resources they invalidate
+ */
+export const INVALIDATED_BY = {
+    create_agent: ['agents'],
+    modify_agent: ['agents'],
+    delete_agent: ['agents'],
+    bulk_delete_agents: ['agents'],
+    clone_agent: ['agents'],
+    migrate_agent: ['agents'],
+    import_agent: ['agents'],
+    attach_tool: ['agents'],
+    detach_tool: ['agents'],
+    bulk_attach_tool_to_agents: ['agents'],
+    bulk_attach_tools_to_agent: ['agents'],
+    attach_memory_block: ['agents'],
+    delete_memory_block: ['agents'],
+    attach_source: ['agents'],
+    detach_source: ['agents'],
+    upload_tool: ['tools', 'agents'],
+    patch_tool_source: ['tools'],
+    add_mcp_tool_to_letta: ['tools', 'agents'],
+};
+
+/**
+ * Read the cache TTL from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {number} TTL in seconds; 0 disables caching
+ */
+export function readCacheTtlSecs(env = process.env) {
+    const value = env.LETTA_CACHE_TTL_SECS;
+    if (value === undefined || value === '') return 0;
+    const secs = Number(value);
+    if (!Number.isFinite(secs) || secs < 0) {
+        throw new Error(
+            'Invalid environment variable LETTA_CACHE_TTL_SECS: expected seconds >= 0',
+        );
+    }
+    return secs;
+}
+
+/**
+ * In-memory cache of tool results grouped by resource, with a fixed TTL
+ */
+export class ResponseCache {
+    /**
+     * @param {number} ttlSecs - Entry lifetime in seconds; 0 disables the cache
+     * @param {Function} [now] - Clock in milliseconds, for tests
+     */
+    constructor(ttlSecs, now = Date.now) {
+        this.ttlMs = ttlSecs * 1000;
+        this.now = now;
+        this.entries = new Map();
+    }
+
+    get enabled() {
+        return this.ttlMs > 0;
+    }
+
+    get(resource, key) {
+        const entry = this.entries.get(resource)?.get(key);
+        if (!entry) return undefined;
+        if (entry.expiresAt <= this.now()) {
+            this.entries.get(resource).delete(key);
+            return undefined;
+        }
+        return entry.value;
+    }
+
+    set(resource, key, value) {
+        if (!this.entries.has(resource)) this.entries.set(resource, new Map());
+        this.entries.get(resource).set(key, { value, expiresAt: this.now() + this.ttlMs });
+    }
+
+    invalidate(resource) {
+        this.entries.delete(resource);
+    }
+}
+
+/**
+ * Run a tool call through the response cache
+ * @param {Object} server - Server instance with a responseCache
+ * @param {string} name - Tool name
+ * @param {Object} args - Tool arguments
+ * @param {Function} call - Runs the tool and returns its result
+ * @returns {Promise<Object>} The tool result, possibly from the cache
+ */
+export async function withResponseCache(server, name, args = {}, call) {
+    const cache = server.responseCache;
+    if (!cache?.enabled) return call();
+
+    const resource = CACHED_TOOLS[name];
+    if (resource) {
+        // eslint-disable-next-line no-unused-vars
+        const { bust_cache, ...keyArgs } = args;
+        const key = JSON.stringify([name, keyArgs, scopeHeaders(server.scope)]);
+        if (!bust_cache) {
+            const cached = cache.get(resource, key);
+            if (cached) return cached;
+        }
+        const result = await call();
+        cache.set(resource, key, result);
+        return result;
+    }
+
+    const result = await call();
+    for (const invalidated of INVALIDATED_BY[name] || []) {
+        cache.invalidate(invalidated);
+    }
+    return result;
+}
+
+/**
+ * Schema for the per-call bust_cache tool argument
+ */
+export const bustCacheProperty = {
+    type: 'boolean',
+    description:
+        'Skip any cached result and fetch fresh data (only matters when LETTA_CACHE_TTL_SECS is set)',
+    default: false,
+};
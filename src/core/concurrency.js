@@ -0,0 +1,65 @@
+/**
+ * Bounded concurrency for fan-out API calls.
+ *
+ * Scans that touch every agent (e.g. checking which agents use a source) run their
+ * per-agent requests in parallel, capped so a large deployment doesn't flood the Letta
+ * server. The cap defaults to 16 and can be set with LETTA_MAX_CONCURRENCY, read once when the
+ * server starts. A call's max_concurrency can lower the cap but not raise it.
+ */
+
+export const DEFAULT_MAX_CONCURRENCY = 16;
+
+/**
+ * Read the concurrency cap from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {number} Maximum number of requests in flight
+ */
+export function readMaxConcurrency(env = process.env) {
+    const value = env.LETTA_MAX_CONCURRENCY;
+    if (value === undefined || value === '') return DEFAULT_MAX_CONCURRENCY;
+    const parsed = Number(value);
+    if (!Number.isInteger(parsed) || parsed < 1) {
+        throw new Error(
+            'Invalid environment variable LETTA_MAX_CONCURRENCY: expected a positive integer',
+        );
+    }
+    return parsed;
+}
+
+/**
+ * The concurrency for a call: its max_concurrency, capped at the server's configured limit
+ * @param {Object} server - LettaServer instance
+ * @param {number} [requested] - max_concurrency argument, if given
+ * @returns {number}
+ */
+export function callConcurrency(server, requested) {
+    return Math.min(requested ?? server.maxConcurrency, server.maxConcurrency);
+}
+
+/**
+ * Map over items with at most `limit` calls in flight. Results keep the input order and
+ * failures are captured per item rather than rejecting the whole batch.
+ * @param {Array} items - Items to process
+ * @param {number} limit - Maximum number of concurrent calls
+ * @param {Function} fn - Async function called with (item, index)
+ * @returns {Promise<Array<{status: string, value?: *, reason?: Error}>>} Settled results
+ */
+export async function mapSettledWithConcurrency(items, limit, fn) {
+    const results = new Array(items.length);
+    let next = 0;
+
+    const worker = async () => {
+        while (next < items.length) {
+            const index = next++;
+            try {
+                results[index] = { status: 'fulfilled', value: await fn(items[index], index) };
+            } catch (reason) {
+                results[index] = { status: 'rejected', reason };
+            }
+        }
+    };
+
+    const workers = Math.max(1, Math.min(limit, items.length));
+    await Promise.all(Array.from({ length: workers }, worker));
+    return results;
+}
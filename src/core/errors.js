@@ -0,0 +1,145 @@
+import { ErrorCode } from '@modelcontextprotocol/sdk/types.js';
+import { isRetryableError } from './retry.js';
+import { isTimeoutError, timeoutErrorMessage } from './timeout.js';
+
+/**
+ * Mapping of Letta API failures onto MCP error codes.
+ *
+ * Client errors (4xx) become InvalidRequest (InvalidParams for 422), server errors (5xx)
+ * become InternalError. Arguments rejected before any request (see invalidRequest) are
+ * InvalidParams too. Every classification carries a machine-readable `error_code` and the
+ * HTTP `status` so callers can branch without matching on message text.
+ */
+
+const STATUS_ERROR_CODES = {
+    400: 'bad_request',
+    401: 'unauthorized',
+    403: 'forbidden',
+    404: 'not_found',
+    405: 'method_not_allowed',
+    409: 'conflict',
+    413: 'payload_too_large',
+    422: 'validation_error',
+    429: 'rate_limited',
+    501: 'not_implemented',
+    502: 'bad_gateway',
+    503: 'service_unavailable',
+    504: 'gateway_timeout',
+};
+
+/**
+ * Classify an error from a tool handler
+ * @param {*} error - Axios error, Error or string
+ * @returns {Object} MCP error code, errorCode, HTTP status (or null), retryable and message
+ */
+export function classifyError(error) {
+    if (typeof error === 'string') {
+        return {
+            code: ErrorCode.InternalError,
+            errorCode: 'internal_error',
+            status: null,
+            retryable: false,
+            message: error,
+        };
+    }
+    if (!(error instanceof Error)) {
+        return {
+            code: ErrorCode.InternalError,
+            errorCode: 'unknown_error',
+            status: null,
+            retryable: false,
+            message: 'Unknown error occurred',
+        };
+    }
+
+    if (error.errorCode === 'invalid_request') {
+        return {
+            code: ErrorCode.InvalidParams,
+            errorCode: 'invalid_request',
+            status: null,
+            retryable: false,
+            message: error.message,
+        };
+    }
+
+    if (error.errorCode === 'serialization_error') {
+        return {
+            code: ErrorCode.InternalError,
+            errorCode: 'serialization_error',
+            status: null,
+            retryable: false,
+            message: error.message,
+        };
+    }
+
+    const status = error.response?.status ?? null;
+    const retryable = isRetryableError(error);
+
+    if (isTimeoutError(error)) {
+        return {
+            code: ErrorCode.RequestTimeout,
+            errorCode: 'timeout',
+            status,
+            retryable: true,
+            message: timeoutErrorMessage(error),
+        };
+    }
+
+    if (status === null) {
+        // No HTTP response: either a dropped connection or a local failure
+        return {
+            code: ErrorCode.InternalError,
+            errorCode: error.code ? 'network_error' : 'internal_error',
+            status,
+            retryable,
+            message: error.message,
+        };
+    }
+
+    let message = error.message;
+    if (status === 404) {
+        message = `Resource not found: ${error.message}`;
+    } else if (status === 422) {
+        message = `Validation error: ${error.message}`;
+    } else if (status === 401 || status === 403) {
+        message = `Authentication/Authorization error: ${error.message}`;
+    }
+
+    let code = ErrorCode.InternalError;
+    if (status === 422) {
+        code = ErrorCode.InvalidParams;
+    } else if (status >= 400 && status < 500) {
+        code = ErrorCode.InvalidRequest;
+    }
+
+    const fallback = status >= 500 ? 'server_error' : status >= 400 ? 'client_error' : 'http_error';
+    return {
+        code,
+        errorCode: STATUS_ERROR_CODES[status] || fallback,
+        status,
+        retryable,
+        message,
+    };
+}
+
+/**
+ * Create an error carrying an HTTP status, for local checks that should be reported the
+ * same way as the matching Letta API failure (e.g. a 409 conflict)
+ * @param {number} status - HTTP status code
+ * @param {string} message - Error message
+ * @returns {Error}
+ */
+export function httpError(status, message) {
+    return Object.assign(new Error(message), { response: { status } });
+}
+
+/**
+ * Create an error for a tool argument that failed local validation, reported as
+ * InvalidParams with error_code `invalid_request` and the path of the offending field
+ * @param {string} field - Argument path, e.g. "llm_config.context_window"
+ * @param {string} message - Error message
+ * @returns {Error}
+ */
+export function invalidRequest(field, message) {
+    return Object.assign(new Error(message), { errorCode: 'invalid_request', field });
+}
@@ -0,0 +1,72 @@
+import fs from 'fs';
+import path from 'path';
+import { invalidRequest } from './errors.js';
+
+/**
+ * Confinement of files written by the export tools (export_agent, bulk_export_agents and
+ * export_agent_messages).
+ *
+ * When LETTA_EXPORT_DIR is set, export paths are resolved inside it and any path that
+ * would land outside it (absolute paths elsewhere, `..` segments, symlinked directories)
+ * is refused. Unset keeps the old behaviour of writing relative to the working directory.
+ */
+
+/**
+ * Read the export directory from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {string|null} Absolute export directory, or null when unrestricted
+ */
+export function readExportDir(env = process.env) {
+    const value = env.LETTA_EXPORT_DIR?.trim();
+    return value ? path.resolve(value) : null;
+}
+
+const OUTSIDE_EXPORT_DIR = 'Invalid argument: output_path must be inside the export directory';
+
+const isInside = (root, target) => {
+    const relative = path.relative(root, target);
+    return (
+        relative !== '' &&
+        relative !== '..' &&
+        !relative.startsWith(`..${path.sep}`) &&
+        !path.isAbsolute(relative)
+    );
+};
+
+/**
+ * Real path of a directory, or of its nearest existing ancestor
+ */
+function realExistingPath(dir) {
+    while (!fs.existsSync(dir)) dir = path.dirname(dir);
+    return fs.realpathSync(dir);
+}
+
+/**
+ * Resolve a requested export path inside the export directory, creating its parent
+ * directories
+ * @param {string} root - Absolute export directory
+ * @param {string} requested - Path from the tool call, relative to root
+ * @returns {string} Absolute path to write
+ * @throws {Error} When the path would fall outside root
+ */
+export function resolveExportPath(root, requested) {
+    const target = path.resolve(root, requested);
+    if (!isInside(root, target)) {
+        throw invalidRequest('output_path', OUTSIDE_EXPORT_DIR);
+    }
+
+    // Compare real paths so a symlink inside the directory can't point out of it. The
+    // parent is checked before it is created, so nothing is made outside the root either.
+    fs.mkdirSync(root, { recursive: true });
+    const realRoot = fs.realpathSync(root);
+    const parent = path.dirname(target);
+    const realParent = realExistingPath(parent);
+    if (realParent !== realRoot && !isInside(realRoot, realParent)) {
+        throw invalidRequest('output_path', OUTSIDE_EXPORT_DIR);
+    }
+    if (fs.lstatSync(target, { throwIfNoEntry: false })?.isSymbolicLink()) {
+        throw invalidRequest('output_path', OUTSIDE_EXPORT_DIR);
+    }
+    fs.mkdirSync(parent, { recursive: true });
+    return target;
+}
@@ -0,0 +1,170 @@
+import { scopeHeaders } from './scope.js';
+import { httpError, invalidRequest } from './errors.js';
+
+/**
+ * Idempotency keys for create tools.
+ *
+ * A create call may pass `idempotency_key`. The first call with a key runs normally and its
+ * result is kept for LETTA_IDEMPOTENCY_TTL_SECS (default 600; 0 disables); a repeat with the
+ * same key gets that result back instead of creating a second resource. A repeat that
+ * arrives while the first call is still running waits for it. Failed calls are not
+ * remembered, so they can be retried with the same key. Reusing a key with different
+ * arguments is a conflict; key order and the response format arguments don't count.
+ */
+
+export const DEFAULT_IDEMPOTENCY_TTL_SECS = 600;
+
+/**
+ * Tools that accept an idempotency_key
+ */
+export const IDEMPOTENT_TOOLS = new Set([
+    'create_agent',
+    'clone_agent',
+    'import_agent',
+    'upload_tool',
+    'create_folder',
+]);
+
+// Arguments that only change how the result is rendered
+const FORMAT_ARGUMENTS = new Set(['idempotency_key', 'pretty_json', 'format']);
+
+/**
+ * Serialize a value with object keys sorted, so equal arguments always match
+ */
+function canonicalJson(value) {
+    if (Array.isArray(value)) return `[${value.map(canonicalJson).join(',')}]`;
+    if (value && typeof value === 'object') {
+        const entries = Object.keys(value)
+            .filter((key) => value[key] !== undefined)
+            .sort()
+            .map((key) => `${JSON.stringify(key)}:${canonicalJson(value[key])}`);
+        return `{${entries.join(',')}}`;
+    }
+    return JSON.stringify(value) ?? 'null';
+}
+
+/**
+ * Fingerprint of the arguments an idempotency key is used with
+ * @param {Object} args - Tool arguments
+ * @returns {string}
+ */
+export function argumentsFingerprint(args = {}) {
+    return canonicalJson(
+        Object.fromEntries(Object.entries(args).filter(([key]) => !FORMAT_ARGUMENTS.has(key))),
+    );
+}
+
+/**
+ * Read the idempotency key lifetime from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {number} TTL in seconds; 0 disables idempotency keys
+ */
+export function readIdempotencyTtlSecs(env = process.env) {
+    const value = env.LETTA_IDEMPOTENCY_TTL_SECS;
+    if (value === undefined || value === '') return DEFAULT_IDEMPOTENCY_TTL_SECS;
+    const secs = Number(value);
+    if (!Number.isFinite(secs) || secs < 0) {
+        throw new Error(
+            'Invalid environment variable LETTA_IDEMPOTENCY_TTL_SECS: expected seconds >= 0',
+        );
+    }
+    return secs;
+}
+
+/**
+ * Results of create calls by idempotency key, kept for a fixed TTL
+ */
+export class IdempotencyStore {
+    /**
+     * @param {number} ttlSecs - How long a key is remembered; 0 disables the store
+     * @param {Function} [now] - Clock in milliseconds, for tests
+     */
+    constructor(ttlSecs, now = Date.now) {
+        this.ttlMs = ttlSecs * 1000;
+        this.now = now;
+        this.entries = new Map();
+    }
+
+    get enabled() {
+        return this.ttlMs > 0;
+    }
+
+    /**
+     * Run a call once per key; repeats share the first call's result
+     * @param {string} key - Idempotency key, namespaced by the caller
+     * @param {string} fingerprint - Serialized arguments the key was first used with
+     * @param {Function} call - Runs the tool
+     * @returns {Promise<Object>}
+     */
+    async run(key, fingerprint, call) {
+        this.sweep();
+        const existing = this.entries.get(key);
+        if (existing) {
+            if (existing.fingerprint !== fingerprint) {
+                throw httpError(409, 'idempotency_key was already used with different arguments');
+            }
+            return existing.result;
+        }
+
+        const result = call();
+        const entry = { fingerprint, result, expiresAt: Infinity };
+        this.entries.set(key, entry);
+        try {
+            await result;
+        } catch (error) {
+            this.entries.delete(key);
+            throw error;
+        }
+        // The TTL starts once the resource exists
+        entry.expiresAt = this.now() + this.ttlMs;
+        return result;
+    }
+
+    sweep() {
+        const now = this.now();
+        for (const [key, entry] of this.entries) {
+            if (entry.expiresAt <= now) this.entries.delete(key);
+        }
+    }
+}
+
+/**
+ * Run a tool call through the idempotency store when it carries an idempotency_key
+ * @param {Object} server - Server instance with an idempotency store
+ * @param {string} name - Tool name
+ * @param {Object} args - Tool arguments
+ * @param {Function} call - Runs the tool and returns its result
+ * @returns {Promise<Object>} The tool result, possibly from an earlier call with the same key
+ */
+export async function withIdempotency(server, name, args = {}, call) {
+    const store = server.idempotency;
+    const idempotencyKey = args?.idempotency_key;
+    if (!store?.enabled || !IDEMPOTENT_TOOLS.has(name) || idempotencyKey === undefined) {
+        return call();
+    }
+    if (typeof idempotencyKey !== 'string' || !idempotencyKey) {
+        server.createErrorResponse(
+            invalidRequest(
+                'idempotency_key',
+                'Invalid argument: idempotency_key must be a non-empty string',
+            ),
+        );
+    }
+
+    const key = JSON.stringify([name, idempotencyKey, scopeHeaders(server.scope)]);
+    try {
+        return await store.run(key, argumentsFingerprint(args), call);
+    } catch (error) {
+        // Errors from the tool are already reported; this classifies a reused key's conflict
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Schema for the idempotency_key argument of create tools
+ */
+export const idempotencyKeyProperty = {
+    type: 'string',
+    description:
+        'Optional client-chosen key that makes the call safe to retry: a repeat with the same key (within LETTA_IDEMPOTENCY_TTL_SECS) returns the original result instead of creating a duplicate',
+};
@@ -0,0 +1,41 @@
+import { invalidRequest } from './errors.js';
+
+/**
+ * Not-found tolerance for the delete tools.
+ *
+ * With `ignore_missing: true` a delete whose target is already gone (the API answers 404)
+ * succeeds with `already_absent: true`, so teardown scripts can be re-run safely.
+ */
+
+/**
+ * Validate the ignore_missing argument
+ * @param {Object} server - LettaServer instance
+ * @param {Object} args - Tool arguments
+ */
+export function validateIgnoreMissing(server, args) {
+    if (args?.ignore_missing !== undefined && typeof args.ignore_missing !== 'boolean') {
+        server.createErrorResponse(
+            invalidRequest('ignore_missing', 'Invalid argument: ignore_missing must be a boolean'),
+        );
+    }
+}
+
+/**
+ * Whether a failed delete should be reported as success
+ * @param {Object} args - Tool arguments
+ * @param {Error} error - Error from the Letta API call
+ * @returns {boolean}
+ */
+export function isAlreadyAbsent(args, error) {
+    return args?.ignore_missing === true && error?.response?.status === 404;
+}
+
+/**
+ * Schema for the ignore_missing argument of delete tools
+ */
+export const ignoreMissingProperty = {
+    type: 'boolean',
+    description:
+        'Treat a resource that no longer exists as successfully deleted, returning already_absent: true instead of a not-found error (default: false)',
+    default: false,
+};
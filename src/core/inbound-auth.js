@@ -0,0 +1,58 @@
+import { createHash, timingSafeEqual } from 'node:crypto';
+
+/**
+ * Optional bearer-token authentication for clients of the network transports.
+ *
+ * When LETTA_MCP_AUTH_TOKEN is set, MCP requests must carry
+ * `Authorization: Bearer <token>` or are rejected with 401. This is separate from
+ * LETTA_PASSWORD, which authenticates this server to Letta.
+ */
+
+/**
+ * Read the inbound auth token from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {string|undefined} Required token, or undefined when auth is off
+ */
+export function readAuthToken(env = process.env) {
+    return env.LETTA_MCP_AUTH_TOKEN || undefined;
+}
+
+// Hash both sides so the comparison is constant-time regardless of length
+const digest = (value) => createHash('sha256').update(value).digest();
+
+/**
+ * Whether an Authorization header carries the expected bearer token
+ * @param {string|undefined} header - Authorization header value
+ * @param {string} token - Expected token
+ * @returns {boolean}
+ */
+export function isAuthorized(header, token) {
+    const match = /^Bearer\s+(.+)$/i.exec(header || '');
+    if (!match) return false;
+    return timingSafeEqual(digest(match[1].trim()), digest(token));
+}
+
+/**
+ * Express middleware requiring the bearer token on every request it guards
+ * @param {string|undefined} token - Required token; the middleware is a no-op without one
+ * @param {Object} [logger] - Logger for rejected requests
+ * @returns {Function}
+ */
+export function bearerAuthMiddleware(token, logger) {
+    if (!token) return (req, res, next) => next();
+
+    return (req, res, next) => {
+        if (isAuthorized(req.headers.authorization, token)) return next();
+
+        logger?.warn(`Rejected unauthenticated request from ${req.ip}`);
+        res.set('WWW-Authenticate', 'Bearer');
+        return res.status(401).json({
+            jsonrpc: '2.0',
+            error: {
+                code: -32001,
+                message: 'Unauthorized: missing or invalid bearer token',
+            },
+            id: null,
+        });
+    };
+}
@@ -0,0 +1,104 @@
+import { toYaml } from './yaml.js';
+import { invalidRequest } from './errors.js';
+
+/**
+ * JSON layout of tool responses.
+ *
+ * Tools return compact JSON, which keeps responses small for LLM clients. Setting
+ * LETTA_JSON_COMPACT=false pretty-prints every response instead, for reading by people;
+ * a call can also choose with its `pretty_json` argument, or pass `format: "yaml"` to get
+ * the same response as YAML.
+ */
+
+export const RESPONSE_FORMATS = ['json', 'yaml'];
+
+/**
+ * Read the default layout from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {boolean} True for compact JSON, false for pretty-printed
+ */
+export function readJsonCompact(env = process.env) {
+    return env.LETTA_JSON_COMPACT !== 'false';
+}
+
+/**
+ * Rewrite the text items of a tool result that hold JSON
+ */
+function mapJsonText(result, format) {
+    if (!Array.isArray(result?.content)) return result;
+    return {
+        ...result,
+        content: result.content.map((item) => {
+            if (item?.type !== 'text' || typeof item.text !== 'string') return item;
+            let value;
+            try {
+                value = JSON.parse(item.text);
+            } catch {
+                return item;
+            }
+            return { ...item, text: format(value) };
+        }),
+    };
+}
+
+/**
+ * Re-indent the JSON text items of a tool result
+ * @param {Object} result - Tool result with a content array
+ * @returns {Object} The result with JSON text pretty-printed; other text is left as is
+ */
+export function prettyPrintResult(result) {
+    return mapJsonText(result, (value) => JSON.stringify(value, null, 2));
+}
+
+/**
+ * Convert the JSON text items of a tool result to YAML
+ * @param {Object} result - Tool result with a content array
+ * @returns {Object} The result with JSON text as YAML; other text is left as is
+ */
+export function yamlResult(result) {
+    return mapJsonText(result, (value) => toYaml(value));
+}
+
+/**
+ * Run a tool call and lay out its JSON response as configured
+ * @param {Object} server - LettaServer instance
+ * @param {Object} args - Tool arguments, optionally containing pretty_json and format
+ * @param {Function} call - Runs the tool and returns its result
+ * @returns {Promise<Object>} The tool result
+ */
+export async function withJsonFormat(server, args, call) {
+    const prettyJson = args?.pretty_json;
+    if (prettyJson !== undefined && typeof prettyJson !== 'boolean') {
+        server.createErrorResponse(
+            invalidRequest('pretty_json', 'Invalid argument: pretty_json must be a boolean'),
+        );
+    }
+    const format = args?.format ?? 'json';
+    if (!RESPONSE_FORMATS.includes(format)) {
+        server.createErrorResponse(
+            invalidRequest('format', 'Invalid argument: format must be "json" or "yaml"'),
+        );
+    }
+    const pretty = prettyJson ?? server.jsonCompact === false;
+    const result = await call();
+    if (format === 'yaml') return yamlResult(result);
+    return pretty ? prettyPrintResult(result) : result;
+}
+
+/**
+ * Schema for the per-call pretty_json tool argument
+ */
+export const prettyJsonProperty = {
+    type: 'boolean',
+    description:
+        'Pretty-print the JSON response for reading (default: compact unless LETTA_JSON_COMPACT=false)',
+};
+
+/**
+ * Schema for the per-call format tool argument
+ */
+export const formatProperty = {
+    type: 'string',
+    enum: RESPONSE_FORMATS,
+    description: 'Response format: "json" (default) or "yaml" for easier reading',
+};
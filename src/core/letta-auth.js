@@ -0,0 +1,52 @@
+/**
+ * How this server authenticates to Letta.
+ *
+ * LETTA_AUTH_MODE picks the scheme:
+ * - bearer (default): LETTA_PASSWORD sent as `Authorization: Bearer` and X-BARE-PASSWORD,
+ *   for self-hosted servers with a password and for Letta Cloud; with LETTA_PASSWORD unset
+ *   or empty this falls back to none, for local development
+ * - api_key: LETTA_API_KEY (or LETTA_PASSWORD) sent as-is in the LETTA_API_KEY_HEADER
+ *   header (default X-API-Key), for gateways that check a key header
+ * - none: no credentials, for a local server without auth
+ */
+
+export const AUTH_MODES = ['bearer', 'api_key', 'none'];
+export const DEFAULT_API_KEY_HEADER = 'X-API-Key';
+
+/**
+ * Read the Letta auth settings from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {{mode: string, secret: string, header?: string}}
+ */
+export function readLettaAuth(env = process.env) {
+    const mode = env.LETTA_AUTH_MODE?.trim().toLowerCase() || 'bearer';
+    if (!AUTH_MODES.includes(mode)) {
+        throw new Error(
+            `Invalid environment variable LETTA_AUTH_MODE: expected one of ${AUTH_MODES.join(', ')}`,
+        );
+    }
+    if (mode === 'none' || (mode === 'bearer' && !env.LETTA_PASSWORD)) {
+        return { mode: 'none', secret: '' };
+    }
+    if (mode === 'bearer') return { mode, secret: env.LETTA_PASSWORD };
+
+    const secret = env.LETTA_API_KEY || env.LETTA_PASSWORD;
+    if (!secret) {
+        throw new Error('LETTA_AUTH_MODE=api_key requires LETTA_API_KEY (or LETTA_PASSWORD)');
+    }
+    return { mode, secret, header: env.LETTA_API_KEY_HEADER?.trim() || DEFAULT_API_KEY_HEADER };
+}
+
+/**
+ * Build the credential headers for a Letta request
+ * @param {Object} auth - Settings from readLettaAuth
+ * @returns {Object} Headers to send
+ */
+export function lettaAuthHeaders(auth) {
+    if (auth.mode === 'none') return {};
+    if (auth.mode === 'api_key') return { [auth.header]: auth.secret };
+    return {
+        'X-BARE-PASSWORD': `password ${auth.secret}`,
+        Authorization: `Bearer ${auth.secret}`,
+    };
+}
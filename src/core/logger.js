@@ -1,7 +1,11 @@
 import winston from 'winston';
+import { spanFields } from './tracing.js';
 
 const { combine, timestamp, printf, colorize, errors } = winston.format;
 
+// Tag entries written during a tool call with its request_id, operation and agent_id
+const spanContext = winston.format((info) => Object.assign(info, spanFields()));
+
 // Custom format for console output
 const consoleFormat = printf(({ level, message, timestamp, stack, ...metadata }) => {
     let log = `${timestamp} [${level}]`;
@@ -34,7 +38,11 @@ const consoleFormat = printf(({ level, message, timestamp, stack, ...metadata })
 // Create logger instance
 const logger = winston.createLogger({
     level: process.env.LOG_LEVEL || 'info',
-    format: combine(errors({ stack: true }), timestamp({ format: 'YYYY-MM-DD HH:mm:ss' })),
+    format: combine(
+        errors({ stack: true }),
+        spanContext(),
+        timestamp({ format: 'YYYY-MM-DD HH:mm:ss' }),
+    ),
     transports: [
         // Console transport - output to stderr to avoid interfering with MCP protocol (stdout)
         new winston.transports.Console({
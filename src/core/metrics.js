@@ -0,0 +1,113 @@
+/**
+ * Per-tool call metrics in Prometheus text format.
+ *
+ * Every tool call is counted by tool and outcome (success or error) and its duration recorded
+ * in a histogram. The HTTP transport serves the result at /metrics. Calls to tools that aren't
+ * registered are counted under `unknown`, so clients can't add series with made-up names.
+ */
+
+export const UNKNOWN_TOOL = 'unknown';
+
+export const DURATION_BUCKETS_SECS = [0.05, 0.1, 0.25, 0.5, 1, 2.5, 5, 10, 30, 60];
+
+const escapeLabel = (value) =>
+    String(value).replace(/\\/g, '\\\\').replace(/\n/g, '\\n').replace(/"/g, '\\"');
+
+/**
+ * In-memory counters and duration histograms keyed by tool name
+ */
+export class MetricsRegistry {
+    constructor() {
+        this.calls = new Map();
+        this.durations = new Map();
+    }
+
+    /**
+     * Record one finished tool call
+     * @param {string} tool - Tool name
+     * @param {string} outcome - 'success' or 'error'
+     * @param {number} durationSecs - Wall-clock duration of the call
+     */
+    recordToolCall(tool, outcome, durationSecs) {
+        const callKey = `${tool}\u0000${outcome}`;
+        this.calls.set(callKey, (this.calls.get(callKey) || 0) + 1);
+
+        let histogram = this.durations.get(tool);
+        if (!histogram) {
+            histogram = { buckets: DURATION_BUCKETS_SECS.map(() => 0), sum: 0, count: 0 };
+            this.durations.set(tool, histogram);
+        }
+        DURATION_BUCKETS_SECS.forEach((bound, i) => {
+            if (durationSecs <= bound) histogram.buckets[i] += 1;
+        });
+        histogram.sum += durationSecs;
+        histogram.count += 1;
+    }
+
+    /**
+     * Render all metrics in the Prometheus text exposition format
+     * @returns {string}
+     */
+    render() {
+        const lines = [
+            '# HELP letta_mcp_tool_calls_total Tool calls handled, by tool and outcome.',
+            '# TYPE letta_mcp_tool_calls_total counter',
+        ];
+        for (const [key, count] of this.calls) {
+            const [tool, outcome] = key.split('\u0000');
+            lines.push(
+                `letta_mcp_tool_calls_total{tool="${escapeLabel(tool)}",outcome="${outcome}"} ${count}`,
+            );
+        }
+
+        lines.push(
+            '# HELP letta_mcp_tool_call_duration_seconds Tool call duration in seconds.',
+            '# TYPE letta_mcp_tool_call_duration_seconds histogram',
+        );
+        for (const [tool, histogram] of this.durations) {
+            const label = `tool="${escapeLabel(tool)}"`;
+            DURATION_BUCKETS_SECS.forEach((bound, i) => {
+                lines.push(
+                    `letta_mcp_tool_call_duration_seconds_bucket{${label},le="${bound}"} ${histogram.buckets[i]}`,
+                );
+            });
+            lines.push(
+                `letta_mcp_tool_call_duration_seconds_bucket{${label},le="+Inf"} ${histogram.count}`,
+                `letta_mcp_tool_call_duration_seconds_sum{${label}} ${histogram.sum}`,
+                `letta_mcp_tool_call_duration_seconds_count{${label}} ${histogram.count}`,
+            );
+        }
+
+        return `${lines.join('\n')}\n`;
+    }
+}
+
+/**
+ * Name to record a tool call under
+ * @param {string} name - Tool name from the request
+ * @param {Set<string>|Map<string, *>} knownTools - Registered tool names
+ * @returns {string} The name, or `unknown` if no such tool is registered
+ */
+export function metricsToolName(name, knownTools) {
+    return knownTools.has(name) ? name : UNKNOWN_TOOL;
+}
+
+/**
+ * Run a tool call and record its outcome and duration
+ * @param {Object} server - Server instance with a metrics registry
+ * @param {string} name - Tool name
+ * @param {Function} call - Runs the tool and returns its result
+ * @returns {Promise<Object>} The tool result
+ */
+export async function withMetrics(server, name, call) {
+    const started = process.hrtime.bigint();
+    const elapsedSecs = () => Number(process.hrtime.bigint() - started) / 1e9;
+    try {
+        const result = await call();
+        server.metrics?.recordToolCall(name, 'success', elapsedSecs());
+        return result;
+    } catch (error) {
+        server.metrics?.recordToolCall(name, 'error', elapsedSecs());
+        throw error;
+    }
+}
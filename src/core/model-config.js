@@ -0,0 +1,134 @@
+import fs from 'fs';
+import { invalidRequest } from './errors.js';
+
+/**
+ * Validation of llm_config and embedding_config tool arguments, so a malformed config is
+ * rejected with the offending field named instead of a generic error from the Letta server.
+ *
+ * Endpoint types are checked as strings rather than against a fixed list, since Letta adds
+ * providers between releases.
+ *
+ * LETTA_DEFAULT_LLM_CONFIG and LETTA_DEFAULT_EMBEDDING_CONFIG (JSON, or the path of a JSON
+ * file) give create_agent configs to use when a call doesn't choose a model itself.
+ */
+
+const LLM_CONFIG_FIELDS = {
+    model: { type: 'string', required: true },
+    model_endpoint_type: { type: 'string', required: true },
+    context_window: { type: 'integer', required: true, min: 1 },
+    model_endpoint: { type: 'string' },
+    model_wrapper: { type: 'string' },
+    handle: { type: 'string' },
+    temperature: { type: 'number', min: 0, max: 2 },
+    max_tokens: { type: 'integer', min: 1 },
+    put_inner_thoughts_in_kwargs: { type: 'boolean' },
+    enable_reasoner: { type: 'boolean' },
+    max_reasoning_tokens: { type: 'integer', min: 0 },
+};
+
+const EMBEDDING_CONFIG_FIELDS = {
+    embedding_endpoint_type: { type: 'string', required: true },
+    embedding_model: { type: 'string', required: true },
+    embedding_dim: { type: 'integer', required: true, min: 1 },
+    embedding_endpoint: { type: 'string' },
+    embedding_chunk_size: { type: 'integer', min: 1 },
+    handle: { type: 'string' },
+    azure_endpoint: { type: 'string' },
+    azure_version: { type: 'string' },
+    azure_deployment: { type: 'string' },
+};
+
+const TYPE_CHECKS = {
+    string: (value) => typeof value === 'string' && value.trim() !== '',
+    integer: Number.isInteger,
+    number: (value) => typeof value === 'number' && Number.isFinite(value),
+    boolean: (value) => typeof value === 'boolean',
+};
+
+const TYPE_NAMES = {
+    string: 'a non-empty string',
+    integer: 'an integer',
+    number: 'a number',
+    boolean: 'a boolean',
+};
+
+/**
+ * Check a config object against its field rules; unknown fields are passed through
+ */
+function validateConfig(name, config, fields) {
+    if (config === null || typeof config !== 'object' || Array.isArray(config)) {
+        throw invalidRequest(name, `Invalid argument: ${name} must be an object`);
+    }
+    for (const [field, rule] of Object.entries(fields)) {
+        const path = `${name}.${field}`;
+        const value = config[field];
+        if (value === undefined || value === null) {
+            if (rule.required) {
+                throw invalidRequest(path, `Missing required argument: ${path}`);
+            }
+            continue;
+        }
+        if (!TYPE_CHECKS[rule.type](value)) {
+            const expected = TYPE_NAMES[rule.type];
+            throw invalidRequest(path, `Invalid argument: ${path} must be ${expected}`);
+        }
+        const tooLow = rule.min !== undefined && value < rule.min;
+        const tooHigh = rule.max !== undefined && value > rule.max;
+        if (tooLow || tooHigh) {
+            const range =
+                rule.max === undefined ? `at least ${rule.min}` : `from ${rule.min} to ${rule.max}`;
+            throw invalidRequest(path, `Invalid argument: ${path} must be ${range}`);
+        }
+    }
+}
+
+/**
+ * Validate an llm_config argument
+ * @param {*} config - The llm_config argument
+ * @throws {Error} invalid_request error naming the first bad field
+ */
+export function validateLlmConfig(config) {
+    validateConfig('llm_config', config, LLM_CONFIG_FIELDS);
+}
+
+/**
+ * Validate an embedding_config argument
+ * @param {*} config - The embedding_config argument
+ * @throws {Error} invalid_request error naming the first bad field
+ */
+export function validateEmbeddingConfig(config) {
+    validateConfig('embedding_config', config, EMBEDDING_CONFIG_FIELDS);
+}
+
+/**
+ * Read a default config from an environment variable holding JSON, or the path of a JSON
+ * file
+ */
+function readDefaultConfig(env, variable, validate) {
+    const raw = env[variable]?.trim();
+    if (!raw) return null;
+    try {
+        const text = raw.startsWith('{') ? raw : fs.readFileSync(raw, 'utf8');
+        const config = JSON.parse(text);
+        validate(config);
+        return config;
+    } catch (error) {
+        throw new Error(`Invalid environment variable ${variable}: ${error.message}`);
+    }
+}
+
+/**
+ * Read the default llm_config and embedding_config for create_agent
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {Object} llmConfig and embeddingConfig, each null when unset
+ */
+export function readDefaultModelConfigs(env = process.env) {
+    return {
+        llmConfig: readDefaultConfig(env, 'LETTA_DEFAULT_LLM_CONFIG', validateLlmConfig),
+        embeddingConfig: readDefaultConfig(
+            env,
+            'LETTA_DEFAULT_EMBEDDING_CONFIG',
+            validateEmbeddingConfig,
+        ),
+    };
+}
@@ -0,0 +1,62 @@
+/**
+ * Origin allowlist for the HTTP transport.
+ *
+ * LETTA_ALLOWED_ORIGINS is a comma-separated list of origins (e.g.
+ * "https://app.example.com,http://localhost") allowed to call the server from a browser.
+ * An entry without a port also matches that host on any port. Unset uses the built-in
+ * defaults; "*" allows any origin and should only be used in development.
+ */
+
+export const DEFAULT_ALLOWED_ORIGINS = [
+    'http://localhost',
+    'http://127.0.0.1',
+    'http://192.168.50.90',
+    'https://letta.oculair.ca',
+    'https://letta2.oculair.ca',
+];
+
+/**
+ * Read the origin allowlist from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {Array<string>|null} Allowed origins, or null when any origin is allowed
+ */
+export function readAllowedOrigins(env = process.env) {
+    const raw = env.LETTA_ALLOWED_ORIGINS;
+    if (raw === undefined || raw.trim() === '') return DEFAULT_ALLOWED_ORIGINS;
+    if (raw.trim() === '*') return null;
+
+    return raw
+        .split(',')
+        .map((entry) => entry.trim().replace(/\/+$/, ''))
+        .filter(Boolean)
+        .map((entry) => {
+            let origin;
+            try {
+                origin = new URL(entry).origin;
+            } catch {
+                origin = null;
+            }
+            if (origin !== entry) {
+                throw new Error(
+                    `Invalid environment variable LETTA_ALLOWED_ORIGINS: "${entry}" is not an origin (scheme://host[:port])`,
+                );
+            }
+            return entry;
+        });
+}
+
+/**
+ * Whether a request origin is on the allowlist
+ * @param {string} origin - Origin header value
+ * @param {Array<string>|null} allowedOrigins - Result of readAllowedOrigins
+ * @returns {boolean}
+ */
+export function isOriginAllowed(origin, allowedOrigins) {
+    if (allowedOrigins === null) return true;
+    return allowedOrigins.some((allowed) => {
+        if (origin === allowed) return true;
+        // Entries without a port accept any port on the same host
+        const port = origin.startsWith(`${allowed}:`) ? origin.slice(allowed.length + 1) : '';
+        return new URL(allowed).port === '' && /^\d+$/.test(port);
+    });
+}
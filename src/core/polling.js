@@ -0,0 +1,85 @@
+import { invalidRequest } from './errors.js';
+
+/**
+ * Polling for long-running Letta work (runs, jobs) with a bounded wait.
+ *
+ * Tools that wait accept `wait_secs` (how long to keep polling, 0 for a single check) and
+ * `poll_interval_secs`. When the wait elapses the latest state is returned rather than
+ * an error, so callers can decide whether to keep waiting.
+ */
+
+export const DEFAULT_WAIT_SECS = 30;
+export const MAX_WAIT_SECS = 600;
+export const DEFAULT_POLL_INTERVAL_SECS = 2;
+
+const sleep = (ms) => new Promise((resolve) => setTimeout(resolve, ms));
+
+/**
+ * Read and validate the wait options from tool arguments
+ * @param {Object} args - Tool arguments, optionally containing wait_secs and poll_interval_secs
+ * @returns {Object} waitSecs and intervalSecs
+ */
+export function readPollOptions(args) {
+    const waitSecs = args?.wait_secs ?? DEFAULT_WAIT_SECS;
+    const intervalSecs = args?.poll_interval_secs ?? DEFAULT_POLL_INTERVAL_SECS;
+    const valid = (value) =>
+        typeof value === 'number' && Number.isFinite(value) && value >= 0 && value <= MAX_WAIT_SECS;
+    if (!valid(waitSecs)) {
+        throw invalidRequest(
+            'wait_secs',
+            `Invalid argument: wait_secs must be a non-negative number of seconds (at most ${MAX_WAIT_SECS})`,
+        );
+    }
+    if (!valid(intervalSecs) || intervalSecs === 0) {
+        throw invalidRequest(
+            'poll_interval_secs',
+            `Invalid argument: poll_interval_secs must be a positive number of seconds (at most ${MAX_WAIT_SECS})`,
+        );
+    }
+    return { waitSecs, intervalSecs };
+}
+
+/**
+ * Fetch a value repeatedly until it is done or the wait elapses
+ * @param {Function} fetch - Async function returning the latest value
+ * @param {Function} isDone - Called with each value; true stops polling
+ * @param {Object} options - waitSecs and intervalSecs from readPollOptions
+ * @returns {Promise<Object>} The last value, whether it is done, poll count and elapsed seconds
+ */
+export async function pollUntil(fetch, isDone, { waitSecs, intervalSecs }) {
+    const startedAt = Date.now();
+    const deadline = startedAt + waitSecs * 1000;
+
+    let value = await fetch();
+    let polls = 1;
+    while (!isDone(value) && Date.now() < deadline) {
+        await sleep(Math.min(intervalSecs * 1000, deadline - Date.now()));
+        value = await fetch();
+        polls += 1;
+    }
+
+    return {
+        value,
+        done: isDone(value),
+        polls,
+        elapsedSecs: (Date.now() - startedAt) / 1000,
+    };
+}
+
+/**
+ * Schemas for the wait_secs and poll_interval_secs tool arguments
+ */
+export const pollProperties = {
+    wait_secs: {
+        type: 'number',
+        description: `Maximum time to wait, in seconds; 0 checks once (default: ${DEFAULT_WAIT_SECS}, max: ${MAX_WAIT_SECS})`,
+        minimum: 0,
+        maximum: MAX_WAIT_SECS,
+    },
+    poll_interval_secs: {
+        type: 'number',
+        description: `Time between status checks, in seconds (default: ${DEFAULT_POLL_INTERVAL_SECS})`,
+        exclusiveMinimum: 0,
+        maximum: MAX_WAIT_SECS,
+    },
+};
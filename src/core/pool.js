@@ -0,0 +1,62 @@
+import http from 'node:http';
+import https from 'node:https';
+
+/**
+ * Connection pooling for Letta API calls.
+ *
+ * Connections are kept alive and reused across requests. LETTA_POOL_MAX_IDLE caps the idle
+ * connections kept open per host (default 10; 0 disables keep-alive) and
+ * LETTA_POOL_IDLE_TIMEOUT_SECS closes connections left idle that long (default 90).
+ */
+
+export const DEFAULT_POOL_MAX_IDLE = 10;
+export const DEFAULT_POOL_IDLE_TIMEOUT_SECS = 90;
+
+/**
+ * Read connection pool settings from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {Object} maxIdle and idleTimeoutSecs
+ */
+export function readPoolOptions(env = process.env) {
+    return {
+        maxIdle: parseSetting(env, 'LETTA_POOL_MAX_IDLE', DEFAULT_POOL_MAX_IDLE, 0),
+        idleTimeoutSecs: parseSetting(
+            env,
+            'LETTA_POOL_IDLE_TIMEOUT_SECS',
+            DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+            1,
+        ),
+    };
+}
+
+function parseSetting(env, name, fallback, minimum) {
+    const value = env[name];
+    if (value === undefined || value === '') return fallback;
+    const parsed = Number(value);
+    if (!Number.isInteger(parsed) || parsed < minimum) {
+        throw new Error(`Invalid environment variable ${name}: expected an integer >= ${minimum}`);
+    }
+    return parsed;
+}
+
+/**
+ * Build the http/https agents for axios from pool settings
+ * @param {Object} options - maxIdle and idleTimeoutSecs from readPoolOptions
+ * @param {Object} [tlsOptions] - Client certificate and CA options for the https agent
+ * @returns {{httpAgent: http.Agent, httpsAgent: https.Agent}}
+ */
+export function createPoolAgents({ maxIdle, idleTimeoutSecs }, tlsOptions = {}) {
+    const agentOptions =
+        maxIdle > 0
+            ? {
+                  keepAlive: true,
+                  maxFreeSockets: maxIdle,
+                  // Idle pooled sockets are destroyed when this socket timeout fires
+                  timeout: idleTimeoutSecs * 1000,
+              }
+            : { keepAlive: false };
+    return {
+        httpAgent: new http.Agent(agentOptions),
+        httpsAgent: new https.Agent({ ...agentOptions, ...tlsOptions }),
+    };
+}
@@ -0,0 +1,110 @@
+/**
+ * Per-client rate limiting for the HTTP and SSE transports.
+ *
+ * Each client IP may make LETTA_RATE_LIMIT requests to the MCP endpoints (/mcp, or /sse and
+ * /message) per LETTA_RATE_WINDOW_SECS (default 300 per 60 seconds). Requests over the limit
+ * get a 429 with a Retry-After header. Requests are counted before bearer-token auth, so
+ * rejected ones count too. LETTA_RATE_LIMIT=0 disables limiting.
+ */
+
+export const DEFAULT_RATE_LIMIT = 300;
+export const DEFAULT_RATE_WINDOW_SECS = 60;
+
+/**
+ * Read the rate limit from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {Object} limit (requests per window; 0 = unlimited) and windowSecs
+ */
+export function readRateLimit(env = process.env) {
+    const limit = parseSetting(env, 'LETTA_RATE_LIMIT', DEFAULT_RATE_LIMIT, 0);
+    const windowSecs = parseSetting(env, 'LETTA_RATE_WINDOW_SECS', DEFAULT_RATE_WINDOW_SECS, 1);
+    return { limit, windowSecs };
+}
+
+function parseSetting(env, name, fallback, minimum) {
+    const value = env[name];
+    if (value === undefined || value === '') return fallback;
+    const parsed = Number(value);
+    if (!Number.isInteger(parsed) || parsed < minimum) {
+        throw new Error(`Invalid environment variable ${name}: expected an integer >= ${minimum}`);
+    }
+    return parsed;
+}
+
+/**
+ * Fixed-window request counter per client key
+ */
+export class RateLimiter {
+    /**
+     * @param {Object} options - limit and windowSecs from readRateLimit
+     * @param {Function} [now] - Clock in milliseconds, for tests
+     */
+    constructor({ limit, windowSecs }, now = Date.now) {
+        this.limit = limit;
+        this.windowMs = windowSecs * 1000;
+        this.now = now;
+        this.windows = new Map();
+        this.lastSweep = now();
+    }
+
+    /**
+     * Count a request from a client
+     * @param {string} key - Client identifier (usually the IP)
+     * @returns {{allowed: boolean, remaining: number, retryAfterSecs: number}}
+     */
+    hit(key) {
+        const now = this.now();
+        this.sweep(now);
+
+        let window = this.windows.get(key);
+        if (!window || window.resetAt <= now) {
+            window = { count: 0, resetAt: now + this.windowMs };
+            this.windows.set(key, window);
+        }
+        window.count += 1;
+
+        return {
+            allowed: window.count <= this.limit,
+            remaining: Math.max(0, this.limit - window.count),
+            retryAfterSecs: Math.ceil((window.resetAt - now) / 1000),
+        };
+    }
+
+    // Drop expired windows once per window so idle clients don't accumulate
+    sweep(now) {
+        if (now - this.lastSweep < this.windowMs) return;
+        for (const [key, window] of this.windows) {
+            if (window.resetAt <= now) this.windows.delete(key);
+        }
+        this.lastSweep = now;
+    }
+}
+
+/**
+ * Express middleware enforcing a per-IP rate limit
+ * @param {Object} options - limit and windowSecs from readRateLimit
+ * @param {Object} [logger] - Logger for rejected requests
+ * @returns {Function} Middleware; a no-op when the limit is 0
+ */
+export function rateLimitMiddleware(options, logger) {
+    if (!options.limit) return (req, res, next) => next();
+
+    const limiter = new RateLimiter(options);
+    return (req, res, next) => {
+        const { allowed, remaining, retryAfterSecs } = limiter.hit(req.ip);
+        res.set('RateLimit-Limit', String(options.limit));
+        res.set('RateLimit-Remaining', String(remaining));
+        if (allowed) return next();
+
+        logger?.warn(`Rate limit exceeded for ${req.ip}`);
+        res.set('Retry-After', String(retryAfterSecs));
+        return res.status(429).json({
+            jsonrpc: '2.0',
+            error: {
+                code: -32000,
+                message: `Too many requests: limit is ${options.limit} per ${options.windowSecs}s`,
+            },
+            id: null,
+        });
+    };
+}
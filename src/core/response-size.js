@@ -0,0 +1,57 @@
+/**
+ * Response size guarding for list tools.
+ *
+ * A large page serialized into one MCP text response can exceed a client's message size
+ * limit. LETTA_MAX_RESPONSE_BYTES caps the serialized size (default 1 MB, 0 disables);
+ * when a page is over the cap its items are cut back to fit and the response is marked
+ * `truncated: true` with a cursor for fetching the rest. The first item is always kept, even
+ * when it alone is over the cap, so paging can move past it.
+ */
+
+export const DEFAULT_MAX_RESPONSE_BYTES = 1_000_000;
+
+/**
+ * Read the response size cap from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {number} Maximum response size in bytes, or 0 for no limit
+ */
+export function readMaxResponseBytes(env = process.env) {
+    const value = env.LETTA_MAX_RESPONSE_BYTES;
+    if (value === undefined || value === '') return DEFAULT_MAX_RESPONSE_BYTES;
+    const parsed = Number(value);
+    if (!Number.isInteger(parsed) || parsed < 0) {
+        throw new Error(
+            'Invalid environment variable LETTA_MAX_RESPONSE_BYTES: expected a non-negative integer',
+        );
+    }
+    return parsed;
+}
+
+const byteLength = (payload) => Buffer.byteLength(JSON.stringify(payload), 'utf8');
+
+/**
+ * Build a response payload, dropping trailing items until it fits within the size cap
+ * @param {Array} items - Items for the page, in order
+ * @param {Function} build - Called with (items, truncated) to build the payload
+ * @param {number} [maxBytes] - Size cap in bytes (0 for no limit)
+ * @returns {Object} The payload, built with as many leading items as fit (at least one)
+ */
+export function fitToResponseSize(items, build, maxBytes = readMaxResponseBytes()) {
+    const full = build(items, false);
+    if (!maxBytes || byteLength(full) <= maxBytes) {
+        return full;
+    }
+
+    // Binary search for the longest prefix that fits, keeping the first item regardless
+    let low = Math.min(1, items.length);
+    let high = items.length - 1;
+    while (low < high) {
+        const mid = Math.ceil((low + high) / 2);
+        if (byteLength(build(items.slice(0, mid), true)) <= maxBytes) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    return build(items.slice(0, low), true);
+}
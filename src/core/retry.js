@@ -0,0 +1,134 @@
+/**
+ * Retry support for Letta API calls.
+ *
+ * Transient failures (dropped connections, timeouts, 502/503/504, 429) are retried with
+ * exponential backoff and jitter. Only idempotent HTTP methods are retried by default;
+ * requests that create, delete or send messages must opt in with `retry: true` in the
+ * axios request config.
+ */
+
+const RETRYABLE_STATUS_CODES = new Set([429, 502, 503, 504]);
+const RETRYABLE_ERROR_CODES = new Set([
+    'ECONNRESET',
+    'ECONNABORTED',
+    'ETIMEDOUT',
+    'EPIPE',
+    'EAI_AGAIN',
+    'ERR_SOCKET_CONNECTION_TIMEOUT',
+]);
+const IDEMPOTENT_METHODS = new Set(['get', 'head', 'options', 'put']);
+
+export const DEFAULT_RETRY_POLICY = {
+    maxAttempts: 3,
+    baseDelayMs: 200,
+    maxDelayMs: 5000,
+    retryNonIdempotent: false,
+};
+
+function readInteger(value, fallback, name) {
+    if (value === undefined || value === '') return fallback;
+    const parsed = Number(value);
+    if (!Number.isInteger(parsed) || parsed < 0) {
+        throw new Error(`Invalid environment variable ${name}: expected a non-negative integer`);
+    }
+    return parsed;
+}
+
+/**
+ * Read the retry policy from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {Object} Policy with maxAttempts, baseDelayMs, maxDelayMs and retryNonIdempotent
+ */
+export function readRetryPolicy(env = process.env) {
+    const maxAttempts = readInteger(
+        env.LETTA_RETRY_MAX_ATTEMPTS,
+        DEFAULT_RETRY_POLICY.maxAttempts,
+        'LETTA_RETRY_MAX_ATTEMPTS',
+    );
+    return {
+        // 0 and 1 both mean a single attempt
+        maxAttempts: Math.max(1, maxAttempts),
+        baseDelayMs: readInteger(
+            env.LETTA_RETRY_BASE_DELAY_MS,
+            DEFAULT_RETRY_POLICY.baseDelayMs,
+            'LETTA_RETRY_BASE_DELAY_MS',
+        ),
+        maxDelayMs: readInteger(
+            env.LETTA_RETRY_MAX_DELAY_MS,
+            DEFAULT_RETRY_POLICY.maxDelayMs,
+            'LETTA_RETRY_MAX_DELAY_MS',
+        ),
+        retryNonIdempotent: env.LETTA_RETRY_NON_IDEMPOTENT === 'true',
+    };
+}
+
+/**
+ * Whether an error is a transient failure worth retrying
+ * @param {Error} error - Axios error
+ * @returns {boolean}
+ */
+export function isRetryableError(error) {
+    if (error?.response) {
+        return RETRYABLE_STATUS_CODES.has(error.response.status);
+    }
+    return RETRYABLE_ERROR_CODES.has(error?.code);
+}
+
+/**
+ * Backoff delay before the given retry (1-based), with jitter in [50%, 100%] of the cap
+ * @param {number} retry - Retry number (1 for the first retry)
+ * @param {Object} policy - Retry policy
+ * @param {Function} [random] - Random source, for tests
+ * @returns {number} Delay in milliseconds
+ */
+export function computeBackoffDelay(retry, policy, random = Math.random) {
+    const exponential = Math.min(policy.maxDelayMs, policy.baseDelayMs * 2 ** (retry - 1));
+    return Math.round(exponential * (0.5 + random() / 2));
+}
+
+/**
+ * Whether a request may be retried under the policy
+ * @param {Object} config - Axios request config
+ * @param {Object} policy - Retry policy
+ * @returns {boolean}
+ */
+export function isRetryAllowed(config, policy) {
+    if (config.retry === false) return false;
+    if (config.retry === true) return true;
+    const method = (config.method || 'get').toLowerCase();
+    return IDEMPOTENT_METHODS.has(method) || policy.retryNonIdempotent;
+}
+
+const sleep = (ms) => new Promise((resolve) => setTimeout(resolve, ms));
+
+/**
+ * Install a response interceptor that retries transient failures on an axios instance
+ * @param {Object} instance - Axios instance
+ * @param {Object} policy - Retry policy
+ * @param {Object} [logger] - Logger for retry notices
+ * @param {Function} [delay] - Sleep implementation, for tests
+ */
+export function installRetryInterceptor(instance, policy, logger, delay = sleep) {
+    if (!instance?.interceptors?.response || policy.maxAttempts <= 1) {
+        return;
+    }
+
+    instance.interceptors.response.use(undefined, async (error) => {
+        const config = error?.config;
+        if (!config || !isRetryableError(error) || !isRetryAllowed(config, policy)) {
+            throw error;
+        }
+
+        config.retryAttempt = (config.retryAttempt || 0) + 1;
+        if (config.retryAttempt >= policy.maxAttempts) {
+            throw error;
+        }
+
+        const delayMs = computeBackoffDelay(config.retryAttempt, policy);
+        logger?.warn(
+            `Retrying ${config.method?.toUpperCase()} ${config.url} after ${error.response?.status || error.code} (retry ${config.retryAttempt}/${policy.maxAttempts - 1}, ${delayMs}ms)`,
+        );
+        await delay(delayMs);
+        return instance.request(config);
+    });
+}
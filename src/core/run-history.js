@@ -0,0 +1,77 @@
+/**
+ * History of run_tool_from_source executions.
+ *
+ * Letta keeps no record of sandbox runs, so the most recent LETTA_RUN_HISTORY_SIZE runs
+ * (default 100; 0 disables) are kept in memory, oldest dropped first, for list_tool_runs.
+ * Each entry has the source, arguments, result, duration and outcome; env_vars are kept
+ * by name only, since they usually carry secrets. Runs are recorded under the project and
+ * user scope of the call that made them, and only listed to calls in the same scope.
+ */
+
+export const DEFAULT_RUN_HISTORY_SIZE = 100;
+
+/**
+ * Read the run history size from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {number} Number of runs kept; 0 disables the history
+ */
+export function readRunHistorySize(env = process.env) {
+    const value = env.LETTA_RUN_HISTORY_SIZE;
+    if (value === undefined || value === '') return DEFAULT_RUN_HISTORY_SIZE;
+    const size = Number(value);
+    if (!Number.isInteger(size) || size < 0) {
+        throw new Error(
+            'Invalid environment variable LETTA_RUN_HISTORY_SIZE: expected an integer >= 0',
+        );
+    }
+    return size;
+}
+
+const scopeKey = (scope) => JSON.stringify(Object.entries(scope).sort());
+
+/**
+ * Bounded, newest-last record of tool runs
+ */
+export class RunHistory {
+    /**
+     * @param {number} size - Maximum number of runs kept; 0 disables the history
+     */
+    constructor(size) {
+        this.size = size;
+        this.runs = [];
+        this.nextId = 1;
+    }
+
+    get enabled() {
+        return this.size > 0;
+    }
+
+    /**
+     * Add a run, dropping the oldest once the history is full
+     * @param {Object} run - Run details
+     * @param {Object} [scope] - Scope headers of the call that made the run
+     * @returns {Object|null} The stored entry, or null when the history is disabled
+     */
+    record(run, scope = {}) {
+        if (!this.enabled) return null;
+        const entry = { id: this.nextId++, ...run };
+        this.runs.push({ scope: scopeKey(scope), entry });
+        if (this.runs.length > this.size) this.runs.splice(0, this.runs.length - this.size);
+        return entry;
+    }
+
+    /**
+     * Recent runs in a scope, newest first
+     * @param {Object} [filter] - Optional name and status to match
+     * @param {Object} [scope] - Scope headers of the listing call
+     * @returns {Object[]}
+     */
+    list({ name, status } = {}, scope = {}) {
+        const key = scopeKey(scope);
+        return this.runs
+            .filter((run) => run.scope === key)
+            .map((run) => run.entry)
+            .filter((run) => (!name || run.name === name) && (!status || run.status === status))
+            .reverse();
+    }
+}
@@ -0,0 +1,55 @@
+import { AsyncLocalStorage } from 'node:async_hooks';
+
+/**
+ * Project and user scoping for multi-tenant Letta deployments.
+ *
+ * LETTA_PROJECT_ID and LETTA_USER_ID set the default scope, sent on every API request as
+ * the X-Project and user_id headers. A tool call can override the project with its
+ * `project_id` argument; the override applies to every request made while handling it.
+ */
+
+const scopeStorage = new AsyncLocalStorage();
+
+/**
+ * Read the default scope from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {Object} Scope with projectId and userId (either may be undefined)
+ */
+export function readDefaultScope(env = process.env) {
+    return {
+        projectId: env.LETTA_PROJECT_ID || undefined,
+        userId: env.LETTA_USER_ID || undefined,
+    };
+}
+
+/**
+ * Run a function with a per-call scope override
+ * @param {Object} args - Tool arguments, optionally containing project_id
+ * @param {Function} fn - Function to run
+ * @returns {*} The function's return value
+ */
+export function runWithScope(args, fn) {
+    const override = args?.project_id ? { projectId: args.project_id } : {};
+    return scopeStorage.run(override, fn);
+}
+
+/**
+ * Build the scoping headers for a request, applying any active per-call override
+ * @param {Object} defaults - Default scope from readDefaultScope
+ * @returns {Object} X-Project and user_id headers that are set
+ */
+export function scopeHeaders(defaults = {}) {
+    const scope = { ...defaults, ...scopeStorage.getStore() };
+    const headers = {};
+    if (scope.projectId) headers['X-Project'] = scope.projectId;
+    if (scope.userId) headers['user_id'] = scope.userId;
+    return headers;
+}
+
+/**
+ * Schema for the per-call project_id tool argument
+ */
+export const projectIdProperty = {
+    type: 'string',
+    description: 'Letta project to scope this call to, overriding LETTA_PROJECT_ID (optional)',
+};
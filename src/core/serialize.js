@@ -0,0 +1,71 @@
+/**
+ * JSON serialization that says what failed.
+ *
+ * JSON.stringify fails on circular references, BigInt values and toJSON methods that
+ * throw, and its error doesn't name the object being serialized. serializeJson reports the
+ * operation and the path of the first offending value (e.g. "agent.llm_config.extra")
+ * as a `serialization_error` instead.
+ */
+
+const childPath = (path, key) =>
+    typeof key === 'number' ? `${path}[${key}]` : path ? `${path}.${key}` : key;
+
+/**
+ * Find the first value JSON.stringify can't serialize
+ * @param {*} value - Value to check
+ * @param {string} [path] - Path of value, for nested calls
+ * @param {Array} [ancestors] - Objects enclosing value, for detecting cycles
+ * @returns {{path: string, reason: string}|null} The offending path and why, or null
+ */
+export function findUnserializable(value, path = '', ancestors = []) {
+    if (typeof value === 'bigint') {
+        return { path, reason: 'BigInt values cannot be serialized' };
+    }
+    if (value === null || typeof value !== 'object') return null;
+    if (ancestors.includes(value)) {
+        return { path, reason: 'circular reference' };
+    }
+    if (typeof value.toJSON === 'function') {
+        try {
+            const converted = value.toJSON();
+            return converted === value
+                ? null
+                : findUnserializable(converted, path, [...ancestors, value]);
+        } catch (error) {
+            return { path, reason: `toJSON() failed: ${error.message}` };
+        }
+    }
+
+    const nested = [...ancestors, value];
+    const entries = Array.isArray(value)
+        ? value.map((item, index) => [index, item])
+        : Object.entries(value);
+    for (const [key, child] of entries) {
+        const problem = findUnserializable(child, childPath(path, key), nested);
+        if (problem) return problem;
+    }
+    return null;
+}
+
+/**
+ * Serialize a value to JSON, reporting which field failed if it can't be
+ * @param {*} value - Value to serialize
+ * @param {string} operation - What is being serialized, e.g. "retrieve_agent response"
+ * @param {number} [space] - Indentation, as for JSON.stringify
+ * @returns {string} JSON text
+ * @throws {Error} With errorCode `serialization_error` and the offending `field`
+ */
+export function serializeJson(value, operation, space) {
+    try {
+        return JSON.stringify(value, null, space);
+    } catch (error) {
+        const problem = findUnserializable(value);
+        const where = problem?.path ? ` at ${problem.path}` : '';
+        throw Object.assign(
+            new Error(
+                `Could not serialize ${operation}: ${problem?.reason ?? error.message}${where}`,
+            ),
+            { errorCode: 'serialization_error', field: problem?.path || undefined },
+        );
+    }
+}
@@ -1,8 +1,28 @@
 #!/usr/bin/env node
 import { Server } from '@modelcontextprotocol/sdk/server/index.js';
-import { McpError, ErrorCode } from '@modelcontextprotocol/sdk/types.js';
+import { McpError } from '@modelcontextprotocol/sdk/types.js';
 import axios from 'axios';
 import { createLogger } from './logger.js';
+import { readRetryPolicy, installRetryInterceptor } from './retry.js';
+import { readMaxConcurrency } from './concurrency.js';
+import { readTimeoutSecs } from './timeout.js';
+import { classifyError } from './errors.js';
+import { readDefaultScope, scopeHeaders } from './scope.js';
+import { ResponseCache, readCacheTtlSecs } from './cache.js';
+import { IdempotencyStore, readIdempotencyTtlSecs } from './idempotency.js';
+import { readExportDir } from './export-dir.js';
+import { MetricsRegistry } from './metrics.js';
+import { installTracingInterceptor } from './tracing.js';
+import { InFlightTracker, readShutdownOptions } from './shutdown.js';
+import { createPoolAgents, readPoolOptions } from './pool.js';
+import { readTlsOptions } from './tls.js';
+import { readStartupCheck } from './startup-check.js';
+import { RunHistory, readRunHistorySize } from './run-history.js';
+import { readJsonCompact } from './json-format.js';
+import { readDefaultModelConfigs } from './model-config.js';
+import { readUnknownArgumentPolicy } from './argument-validation.js';
+import { readApiBase } from './base-url.js';
+import { lettaAuthHeaders, readLettaAuth } from './letta-auth.js';
 
 /**
  * Core LettaServer class that handles initialization and API communication
@@ -43,22 +63,94 @@ export class LettaServer {
         // Flag to track if handlers have been registered
         this.handlersRegistered = false;
 
-        // Validate environment variables
-        this.apiBase = process.env.LETTA_BASE_URL ?? '';
-        this.password = process.env.LETTA_PASSWORD ?? '';
+        // Validate environment variables; the API base may sit under a proxy subpath
+        this.apiBase = readApiBase(process.env);
         if (!this.apiBase) {
             throw new Error('Missing required environment variable: LETTA_BASE_URL');
         }
 
+        // Bearer password, API key header or no credentials (LETTA_AUTH_MODE)
+        this.auth = readLettaAuth(process.env);
+        if (this.auth.mode === 'none') {
+            this.logger.info('Letta authentication disabled: no credentials will be sent');
+        }
+
+        // Default request timeout; unset means requests never time out
+        this.timeoutSecs = readTimeoutSecs(process.env);
+
+        // Keep-alive connection pool (LETTA_POOL_MAX_IDLE, LETTA_POOL_IDLE_TIMEOUT_SECS)
+        this.poolOptions = readPoolOptions(process.env);
+
+        // Client certificate and extra CAs for mTLS gateways (LETTA_TLS_* variables)
+        this.tlsOptions = readTlsOptions(process.env);
+
         // Initialize axios instance
-        this.apiBase = `${this.apiBase}/v1`;
         this.api = axios.create({
+            ...createPoolAgents(this.poolOptions, this.tlsOptions),
             baseURL: this.apiBase,
             headers: {
                 'Content-Type': 'application/json',
                 Accept: 'application/json',
             },
+            ...(this.timeoutSecs && { timeout: this.timeoutSecs * 1000 }),
         });
+
+        // Retry transient failures on idempotent requests (see LETTA_RETRY_* variables)
+        this.retryPolicy = readRetryPolicy(process.env);
+        installRetryInterceptor(this.api, this.retryPolicy, this.logger);
+
+        // Tag Letta requests with the active tool call's request ID (X-Request-Id)
+        installTracingInterceptor(this.api);
+
+        // Default project/user scope (LETTA_PROJECT_ID, LETTA_USER_ID)
+        this.scope = readDefaultScope(process.env);
+
+        // Backend version and supported tools, filled in by get_server_capabilities
+        this.backendCapabilities = null;
+
+        // Registered tool definitions, filled in by registerToolHandlers for list_operations
+        this.toolDefinitions = [];
+
+        // In-progress chunked source uploads by upload ID, pruned once their TTL lapses
+        this.uploadSessions = new Map();
+        this.uploadPruneTimer = null;
+
+        // Background work run by this server itself (e.g. migrate_agent), by job ID
+        this.localJobs = new Map();
+
+        // Cap on parallel requests in fan-out scans (LETTA_MAX_CONCURRENCY)
+        this.maxConcurrency = readMaxConcurrency(process.env);
+
+        // Recent run_tool_from_source executions for list_tool_runs (LETTA_RUN_HISTORY_SIZE)
+        this.runHistory = new RunHistory(readRunHistorySize(process.env));
+
+        // Cached list_agents/list_tools results; disabled unless LETTA_CACHE_TTL_SECS is set
+        this.responseCache = new ResponseCache(readCacheTtlSecs(process.env));
+
+        // Results of create calls by idempotency_key (LETTA_IDEMPOTENCY_TTL_SECS)
+        this.idempotency = new IdempotencyStore(readIdempotencyTtlSecs(process.env));
+
+        // Compact or pretty-printed JSON responses (LETTA_JSON_COMPACT)
+        this.jsonCompact = readJsonCompact(process.env);
+
+        // Configs create_agent uses when a call doesn't pick a model (LETTA_DEFAULT_*_CONFIG)
+        this.defaultModelConfigs = readDefaultModelConfigs(process.env);
+
+        // Directory agent exports are written into, or null for anywhere (LETTA_EXPORT_DIR)
+        this.exportDir = readExportDir(process.env);
+
+        // Reject, warn about or ignore arguments a tool doesn't accept (LETTA_UNKNOWN_ARGUMENTS)
+        this.unknownArguments = readUnknownArgumentPolicy(process.env);
+
+        // Per-tool call counts and durations, served at /metrics by the HTTP transport
+        this.metrics = new MetricsRegistry();
+
+        // Running tool calls, drained on shutdown (LETTA_SHUTDOWN_TIMEOUT_SECS)
+        this.inFlight = new InFlightTracker();
+        this.shutdownOptions = readShutdownOptions(process.env);
+
+        // Check Letta is reachable before accepting connections (LETTA_STARTUP_CHECK)
+        this.startupCheck = readStartupCheck(process.env);
     }
 
     /**
@@ -69,11 +161,11 @@ export class LettaServer {
         return {
             'Content-Type': 'application/json',
             Accept: 'application/json',
-            'X-BARE-PASSWORD': `password ${this.password}`,
-            Authorization: `Bearer ${this.password}`,
+            ...lettaAuthHeaders(this.auth),
             // Identify as SDK v1.0 compatible client for proper API behavior
             'User-Agent': 'letta-mcp-server/2.0.1 (sdk-v1.0-compatible)',
             'X-Letta-SDK-Version': '1.0',
+            ...scopeHeaders(this.scope),
         };
     }
 
@@ -84,29 +176,14 @@ export class LettaServer {
      * @throws {McpError} Always throws an McpError for proper JSON-RPC handling
      */
     createErrorResponse(error, context) {
-        let errorMessage = '';
-        let errorCode = ErrorCode.InternalError;
-
-        if (typeof error === 'string') {
-            errorMessage = error;
-        } else if (error instanceof Error) {
-            errorMessage = error.message;
-
-            // Handle specific HTTP error codes
-            if (error.response?.status === 404) {
-                errorCode = ErrorCode.InvalidRequest;
-                errorMessage = `Resource not found: ${error.message}`;
-            } else if (error.response?.status === 422) {
-                errorCode = ErrorCode.InvalidParams;
-                errorMessage = `Validation error: ${error.message}`;
-            } else if (error.response?.status === 401 || error.response?.status === 403) {
-                errorCode = ErrorCode.InvalidRequest;
-                errorMessage = `Authentication/Authorization error: ${error.message}`;
-            }
-        } else {
-            errorMessage = 'Unknown error occurred';
+        // Already reported (e.g. a validation failure inside a handler's try block)
+        if (error instanceof McpError) {
+            throw error;
         }
 
+        const { code, errorCode, status, retryable, message } = classifyError(error);
+        let errorMessage = message;
+
         // Add context if provided
         if (context) {
             errorMessage = `${context}: ${errorMessage}`;
@@ -117,6 +194,13 @@ export class LettaServer {
             errorMessage += ` Details: ${JSON.stringify(error.response.data)}`;
         }
 
-        throw new McpError(errorCode, errorMessage);
+        // Structured fields for clients that act on the failure (e.g. retry on `retryable`)
+        throw new McpError(code, errorMessage, {
+            error_code: errorCode,
+            status,
+            retryable,
+            ...(error?.field && { field: error.field }),
+            ...(error?.fields && { fields: error.fields }),
+        });
     }
 }
@@ -0,0 +1,132 @@
+/**
+ * Graceful shutdown support.
+ *
+ * On SIGTERM/SIGINT the transports stop accepting new work, then wait up to
+ * LETTA_SHUTDOWN_TIMEOUT_SECS (default 25) for in-flight tool calls to finish before closing.
+ * With LETTA_CANCEL_RUNS_ON_SHUTDOWN=true, agent runs still going at the deadline are
+ * cancelled on the Letta server instead of being left running unattended.
+ */
+
+export const DEFAULT_SHUTDOWN_TIMEOUT_SECS = 25;
+
+/**
+ * Read shutdown options from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {Object} timeoutSecs and cancelRuns
+ */
+export function readShutdownOptions(env = process.env) {
+    const raw = env.LETTA_SHUTDOWN_TIMEOUT_SECS;
+    let timeoutSecs = DEFAULT_SHUTDOWN_TIMEOUT_SECS;
+    if (raw !== undefined && raw !== '') {
+        timeoutSecs = Number(raw);
+        if (!Number.isFinite(timeoutSecs) || timeoutSecs < 0) {
+            throw new Error(
+                'Invalid environment variable LETTA_SHUTDOWN_TIMEOUT_SECS: expected seconds >= 0',
+            );
+        }
+    }
+    return { timeoutSecs, cancelRuns: env.LETTA_CANCEL_RUNS_ON_SHUTDOWN === 'true' };
+}
+
+/**
+ * Tracks running tool calls so shutdown can wait for them
+ */
+export class InFlightTracker {
+    constructor() {
+        this.calls = new Map();
+        this.nextId = 0;
+        this.draining = false;
+        this.idleWaiters = [];
+    }
+
+    get size() {
+        return this.calls.size;
+    }
+
+    /**
+     * Run a tool call, tracking it until it settles. Refused once draining has started.
+     * @param {string} name - Tool name
+     * @param {Object} args - Tool arguments
+     * @param {Function} fn - Runs the tool
+     * @returns {Promise<*>}
+     */
+    async track(name, args, fn) {
+        if (this.draining) {
+            throw new Error('Server is shutting down; not accepting new tool calls');
+        }
+        const id = this.nextId++;
+        this.calls.set(id, { name, agentId: args?.agent_id });
+        try {
+            return await fn();
+        } finally {
+            this.calls.delete(id);
+            if (this.calls.size === 0) {
+                this.idleWaiters.splice(0).forEach((resolve) => resolve(true));
+            }
+        }
+    }
+
+    /**
+     * Agent IDs targeted by the calls still running
+     * @returns {Array<string>}
+     */
+    activeAgentIds() {
+        const ids = [...this.calls.values()].map((call) => call.agentId);
+        return [...new Set(ids.filter((id) => typeof id === 'string'))];
+    }
+
+    /**
+     * Stop accepting calls and wait for running ones to finish
+     * @param {number} timeoutMs - Longest time to wait
+     * @returns {Promise<boolean>} true if every call finished in time
+     */
+    drain(timeoutMs) {
+        this.draining = true;
+        if (this.calls.size === 0) return Promise.resolve(true);
+        return new Promise((resolve) => {
+            const timer = setTimeout(() => resolve(false), timeoutMs);
+            this.idleWaiters.push((drained) => {
+                clearTimeout(timer);
+                resolve(drained);
+            });
+        });
+    }
+}
+
+/**
+ * Drain in-flight tool calls, cancelling the agents' runs if they outlast the timeout and
+ * cancelRuns is set
+ * @param {Object} server - LettaServer with inFlight tracker and api client
+ * @param {Object} options - timeoutSecs and cancelRuns from readShutdownOptions
+ * @param {Object} logger - Logger for progress
+ * @returns {Promise<boolean>} true if every call finished in time
+ */
+export async function drainInFlight(server, options, logger) {
+    const tracker = server.inFlight;
+    if (tracker.size > 0) {
+        logger.info(`Waiting up to ${options.timeoutSecs}s for ${tracker.size} tool call(s)`);
+    }
+    const drained = await tracker.drain(options.timeoutSecs * 1000);
+    if (drained) return true;
+
+    const agentIds = tracker.activeAgentIds();
+    logger.warn(`Shutdown timeout reached with ${tracker.size} tool call(s) still running`);
+    if (options.cancelRuns && agentIds.length > 0) {
+        const headers = server.getApiHeaders();
+        await Promise.allSettled(
+            agentIds.map(async (agentId) => {
+                try {
+                    await server.api.post(
+                        `/agents/${encodeURIComponent(agentId)}/messages/cancel`,
+                        {},
+                        { headers },
+                    );
+                    logger.info(`Cancelled active runs for agent ${agentId}`);
+                } catch (error) {
+                    logger.warn(`Could not cancel runs for agent ${agentId}: ${error.message}`);
+                }
+            }),
+        );
+    }
+    return false;
+}
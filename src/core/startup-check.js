@@ -0,0 +1,82 @@
+import { isTimeoutError } from './timeout.js';
+
+/**
+ * Startup connectivity check.
+ *
+ * With LETTA_STARTUP_CHECK=true the server makes one authenticated request to Letta before
+ * accepting connections and refuses to start if it fails, with a message that tells DNS
+ * failures, refused connections, rejected credentials and a wrong LETTA_BASE_URL apart.
+ * Off by default so the server can start before Letta does.
+ */
+
+export const STARTUP_CHECK_TIMEOUT_MS = 10000;
+
+/**
+ * Read whether to check the Letta connection at startup
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {boolean}
+ */
+export function readStartupCheck(env = process.env) {
+    return env.LETTA_STARTUP_CHECK === 'true';
+}
+
+/**
+ * Explain why the startup check failed
+ * @param {Error} error - Axios error from the check request
+ * @param {string} apiBase - Letta API base URL
+ * @returns {string} Diagnostic message
+ */
+export function diagnoseConnectionError(error, apiBase) {
+    const host = (() => {
+        try {
+            return new URL(apiBase).host;
+        } catch {
+            return apiBase;
+        }
+    })();
+    const status = error.response?.status;
+
+    if (error.code === 'ENOTFOUND' || error.code === 'EAI_AGAIN') {
+        return `Cannot resolve Letta host ${host}: check the hostname in LETTA_BASE_URL`;
+    }
+    if (error.code === 'ECONNREFUSED') {
+        return `Connection to Letta at ${host} refused: is the Letta server running on that port?`;
+    }
+    if (isTimeoutError(error)) {
+        return `Letta at ${host} did not respond within ${STARTUP_CHECK_TIMEOUT_MS / 1000}s`;
+    }
+    if (status === 401 || status === 403) {
+        return `Letta at ${host} rejected the credentials (HTTP ${status}): check LETTA_PASSWORD, LETTA_API_KEY and LETTA_AUTH_MODE`;
+    }
+    if (status === 404) {
+        return `No Letta API at ${apiBase} (HTTP 404): check LETTA_BASE_URL points at the Letta server root`;
+    }
+    if (status) {
+        return `Letta at ${host} returned HTTP ${status}: ${error.message}`;
+    }
+    return `Cannot connect to Letta at ${host}: ${error.message}`;
+}
+
+/**
+ * Make one authenticated request to Letta, throwing a diagnostic error if it fails
+ * @param {Object} server - LettaServer instance
+ */
+export async function checkLettaConnection(server) {
+    let response;
+    try {
+        response = await server.api.get('/agents/', {
+            headers: server.getApiHeaders(),
+            params: { limit: 1 },
+            timeout: STARTUP_CHECK_TIMEOUT_MS,
+        });
+    } catch (error) {
+        const diagnosis = diagnoseConnectionError(error, server.apiBase);
+        throw new Error(`Letta startup check failed: ${diagnosis}`);
+    }
+    // Something other than Letta (a proxy page, another service) answered at this URL
+    if (!Array.isArray(response.data)) {
+        throw new Error(
+            `Letta startup check failed: ${server.apiBase}/agents/ did not return an agent list; check LETTA_BASE_URL points at the Letta server root`,
+        );
+    }
+}
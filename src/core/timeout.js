@@ -0,0 +1,75 @@
+import { invalidRequest } from './errors.js';
+
+/**
+ * Request timeout configuration for Letta API calls.
+ *
+ * LETTA_TIMEOUT_SECS sets the default for every request; long-running tools accept a
+ * `timeout_secs` argument to override it per call. Timeouts surface as a distinct
+ * "Operation timed out after Ns" error so clients can tell them apart from other failures.
+ */
+
+/**
+ * Read the default request timeout from the environment
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {number|undefined} Timeout in seconds, or undefined for no timeout
+ */
+export function readTimeoutSecs(env = process.env) {
+    const raw = env.LETTA_TIMEOUT_SECS;
+    if (raw === undefined || raw === '') return undefined;
+    const secs = Number(raw);
+    if (!Number.isFinite(secs) || secs < 0) {
+        throw new Error('Invalid environment variable LETTA_TIMEOUT_SECS: expected seconds >= 0');
+    }
+    // 0 disables the timeout, matching axios semantics
+    return secs || undefined;
+}
+
+/**
+ * Build the axios config for a per-request timeout override
+ * @param {Object} args - Tool arguments, optionally containing timeout_secs
+ * @returns {Object} `{ timeout }` in milliseconds, or an empty object when not overridden
+ */
+export function requestTimeout(args) {
+    const secs = args?.timeout_secs;
+    if (secs === undefined) return {};
+    if (typeof secs !== 'number' || !Number.isFinite(secs) || secs <= 0) {
+        throw invalidRequest(
+            'timeout_secs',
+            'Invalid argument: timeout_secs must be a positive number',
+        );
+    }
+    return { timeout: Math.round(secs * 1000) };
+}
+
+/**
+ * Whether an axios error was caused by the request timing out
+ * @param {Error} error
+ * @returns {boolean}
+ */
+export function isTimeoutError(error) {
+    if (error?.response) return false;
+    return (
+        error?.code === 'ETIMEDOUT' ||
+        (error?.code === 'ECONNABORTED' && /timeout/i.test(error.message || ''))
+    );
+}
+
+/**
+ * Describe a timeout error, including the limit that was hit when known
+ * @param {Error} error - Axios timeout error
+ * @returns {string}
+ */
+export function timeoutErrorMessage(error) {
+    const ms = error?.config?.timeout;
+    return ms ? `Operation timed out after ${ms / 1000}s` : 'Operation timed out';
+}
+
+/**
+ * Schema for the per-request timeout_secs tool argument
+ */
+export const timeoutSecsProperty = {
+    type: 'number',
+    description:
+        'Request timeout for this call in seconds, overriding LETTA_TIMEOUT_SECS (useful for long operations)',
+    exclusiveMinimum: 0,
+};
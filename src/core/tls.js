@@ -0,0 +1,48 @@
+import fs from 'node:fs';
+import tls from 'node:tls';
+
+/**
+ * TLS settings for connecting to a Letta server behind mutual TLS or a private CA.
+ *
+ * LETTA_TLS_CERT_FILE and LETTA_TLS_KEY_FILE give a PEM client certificate and key
+ * (LETTA_TLS_KEY_PASSPHRASE if the key is encrypted); LETTA_TLS_CA_FILE gives a PEM CA
+ * bundle trusted in addition to the default roots. Files are read once at startup.
+ */
+
+/**
+ * Read a PEM file named by an environment variable
+ */
+function readPemFile(env, name) {
+    const file = env[name]?.trim();
+    if (!file) return undefined;
+    try {
+        return fs.readFileSync(file, 'utf8');
+    } catch (error) {
+        throw new Error(
+            `Invalid environment variable ${name}: cannot read ${file} (${error.code ?? error.message})`,
+        );
+    }
+}
+
+/**
+ * Read TLS settings from environment variables
+ * @param {Object} env - Environment (usually process.env)
+ * @returns {Object} https.Agent options (cert, key, passphrase, ca); empty when unset
+ */
+export function readTlsOptions(env = process.env) {
+    const cert = readPemFile(env, 'LETTA_TLS_CERT_FILE');
+    const key = readPemFile(env, 'LETTA_TLS_KEY_FILE');
+    if (Boolean(cert) !== Boolean(key)) {
+        throw new Error(
+            'Invalid TLS configuration: LETTA_TLS_CERT_FILE and LETTA_TLS_KEY_FILE must be set together',
+        );
+    }
+    const ca = readPemFile(env, 'LETTA_TLS_CA_FILE');
+    const passphrase = env.LETTA_TLS_KEY_PASSPHRASE;
+
+    return {
+        ...(cert && { cert, key }),
+        ...(cert && passphrase && { passphrase }),
+        ...(ca && { ca: [...tls.rootCertificates, ca] }),
+    };
+}
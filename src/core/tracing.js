@@ -0,0 +1,90 @@
+import { AsyncLocalStorage } from 'node:async_hooks';
+import { randomUUID } from 'node:crypto';
+
+/**
+ * Request-scoped tracing spans.
+ *
+ * Each tool call runs in a span carrying a generated request_id, the operation (tool name)
+ * and the agent_id it targets. Log lines written inside the span include those fields, and
+ * Letta API requests carry the request ID in an X-Request-Id header. Fan-out tools open a
+ * child span per agent so a failing agent can be picked out of a broadcast.
+ */
+
+const spanStorage = new AsyncLocalStorage();
+
+/**
+ * Fields of the active span, for attaching to log entries
+ * @returns {Object} request_id, operation and agent_id that are set (empty outside a span)
+ */
+export function spanFields() {
+    const span = spanStorage.getStore();
+    if (!span) return {};
+    const fields = { request_id: span.request_id, operation: span.operation };
+    if (span.agent_id) fields.agent_id = span.agent_id;
+    return fields;
+}
+
+/**
+ * Run a function in a span. A span opened inside another keeps its request ID and
+ * overrides the fields given.
+ * @param {Object} fields - Span fields such as operation and agent_id
+ * @param {Function} fn - Function to run
+ * @returns {*} The function's return value
+ */
+export function runInSpan(fields, fn) {
+    const parent = spanStorage.getStore();
+    const span = { ...parent, request_id: parent?.request_id ?? randomUUID() };
+    for (const [key, value] of Object.entries(fields)) {
+        if (value !== undefined) span[key] = value;
+    }
+    return spanStorage.run(span, fn);
+}
+
+/**
+ * Run a tool call in a new span and log its outcome and duration when it closes
+ * @param {Object} logger - Logger for the span close entry
+ * @param {string} operation - Tool name
+ * @param {Object} args - Tool arguments; agent_id is recorded on the span when present
+ * @param {Function} fn - Runs the tool
+ * @returns {Promise<*>} The tool result
+ */
+export function withSpan(logger, operation, args, fn) {
+    const agentId = typeof args?.agent_id === 'string' ? args.agent_id : undefined;
+    return runInSpan({ operation, agent_id: agentId }, async () => {
+        const started = Date.now();
+        try {
+            const result = await fn();
+            logger.info(`${operation} finished`, {
+                outcome: 'success',
+                duration_ms: Date.now() - started,
+            });
+            return result;
+        } catch (error) {
+            logger.warn(`${operation} failed: ${error.message}`, {
+                outcome: 'error',
+                duration_ms: Date.now() - started,
+            });
+            throw error;
+        }
+    });
+}
+
+/**
+ * Send the active span's request ID to Letta on every request
+ * @param {Object} instance - Axios instance
+ */
+export function installTracingInterceptor(instance) {
+    if (!instance?.interceptors?.request) return;
+    instance.interceptors.request.use((config) => {
+        const requestId = spanStorage.getStore()?.request_id;
+        if (requestId) {
+            config.headers = config.headers || {};
+            if (typeof config.headers.set === 'function') {
+                config.headers.set('X-Request-Id', requestId);
+            } else {
+                config.headers['X-Request-Id'] = requestId;
+            }
+        }
+        return config;
+    });
+}
@@ -0,0 +1,99 @@
+/**
+ * YAML serialization of JSON values, for the `format: "yaml"` tool argument.
+ *
+ * Only what JSON can hold is supported (objects, arrays, strings, numbers, booleans and
+ * null), so a small emitter is enough. Strings are quoted whenever plain YAML would read
+ * them differently, and multi-line strings are written as literal blocks.
+ */
+
+const RESERVED_WORDS = /^(?:~|null|true|false|yes|no|on|off|y|n)$/i;
+const NUMBER_LIKE = /^[-+]?(?:\.?\d|\.(?:inf|nan)$)/i;
+const SPECIAL_START = /^[-?:,[\]{}#&*!|>'"%@`\s]/;
+
+const INDENT = '  ';
+
+/**
+ * Whether a string can be written without quotes
+ */
+function isPlainSafe(text) {
+    return (
+        text !== '' &&
+        !RESERVED_WORDS.test(text) &&
+        !NUMBER_LIKE.test(text) &&
+        !SPECIAL_START.test(text) &&
+        !/\s$/.test(text) &&
+        !/: |:$| #|[\n\r\t]/.test(text) &&
+        // eslint-disable-next-line no-control-regex
+        !/[\u0000-\u001f\u007f]/.test(text)
+    );
+}
+
+/**
+ * Write a string scalar, as a literal block when it spans lines
+ */
+function formatString(text, indent) {
+    if (isPlainSafe(text)) return text;
+    const multiLine = text.includes('\n') && !text.endsWith('\n\n') && !/^\n*[ \t]/.test(text);
+    // eslint-disable-next-line no-control-regex
+    if (multiLine && !/[\r\u0000-\u0008\u000b-\u001f\u007f]/.test(text)) {
+        const chomp = text.endsWith('\n') ? '' : '-';
+        const lines = text.replace(/\n$/, '').split('\n');
+        return `|${chomp}\n${lines.map((line) => (line ? indent + line : '')).join('\n')}`;
+    }
+    // A JSON string is a valid double-quoted YAML scalar
+    return JSON.stringify(text);
+}
+
+const formatKey = (key) => (isPlainSafe(key) ? key : JSON.stringify(key));
+
+const isCollection = (value) =>
+    value !== null &&
+    typeof value === 'object' &&
+    (Array.isArray(value) ? value.length > 0 : Object.keys(value).length > 0);
+
+/**
+ * Write a scalar or empty collection on one line
+ */
+function formatScalar(value, indent) {
+    if (value === null || value === undefined) return 'null';
+    if (typeof value === 'string') return formatString(value, indent);
+    if (typeof value === 'number') return Number.isFinite(value) ? String(value) : 'null';
+    if (typeof value === 'boolean') return String(value);
+    return Array.isArray(value) ? '[]' : '{}';
+}
+
+/**
+ * Write a non-empty collection as indented block lines
+ */
+function formatBlock(value, indent) {
+    const nested = indent + INDENT;
+    if (Array.isArray(value)) {
+        return value
+            .map((item) => {
+                if (!isCollection(item)) return `${indent}- ${formatScalar(item, nested)}`;
+                // The first line of a nested collection sits after the dash
+                return `${indent}- ${formatBlock(item, nested).slice(nested.length)}`;
+            })
+            .join('\n');
+    }
+    return Object.entries(value)
+        .filter(([, item]) => item !== undefined)
+        .map(([key, item]) => {
+            const prefix = `${indent}${formatKey(key)}:`;
+            if (!isCollection(item)) return `${prefix} ${formatScalar(item, nested)}`;
+            // Sequences under a key conventionally sit at the key's own indent
+            const childIndent = Array.isArray(item) ? indent : nested;
+            return `${prefix}\n${formatBlock(item, childIndent)}`;
+        })
+        .join('\n');
+}
+
+/**
+ * Serialize a JSON value as a YAML document
+ * @param {*} value - JSON-compatible value
+ * @returns {string} YAML text ending in a newline
+ */
+export function toYaml(value) {
+    const body = isCollection(value) ? formatBlock(value, '') : formatScalar(value, INDENT);
+    return `${body}\n`;
+}
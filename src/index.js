@@ -5,8 +5,9 @@ import { registerToolHandlers } from './tools/index.js';
 import { registerPromptHandlers } from './handlers/prompts.js';
 import { registerResourceHandlers } from './handlers/resources.js';
 import { initializeExamples } from './examples/index.js';
-import { runStdio, runSSE, runHTTP } from './transports/index.js';
+import { runStdio, runSSE, runHTTP, runUDS } from './transports/index.js';
 import { createLogger } from './core/logger.js';
+import { checkLettaConnection } from './core/startup-check.js';
 
 // Load environment variables
 dotenv.config();
@@ -22,6 +23,12 @@ async function main() {
         // Create server instance
         const server = new LettaServer();
 
+        // Fail fast if Letta can't be reached, rather than on the first tool call
+        if (server.startupCheck) {
+            await checkLettaConnection(server);
+            logger.info(`Connected to Letta at ${server.apiBase}`);
+        }
+
         // Register all handlers before connecting to transport
         registerToolHandlers(server);
         registerPromptHandlers(server);
@@ -36,11 +43,15 @@ async function main() {
         // Determine transport mode from command line arguments
         const useSSE = process.argv.includes('--sse');
         const useHTTP = process.argv.includes('--http');
+        const useUDS = process.argv.includes('--uds');
 
         // Run server with appropriate transport
         if (useHTTP) {
             logger.info('Starting Letta server with HTTP transport');
             await runHTTP(server);
+        } else if (useUDS) {
+            logger.info('Starting Letta server with unix socket transport');
+            await runUDS(server);
         } else if (useSSE) {
             logger.info('Starting Letta server with SSE transport');
             await runSSE(server);
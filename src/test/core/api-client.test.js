@@ -1,12 +1,16 @@
 import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
 import { LettaServer } from '../../core/server.js';
 import axios from 'axios';
+import http from 'node:http';
+import https from 'node:https';
 import { createMockLettaServer } from '../utils/mock-server.js';
 
 // Mock dependencies
 vi.mock('@modelcontextprotocol/sdk/server/index.js');
 vi.mock('axios');
-vi.mock('../../core/logger.js');
+vi.mock('../../core/logger.js', () => ({
+    createLogger: () => ({ info: vi.fn(), error: vi.fn(), warn: vi.fn(), debug: vi.fn() }),
+}));
 
 describe('API Client Configuration (LMP-85)', () => {
     let mockAxiosInstance;
@@ -52,6 +56,8 @@ describe('API Client Configuration (LMP-85)', () => {
             new LettaServer();
 
             expect(axios.create).toHaveBeenCalledWith({
+                httpAgent: expect.any(http.Agent),
+                httpsAgent: expect.any(https.Agent),
                 baseURL: 'https://api.letta.com/v1',
                 headers: {
                     'Content-Type': 'application/json',
@@ -67,7 +73,9 @@ describe('API Client Configuration (LMP-85)', () => {
             new LettaServer();
 
             expect(axios.create).toHaveBeenCalledWith({
-                baseURL: 'https://api.letta.com//v1',
+                httpAgent: expect.any(http.Agent),
+                httpsAgent: expect.any(https.Agent),
+                baseURL: 'https://api.letta.com/v1',
                 headers: {
                     'Content-Type': 'application/json',
                     Accept: 'application/json',
@@ -81,9 +89,10 @@ describe('API Client Configuration (LMP-85)', () => {
 
             new LettaServer();
 
-            // This is the current behavior - it appends /v1 regardless
             expect(axios.create).toHaveBeenCalledWith({
-                baseURL: 'https://api.letta.com/v1/v1',
+                httpAgent: expect.any(http.Agent),
+                httpsAgent: expect.any(https.Agent),
+                baseURL: 'https://api.letta.com/v1',
                 headers: {
                     'Content-Type': 'application/json',
                     Accept: 'application/json',
@@ -134,7 +143,7 @@ describe('API Client Configuration (LMP-85)', () => {
             });
         });
 
-        it('should handle empty password', () => {
+        it('should omit auth headers for an empty password', () => {
             process.env.LETTA_PASSWORD = '';
             const server = new LettaServer();
 
@@ -143,8 +152,6 @@ describe('API Client Configuration (LMP-85)', () => {
             expect(headers).toEqual({
                 'Content-Type': 'application/json',
                 Accept: 'application/json',
-                'X-BARE-PASSWORD': 'password ',
-                Authorization: 'Bearer ',
                 'User-Agent': 'letta-mcp-server/2.0.1 (sdk-v1.0-compatible)',
                 'X-Letta-SDK-Version': '1.0',
             });
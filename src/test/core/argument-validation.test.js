@@ -0,0 +1,142 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+    findArgumentProblems,
+    readUnknownArgumentPolicy,
+    validateArguments,
+} from '../../core/argument-validation.js';
+
+const schema = {
+    type: 'object',
+    properties: {
+        agent_id: { type: 'string' },
+        block_label: { type: 'string' },
+        value: { type: 'string' },
+        limit: { type: 'integer' },
+        order: { type: 'string', enum: ['asc', 'desc'] },
+        cursor: { type: ['string', 'null'] },
+    },
+    required: ['agent_id', 'block_label', 'value'],
+};
+
+describe('Argument Validation', () => {
+    describe('findArgumentProblems', () => {
+        it('should collect every missing required argument', () => {
+            expect(findArgumentProblems(schema, { value: 'x' })).toEqual({
+                missing: ['agent_id', 'block_label'],
+                invalid: [],
+                unknown: [],
+            });
+            expect(findArgumentProblems(schema, undefined).missing).toEqual([
+                'agent_id',
+                'block_label',
+                'value',
+            ]);
+        });
+
+        it('should treat null and empty strings as missing', () => {
+            const { missing } = findArgumentProblems(schema, {
+                agent_id: null,
+                block_label: '',
+                value: 'x',
+            });
+            expect(missing).toEqual(['agent_id', 'block_label']);
+        });
+
+        it('should check types and enums of the arguments given', () => {
+            const { invalid } = findArgumentProblems(schema, {
+                agent_id: 'agent-1',
+                block_label: 'human',
+                value: 'x',
+                limit: 2.5,
+                order: 'sideways',
+                cursor: 7,
+            });
+            expect(invalid).toEqual([
+                { field: 'limit', message: 'limit must be an integer' },
+                { field: 'order', message: 'order must be one of asc, desc' },
+                { field: 'cursor', message: 'cursor must be a string or null' },
+            ]);
+        });
+
+        it('should collect arguments the schema does not declare', () => {
+            const args = { agent_id: 'a', block_label: 'b', value: 'c', query: 'x' };
+            expect(findArgumentProblems(schema, args).unknown).toEqual(['query']);
+            expect(
+                findArgumentProblems({ ...schema, additionalProperties: true }, args).unknown,
+            ).toEqual([]);
+        });
+
+        it('should allow null for optional arguments', () => {
+            const args = { agent_id: 'a', block_label: 'b', value: 'c', limit: null };
+            expect(findArgumentProblems(schema, args).invalid).toEqual([]);
+        });
+    });
+
+    describe('validateArguments', () => {
+        it('should pass valid arguments', () => {
+            expect(() =>
+                validateArguments(schema, { agent_id: 'a', block_label: 'b', value: 'c' }),
+            ).not.toThrow();
+        });
+
+        it('should keep the single-field message for one missing argument', () => {
+            expect(() => validateArguments(schema, { block_label: 'b', value: 'c' })).toThrow(
+                /^Missing required argument: agent_id$/,
+            );
+        });
+
+        it('should list every problem in one invalid_request error', () => {
+            let error;
+            try {
+                validateArguments(schema, { limit: 'ten' });
+            } catch (e) {
+                error = e;
+            }
+
+            expect(error.message).toBe(
+                'Missing required arguments: agent_id, block_label, value. Invalid argument: limit must be an integer',
+            );
+            expect(error.errorCode).toBe('invalid_request');
+            expect(error.field).toBe('agent_id');
+            expect(error.fields).toEqual(['agent_id', 'block_label', 'value', 'limit']);
+        });
+
+        it('should reject unknown arguments by default', () => {
+            expect(() =>
+                validateArguments(
+                    schema,
+                    { agent_id: 'a', block_label: 'b', value: 'c', messages: [] },
+                    { toolName: 'update_core_memory' },
+                ),
+            ).toThrow('Unknown argument for update_core_memory: messages');
+        });
+
+        it('should only warn about or ignore unknown arguments when configured', () => {
+            const args = { agent_id: 'a', block_label: 'b', value: 'c', query: 'x' };
+            const logger = { warn: vi.fn() };
+
+            expect(() =>
+                validateArguments(schema, args, { unknownArguments: 'warn', logger }),
+            ).not.toThrow();
+            expect(logger.warn).toHaveBeenCalledWith(
+                'Ignoring arguments not accepted by this tool: query',
+            );
+            expect(() =>
+                validateArguments(schema, args, { unknownArguments: 'ignore' }),
+            ).not.toThrow();
+        });
+    });
+
+    describe('readUnknownArgumentPolicy', () => {
+        it('should default to reject', () => {
+            expect(readUnknownArgumentPolicy({})).toBe('reject');
+            expect(readUnknownArgumentPolicy({ LETTA_UNKNOWN_ARGUMENTS: 'warn' })).toBe('warn');
+        });
+
+        it('should reject unknown policies', () => {
+            expect(() => readUnknownArgumentPolicy({ LETTA_UNKNOWN_ARGUMENTS: 'strict' })).toThrow(
+                'Invalid environment variable LETTA_UNKNOWN_ARGUMENTS',
+            );
+        });
+    });
+});
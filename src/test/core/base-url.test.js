@@ -0,0 +1,37 @@
+import { describe, it, expect } from 'vitest';
+import { readApiBase } from '../../core/base-url.js';
+
+describe('API Base URL', () => {
+    it('should be null when LETTA_BASE_URL is unset or blank', () => {
+        expect(readApiBase({})).toBeNull();
+        expect(readApiBase({ LETTA_BASE_URL: '  ' })).toBeNull();
+    });
+
+    it('should append /v1 to the server root', () => {
+        expect(readApiBase({ LETTA_BASE_URL: 'http://localhost:8283' })).toBe(
+            'http://localhost:8283/v1',
+        );
+    });
+
+    it('should keep a reverse proxy subpath', () => {
+        expect(readApiBase({ LETTA_BASE_URL: 'https://host/letta' })).toBe(
+            'https://host/letta/v1',
+        );
+        expect(readApiBase({ LETTA_BASE_URL: 'https://host/tools/letta/' })).toBe(
+            'https://host/tools/letta/v1',
+        );
+    });
+
+    it('should accept a URL that already ends in /v1', () => {
+        expect(readApiBase({ LETTA_BASE_URL: 'https://host/v1' })).toBe('https://host/v1');
+        expect(readApiBase({ LETTA_BASE_URL: 'https://host/letta/v1/' })).toBe(
+            'https://host/letta/v1',
+        );
+    });
+
+    it('should not mistake a path ending in v1 for the API prefix', () => {
+        expect(readApiBase({ LETTA_BASE_URL: 'https://host/apiv1' })).toBe(
+            'https://host/apiv1/v1',
+        );
+    });
+});
@@ -0,0 +1,128 @@
+import { describe, it, expect, vi } from 'vitest';
+import { ResponseCache, readCacheTtlSecs, withResponseCache } from '../../core/cache.js';
+import { runWithScope } from '../../core/scope.js';
+
+const result = (text) => ({ content: [{ type: 'text', text }] });
+
+describe('Response Cache', () => {
+    describe('readCacheTtlSecs', () => {
+        it('should be disabled by default', () => {
+            expect(readCacheTtlSecs({})).toBe(0);
+        });
+
+        it('should read LETTA_CACHE_TTL_SECS', () => {
+            expect(readCacheTtlSecs({ LETTA_CACHE_TTL_SECS: '30' })).toBe(30);
+        });
+
+        it('should reject negative or non-numeric values', () => {
+            for (const value of ['-1', 'soon']) {
+                expect(() => readCacheTtlSecs({ LETTA_CACHE_TTL_SECS: value })).toThrow(
+                    'Invalid environment variable LETTA_CACHE_TTL_SECS',
+                );
+            }
+        });
+    });
+
+    describe('ResponseCache', () => {
+        it('should expire entries after the TTL', () => {
+            let now = 0;
+            const cache = new ResponseCache(10, () => now);
+            cache.set('agents', 'key', 'value');
+
+            now = 9_999;
+            expect(cache.get('agents', 'key')).toBe('value');
+            now = 10_000;
+            expect(cache.get('agents', 'key')).toBeUndefined();
+        });
+    });
+
+    describe('withResponseCache', () => {
+        const makeServer = (ttlSecs = 60) => ({
+            responseCache: new ResponseCache(ttlSecs),
+            scope: {},
+        });
+
+        it('should call through when the cache is disabled', async () => {
+            const server = makeServer(0);
+            const call = vi.fn().mockResolvedValue(result('agents'));
+
+            await withResponseCache(server, 'list_agents', {}, call);
+            await withResponseCache(server, 'list_agents', {}, call);
+
+            expect(call).toHaveBeenCalledTimes(2);
+        });
+
+        it('should serve repeated calls with the same arguments from the cache', async () => {
+            const server = makeServer();
+            const call = vi.fn().mockResolvedValue(result('agents'));
+
+            await withResponseCache(server, 'list_agents', { limit: 5 }, call);
+            const cached = await withResponseCache(server, 'list_agents', { limit: 5 }, call);
+            await withResponseCache(server, 'list_agents', { limit: 10 }, call);
+
+            expect(cached).toEqual(result('agents'));
+            expect(call).toHaveBeenCalledTimes(2);
+        });
+
+        it('should skip the cache when bust_cache is set and store the fresh result', async () => {
+            const server = makeServer();
+            const call = vi
+                .fn()
+                .mockResolvedValueOnce(result('old'))
+                .mockResolvedValueOnce(result('new'));
+
+            await withResponseCache(server, 'list_tools', {}, call);
+            await withResponseCache(server, 'list_tools', { bust_cache: true }, call);
+            const cached = await withResponseCache(server, 'list_tools', {}, call);
+
+            expect(cached).toEqual(result('new'));
+            expect(call).toHaveBeenCalledTimes(2);
+        });
+
+        it('should keep projects separate', async () => {
+            const server = makeServer();
+            const call = vi.fn().mockResolvedValue(result('agents'));
+
+            await runWithScope({ project_id: 'project-a' }, () =>
+                withResponseCache(server, 'list_agents', {}, call),
+            );
+            await runWithScope({ project_id: 'project-b' }, () =>
+                withResponseCache(server, 'list_agents', {}, call),
+            );
+
+            expect(call).toHaveBeenCalledTimes(2);
+        });
+
+        it('should invalidate cached listings after a mutating tool succeeds', async () => {
+            const server = makeServer();
+            const listAgents = vi.fn().mockResolvedValue(result('agents'));
+            const listTools = vi.fn().mockResolvedValue(result('tools'));
+
+            await withResponseCache(server, 'list_agents', {}, listAgents);
+            await withResponseCache(server, 'list_tools', {}, listTools);
+            await withResponseCache(server, 'delete_agent', { agent_id: 'a' }, async () =>
+                result('deleted'),
+            );
+            await withResponseCache(server, 'list_agents', {}, listAgents);
+            await withResponseCache(server, 'list_tools', {}, listTools);
+
+            expect(listAgents).toHaveBeenCalledTimes(2);
+            expect(listTools).toHaveBeenCalledTimes(1);
+        });
+
+        it('should not cache failures', async () => {
+            const server = makeServer();
+            const call = vi
+                .fn()
+                .mockRejectedValueOnce(new Error('Server error'))
+                .mockResolvedValueOnce(result('agents'));
+
+            await expect(withResponseCache(server, 'list_agents', {}, call)).rejects.toThrow(
+                'Server error',
+            );
+            await withResponseCache(server, 'list_agents', {}, call);
+
+            expect(call).toHaveBeenCalledTimes(2);
+        });
+    });
+});
@@ -0,0 +1,71 @@
+import { describe, it, expect } from 'vitest';
+import {
+    DEFAULT_MAX_CONCURRENCY,
+    callConcurrency,
+    readMaxConcurrency,
+    mapSettledWithConcurrency,
+} from '../../core/concurrency.js';
+
+describe('Concurrency', () => {
+    describe('readMaxConcurrency', () => {
+        it('should default when LETTA_MAX_CONCURRENCY is unset', () => {
+            expect(readMaxConcurrency({})).toBe(DEFAULT_MAX_CONCURRENCY);
+        });
+
+        it('should read LETTA_MAX_CONCURRENCY', () => {
+            expect(readMaxConcurrency({ LETTA_MAX_CONCURRENCY: '4' })).toBe(4);
+        });
+
+        it('should reject zero and non-integers', () => {
+            for (const value of ['0', '-2', 'many']) {
+                expect(() => readMaxConcurrency({ LETTA_MAX_CONCURRENCY: value })).toThrow(
+                    'Invalid environment variable LETTA_MAX_CONCURRENCY',
+                );
+            }
+        });
+    });
+
+    describe('callConcurrency', () => {
+        it('should default to the server limit and never exceed it', () => {
+            const server = { maxConcurrency: 4 };
+            expect(callConcurrency(server)).toBe(4);
+            expect(callConcurrency(server, 2)).toBe(2);
+            expect(callConcurrency(server, 64)).toBe(4);
+        });
+    });
+
+    describe('mapSettledWithConcurrency', () => {
+        it('should keep input order and never exceed the limit', async () => {
+            let inFlight = 0;
+            let peak = 0;
+            const items = [30, 5, 20, 1, 10, 15];
+
+            const results = await mapSettledWithConcurrency(items, 2, async (ms) => {
+                inFlight++;
+                peak = Math.max(peak, inFlight);
+                await new Promise((resolve) => setTimeout(resolve, ms));
+                inFlight--;
+                return ms * 2;
+            });
+
+            expect(peak).toBe(2);
+            expect(results.map((r) => r.value)).toEqual([60, 10, 40, 2, 20, 30]);
+        });
+
+        it('should capture failures per item', async () => {
+            const results = await mapSettledWithConcurrency([1, 2, 3], 3, async (n) => {
+                if (n === 2) throw new Error('boom');
+                return n;
+            });
+
+            expect(results[0]).toEqual({ status: 'fulfilled', value: 1 });
+            expect(results[1].status).toBe('rejected');
+            expect(results[1].reason.message).toBe('boom');
+            expect(results[2]).toEqual({ status: 'fulfilled', value: 3 });
+        });
+
+        it('should handle an empty list', async () => {
+            expect(await mapSettledWithConcurrency([], 4, async () => 1)).toEqual([]);
+        });
+    });
+});
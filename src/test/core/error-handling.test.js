@@ -2,6 +2,8 @@ import { describe, it, expect, beforeEach, vi } from 'vitest';
 import { LettaServer } from '../../core/server.js';
 import { McpError, ErrorCode } from '@modelcontextprotocol/sdk/types.js';
 import { createMockLettaServer } from '../utils/mock-server.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+import { handleModifyAgent } from '../../tools/agents/modify-agent.js';
 
 // Mock dependencies
 vi.mock('@modelcontextprotocol/sdk/server/index.js');
@@ -128,6 +130,20 @@ describe('LettaServer Error Handling (LMP-83)', () => {
                 }
             });
 
+            it('should map request timeouts to RequestTimeout with the limit', () => {
+                const timeoutError = new Error('timeout of 45000ms exceeded');
+                timeoutError.code = 'ECONNABORTED';
+                timeoutError.config = { timeout: 45000 };
+
+                expect(() => server.createErrorResponse(timeoutError)).toThrow(McpError);
+                try {
+                    server.createErrorResponse(timeoutError);
+                } catch (error) {
+                    expect(error.code).toBe(ErrorCode.RequestTimeout);
+                    expect(error.message).toContain('Operation timed out after 45s');
+                }
+            });
+
             it('should map 422 errors to InvalidParams', () => {
                 const error422 = new Error('Validation failed');
                 error422.response = { status: 422 };
@@ -180,6 +196,121 @@ describe('LettaServer Error Handling (LMP-83)', () => {
             });
         });
 
+        describe('Structured Error Data', () => {
+            it('should attach error_code, status and retryable to HTTP errors', () => {
+                const error409 = new Error('Request failed with status code 409');
+                error409.response = { status: 409 };
+
+                try {
+                    server.createErrorResponse(error409);
+                } catch (error) {
+                    expect(error.code).toBe(ErrorCode.InvalidRequest);
+                    expect(error.data).toEqual({
+                        error_code: 'conflict',
+                        status: 409,
+                        retryable: false,
+                    });
+                }
+            });
+
+            it('should mark gateway errors as retryable internal errors', () => {
+                const error503 = new Error('Request failed with status code 503');
+                error503.response = { status: 503 };
+
+                try {
+                    server.createErrorResponse(error503);
+                } catch (error) {
+                    expect(error.code).toBe(ErrorCode.InternalError);
+                    expect(error.data).toEqual({
+                        error_code: 'service_unavailable',
+                        status: 503,
+                        retryable: true,
+                    });
+                }
+            });
+
+            it('should report string errors without a status', () => {
+                try {
+                    server.createErrorResponse('Missing required argument: agent_id');
+                } catch (error) {
+                    expect(error.data).toEqual({
+                        error_code: 'internal_error',
+                        status: null,
+                        retryable: false,
+                    });
+                }
+            });
+
+            it('should report invalid arguments as InvalidParams naming the field', () => {
+                const invalid = invalidRequest('llm_config.model', 'Invalid argument');
+                try {
+                    server.createErrorResponse(invalid);
+                } catch (error) {
+                    expect(error.code).toBe(ErrorCode.InvalidParams);
+                    expect(error.data).toEqual({
+                        error_code: 'invalid_request',
+                        status: null,
+                        retryable: false,
+                        field: 'llm_config.model',
+                    });
+                }
+            });
+
+            it('should report handler not-found errors as not_found', () => {
+                try {
+                    server.createErrorResponse(httpError(404, 'Agent not found: agent-1'));
+                } catch (error) {
+                    expect(error.code).toBe(ErrorCode.InvalidRequest);
+                    expect(error.message).toContain('Agent not found: agent-1');
+                    expect(error.data).toEqual({
+                        error_code: 'not_found',
+                        status: 404,
+                        retryable: false,
+                    });
+                }
+            });
+
+            it('should report a Letta validation failure from a handler with its status', async () => {
+                const rejected = httpError(422, 'Request failed with status code 422');
+                rejected.response.data = { detail: 'name is too long' };
+                server.api = { patch: vi.fn().mockRejectedValue(rejected) };
+
+                let thrown;
+                try {
+                    await handleModifyAgent(server, {
+                        agent_id: 'agent-1',
+                        update_data: { name: 'x'.repeat(500) },
+                    });
+                } catch (error) {
+                    thrown = error;
+                }
+
+                expect(thrown.code).toBe(ErrorCode.InvalidParams);
+                expect(thrown.message).toContain('Failed to update agent agent-1');
+                expect(thrown.message).toContain('name is too long');
+                expect(thrown.data).toEqual({
+                    error_code: 'validation_error',
+                    status: 422,
+                    retryable: false,
+                });
+            });
+
+            it('should rethrow an McpError unchanged so nested reports keep their data', () => {
+                const conflict = new Error('Folder is still attached');
+                conflict.response = { status: 409 };
+
+                let first;
+                try {
+                    server.createErrorResponse(conflict);
+                } catch (error) {
+                    first = error;
+                }
+
+                expect(() => server.createErrorResponse(first, 'Outer context')).toThrow(first);
+                expect(first.data.error_code).toBe('conflict');
+            });
+        });
+
         describe('Response Data Handling', () => {
             it('should include response data in error message', () => {
                 const errorWithData = new Error('API Error');
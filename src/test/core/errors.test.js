@@ -0,0 +1,93 @@
+import { describe, it, expect } from 'vitest';
+import { ErrorCode } from '@modelcontextprotocol/sdk/types.js';
+import { classifyError, invalidRequest } from '../../core/errors.js';
+
+const httpError = (status) =>
+    Object.assign(new Error(`Request failed with status code ${status}`), {
+        response: { status },
+    });
+
+describe('Error Classification', () => {
+    it('should map client errors to InvalidRequest with a specific error_code', () => {
+        const cases = {
+            400: 'bad_request',
+            401: 'unauthorized',
+            403: 'forbidden',
+            404: 'not_found',
+            409: 'conflict',
+            429: 'rate_limited',
+            418: 'client_error',
+        };
+        for (const [status, errorCode] of Object.entries(cases)) {
+            const result = classifyError(httpError(Number(status)));
+            expect(result.code).toBe(ErrorCode.InvalidRequest);
+            expect(result.errorCode).toBe(errorCode);
+            expect(result.status).toBe(Number(status));
+        }
+    });
+
+    it('should map 422 to InvalidParams', () => {
+        const result = classifyError(httpError(422));
+        expect(result.code).toBe(ErrorCode.InvalidParams);
+        expect(result.errorCode).toBe('validation_error');
+        expect(result.message).toContain('Validation error');
+    });
+
+    it('should map server errors to InternalError', () => {
+        expect(classifyError(httpError(500))).toMatchObject({
+            code: ErrorCode.InternalError,
+            errorCode: 'server_error',
+            status: 500,
+            retryable: false,
+        });
+        expect(classifyError(httpError(502))).toMatchObject({
+            errorCode: 'bad_gateway',
+            retryable: true,
+        });
+    });
+
+    it('should classify timeouts and dropped connections', () => {
+        const timeout = Object.assign(new Error('timeout of 5000ms exceeded'), {
+            code: 'ECONNABORTED',
+            config: { timeout: 5000 },
+        });
+        expect(classifyError(timeout)).toMatchObject({
+            code: ErrorCode.RequestTimeout,
+            errorCode: 'timeout',
+            retryable: true,
+        });
+
+        const reset = Object.assign(new Error('socket hang up'), { code: 'ECONNRESET' });
+        expect(classifyError(reset)).toMatchObject({
+            code: ErrorCode.InternalError,
+            errorCode: 'network_error',
+            status: null,
+            retryable: true,
+        });
+    });
+
+    it('should classify strings and non-errors as internal', () => {
+        expect(classifyError('Something broke')).toMatchObject({
+            code: ErrorCode.InternalError,
+            errorCode: 'internal_error',
+            message: 'Something broke',
+        });
+        expect(classifyError(null)).toMatchObject({
+            errorCode: 'unknown_error',
+            message: 'Unknown error occurred',
+        });
+    });
+
+    it('should classify locally rejected arguments as invalid_request', () => {
+        const error = invalidRequest('embedding_config.embedding_dim', 'Invalid argument');
+
+        expect(classifyError(error)).toEqual({
+            code: ErrorCode.InvalidParams,
+            errorCode: 'invalid_request',
+            status: null,
+            retryable: false,
+            message: 'Invalid argument',
+        });
+        expect(error.field).toBe('embedding_config.embedding_dim');
+    });
+});
@@ -0,0 +1,73 @@
+import { describe, it, expect, beforeEach, afterEach } from 'vitest';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { readExportDir, resolveExportPath } from '../../core/export-dir.js';
+
+describe('Export Directory', () => {
+    let root;
+
+    beforeEach(() => {
+        root = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-exports-'));
+    });
+
+    afterEach(() => {
+        fs.rmSync(root, { recursive: true, force: true });
+    });
+
+    describe('readExportDir', () => {
+        it('should leave exports unrestricted when unset', () => {
+            expect(readExportDir({})).toBeNull();
+            expect(readExportDir({ LETTA_EXPORT_DIR: ' ' })).toBeNull();
+        });
+
+        it('should resolve the directory to an absolute path', () => {
+            expect(readExportDir({ LETTA_EXPORT_DIR: 'exports' })).toBe(path.resolve('exports'));
+        });
+    });
+
+    describe('resolveExportPath', () => {
+        it('should resolve paths inside the directory and create their parents', () => {
+            const target = resolveExportPath(root, 'ci/nightly/agent.json');
+
+            expect(target).toBe(path.join(root, 'ci', 'nightly', 'agent.json'));
+            expect(fs.statSync(path.join(root, 'ci', 'nightly')).isDirectory()).toBe(true);
+        });
+
+        it('should allow absolute paths that are inside the directory', () => {
+            const inside = path.join(root, 'agent.json');
+
+            expect(resolveExportPath(root, inside)).toBe(inside);
+        });
+
+        it('should refuse paths that escape the directory', () => {
+            const escapes = ['../agent.json', 'a/../../agent.json', '/etc/agent.json', '.'];
+            for (const requested of escapes) {
+                expect(() => resolveExportPath(root, requested)).toThrow(
+                    'Invalid argument: output_path must be inside the export directory',
+                );
+            }
+        });
+
+        it('should refuse paths through a symlink that points outside', () => {
+            const outside = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-outside-'));
+            fs.symlinkSync(outside, path.join(root, 'link'));
+            try {
+                expect(() => resolveExportPath(root, 'link/nested/agent.json')).toThrow(
+                    'must be inside the export directory',
+                );
+                expect(fs.existsSync(path.join(outside, 'nested'))).toBe(false);
+            } finally {
+                fs.rmSync(outside, { recursive: true, force: true });
+            }
+        });
+
+        it('should refuse to write through an existing symlink', () => {
+            fs.symlinkSync('/etc/passwd', path.join(root, 'agent.json'));
+
+            expect(() => resolveExportPath(root, 'agent.json')).toThrow(
+                'must be inside the export directory',
+            );
+        });
+    });
+});
@@ -0,0 +1,175 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+    DEFAULT_IDEMPOTENCY_TTL_SECS,
+    IdempotencyStore,
+    readIdempotencyTtlSecs,
+    withIdempotency,
+} from '../../core/idempotency.js';
+import { createMockLettaServer } from '../utils/mock-server.js';
+
+const result = (text) => ({ content: [{ type: 'text', text }] });
+
+describe('Idempotency Keys', () => {
+    describe('readIdempotencyTtlSecs', () => {
+        it('should default to ten minutes', () => {
+            expect(readIdempotencyTtlSecs({})).toBe(DEFAULT_IDEMPOTENCY_TTL_SECS);
+        });
+
+        it('should read LETTA_IDEMPOTENCY_TTL_SECS', () => {
+            expect(readIdempotencyTtlSecs({ LETTA_IDEMPOTENCY_TTL_SECS: '0' })).toBe(0);
+        });
+
+        it('should reject negative or non-numeric values', () => {
+            for (const value of ['-1', 'later']) {
+                expect(() =>
+                    readIdempotencyTtlSecs({ LETTA_IDEMPOTENCY_TTL_SECS: value }),
+                ).toThrow('Invalid environment variable LETTA_IDEMPOTENCY_TTL_SECS');
+            }
+        });
+    });
+
+    describe('IdempotencyStore', () => {
+        it('should forget a key once the TTL has passed', async () => {
+            let now = 0;
+            const store = new IdempotencyStore(10, () => now);
+            const call = vi.fn().mockResolvedValue(result('created'));
+
+            await store.run('key', '{}', call);
+            now = 9_999;
+            await store.run('key', '{}', call);
+            expect(call).toHaveBeenCalledTimes(1);
+
+            now = 10_000;
+            await store.run('key', '{}', call);
+            expect(call).toHaveBeenCalledTimes(2);
+        });
+    });
+
+    describe('withIdempotency', () => {
+        const makeServer = (ttlSecs = 60) => {
+            const server = createMockLettaServer();
+            server.idempotency = new IdempotencyStore(ttlSecs);
+            server.scope = {};
+            return server;
+        };
+
+        it('should return the first result for a repeated key', async () => {
+            const server = makeServer();
+            const call = vi
+                .fn()
+                .mockResolvedValueOnce(result('agent-1'))
+                .mockResolvedValueOnce(result('agent-2'));
+            const args = { name: 'Bot', description: 'Test', idempotency_key: 'abc' };
+
+            const first = await withIdempotency(server, 'create_agent', args, call);
+            const retry = await withIdempotency(server, 'create_agent', { ...args }, call);
+
+            expect(call).toHaveBeenCalledTimes(1);
+            expect(retry).toBe(first);
+        });
+
+        it('should share a call that is still running', async () => {
+            const server = makeServer();
+            let finish;
+            const call = vi.fn(
+                () =>
+                    new Promise((resolve) => {
+                        finish = resolve;
+                    }),
+            );
+            const args = { name: 'Bot', idempotency_key: 'abc' };
+
+            const first = withIdempotency(server, 'create_folder', args, call);
+            const retry = withIdempotency(server, 'create_folder', args, call);
+            finish(result('folder-1'));
+
+            expect(await retry).toBe(await first);
+            expect(call).toHaveBeenCalledTimes(1);
+        });
+
+        it('should allow a retry after the first call failed', async () => {
+            const server = makeServer();
+            const call = vi
+                .fn()
+                .mockRejectedValueOnce(new Error('Bad gateway'))
+                .mockResolvedValueOnce(result('tool-1'));
+            const args = { name: 'tool', idempotency_key: 'abc' };
+
+            await expect(withIdempotency(server, 'upload_tool', args, call)).rejects.toThrow(
+                'Bad gateway',
+            );
+            await expect(withIdempotency(server, 'upload_tool', args, call)).resolves.toEqual(
+                result('tool-1'),
+            );
+            expect(call).toHaveBeenCalledTimes(2);
+        });
+
+        it('should reject a key reused with different arguments', async () => {
+            const server = makeServer();
+            const call = vi.fn().mockResolvedValue(result('agent-1'));
+
+            const args = { name: 'A', idempotency_key: 'k' };
+            await withIdempotency(server, 'create_agent', args, call);
+
+            await expect(
+                withIdempotency(server, 'create_agent', { ...args, name: 'B' }, call),
+            ).rejects.toThrow('idempotency_key was already used with different arguments');
+            expect(server.createErrorResponse).toHaveBeenCalledWith(
+                expect.objectContaining({ response: { status: 409 } }),
+            );
+        });
+
+        it('should ignore argument order and response format arguments', async () => {
+            const server = makeServer();
+            const call = vi.fn().mockResolvedValue(result('agent-1'));
+
+            await withIdempotency(
+                server,
+                'create_agent',
+                { name: 'A', llm_config: { model: 'm', context_window: 8 }, idempotency_key: 'k' },
+                call,
+            );
+            await withIdempotency(
+                server,
+                'create_agent',
+                {
+                    idempotency_key: 'k',
+                    llm_config: { context_window: 8, model: 'm' },
+                    name: 'A',
+                    pretty_json: true,
+                    format: 'yaml',
+                },
+                call,
+            );
+
+            expect(call).toHaveBeenCalledTimes(1);
+        });
+
+        it('should keep keys separate per tool', async () => {
+            const server = makeServer();
+            const call = vi.fn().mockResolvedValue(result('created'));
+
+            await withIdempotency(server, 'create_agent', { idempotency_key: 'k' }, call);
+            await withIdempotency(server, 'create_folder', { idempotency_key: 'k' }, call);
+
+            expect(call).toHaveBeenCalledTimes(2);
+        });
+
+        it('should call through without a key, for other tools, or when disabled', async () => {
+            const call = vi.fn().mockResolvedValue(result('ok'));
+
+            await withIdempotency(makeServer(), 'create_agent', { name: 'A' }, call);
+            await withIdempotency(makeServer(), 'create_agent', { name: 'A' }, call);
+            await withIdempotency(makeServer(), 'delete_agent', { idempotency_key: 'k' }, call);
+            await withIdempotency(makeServer(0), 'create_agent', { idempotency_key: 'k' }, call);
+
+            expect(call).toHaveBeenCalledTimes(4);
+        });
+
+        it('should reject a non-string key', async () => {
+            await expect(
+                withIdempotency(makeServer(), 'create_agent', { idempotency_key: 42 }, vi.fn()),
+            ).rejects.toThrow('idempotency_key must be a non-empty string');
+        });
+    });
+});
@@ -0,0 +1,27 @@
+import { describe, it, expect } from 'vitest';
+import { isAlreadyAbsent, validateIgnoreMissing } from '../../core/ignore-missing.js';
+import { createMockLettaServer } from '../utils/mock-server.js';
+
+const notFound = { response: { status: 404 } };
+
+describe('ignore_missing', () => {
+    it('should treat only a 404 as already absent, and only when asked', () => {
+        expect(isAlreadyAbsent({ ignore_missing: true }, notFound)).toBe(true);
+        expect(isAlreadyAbsent({ ignore_missing: false }, notFound)).toBe(false);
+        expect(isAlreadyAbsent({}, notFound)).toBe(false);
+        expect(isAlreadyAbsent({ ignore_missing: true }, { response: { status: 500 } })).toBe(
+            false,
+        );
+        expect(isAlreadyAbsent({ ignore_missing: true }, new Error('Network Error'))).toBe(false);
+    });
+
+    it('should require a boolean', () => {
+        const server = createMockLettaServer();
+
+        expect(() => validateIgnoreMissing(server, {})).not.toThrow();
+        expect(() => validateIgnoreMissing(server, { ignore_missing: true })).not.toThrow();
+        expect(() => validateIgnoreMissing(server, { ignore_missing: 1 })).toThrow(
+            'Invalid argument: ignore_missing must be a boolean',
+        );
+    });
+});
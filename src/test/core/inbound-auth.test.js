@@ -0,0 +1,61 @@
+import { describe, it, expect, vi } from 'vitest';
+import { bearerAuthMiddleware, isAuthorized, readAuthToken } from '../../core/inbound-auth.js';
+
+const mockResponse = () => {
+    const res = { headers: {} };
+    res.set = vi.fn((name, value) => {
+        res.headers[name] = value;
+        return res;
+    });
+    res.status = vi.fn(() => res);
+    res.json = vi.fn(() => res);
+    return res;
+};
+
+describe('Inbound Auth', () => {
+    it('should be off unless LETTA_MCP_AUTH_TOKEN is set', () => {
+        expect(readAuthToken({})).toBeUndefined();
+        expect(readAuthToken({ LETTA_MCP_AUTH_TOKEN: '' })).toBeUndefined();
+        expect(readAuthToken({ LETTA_MCP_AUTH_TOKEN: 's3cret' })).toBe('s3cret');
+    });
+
+    it('should accept only a matching bearer token', () => {
+        expect(isAuthorized('Bearer s3cret', 's3cret')).toBe(true);
+        expect(isAuthorized('bearer s3cret', 's3cret')).toBe(true);
+        expect(isAuthorized('Bearer wrong', 's3cret')).toBe(false);
+        expect(isAuthorized('Basic s3cret', 's3cret')).toBe(false);
+        expect(isAuthorized(undefined, 's3cret')).toBe(false);
+    });
+
+    it('should reject requests without the token with 401', () => {
+        const middleware = bearerAuthMiddleware('s3cret');
+        const next = vi.fn();
+        const res = mockResponse();
+
+        middleware({ headers: {}, ip: '1.1.1.1' }, res, next);
+
+        expect(next).not.toHaveBeenCalled();
+        expect(res.status).toHaveBeenCalledWith(401);
+        expect(res.headers['WWW-Authenticate']).toBe('Bearer');
+        expect(res.json.mock.calls[0][0].error.message).toBe(
+            'Unauthorized: missing or invalid bearer token',
+        );
+    });
+
+    it('should let authorized requests through', () => {
+        const middleware = bearerAuthMiddleware('s3cret');
+        const next = vi.fn();
+
+        middleware({ headers: { authorization: 'Bearer s3cret' } }, mockResponse(), next);
+
+        expect(next).toHaveBeenCalled();
+    });
+
+    it('should let everything through when no token is configured', () => {
+        const next = vi.fn();
+
+        bearerAuthMiddleware(undefined)({ headers: {} }, mockResponse(), next);
+
+        expect(next).toHaveBeenCalled();
+    });
+});
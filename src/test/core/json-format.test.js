@@ -0,0 +1,116 @@
+import { describe, it, expect } from 'vitest';
+import {
+    prettyPrintResult,
+    readJsonCompact,
+    withJsonFormat,
+    yamlResult,
+} from '../../core/json-format.js';
+import { createMockLettaServer } from '../utils/mock-server.js';
+
+const result = (text) => ({ content: [{ type: 'text', text }] });
+
+describe('JSON Response Format', () => {
+    describe('readJsonCompact', () => {
+        it('should default to compact JSON', () => {
+            expect(readJsonCompact({})).toBe(true);
+            expect(readJsonCompact({ LETTA_JSON_COMPACT: 'true' })).toBe(true);
+        });
+
+        it('should switch to pretty-printing when LETTA_JSON_COMPACT is false', () => {
+            expect(readJsonCompact({ LETTA_JSON_COMPACT: 'false' })).toBe(false);
+        });
+    });
+
+    describe('prettyPrintResult', () => {
+        it('should indent JSON text and leave other content alone', () => {
+            const image = { type: 'image', data: 'abc', mimeType: 'image/png' };
+            const formatted = prettyPrintResult({
+                content: [
+                    { type: 'text', text: '{"a":1}' },
+                    { type: 'text', text: 'plain' },
+                    image,
+                ],
+            });
+
+            expect(formatted.content).toEqual([
+                { type: 'text', text: '{\n  "a": 1\n}' },
+                { type: 'text', text: 'plain' },
+                image,
+            ]);
+        });
+    });
+
+    describe('yamlResult', () => {
+        it('should convert JSON text to YAML and leave other text alone', () => {
+            const formatted = yamlResult({
+                content: [
+                    { type: 'text', text: '{"a":1,"b":["x"]}' },
+                    { type: 'text', text: 'plain' },
+                ],
+            });
+
+            expect(formatted.content).toEqual([
+                { type: 'text', text: 'a: 1\nb:\n- x\n' },
+                { type: 'text', text: 'plain' },
+            ]);
+        });
+    });
+
+    describe('withJsonFormat', () => {
+        it('should leave responses compact by default', async () => {
+            const server = createMockLettaServer();
+
+            const formatted = await withJsonFormat(server, {}, async () => result('{"a":1}'));
+
+            expect(formatted.content[0].text).toBe('{"a":1}');
+        });
+
+        it('should pretty-print when the server is configured for it', async () => {
+            const server = createMockLettaServer();
+            server.jsonCompact = false;
+
+            const formatted = await withJsonFormat(server, {}, async () => result('{"a":1}'));
+
+            expect(formatted.content[0].text).toBe('{\n  "a": 1\n}');
+        });
+
+        it('should let the call override the server setting', async () => {
+            const server = createMockLettaServer();
+            server.jsonCompact = false;
+
+            const formatted = await withJsonFormat(server, { pretty_json: false }, async () =>
+                result('{"a":1}'),
+            );
+
+            expect(formatted.content[0].text).toBe('{"a":1}');
+        });
+
+        it('should reject a non-boolean pretty_json', async () => {
+            const server = createMockLettaServer();
+
+            await expect(
+                withJsonFormat(server, { pretty_json: 'yes' }, async () => result('{}')),
+            ).rejects.toThrow('Invalid argument: pretty_json must be a boolean');
+        });
+
+        it('should return YAML when format is yaml, whatever the layout', async () => {
+            const server = createMockLettaServer();
+
+            const formatted = await withJsonFormat(
+                server,
+                { format: 'yaml', pretty_json: true },
+                async () => result('{"a":1}'),
+            );
+
+            expect(formatted.content[0].text).toBe('a: 1\n');
+        });
+
+        it('should reject an unknown format', async () => {
+            const server = createMockLettaServer();
+
+            await expect(
+                withJsonFormat(server, { format: 'xml' }, async () => result('{}')),
+            ).rejects.toThrow('Invalid argument: format must be "json" or "yaml"');
+        });
+    });
+});
@@ -0,0 +1,77 @@
+import { describe, it, expect } from 'vitest';
+import { DEFAULT_API_KEY_HEADER, lettaAuthHeaders, readLettaAuth } from '../../core/letta-auth.js';
+
+describe('Letta Auth', () => {
+    describe('readLettaAuth', () => {
+        it('should default to bearer with LETTA_PASSWORD', () => {
+            expect(readLettaAuth({ LETTA_PASSWORD: 'pw' })).toEqual({
+                mode: 'bearer',
+                secret: 'pw',
+            });
+        });
+
+        it('should fall back to none when LETTA_PASSWORD is unset or empty', () => {
+            expect(readLettaAuth({})).toEqual({ mode: 'none', secret: '' });
+            expect(readLettaAuth({ LETTA_AUTH_MODE: 'bearer', LETTA_PASSWORD: '' })).toEqual({
+                mode: 'none',
+                secret: '',
+            });
+        });
+
+        it('should read an API key and header', () => {
+            expect(
+                readLettaAuth({
+                    LETTA_AUTH_MODE: 'api_key',
+                    LETTA_API_KEY: 'sk-123',
+                    LETTA_API_KEY_HEADER: 'X-Letta-Key',
+                }),
+            ).toEqual({ mode: 'api_key', secret: 'sk-123', header: 'X-Letta-Key' });
+        });
+
+        it('should fall back to LETTA_PASSWORD and the default header for api_key', () => {
+            expect(readLettaAuth({ LETTA_AUTH_MODE: 'API_KEY', LETTA_PASSWORD: 'pw' })).toEqual({
+                mode: 'api_key',
+                secret: 'pw',
+                header: DEFAULT_API_KEY_HEADER,
+            });
+        });
+
+        it('should require a key in api_key mode', () => {
+            expect(() => readLettaAuth({ LETTA_AUTH_MODE: 'api_key' })).toThrow(
+                'LETTA_AUTH_MODE=api_key requires LETTA_API_KEY',
+            );
+        });
+
+        it('should ignore credentials in none mode', () => {
+            expect(readLettaAuth({ LETTA_AUTH_MODE: 'none', LETTA_PASSWORD: 'pw' })).toEqual({
+                mode: 'none',
+                secret: '',
+            });
+        });
+
+        it('should reject an unknown mode', () => {
+            expect(() => readLettaAuth({ LETTA_AUTH_MODE: 'basic' })).toThrow(
+                'Invalid environment variable LETTA_AUTH_MODE: expected one of bearer, api_key, none',
+            );
+        });
+    });
+
+    describe('lettaAuthHeaders', () => {
+        it('should send the password as bearer and bare password headers', () => {
+            expect(lettaAuthHeaders({ mode: 'bearer', secret: 'pw' })).toEqual({
+                'X-BARE-PASSWORD': 'password pw',
+                Authorization: 'Bearer pw',
+            });
+        });
+
+        it('should send the API key in its header only', () => {
+            expect(
+                lettaAuthHeaders({ mode: 'api_key', secret: 'sk-123', header: 'X-API-Key' }),
+            ).toEqual({ 'X-API-Key': 'sk-123' });
+        });
+
+        it('should send no credentials in none mode', () => {
+            expect(lettaAuthHeaders({ mode: 'none', secret: '' })).toEqual({});
+        });
+    });
+});
@@ -0,0 +1,74 @@
+import { describe, it, expect, vi } from 'vitest';
+import { MetricsRegistry, metricsToolName, withMetrics } from '../../core/metrics.js';
+
+describe('Metrics', () => {
+    describe('MetricsRegistry', () => {
+        it('should render counters and histograms in Prometheus format', () => {
+            const metrics = new MetricsRegistry();
+            metrics.recordToolCall('list_agents', 'success', 0.2);
+            metrics.recordToolCall('list_agents', 'success', 3);
+            metrics.recordToolCall('list_agents', 'error', 0.01);
+
+            const text = metrics.render();
+
+            expect(text).toContain('# TYPE letta_mcp_tool_calls_total counter');
+            expect(text).toContain(
+                'letta_mcp_tool_calls_total{tool="list_agents",outcome="success"} 2',
+            );
+            expect(text).toContain(
+                'letta_mcp_tool_calls_total{tool="list_agents",outcome="error"} 1',
+            );
+            expect(text).toContain(
+                'letta_mcp_tool_call_duration_seconds_bucket{tool="list_agents",le="0.25"} 2',
+            );
+            expect(text).toContain(
+                'letta_mcp_tool_call_duration_seconds_bucket{tool="list_agents",le="+Inf"} 3',
+            );
+            expect(text).toContain(
+                'letta_mcp_tool_call_duration_seconds_sum{tool="list_agents"} 3.21',
+            );
+            expect(text).toContain(
+                'letta_mcp_tool_call_duration_seconds_count{tool="list_agents"} 3',
+            );
+        });
+
+        it('should escape label values', () => {
+            const metrics = new MetricsRegistry();
+            metrics.recordToolCall('bad"name', 'error', 0);
+
+            expect(metrics.render()).toContain('tool="bad\\"name"');
+        });
+    });
+
+    describe('withMetrics', () => {
+        it('should record successful calls', async () => {
+            const server = { metrics: new MetricsRegistry() };
+            const result = await withMetrics(server, 'get_job', async () => 'ok');
+
+            expect(result).toBe('ok');
+            expect(server.metrics.render()).toContain(
+                'letta_mcp_tool_calls_total{tool="get_job",outcome="success"} 1',
+            );
+        });
+
+        it('should record and rethrow failures', async () => {
+            const server = { metrics: new MetricsRegistry() };
+            const call = vi.fn().mockRejectedValue(new Error('Server error'));
+
+            await expect(withMetrics(server, 'get_job', call)).rejects.toThrow('Server error');
+            expect(server.metrics.render()).toContain(
+                'letta_mcp_tool_calls_total{tool="get_job",outcome="error"} 1',
+            );
+        });
+    });
+
+    describe('metricsToolName', () => {
+        it('should keep registered tool names and bucket the rest as unknown', () => {
+            const known = new Set(['get_job', 'list_agents']);
+
+            expect(metricsToolName('get_job', known)).toBe('get_job');
+            expect(metricsToolName('get_job_<random>', known)).toBe('unknown');
+            expect(metricsToolName(undefined, known)).toBe('unknown');
+        });
+    });
+});
@@ -0,0 +1,118 @@
+import { describe, it, expect } from 'vitest';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import {
+    readDefaultModelConfigs,
+    validateEmbeddingConfig,
+    validateLlmConfig,
+} from '../../core/model-config.js';
+
+const llmConfig = { model: 'gpt-4o', model_endpoint_type: 'openai', context_window: 128000 };
+const embeddingConfig = {
+    embedding_endpoint_type: 'openai',
+    embedding_model: 'text-embedding-3-small',
+    embedding_dim: 1536,
+};
+
+/**
+ * Run a validator and return the error it throws
+ */
+const rejection = (validate, config) => {
+    try {
+        validate(config);
+    } catch (error) {
+        return error;
+    }
+    throw new Error('Expected the config to be rejected');
+};
+
+describe('Model Config Validation', () => {
+    describe('validateLlmConfig', () => {
+        it('should accept a complete config, including fields it does not know', () => {
+            expect(() =>
+                validateLlmConfig({ ...llmConfig, temperature: 0.2, max_tokens: null, extra: 1 }),
+            ).not.toThrow();
+        });
+
+        it('should name a missing required field', () => {
+            const { context_window: _omitted, ...config } = llmConfig;
+
+            const error = rejection(validateLlmConfig, config);
+
+            expect(error.message).toBe('Missing required argument: llm_config.context_window');
+            expect(error.errorCode).toBe('invalid_request');
+            expect(error.field).toBe('llm_config.context_window');
+        });
+
+        it('should name a field of the wrong type or out of range', () => {
+            expect(rejection(validateLlmConfig, { ...llmConfig, model: ' ' }).message).toBe(
+                'Invalid argument: llm_config.model must be a non-empty string',
+            );
+            expect(rejection(validateLlmConfig, { ...llmConfig, context_window: 0 }).message).toBe(
+                'Invalid argument: llm_config.context_window must be at least 1',
+            );
+            expect(rejection(validateLlmConfig, { ...llmConfig, temperature: 3 }).message).toBe(
+                'Invalid argument: llm_config.temperature must be from 0 to 2',
+            );
+        });
+
+        it('should reject a config that is not an object', () => {
+            const error = rejection(validateLlmConfig, '{"model": "gpt-4o"}');
+
+            expect(error.message).toBe('Invalid argument: llm_config must be an object');
+            expect(error.field).toBe('llm_config');
+        });
+    });
+
+    describe('validateEmbeddingConfig', () => {
+        it('should accept a complete config', () => {
+            expect(() => validateEmbeddingConfig(embeddingConfig)).not.toThrow();
+        });
+
+        it('should name a missing or invalid field', () => {
+            const { embedding_model: _omitted, ...config } = embeddingConfig;
+
+            expect(rejection(validateEmbeddingConfig, config).field).toBe(
+                'embedding_config.embedding_model',
+            );
+            expect(
+                rejection(validateEmbeddingConfig, { ...embeddingConfig, embedding_dim: 1.5 })
+                    .message,
+            ).toBe('Invalid argument: embedding_config.embedding_dim must be an integer');
+        });
+    });
+
+    describe('readDefaultModelConfigs', () => {
+        it('should have no defaults when unset', () => {
+            expect(readDefaultModelConfigs({})).toEqual({ llmConfig: null, embeddingConfig: null });
+        });
+
+        it('should read inline JSON and JSON files', () => {
+            const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-config-'));
+            const file = path.join(dir, 'embedding.json');
+            fs.writeFileSync(file, JSON.stringify(embeddingConfig));
+            try {
+                expect(
+                    readDefaultModelConfigs({
+                        LETTA_DEFAULT_LLM_CONFIG: JSON.stringify(llmConfig),
+                        LETTA_DEFAULT_EMBEDDING_CONFIG: file,
+                    }),
+                ).toEqual({ llmConfig, embeddingConfig });
+            } finally {
+                fs.rmSync(dir, { recursive: true, force: true });
+            }
+        });
+
+        it('should refuse to start with an invalid default', () => {
+            expect(() =>
+                readDefaultModelConfigs({ LETTA_DEFAULT_LLM_CONFIG: '{"model": "gpt-4o"}' }),
+            ).toThrow(
+                'LETTA_DEFAULT_LLM_CONFIG: Missing required argument: llm_config.model_endpoint_type',
+            );
+            expect(() =>
+                readDefaultModelConfigs({ LETTA_DEFAULT_EMBEDDING_CONFIG: '/missing/file.json' }),
+            ).toThrow('Invalid environment variable LETTA_DEFAULT_EMBEDDING_CONFIG');
+        });
+    });
+});
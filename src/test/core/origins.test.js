@@ -0,0 +1,61 @@
+import { describe, it, expect } from 'vitest';
+import {
+    DEFAULT_ALLOWED_ORIGINS,
+    isOriginAllowed,
+    readAllowedOrigins,
+} from '../../core/origins.js';
+
+describe('Allowed Origins', () => {
+    describe('readAllowedOrigins', () => {
+        it('should use the defaults when LETTA_ALLOWED_ORIGINS is unset', () => {
+            expect(readAllowedOrigins({})).toEqual(DEFAULT_ALLOWED_ORIGINS);
+            expect(readAllowedOrigins({ LETTA_ALLOWED_ORIGINS: ' ' })).toEqual(
+                DEFAULT_ALLOWED_ORIGINS,
+            );
+        });
+
+        it('should parse a comma-separated list', () => {
+            expect(
+                readAllowedOrigins({
+                    LETTA_ALLOWED_ORIGINS: 'https://app.example.com/, http://localhost:8080',
+                }),
+            ).toEqual(['https://app.example.com', 'http://localhost:8080']);
+        });
+
+        it('should allow any origin for "*"', () => {
+            expect(readAllowedOrigins({ LETTA_ALLOWED_ORIGINS: '*' })).toBeNull();
+        });
+
+        it('should reject entries that are not origins', () => {
+            for (const value of ['example.com', 'https://example.com/app']) {
+                expect(() => readAllowedOrigins({ LETTA_ALLOWED_ORIGINS: value })).toThrow(
+                    'Invalid environment variable LETTA_ALLOWED_ORIGINS',
+                );
+            }
+        });
+    });
+
+    describe('isOriginAllowed', () => {
+        const allowed = ['https://app.example.com', 'http://localhost', 'http://10.0.0.5:8080'];
+
+        it('should match listed origins exactly', () => {
+            expect(isOriginAllowed('https://app.example.com', allowed)).toBe(true);
+            expect(isOriginAllowed('http://app.example.com', allowed)).toBe(false);
+        });
+
+        it('should accept any port only for entries without one', () => {
+            expect(isOriginAllowed('http://localhost:5173', allowed)).toBe(true);
+            expect(isOriginAllowed('http://10.0.0.5:8080', allowed)).toBe(true);
+            expect(isOriginAllowed('http://10.0.0.5:9090', allowed)).toBe(false);
+        });
+
+        it('should not accept hosts that merely share a prefix', () => {
+            expect(isOriginAllowed('https://app.example.com.evil.net', allowed)).toBe(false);
+            expect(isOriginAllowed('http://localhost.evil.net', allowed)).toBe(false);
+        });
+
+        it('should accept anything when unrestricted', () => {
+            expect(isOriginAllowed('http://evil.com', null)).toBe(true);
+        });
+    });
+});
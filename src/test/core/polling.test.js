@@ -0,0 +1,68 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+    DEFAULT_WAIT_SECS,
+    DEFAULT_POLL_INTERVAL_SECS,
+    readPollOptions,
+    pollUntil,
+} from '../../core/polling.js';
+
+describe('Polling', () => {
+    describe('readPollOptions', () => {
+        it('should apply defaults', () => {
+            expect(readPollOptions({})).toEqual({
+                waitSecs: DEFAULT_WAIT_SECS,
+                intervalSecs: DEFAULT_POLL_INTERVAL_SECS,
+            });
+        });
+
+        it('should allow a zero wait but not a zero interval', () => {
+            expect(readPollOptions({ wait_secs: 0 }).waitSecs).toBe(0);
+            expect(() => readPollOptions({ poll_interval_secs: 0 })).toThrow(
+                'Invalid argument: poll_interval_secs',
+            );
+            expect(() => readPollOptions({ wait_secs: 10_000 })).toThrow(
+                'Invalid argument: wait_secs',
+            );
+        });
+
+        it('should report bad options as invalid requests naming the argument', () => {
+            let error;
+            try {
+                readPollOptions({ wait_secs: -1 });
+            } catch (thrown) {
+                error = thrown;
+            }
+            expect(error).toMatchObject({ errorCode: 'invalid_request', field: 'wait_secs' });
+        });
+    });
+
+    describe('pollUntil', () => {
+        it('should stop as soon as the value is done', async () => {
+            const fetch = vi
+                .fn()
+                .mockResolvedValueOnce('pending')
+                .mockResolvedValueOnce('done')
+                .mockResolvedValue('unreachable');
+
+            const result = await pollUntil(fetch, (value) => value === 'done', {
+                waitSecs: 5,
+                intervalSecs: 0.001,
+            });
+
+            expect(result).toMatchObject({ value: 'done', done: true, polls: 2 });
+        });
+
+        it('should return the latest value when the wait elapses', async () => {
+            const fetch = vi.fn().mockResolvedValue('pending');
+
+            const result = await pollUntil(fetch, () => false, {
+                waitSecs: 0.02,
+                intervalSecs: 0.005,
+            });
+
+            expect(result.done).toBe(false);
+            expect(result.value).toBe('pending');
+            expect(result.polls).toBeGreaterThan(1);
+        });
+    });
+});
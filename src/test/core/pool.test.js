@@ -0,0 +1,62 @@
+import { describe, it, expect } from 'vitest';
+import {
+    DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+    DEFAULT_POOL_MAX_IDLE,
+    createPoolAgents,
+    readPoolOptions,
+} from '../../core/pool.js';
+
+describe('Connection Pool', () => {
+    describe('readPoolOptions', () => {
+        it('should use defaults when unset', () => {
+            expect(readPoolOptions({})).toEqual({
+                maxIdle: DEFAULT_POOL_MAX_IDLE,
+                idleTimeoutSecs: DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+            });
+        });
+
+        it('should read both settings', () => {
+            expect(
+                readPoolOptions({ LETTA_POOL_MAX_IDLE: '0', LETTA_POOL_IDLE_TIMEOUT_SECS: '30' }),
+            ).toEqual({ maxIdle: 0, idleTimeoutSecs: 30 });
+        });
+
+        it('should reject invalid values', () => {
+            expect(() => readPoolOptions({ LETTA_POOL_MAX_IDLE: '-1' })).toThrow(
+                'Invalid environment variable LETTA_POOL_MAX_IDLE',
+            );
+            expect(() => readPoolOptions({ LETTA_POOL_IDLE_TIMEOUT_SECS: '0' })).toThrow(
+                'Invalid environment variable LETTA_POOL_IDLE_TIMEOUT_SECS',
+            );
+        });
+    });
+
+    describe('createPoolAgents', () => {
+        it('should keep idle connections alive up to the limit', () => {
+            const { httpAgent, httpsAgent } = createPoolAgents({
+                maxIdle: 10,
+                idleTimeoutSecs: 90,
+            });
+
+            for (const agent of [httpAgent, httpsAgent]) {
+                expect(agent.keepAlive).toBe(true);
+                expect(agent.maxFreeSockets).toBe(10);
+                expect(agent.options.timeout).toBe(90000);
+            }
+        });
+
+        it('should disable keep-alive when maxIdle is 0', () => {
+            const { httpsAgent } = createPoolAgents({ maxIdle: 0, idleTimeoutSecs: 90 });
+            expect(httpsAgent.keepAlive).toBe(false);
+        });
+
+        it('should pass TLS options to the https agent only', () => {
+            const { httpAgent, httpsAgent } = createPoolAgents(
+                { maxIdle: 10, idleTimeoutSecs: 90 },
+                { cert: 'CERT', key: 'KEY' },
+            );
+            expect(httpsAgent.options).toMatchObject({ cert: 'CERT', key: 'KEY', keepAlive: true });
+            expect(httpAgent.options).not.toHaveProperty('cert');
+        });
+    });
+});
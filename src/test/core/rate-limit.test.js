@@ -0,0 +1,101 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+    DEFAULT_RATE_LIMIT,
+    DEFAULT_RATE_WINDOW_SECS,
+    RateLimiter,
+    rateLimitMiddleware,
+    readRateLimit,
+} from '../../core/rate-limit.js';
+
+const mockResponse = () => {
+    const res = { headers: {} };
+    res.set = vi.fn((name, value) => {
+        res.headers[name] = value;
+        return res;
+    });
+    res.status = vi.fn(() => res);
+    res.json = vi.fn(() => res);
+    return res;
+};
+
+describe('Rate Limit', () => {
+    describe('readRateLimit', () => {
+        it('should use defaults when unset', () => {
+            expect(readRateLimit({})).toEqual({
+                limit: DEFAULT_RATE_LIMIT,
+                windowSecs: DEFAULT_RATE_WINDOW_SECS,
+            });
+        });
+
+        it('should read LETTA_RATE_LIMIT and LETTA_RATE_WINDOW_SECS', () => {
+            expect(
+                readRateLimit({ LETTA_RATE_LIMIT: '10', LETTA_RATE_WINDOW_SECS: '5' }),
+            ).toEqual({ limit: 10, windowSecs: 5 });
+            expect(readRateLimit({ LETTA_RATE_LIMIT: '0' }).limit).toBe(0);
+        });
+
+        it('should reject invalid values', () => {
+            expect(() => readRateLimit({ LETTA_RATE_LIMIT: '-1' })).toThrow(
+                'Invalid environment variable LETTA_RATE_LIMIT',
+            );
+            expect(() => readRateLimit({ LETTA_RATE_WINDOW_SECS: '0' })).toThrow(
+                'Invalid environment variable LETTA_RATE_WINDOW_SECS',
+            );
+        });
+    });
+
+    describe('RateLimiter', () => {
+        it('should allow up to the limit per window and per key', () => {
+            let now = 0;
+            const limiter = new RateLimiter({ limit: 2, windowSecs: 10 }, () => now);
+
+            expect(limiter.hit('1.1.1.1').allowed).toBe(true);
+            expect(limiter.hit('1.1.1.1').allowed).toBe(true);
+            const blocked = limiter.hit('1.1.1.1');
+            expect(blocked.allowed).toBe(false);
+            expect(blocked.retryAfterSecs).toBe(10);
+            expect(limiter.hit('2.2.2.2').allowed).toBe(true);
+
+            now = 10_000;
+            expect(limiter.hit('1.1.1.1').allowed).toBe(true);
+        });
+
+        it('should drop expired windows', () => {
+            let now = 0;
+            const limiter = new RateLimiter({ limit: 1, windowSecs: 1 }, () => now);
+            limiter.hit('1.1.1.1');
+
+            now = 2_000;
+            limiter.hit('2.2.2.2');
+
+            expect([...limiter.windows.keys()]).toEqual(['2.2.2.2']);
+        });
+    });
+
+    describe('rateLimitMiddleware', () => {
+        it('should respond 429 with Retry-After once the limit is exceeded', () => {
+            const middleware = rateLimitMiddleware({ limit: 1, windowSecs: 60 });
+            const next = vi.fn();
+
+            middleware({ ip: '1.1.1.1' }, mockResponse(), next);
+            const res = mockResponse();
+            middleware({ ip: '1.1.1.1' }, res, next);
+
+            expect(next).toHaveBeenCalledTimes(1);
+            expect(res.status).toHaveBeenCalledWith(429);
+            expect(res.headers['Retry-After']).toBe('60');
+            expect(res.json.mock.calls[0][0].error.message).toBe(
+                'Too many requests: limit is 1 per 60s',
+            );
+        });
+
+        it('should pass everything through when the limit is 0', () => {
+            const middleware = rateLimitMiddleware({ limit: 0, windowSecs: 60 });
+            const next = vi.fn();
+
+            for (let i = 0; i < 5; i++) middleware({ ip: '1.1.1.1' }, mockResponse(), next);
+
+            expect(next).toHaveBeenCalledTimes(5);
+        });
+    });
+});
@@ -0,0 +1,67 @@
+import { describe, it, expect } from 'vitest';
+import {
+    DEFAULT_MAX_RESPONSE_BYTES,
+    readMaxResponseBytes,
+    fitToResponseSize,
+} from '../../core/response-size.js';
+
+const build = (page, truncated) => ({
+    items: page,
+    ...(truncated && { truncated: true, next_cursor: page[page.length - 1]?.id ?? null }),
+});
+
+const items = Array.from({ length: 20 }, (_, i) => ({ id: `item-${i}`, text: 'x'.repeat(100) }));
+
+describe('Response Size', () => {
+    describe('readMaxResponseBytes', () => {
+        it('should default when LETTA_MAX_RESPONSE_BYTES is unset', () => {
+            expect(readMaxResponseBytes({})).toBe(DEFAULT_MAX_RESPONSE_BYTES);
+        });
+
+        it('should read LETTA_MAX_RESPONSE_BYTES, with 0 disabling the cap', () => {
+            expect(readMaxResponseBytes({ LETTA_MAX_RESPONSE_BYTES: '5000' })).toBe(5000);
+            expect(readMaxResponseBytes({ LETTA_MAX_RESPONSE_BYTES: '0' })).toBe(0);
+        });
+
+        it('should reject negative and non-integer values', () => {
+            for (const value of ['-1', '1.5', 'big']) {
+                expect(() => readMaxResponseBytes({ LETTA_MAX_RESPONSE_BYTES: value })).toThrow(
+                    'Invalid environment variable LETTA_MAX_RESPONSE_BYTES',
+                );
+            }
+        });
+    });
+
+    describe('fitToResponseSize', () => {
+        it('should return the full payload when it fits', () => {
+            expect(fitToResponseSize(items, build, 100_000)).toEqual({ items });
+        });
+
+        it('should not limit the payload when the cap is 0', () => {
+            expect(fitToResponseSize(items, build, 0)).toEqual({ items });
+        });
+
+        it('should keep the longest prefix that fits and mark it truncated', () => {
+            const payload = fitToResponseSize(items, build, 1000);
+
+            expect(JSON.stringify(payload).length).toBeLessThanOrEqual(1000);
+            expect(payload.truncated).toBe(true);
+            expect(payload.items).toEqual(items.slice(0, payload.items.length));
+            expect(payload.next_cursor).toBe(payload.items[payload.items.length - 1].id);
+
+            // One more item would not have fit
+            const next = build(items.slice(0, payload.items.length + 1), true);
+            expect(JSON.stringify(next).length).toBeGreaterThan(1000);
+        });
+
+        it('should keep the first item when it alone is over the cap', () => {
+            const payload = fitToResponseSize(items, build, 50);
+
+            expect(payload).toEqual({
+                items: [items[0]],
+                truncated: true,
+                next_cursor: items[0].id,
+            });
+        });
+    });
+});
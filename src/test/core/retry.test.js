@@ -0,0 +1,151 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+    DEFAULT_RETRY_POLICY,
+    readRetryPolicy,
+    isRetryableError,
+    isRetryAllowed,
+    computeBackoffDelay,
+    installRetryInterceptor,
+} from '../../core/retry.js';
+
+const httpError = (status) => Object.assign(new Error(`HTTP ${status}`), { response: { status } });
+const networkError = (code) => Object.assign(new Error(code), { code });
+const noDelay = () => Promise.resolve();
+
+describe('Retry Policy', () => {
+    describe('readRetryPolicy', () => {
+        it('should use defaults when no variables are set', () => {
+            expect(readRetryPolicy({})).toEqual(DEFAULT_RETRY_POLICY);
+        });
+
+        it('should read LETTA_RETRY_* variables', () => {
+            expect(
+                readRetryPolicy({
+                    LETTA_RETRY_MAX_ATTEMPTS: '5',
+                    LETTA_RETRY_BASE_DELAY_MS: '50',
+                    LETTA_RETRY_MAX_DELAY_MS: '1000',
+                    LETTA_RETRY_NON_IDEMPOTENT: 'true',
+                }),
+            ).toEqual({
+                maxAttempts: 5,
+                baseDelayMs: 50,
+                maxDelayMs: 1000,
+                retryNonIdempotent: true,
+            });
+        });
+
+        it('should treat 0 attempts as a single attempt', () => {
+            expect(readRetryPolicy({ LETTA_RETRY_MAX_ATTEMPTS: '0' }).maxAttempts).toBe(1);
+        });
+
+        it('should reject invalid values', () => {
+            expect(() => readRetryPolicy({ LETTA_RETRY_MAX_ATTEMPTS: 'lots' })).toThrow(
+                'Invalid environment variable LETTA_RETRY_MAX_ATTEMPTS',
+            );
+        });
+    });
+
+    describe('isRetryableError', () => {
+        it('should retry gateway errors, rate limits and dropped connections', () => {
+            for (const status of [429, 502, 503, 504]) {
+                expect(isRetryableError(httpError(status))).toBe(true);
+            }
+            for (const code of ['ECONNRESET', 'ETIMEDOUT', 'ECONNABORTED']) {
+                expect(isRetryableError(networkError(code))).toBe(true);
+            }
+        });
+
+        it('should not retry client errors or unknown failures', () => {
+            for (const status of [400, 401, 404, 422, 500]) {
+                expect(isRetryableError(httpError(status))).toBe(false);
+            }
+            expect(isRetryableError(new Error('boom'))).toBe(false);
+        });
+    });
+
+    describe('isRetryAllowed', () => {
+        it('should only retry idempotent methods by default', () => {
+            expect(isRetryAllowed({ method: 'get' }, DEFAULT_RETRY_POLICY)).toBe(true);
+            expect(isRetryAllowed({ method: 'put' }, DEFAULT_RETRY_POLICY)).toBe(true);
+            expect(isRetryAllowed({ method: 'post' }, DEFAULT_RETRY_POLICY)).toBe(false);
+            expect(isRetryAllowed({ method: 'delete' }, DEFAULT_RETRY_POLICY)).toBe(false);
+        });
+
+        it('should honor per-request opt-in and opt-out', () => {
+            expect(isRetryAllowed({ method: 'post', retry: true }, DEFAULT_RETRY_POLICY)).toBe(
+                true,
+            );
+            expect(isRetryAllowed({ method: 'get', retry: false }, DEFAULT_RETRY_POLICY)).toBe(
+                false,
+            );
+        });
+
+        it('should retry everything when non-idempotent retries are enabled', () => {
+            const policy = { ...DEFAULT_RETRY_POLICY, retryNonIdempotent: true };
+            expect(isRetryAllowed({ method: 'post' }, policy)).toBe(true);
+        });
+    });
+
+    describe('computeBackoffDelay', () => {
+        const policy = { ...DEFAULT_RETRY_POLICY, baseDelayMs: 100, maxDelayMs: 1000 };
+
+        it('should grow exponentially up to the cap', () => {
+            const max = () => 1;
+            expect(computeBackoffDelay(1, policy, max)).toBe(100);
+            expect(computeBackoffDelay(2, policy, max)).toBe(200);
+            expect(computeBackoffDelay(3, policy, max)).toBe(400);
+            expect(computeBackoffDelay(10, policy, max)).toBe(1000);
+        });
+
+        it('should apply jitter between half and the full delay', () => {
+            expect(computeBackoffDelay(2, policy, () => 0)).toBe(100);
+            expect(computeBackoffDelay(2, policy, () => 0.5)).toBe(150);
+        });
+    });
+
+    describe('installRetryInterceptor', () => {
+        const createInstance = () => {
+            const instance = {
+                interceptors: { response: { use: vi.fn() } },
+                request: vi.fn(),
+            };
+            installRetryInterceptor(instance, DEFAULT_RETRY_POLICY, { warn: vi.fn() }, noDelay);
+            const [, onRejected] = instance.interceptors.response.use.mock.calls[0];
+            return { instance, onRejected };
+        };
+
+        it('should re-issue idempotent requests that fail transiently', async () => {
+            const { instance, onRejected } = createInstance();
+            instance.request.mockResolvedValueOnce({ data: 'ok' });
+            const error = httpError(503);
+            error.config = { method: 'get', url: '/agents/' };
+
+            await expect(onRejected(error)).resolves.toEqual({ data: 'ok' });
+            expect(instance.request).toHaveBeenCalledWith(
+                expect.objectContaining({ url: '/agents/', retryAttempt: 1 }),
+            );
+        });
+
+        it('should not re-issue non-idempotent requests by default', async () => {
+            const { instance, onRejected } = createInstance();
+            const error = httpError(503);
+            error.config = { method: 'post', url: '/agents/' };
+
+            await expect(onRejected(error)).rejects.toBe(error);
+            expect(instance.request).not.toHaveBeenCalled();
+        });
+
+        it('should stop once the attempt budget is spent', async () => {
+            const { instance, onRejected } = createInstance();
+            const error = httpError(503);
+            error.config = { method: 'get', url: '/agents/', retryAttempt: 2 };
+
+            await expect(onRejected(error)).rejects.toBe(error);
+            expect(instance.request).not.toHaveBeenCalled();
+        });
+
+        it('should skip instances without interceptors', () => {
+            expect(() => installRetryInterceptor({}, DEFAULT_RETRY_POLICY)).not.toThrow();
+        });
+    });
+});
@@ -0,0 +1,79 @@
+import { describe, it, expect } from 'vitest';
+import {
+    DEFAULT_RUN_HISTORY_SIZE,
+    RunHistory,
+    readRunHistorySize,
+} from '../../core/run-history.js';
+
+describe('Run History', () => {
+    describe('readRunHistorySize', () => {
+        it('should default when unset', () => {
+            expect(readRunHistorySize({})).toBe(DEFAULT_RUN_HISTORY_SIZE);
+            expect(readRunHistorySize({ LETTA_RUN_HISTORY_SIZE: '' })).toBe(
+                DEFAULT_RUN_HISTORY_SIZE,
+            );
+        });
+
+        it('should read a size, including 0', () => {
+            expect(readRunHistorySize({ LETTA_RUN_HISTORY_SIZE: '25' })).toBe(25);
+            expect(readRunHistorySize({ LETTA_RUN_HISTORY_SIZE: '0' })).toBe(0);
+        });
+
+        it('should reject invalid sizes', () => {
+            for (const value of ['-1', '2.5', 'many']) {
+                expect(() => readRunHistorySize({ LETTA_RUN_HISTORY_SIZE: value })).toThrow(
+                    'Invalid environment variable LETTA_RUN_HISTORY_SIZE',
+                );
+            }
+        });
+    });
+
+    describe('RunHistory', () => {
+        it('should list runs newest first with increasing IDs', () => {
+            const history = new RunHistory(10);
+            history.record({ name: 'a', status: 'success' });
+            history.record({ name: 'b', status: 'error' });
+
+            expect(history.list()).toEqual([
+                { id: 2, name: 'b', status: 'error' },
+                { id: 1, name: 'a', status: 'success' },
+            ]);
+        });
+
+        it('should drop the oldest runs once full', () => {
+            const history = new RunHistory(2);
+            for (const name of ['a', 'b', 'c']) history.record({ name, status: 'success' });
+
+            expect(history.list().map((run) => run.name)).toEqual(['c', 'b']);
+        });
+
+        it('should filter by name and status', () => {
+            const history = new RunHistory(10);
+            history.record({ name: 'a', status: 'success' });
+            history.record({ name: 'a', status: 'error' });
+            history.record({ name: 'b', status: 'error' });
+
+            expect(history.list({ name: 'a' })).toHaveLength(2);
+            expect(history.list({ status: 'error' }).map((run) => run.name)).toEqual(['b', 'a']);
+            expect(history.list({ name: 'a', status: 'error' })).toHaveLength(1);
+        });
+
+        it('should only list runs recorded in the same scope', () => {
+            const history = new RunHistory(10);
+            history.record({ name: 'a', status: 'success' }, { 'X-Project': 'p1' });
+            history.record({ name: 'b', status: 'success' }, { 'X-Project': 'p2' });
+            history.record({ name: 'c', status: 'success' });
+
+            expect(history.list({}, { 'X-Project': 'p1' }).map((run) => run.name)).toEqual(['a']);
+            expect(history.list().map((run) => run.name)).toEqual(['c']);
+        });
+
+        it('should record nothing when disabled', () => {
+            const history = new RunHistory(0);
+
+            expect(history.enabled).toBe(false);
+            expect(history.record({ name: 'a' })).toBeNull();
+            expect(history.list()).toEqual([]);
+        });
+    });
+});
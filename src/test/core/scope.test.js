@@ -0,0 +1,35 @@
+import { describe, it, expect } from 'vitest';
+import { readDefaultScope, runWithScope, scopeHeaders } from '../../core/scope.js';
+
+describe('Request Scope', () => {
+    it('should read the default scope from the environment', () => {
+        const env = { LETTA_PROJECT_ID: 'project-1', LETTA_USER_ID: 'user-1' };
+        expect(readDefaultScope(env)).toEqual({ projectId: 'project-1', userId: 'user-1' });
+        expect(readDefaultScope({})).toEqual({ projectId: undefined, userId: undefined });
+    });
+
+    it('should only emit headers that are set', () => {
+        expect(scopeHeaders({})).toEqual({});
+        expect(scopeHeaders({ projectId: 'project-1', userId: 'user-1' })).toEqual({
+            'X-Project': 'project-1',
+            user_id: 'user-1',
+        });
+    });
+
+    it('should apply a per-call project override inside runWithScope only', async () => {
+        const defaults = { projectId: 'project-1', userId: 'user-1' };
+
+        const inside = await runWithScope({ project_id: 'project-2' }, async () => {
+            await Promise.resolve();
+            return scopeHeaders(defaults);
+        });
+
+        expect(inside).toEqual({ 'X-Project': 'project-2', user_id: 'user-1' });
+        expect(scopeHeaders(defaults)['X-Project']).toBe('project-1');
+    });
+
+    it('should keep the defaults when no project_id is given', () => {
+        const headers = runWithScope({}, () => scopeHeaders({ projectId: 'project-1' }));
+        expect(headers).toEqual({ 'X-Project': 'project-1' });
+    });
+});
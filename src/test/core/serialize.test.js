@@ -0,0 +1,90 @@
+import { describe, it, expect } from 'vitest';
+import { ErrorCode } from '@modelcontextprotocol/sdk/types.js';
+import { classifyError } from '../../core/errors.js';
+import { findUnserializable, serializeJson } from '../../core/serialize.js';
+
+describe('Serialization', () => {
+    describe('findUnserializable', () => {
+        it('should find nothing wrong with plain JSON values', () => {
+            expect(findUnserializable({ a: [1, 'two', null, { b: true }] })).toBeNull();
+        });
+
+        it('should report a circular reference with its path', () => {
+            const agent = { llm_config: { model: 'gpt-4o' } };
+            agent.llm_config.owner = agent;
+
+            expect(findUnserializable({ agent })).toEqual({
+                path: 'agent.llm_config.owner',
+                reason: 'circular reference',
+            });
+        });
+
+        it('should report BigInt values inside arrays', () => {
+            expect(findUnserializable({ tools: [{ id: 't1' }, { limit: 10n }] })).toEqual({
+                path: 'tools[1].limit',
+                reason: 'BigInt values cannot be serialized',
+            });
+        });
+
+        it('should report a toJSON method that throws', () => {
+            const value = {
+                metadata: {
+                    toJSON() {
+                        throw new Error('bad metadata');
+                    },
+                },
+            };
+
+            expect(findUnserializable(value)).toEqual({
+                path: 'metadata',
+                reason: 'toJSON() failed: bad metadata',
+            });
+        });
+
+        it('should accept values with a working toJSON, such as dates', () => {
+            expect(findUnserializable({ created_at: new Date(0) })).toBeNull();
+        });
+    });
+
+    describe('serializeJson', () => {
+        it('should serialize like JSON.stringify', () => {
+            expect(serializeJson({ a: 1 }, 'test')).toBe('{"a":1}');
+            expect(serializeJson({ a: 1 }, 'test', 2)).toBe('{\n  "a": 1\n}');
+        });
+
+        it('should name the operation and offending field on failure', () => {
+            const config = { name: 'agent' };
+            config.self = config;
+
+            let thrown;
+            try {
+                serializeJson({ agent: config }, 'retrieve_agent response');
+            } catch (error) {
+                thrown = error;
+            }
+
+            expect(thrown.message).toBe(
+                'Could not serialize retrieve_agent response: circular reference at agent.self',
+            );
+            expect(thrown.field).toBe('agent.self');
+            expect(thrown.errorCode).toBe('serialization_error');
+        });
+
+        it('should be classified as a serialization_error', () => {
+            let thrown;
+            try {
+                serializeJson({ count: 1n }, 'test');
+            } catch (error) {
+                thrown = error;
+            }
+
+            expect(classifyError(thrown)).toEqual({
+                code: ErrorCode.InternalError,
+                errorCode: 'serialization_error',
+                status: null,
+                retryable: false,
+                message: 'Could not serialize test: BigInt values cannot be serialized at count',
+            });
+        });
+    });
+});
@@ -2,6 +2,8 @@ import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
 import { LettaServer } from '../../core/server.js';
 import { Server } from '@modelcontextprotocol/sdk/server/index.js';
 import axios from 'axios';
+import http from 'node:http';
+import https from 'node:https';
 import { createLogger } from '../../core/logger.js';
 
 // Mock dependencies
@@ -90,12 +92,16 @@ describe('LettaServer Initialization (LMP-82)', () => {
             }).not.toThrow();
         });
 
-        it('should use empty string for password when not set', () => {
-            process.env.LETTA_BASE_URL = 'https://test.letta.com';
+        it('should disable auth when LETTA_PASSWORD is not set', () => {
+            process.env.LETTA_BASE_URL = 'http://localhost:8283';
             delete process.env.LETTA_PASSWORD;
 
             const server = new LettaServer();
-            expect(server.password).toBe('');
+            const headers = server.getApiHeaders();
+
+            expect(server.auth.mode).toBe('none');
+            expect(headers.Authorization).toBeUndefined();
+            expect(headers['X-BARE-PASSWORD']).toBeUndefined();
         });
     });
 
@@ -171,6 +177,8 @@ describe('LettaServer Initialization (LMP-82)', () => {
             const server = new LettaServer();
 
             expect(axios.create).toHaveBeenCalledWith({
+                httpAgent: expect.any(http.Agent),
+                httpsAgent: expect.any(https.Agent),
                 baseURL: 'https://test.letta.com/v1',
                 headers: {
                     'Content-Type': 'application/json',
@@ -187,7 +195,9 @@ describe('LettaServer Initialization (LMP-82)', () => {
             new LettaServer();
 
             expect(axios.create).toHaveBeenCalledWith({
-                baseURL: 'https://test.letta.com/v1/v1',
+                httpAgent: expect.any(http.Agent),
+                httpsAgent: expect.any(https.Agent),
+                baseURL: 'https://test.letta.com/v1',
                 headers: {
                     'Content-Type': 'application/json',
                     Accept: 'application/json',
@@ -211,6 +221,38 @@ describe('LettaServer Initialization (LMP-82)', () => {
             );
         });
 
+        it('should apply LETTA_TIMEOUT_SECS as the default request timeout', () => {
+            process.env.LETTA_BASE_URL = 'https://test.letta.com';
+            process.env.LETTA_PASSWORD = 'test-password';
+            process.env.LETTA_TIMEOUT_SECS = '90';
+
+            try {
+                const server = new LettaServer();
+                expect(server.timeoutSecs).toBe(90);
+                expect(axios.create).toHaveBeenCalledWith(
+                    expect.objectContaining({ timeout: 90000 }),
+                );
+            } finally {
+                delete process.env.LETTA_TIMEOUT_SECS;
+            }
+        });
+
+        it('should pass LETTA_POOL_* settings to the connection pool', () => {
+            process.env.LETTA_BASE_URL = 'https://test.letta.com';
+            process.env.LETTA_PASSWORD = 'test-password';
+            process.env.LETTA_POOL_MAX_IDLE = '32';
+
+            try {
+                const server = new LettaServer();
+                expect(server.poolOptions).toEqual({ maxIdle: 32, idleTimeoutSecs: 90 });
+                const { httpsAgent } = axios.create.mock.calls[0][0];
+                expect(httpsAgent.keepAlive).toBe(true);
+                expect(httpsAgent.maxFreeSockets).toBe(32);
+            } finally {
+                delete process.env.LETTA_POOL_MAX_IDLE;
+            }
+        });
+
         it('should store axios instance reference', () => {
             process.env.LETTA_BASE_URL = 'https://test.letta.com';
             process.env.LETTA_PASSWORD = 'test-password';
@@ -232,19 +274,19 @@ describe('LettaServer Initialization (LMP-82)', () => {
             expect(server).toHaveProperty('logger');
             expect(server).toHaveProperty('server');
             expect(server).toHaveProperty('apiBase');
-            expect(server).toHaveProperty('password');
+            expect(server).toHaveProperty('auth');
             expect(server).toHaveProperty('api');
         });
 
         it('should store password from environment', () => {
             const server = new LettaServer();
-            expect(server.password).toBe('test-password');
+            expect(server.auth.secret).toBe('test-password');
         });
 
         it('should handle special characters in password', () => {
             process.env.LETTA_PASSWORD = 'p@$$w0rd!#$%^&*()';
             const server = new LettaServer();
-            expect(server.password).toBe('p@$$w0rd!#$%^&*()');
+            expect(server.auth.secret).toBe('p@$$w0rd!#$%^&*()');
         });
     });
 
@@ -272,7 +314,7 @@ describe('LettaServer Initialization (LMP-82)', () => {
             process.env.LETTA_PASSWORD = longPassword;
 
             const server = new LettaServer();
-            expect(server.password).toBe(longPassword);
+            expect(server.auth.secret).toBe(longPassword);
         });
 
         it('should handle URLs with trailing slashes', () => {
@@ -280,7 +322,7 @@ describe('LettaServer Initialization (LMP-82)', () => {
             process.env.LETTA_PASSWORD = 'test-password';
 
             const server = new LettaServer();
-            expect(server.apiBase).toBe('https://test.letta.com//v1');
+            expect(server.apiBase).toBe('https://test.letta.com/v1');
         });
     });
 
@@ -0,0 +1,100 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+    DEFAULT_SHUTDOWN_TIMEOUT_SECS,
+    InFlightTracker,
+    drainInFlight,
+    readShutdownOptions,
+} from '../../core/shutdown.js';
+import { createMockLettaServer } from '../utils/mock-server.js';
+
+const mockLogger = () => ({ info: vi.fn(), warn: vi.fn() });
+
+describe('Shutdown', () => {
+    describe('readShutdownOptions', () => {
+        it('should use defaults when unset', () => {
+            expect(readShutdownOptions({})).toEqual({
+                timeoutSecs: DEFAULT_SHUTDOWN_TIMEOUT_SECS,
+                cancelRuns: false,
+            });
+        });
+
+        it('should read the timeout and cancel flag', () => {
+            expect(
+                readShutdownOptions({
+                    LETTA_SHUTDOWN_TIMEOUT_SECS: '5',
+                    LETTA_CANCEL_RUNS_ON_SHUTDOWN: 'true',
+                }),
+            ).toEqual({ timeoutSecs: 5, cancelRuns: true });
+        });
+
+        it('should reject invalid timeouts', () => {
+            expect(() => readShutdownOptions({ LETTA_SHUTDOWN_TIMEOUT_SECS: '-1' })).toThrow(
+                'Invalid environment variable LETTA_SHUTDOWN_TIMEOUT_SECS',
+            );
+        });
+    });
+
+    describe('InFlightTracker', () => {
+        it('should wait for running calls to finish', async () => {
+            const tracker = new InFlightTracker();
+            let finish;
+            const call = tracker.track('prompt_agent', {}, () => new Promise((r) => (finish = r)));
+
+            const drained = tracker.drain(1000);
+            expect(tracker.size).toBe(1);
+            finish('done');
+
+            await expect(call).resolves.toBe('done');
+            await expect(drained).resolves.toBe(true);
+        });
+
+        it('should give up after the timeout', async () => {
+            const tracker = new InFlightTracker();
+            tracker.track('prompt_agent', { agent_id: 'agent-1' }, () => new Promise(() => {}));
+
+            await expect(tracker.drain(10)).resolves.toBe(false);
+            expect(tracker.activeAgentIds()).toEqual(['agent-1']);
+        });
+
+        it('should refuse new calls once draining', async () => {
+            const tracker = new InFlightTracker();
+            await tracker.drain(10);
+
+            await expect(tracker.track('list_agents', {}, async () => 'ok')).rejects.toThrow(
+                'Server is shutting down',
+            );
+        });
+    });
+
+    describe('drainInFlight', () => {
+        it('should cancel runs for agents still busy at the deadline when configured', async () => {
+            const server = createMockLettaServer();
+            server.api.post.mockResolvedValue({ data: {} });
+            const hang = () => new Promise(() => {});
+            server.inFlight.track('prompt_agent', { agent_id: 'agent-1' }, hang);
+
+            const drained = await drainInFlight(
+                server,
+                { timeoutSecs: 0.01, cancelRuns: true },
+                mockLogger(),
+            );
+
+            expect(drained).toBe(false);
+            expect(server.api.post).toHaveBeenCalledWith(
+                '/agents/agent-1/messages/cancel',
+                {},
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+        });
+
+        it('should leave runs alone unless cancelRuns is set', async () => {
+            const server = createMockLettaServer();
+            const hang = () => new Promise(() => {});
+            server.inFlight.track('prompt_agent', { agent_id: 'agent-1' }, hang);
+
+            await drainInFlight(server, { timeoutSecs: 0.01, cancelRuns: false }, mockLogger());
+
+            expect(server.api.post).not.toHaveBeenCalled();
+        });
+    });
+});
@@ -0,0 +1,103 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+    checkLettaConnection,
+    diagnoseConnectionError,
+    readStartupCheck,
+    STARTUP_CHECK_TIMEOUT_MS,
+} from '../../core/startup-check.js';
+
+const API_BASE = 'https://letta.example.com/v1';
+
+const networkError = (code, message = code) => Object.assign(new Error(message), { code });
+const httpError = (status) =>
+    Object.assign(new Error(`Request failed with status code ${status}`), {
+        response: { status },
+    });
+
+const mockServer = (get) => ({
+    apiBase: API_BASE,
+    api: { get },
+    getApiHeaders: () => ({ Authorization: 'Bearer pw' }),
+});
+
+describe('Startup Check', () => {
+    it('should be off unless LETTA_STARTUP_CHECK is true', () => {
+        expect(readStartupCheck({})).toBe(false);
+        expect(readStartupCheck({ LETTA_STARTUP_CHECK: 'false' })).toBe(false);
+        expect(readStartupCheck({ LETTA_STARTUP_CHECK: 'true' })).toBe(true);
+    });
+
+    describe('diagnoseConnectionError', () => {
+        it('should report DNS failures', () => {
+            expect(diagnoseConnectionError(networkError('ENOTFOUND'), API_BASE)).toBe(
+                'Cannot resolve Letta host letta.example.com: check the hostname in LETTA_BASE_URL',
+            );
+        });
+
+        it('should report refused connections', () => {
+            expect(diagnoseConnectionError(networkError('ECONNREFUSED'), API_BASE)).toContain(
+                'Connection to Letta at letta.example.com refused',
+            );
+        });
+
+        it('should report timeouts', () => {
+            const error = networkError('ECONNABORTED', 'timeout of 10000ms exceeded');
+            expect(diagnoseConnectionError(error, API_BASE)).toBe(
+                `Letta at letta.example.com did not respond within ${STARTUP_CHECK_TIMEOUT_MS / 1000}s`,
+            );
+        });
+
+        it('should report rejected credentials', () => {
+            for (const status of [401, 403]) {
+                expect(diagnoseConnectionError(httpError(status), API_BASE)).toContain(
+                    `rejected the credentials (HTTP ${status})`,
+                );
+            }
+        });
+
+        it('should report a wrong base URL', () => {
+            expect(diagnoseConnectionError(httpError(404), API_BASE)).toContain(
+                `No Letta API at ${API_BASE} (HTTP 404)`,
+            );
+        });
+
+        it('should fall back to the status or error message', () => {
+            expect(diagnoseConnectionError(httpError(500), API_BASE)).toContain(
+                'returned HTTP 500',
+            );
+            expect(diagnoseConnectionError(networkError('ECONNRESET'), API_BASE)).toBe(
+                'Cannot connect to Letta at letta.example.com: ECONNRESET',
+            );
+        });
+    });
+
+    describe('checkLettaConnection', () => {
+        it('should make one authenticated request with a timeout', async () => {
+            const get = vi.fn().mockResolvedValue({ data: [] });
+
+            await checkLettaConnection(mockServer(get));
+
+            expect(get).toHaveBeenCalledWith('/agents/', {
+                headers: { Authorization: 'Bearer pw' },
+                params: { limit: 1 },
+                timeout: STARTUP_CHECK_TIMEOUT_MS,
+            });
+        });
+
+        it('should throw the diagnosis when the request fails', async () => {
+            const get = vi.fn().mockRejectedValue(httpError(401));
+
+            await expect(checkLettaConnection(mockServer(get))).rejects.toThrow(
+                /^Letta startup check failed: .*rejected the credentials/,
+            );
+        });
+
+        it('should reject a response that is not an agent list', async () => {
+            const get = vi.fn().mockResolvedValue({ data: '<html>Welcome</html>' });
+
+            await expect(checkLettaConnection(mockServer(get))).rejects.toThrow(
+                'did not return an agent list',
+            );
+        });
+    });
+});
@@ -0,0 +1,79 @@
+import { describe, it, expect } from 'vitest';
+import {
+    readTimeoutSecs,
+    requestTimeout,
+    isTimeoutError,
+    timeoutErrorMessage,
+} from '../../core/timeout.js';
+
+describe('Request Timeouts', () => {
+    describe('readTimeoutSecs', () => {
+        it('should default to no timeout', () => {
+            expect(readTimeoutSecs({})).toBeUndefined();
+            expect(readTimeoutSecs({ LETTA_TIMEOUT_SECS: '0' })).toBeUndefined();
+        });
+
+        it('should parse LETTA_TIMEOUT_SECS', () => {
+            expect(readTimeoutSecs({ LETTA_TIMEOUT_SECS: '30' })).toBe(30);
+            expect(readTimeoutSecs({ LETTA_TIMEOUT_SECS: '2.5' })).toBe(2.5);
+        });
+
+        it('should reject invalid values', () => {
+            expect(() => readTimeoutSecs({ LETTA_TIMEOUT_SECS: 'soon' })).toThrow(
+                'Invalid environment variable LETTA_TIMEOUT_SECS',
+            );
+            expect(() => readTimeoutSecs({ LETTA_TIMEOUT_SECS: '-1' })).toThrow();
+        });
+    });
+
+    describe('requestTimeout', () => {
+        it('should return no override without timeout_secs', () => {
+            expect(requestTimeout({})).toEqual({});
+            expect(requestTimeout(undefined)).toEqual({});
+        });
+
+        it('should convert timeout_secs to milliseconds', () => {
+            expect(requestTimeout({ timeout_secs: 120 })).toEqual({ timeout: 120000 });
+            expect(requestTimeout({ timeout_secs: 0.5 })).toEqual({ timeout: 500 });
+        });
+
+        it('should reject non-positive values', () => {
+            for (const value of [0, -5, '10', NaN]) {
+                expect(() => requestTimeout({ timeout_secs: value })).toThrow(
+                    'timeout_secs must be a positive number',
+                );
+            }
+        });
+    });
+
+    describe('isTimeoutError', () => {
+        it('should recognize axios timeout errors', () => {
+            const aborted = Object.assign(new Error('timeout of 1000ms exceeded'), {
+                code: 'ECONNABORTED',
+            });
+            const timedOut = Object.assign(new Error('timed out'), { code: 'ETIMEDOUT' });
+            expect(isTimeoutError(aborted)).toBe(true);
+            expect(isTimeoutError(timedOut)).toBe(true);
+        });
+
+        it('should ignore other failures', () => {
+            const aborted = Object.assign(new Error('Request aborted'), { code: 'ECONNABORTED' });
+            const httpError = Object.assign(new Error('Gateway Timeout'), {
+                code: 'ETIMEDOUT',
+                response: { status: 504 },
+            });
+            expect(isTimeoutError(aborted)).toBe(false);
+            expect(isTimeoutError(httpError)).toBe(false);
+            expect(isTimeoutError(new Error('boom'))).toBe(false);
+        });
+    });
+
+    describe('timeoutErrorMessage', () => {
+        it('should include the limit when known', () => {
+            expect(timeoutErrorMessage({ config: { timeout: 30000 } })).toBe(
+                'Operation timed out after 30s',
+            );
+            expect(timeoutErrorMessage({})).toBe('Operation timed out');
+        });
+    });
+});
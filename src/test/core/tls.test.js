@@ -0,0 +1,63 @@
+import { describe, it, expect, beforeEach, afterEach } from 'vitest';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import tls from 'tls';
+import { readTlsOptions } from '../../core/tls.js';
+
+describe('TLS Options', () => {
+    let dir;
+    const write = (name, content) => {
+        const file = path.join(dir, name);
+        fs.writeFileSync(file, content);
+        return file;
+    };
+
+    beforeEach(() => {
+        dir = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-tls-'));
+    });
+
+    afterEach(() => {
+        fs.rmSync(dir, { recursive: true, force: true });
+    });
+
+    it('should be empty when no TLS variables are set', () => {
+        expect(readTlsOptions({})).toEqual({});
+    });
+
+    it('should read a client certificate and key', () => {
+        const env = {
+            LETTA_TLS_CERT_FILE: write('client.crt', 'CERT PEM'),
+            LETTA_TLS_KEY_FILE: write('client.key', 'KEY PEM'),
+            LETTA_TLS_KEY_PASSPHRASE: 'secret',
+        };
+
+        expect(readTlsOptions(env)).toEqual({
+            cert: 'CERT PEM',
+            key: 'KEY PEM',
+            passphrase: 'secret',
+        });
+    });
+
+    it('should trust a CA bundle in addition to the default roots', () => {
+        const options = readTlsOptions({ LETTA_TLS_CA_FILE: write('ca.pem', 'CA PEM') });
+
+        expect(options.ca).toHaveLength(tls.rootCertificates.length + 1);
+        expect(options.ca[options.ca.length - 1]).toBe('CA PEM');
+        expect(options).not.toHaveProperty('cert');
+    });
+
+    it('should require the certificate and key together', () => {
+        expect(() =>
+            readTlsOptions({ LETTA_TLS_CERT_FILE: write('client.crt', 'CERT PEM') }),
+        ).toThrow('LETTA_TLS_CERT_FILE and LETTA_TLS_KEY_FILE must be set together');
+    });
+
+    it('should report a file that cannot be read', () => {
+        const missing = path.join(dir, 'missing.pem');
+
+        expect(() => readTlsOptions({ LETTA_TLS_CA_FILE: missing })).toThrow(
+            `Invalid environment variable LETTA_TLS_CA_FILE: cannot read ${missing} (ENOENT)`,
+        );
+    });
+});
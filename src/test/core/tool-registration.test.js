@@ -5,7 +5,9 @@ import { registerToolHandlers } from '../../tools/index.js';
 // Mock dependencies
 vi.mock('@modelcontextprotocol/sdk/server/index.js');
 vi.mock('axios');
-vi.mock('../../core/logger.js');
+vi.mock('../../core/logger.js', () => ({
+    createLogger: () => ({ info: vi.fn(), warn: vi.fn(), error: vi.fn(), debug: vi.fn() }),
+}));
 
 describe('Tool Registration (LMP-84)', () => {
     let server;
@@ -118,6 +120,92 @@ describe('Tool Registration (LMP-84)', () => {
 
             await expect(callToolHandler(request)).rejects.toThrow();
         });
+
+        it('should report every argument problem in one error', async () => {
+            registerToolHandlers(server);
+            server.api = { get: vi.fn() };
+
+            const callToolHandler = registeredHandlers[1].handler;
+            const error = await callToolHandler({
+                params: { name: 'modify_passage', arguments: { include_embeddings: 'yes' } },
+            }).catch((e) => e);
+
+            expect(error.message).toContain(
+                'Missing required arguments: agent_id, memory_id. Invalid argument: include_embeddings must be a boolean',
+            );
+            expect(error.data).toMatchObject({
+                error_code: 'invalid_request',
+                fields: ['agent_id', 'memory_id', 'include_embeddings'],
+            });
+            expect(server.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should reject arguments the tool does not accept', async () => {
+            registerToolHandlers(server);
+            server.api = { get: vi.fn() };
+
+            const callToolHandler = registeredHandlers[1].handler;
+            await expect(
+                callToolHandler({
+                    params: { name: 'list_agents', arguments: { query: 'support' } },
+                }),
+            ).rejects.toThrow('Unknown argument for list_agents: query');
+            expect(server.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should scope Letta requests to the call project_id', async () => {
+            registerToolHandlers(server);
+            server.api = { get: vi.fn().mockResolvedValue({ data: [] }) };
+
+            const callToolHandler = registeredHandlers[1].handler;
+            await callToolHandler({
+                params: { name: 'list_agents', arguments: { project_id: 'project-42' } },
+            });
+
+            const [, config] = server.api.get.mock.calls[0];
+            expect(config.headers['X-Project']).toBe('project-42');
+            // The override only lasts for the call
+            expect(server.getApiHeaders()).not.toHaveProperty('X-Project');
+        });
+
+        it('should advertise project_id on tools that call the Letta API', async () => {
+            registerToolHandlers(server);
+
+            const { tools } = await registeredHandlers[0].handler({});
+            const byName = Object.fromEntries(tools.map((tool) => [tool.name, tool]));
+            expect(byName.list_agents.inputSchema.properties).toHaveProperty('project_id');
+            expect(byName.generate_tool_schema.inputSchema.properties).not.toHaveProperty(
+                'project_id',
+            );
+        });
+
+        it('should pretty-print the response when the call sets pretty_json', async () => {
+            registerToolHandlers(server);
+            server.api = { get: vi.fn().mockResolvedValue({ data: [] }) };
+
+            const callToolHandler = registeredHandlers[1].handler;
+            const result = await callToolHandler({
+                params: { name: 'list_agents', arguments: { pretty_json: true } },
+            });
+
+            expect(result.content[0].text).toContain('\n  "');
+            const { tools } = await registeredHandlers[0].handler({});
+            expect(tools.every((tool) => tool.inputSchema.properties.pretty_json)).toBe(true);
+        });
+
+        it('should return YAML when the call sets format', async () => {
+            registerToolHandlers(server);
+            server.api = { get: vi.fn().mockResolvedValue({ data: [{ id: 'agent-1' }] }) };
+
+            const callToolHandler = registeredHandlers[1].handler;
+            const result = await callToolHandler({
+                params: { name: 'list_agents', arguments: { format: 'yaml' } },
+            });
+
+            expect(result.content[0].text).toContain('- id: agent-1');
+            const { tools } = await registeredHandlers[0].handler({});
+            expect(tools.every((tool) => tool.inputSchema.properties.format)).toBe(true);
+        });
     });
 
     describe('Error Handling', () => {
@@ -0,0 +1,96 @@
+import { describe, it, expect, vi } from 'vitest';
+import {
+    installTracingInterceptor,
+    runInSpan,
+    spanFields,
+    withSpan,
+} from '../../core/tracing.js';
+
+const mockLogger = () => ({ info: vi.fn(), warn: vi.fn() });
+
+describe('Tracing', () => {
+    describe('spans', () => {
+        it('should have no fields outside a span', () => {
+            expect(spanFields()).toEqual({});
+        });
+
+        it('should carry operation and agent_id with a generated request ID', async () => {
+            const fields = await withSpan(mockLogger(), 'prompt_agent', { agent_id: 'a-1' }, () =>
+                Promise.resolve(spanFields()),
+            );
+
+            expect(fields.operation).toBe('prompt_agent');
+            expect(fields.agent_id).toBe('a-1');
+            expect(fields.request_id).toMatch(/^[0-9a-f-]{36}$/);
+        });
+
+        it('should keep the request ID in child spans and override agent_id', async () => {
+            const logger = mockLogger();
+            const [parent, children] = await withSpan(logger, 'bulk_send_message', {}, async () => [
+                spanFields(),
+                await Promise.all(
+                    ['a-1', 'a-2'].map((agentId) =>
+                        runInSpan({ agent_id: agentId }, async () => spanFields()),
+                    ),
+                ),
+            ]);
+
+            expect(parent).not.toHaveProperty('agent_id');
+            expect(children.map((child) => child.agent_id)).toEqual(['a-1', 'a-2']);
+            expect(children.every((child) => child.request_id === parent.request_id)).toBe(true);
+            expect(children[0].operation).toBe('bulk_send_message');
+        });
+
+        it('should give separate calls different request IDs', async () => {
+            const run = () => withSpan(mockLogger(), 'list_agents', {}, async () => spanFields());
+            const [first, second] = await Promise.all([run(), run()]);
+
+            expect(first.request_id).not.toBe(second.request_id);
+        });
+    });
+
+    describe('withSpan', () => {
+        it('should log the duration when the span closes', async () => {
+            const logger = mockLogger();
+
+            await withSpan(logger, 'list_agents', {}, async () => 'ok');
+
+            expect(logger.info).toHaveBeenCalledWith(
+                'list_agents finished',
+                expect.objectContaining({ outcome: 'success', duration_ms: expect.any(Number) }),
+            );
+        });
+
+        it('should log failures and rethrow', async () => {
+            const logger = mockLogger();
+
+            await expect(
+                withSpan(logger, 'get_job', {}, async () => {
+                    throw new Error('Server error');
+                }),
+            ).rejects.toThrow('Server error');
+            expect(logger.warn).toHaveBeenCalledWith(
+                'get_job failed: Server error',
+                expect.objectContaining({ outcome: 'error' }),
+            );
+        });
+    });
+
+    describe('installTracingInterceptor', () => {
+        it('should add X-Request-Id to requests made inside a span', async () => {
+            let interceptor;
+            installTracingInterceptor({
+                interceptors: { request: { use: (fn) => (interceptor = fn) } },
+            });
+
+            const outside = interceptor({ headers: {} });
+            const inside = await runInSpan({ operation: 'get_job' }, async () => ({
+                config: interceptor({ headers: {} }),
+                fields: spanFields(),
+            }));
+
+            expect(outside.headers).not.toHaveProperty('X-Request-Id');
+            expect(inside.config.headers['X-Request-Id']).toBe(inside.fields.request_id);
+        });
+    });
+});
@@ -0,0 +1,98 @@
+import { describe, it, expect } from 'vitest';
+import { toYaml } from '../../core/yaml.js';
+
+describe('YAML Serialization', () => {
+    describe('Scalars', () => {
+        it('should write plain scalars as they are', () => {
+            expect(toYaml('hello world')).toBe('hello world\n');
+            expect(toYaml(42)).toBe('42\n');
+            expect(toYaml(-1.5)).toBe('-1.5\n');
+            expect(toYaml(true)).toBe('true\n');
+            expect(toYaml(null)).toBe('null\n');
+        });
+
+        it('should quote strings that YAML would read as another type', () => {
+            expect(toYaml('true')).toBe('"true"\n');
+            expect(toYaml('No')).toBe('"No"\n');
+            expect(toYaml('null')).toBe('"null"\n');
+            expect(toYaml('~')).toBe('"~"\n');
+            expect(toYaml('123')).toBe('"123"\n');
+            expect(toYaml('1.0')).toBe('"1.0"\n');
+            expect(toYaml('.inf')).toBe('".inf"\n');
+            expect(toYaml('2024-01-01')).toBe('"2024-01-01"\n');
+            expect(toYaml('')).toBe('""\n');
+        });
+
+        it('should quote strings with YAML syntax in them', () => {
+            expect(toYaml('- item')).toBe('"- item"\n');
+            expect(toYaml('key: value')).toBe('"key: value"\n');
+            expect(toYaml('a # comment')).toBe('"a # comment"\n');
+            expect(toYaml('{braces}')).toBe('"{braces}"\n');
+            expect(toYaml(' padded ')).toBe('" padded "\n');
+            expect(toYaml('tab\there')).toBe('"tab\\there"\n');
+        });
+
+        it('should leave ordinary punctuation and ids unquoted', () => {
+            expect(toYaml('agent-123e4567')).toBe('agent-123e4567\n');
+            expect(toYaml('https://example.com/a')).toBe('https://example.com/a\n');
+            expect(toYaml("It's fine")).toBe("It's fine\n");
+        });
+    });
+
+    describe('Collections', () => {
+        it('should write nested objects and arrays as blocks', () => {
+            const value = {
+                id: 'agent-1',
+                memory: { blocks: [{ label: 'human', limit: 5000 }, { label: 'persona' }] },
+                tags: ['a', 'b'],
+            };
+
+            expect(toYaml(value)).toBe(
+                [
+                    'id: agent-1',
+                    'memory:',
+                    '  blocks:',
+                    '  - label: human',
+                    '    limit: 5000',
+                    '  - label: persona',
+                    'tags:',
+                    '- a',
+                    '- b',
+                    '',
+                ].join('\n'),
+            );
+        });
+
+        it('should write nested arrays after the dash', () => {
+            expect(toYaml([[1, 2], [3]])).toBe('- - 1\n  - 2\n- - 3\n');
+        });
+
+        it('should write empty collections inline', () => {
+            expect(toYaml({ tools: [], metadata: {} })).toBe('tools: []\nmetadata: {}\n');
+            expect(toYaml([])).toBe('[]\n');
+        });
+
+        it('should quote keys that need it', () => {
+            expect(toYaml({ 'a: b': 1, yes: 2 })).toBe('"a: b": 1\n"yes": 2\n');
+        });
+    });
+
+    describe('Multi-line strings', () => {
+        it('should write multi-line strings as literal blocks', () => {
+            expect(toYaml({ source: 'def f():\n    return 1\n' })).toBe(
+                'source: |\n  def f():\n      return 1\n',
+            );
+        });
+
+        it('should strip the final newline when the string has none', () => {
+            expect(toYaml({ value: 'line one\n\nline three' })).toBe(
+                'value: |-\n  line one\n\n  line three\n',
+            );
+        });
+
+        it('should fall back to quoting when a block cannot hold the string', () => {
+            expect(toYaml({ value: '  indented\nnext' })).toBe('value: "  indented\\nnext"\n');
+            expect(toYaml({ value: 'ends\n\n' })).toBe('value: "ends\\n\\n"\n');
+        });
+    });
+});
@@ -9,6 +9,7 @@ describe('LettaServer', () => {
         originalEnv = {
             LETTA_BASE_URL: process.env.LETTA_BASE_URL,
             LETTA_PASSWORD: process.env.LETTA_PASSWORD,
+            LETTA_AUTH_MODE: process.env.LETTA_AUTH_MODE,
         };
     });
 
@@ -16,6 +17,8 @@ describe('LettaServer', () => {
         // Restore original env vars
         process.env.LETTA_BASE_URL = originalEnv.LETTA_BASE_URL;
         process.env.LETTA_PASSWORD = originalEnv.LETTA_PASSWORD;
+        if (originalEnv.LETTA_AUTH_MODE === undefined) delete process.env.LETTA_AUTH_MODE;
+        else process.env.LETTA_AUTH_MODE = originalEnv.LETTA_AUTH_MODE;
     });
 
     it('should throw error when LETTA_BASE_URL is not set', () => {
@@ -55,7 +58,7 @@ describe('LettaServer', () => {
 
         const server = new LettaServer();
 
-        expect(server.api.defaults.baseURL).toBe('https://test.letta.com/v1/v1');
+        expect(server.api.defaults.baseURL).toBe('https://test.letta.com/v1');
         // No default timeout is set in the current implementation
     });
 
@@ -75,4 +78,16 @@ describe('LettaServer', () => {
             'X-Letta-SDK-Version': '1.0',
         });
     });
+
+    it('should omit credentials when LETTA_AUTH_MODE is none', () => {
+        process.env.LETTA_BASE_URL = 'http://localhost:8283';
+        process.env.LETTA_PASSWORD = 'test-password';
+        process.env.LETTA_AUTH_MODE = 'none';
+
+        const headers = new LettaServer().getApiHeaders();
+
+        expect(headers.Authorization).toBeUndefined();
+        expect(headers['X-BARE-PASSWORD']).toBeUndefined();
+        expect(headers['Content-Type']).toBe('application/json');
+    });
 });
@@ -218,6 +218,26 @@ describe('Bulk Delete Agents', () => {
             expect(parsedResult.results[2].status).toBe('success');
         });
 
+        it('should count already deleted agents as success with ignore_missing', async () => {
+            mockApi.delete.mockResolvedValueOnce({ status: 200 }).mockRejectedValueOnce({
+                response: { status: 404, data: {} },
+                message: 'Not found',
+            });
+
+            const result = await handleBulkDeleteAgents(mockServer, {
+                agent_ids: ['agent-1', 'agent-2'],
+                ignore_missing: true,
+            });
+            const parsedResult = expectValidToolResponse(result);
+
+            expect(parsedResult.summary).toMatchObject({ success_count: 2, error_count: 0 });
+            expect(parsedResult.results[1]).toMatchObject({
+                agent_id: 'agent-2',
+                status: 'success',
+                already_absent: true,
+            });
+        });
+
         it('should handle no agents found', async () => {
             // Mock empty list response
             mockApi.get.mockImplementationOnce(() => Promise.resolve({ status: 200, data: [] }));
@@ -422,5 +442,92 @@ describe('Bulk Delete Agents', () => {
                 handleBulkDeleteAgents(mockServer, { agent_tag_filter: 'test' }),
             ).rejects.toThrow('Failed during bulk delete operation');
         });
+
+        it('should reject a non-positive max_concurrency', async () => {
+            await expect(
+                handleBulkDeleteAgents(mockServer, { agent_ids: ['agent-1'], max_concurrency: 0 }),
+            ).rejects.toThrow('max_concurrency must be a positive integer');
+            expect(mockApi.delete).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('Concurrency and Progress', () => {
+        it('should keep at most max_concurrency deletes in flight', async () => {
+            let inFlight = 0;
+            let peak = 0;
+            mockApi.delete.mockImplementation(async () => {
+                inFlight += 1;
+                peak = Math.max(peak, inFlight);
+                await new Promise((resolve) => setTimeout(resolve, 5));
+                inFlight -= 1;
+                return { status: 200 };
+            });
+            const agentIds = Array.from({ length: 10 }, (_, i) => `agent-${i}`);
+
+            const result = await handleBulkDeleteAgents(mockServer, {
+                agent_ids: agentIds,
+                max_concurrency: 3,
+            });
+            const parsedResult = expectValidToolResponse(result);
+
+            expect(peak).toBe(3);
+            expect(parsedResult.summary.success_count).toBe(10);
+            expect(parsedResult.results.map((r) => r.agent_id)).toEqual(agentIds);
+        });
+
+        it('should send a progress notification for each deleted agent', async () => {
+            mockApi.delete
+                .mockResolvedValueOnce({ status: 200 })
+                .mockRejectedValueOnce(new Error('Delete failed'));
+            const sendNotification = vi.fn().mockResolvedValue(undefined);
+
+            await handleBulkDeleteAgents(
+                mockServer,
+                { agent_ids: ['agent-1', 'agent-2'], max_concurrency: 1 },
+                { _meta: { progressToken: 'tok' }, sendNotification },
+            );
+
+            expect(sendNotification).toHaveBeenCalledTimes(2);
+            const params = sendNotification.mock.calls.map(([n]) => n.params);
+            expect(params.map((p) => [p.progress, p.total])).toEqual([
+                [1, 2],
+                [2, 2],
+            ]);
+            expect(sendNotification.mock.calls[0][0].method).toBe('notifications/progress');
+            expect(JSON.parse(params[0].message)).toMatchObject({
+                agent_id: 'agent-1',
+                status: 'success',
+            });
+            expect(JSON.parse(params[1].message)).toMatchObject({
+                agent_id: 'agent-2',
+                status: 'error',
+            });
+        });
+
+        it('should not send notifications without a progress token', async () => {
+            mockApi.delete.mockResolvedValue({ status: 200 });
+            const sendNotification = vi.fn();
+
+            await handleBulkDeleteAgents(
+                mockServer,
+                { agent_ids: ['agent-1'] },
+                { _meta: {}, sendNotification },
+            );
+
+            expect(sendNotification).not.toHaveBeenCalled();
+        });
+
+        it('should finish deleting when a progress notification fails', async () => {
+            mockApi.delete.mockResolvedValue({ status: 200 });
+            const sendNotification = vi.fn().mockRejectedValue(new Error('client gone'));
+
+            const result = await handleBulkDeleteAgents(
+                mockServer,
+                { agent_ids: ['agent-1', 'agent-2'] },
+                { _meta: { progressToken: 1 }, sendNotification },
+            );
+
+            expect(expectValidToolResponse(result).summary.success_count).toBe(2);
+        });
     });
 });
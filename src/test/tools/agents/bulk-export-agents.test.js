@@ -0,0 +1,145 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import {
+    handleBulkExportAgents,
+    bulkExportAgentsDefinition,
+} from '../../../tools/agents/bulk-export-agents.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const notFound = () => {
+    const error = new Error('Not found');
+    error.response = { status: 404, data: {} };
+    return error;
+};
+
+describe('Bulk Export Agents', () => {
+    let mockServer;
+    let exportDir;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        exportDir = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-exports-'));
+        mockServer.exportDir = exportDir;
+    });
+
+    afterEach(() => {
+        fs.rmSync(exportDir, { recursive: true, force: true });
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(bulkExportAgentsDefinition.name).toBe('bulk_export_agents');
+            expect(bulkExportAgentsDefinition.inputSchema.properties).toHaveProperty('agent_ids');
+            expect(bulkExportAgentsDefinition.inputSchema.properties).toHaveProperty(
+                'agent_tag_filter',
+            );
+            expect(bulkExportAgentsDefinition.inputSchema.required).toEqual([]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should write the exports to one archive keyed by agent ID', async () => {
+            mockServer.api.get.mockImplementation((url) =>
+                Promise.resolve({ data: { agents: [{ id: url.split('/')[2] }] } }),
+            );
+
+            const result = await handleBulkExportAgents(mockServer, {
+                agent_ids: ['agent-1', 'agent-2'],
+                output_path: 'nightly/agents.json',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(true);
+            expect(data.summary).toEqual({ total_agents: 2, success_count: 2, error_count: 0 });
+            expect(data.file_path).toBe(path.join(exportDir, 'nightly', 'agents.json'));
+            const archive = JSON.parse(fs.readFileSync(data.file_path, 'utf8'));
+            expect(Object.keys(archive.agents)).toEqual(['agent-1', 'agent-2']);
+            expect(data.size_bytes).toBe(fs.statSync(data.file_path).size);
+            expect(data.results[0]).toMatchObject({ agent_id: 'agent-1', status: 'success' });
+        });
+
+        it('should select agents by tag and return the exports inline', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: [{ id: 'agent-1', name: 'Support' }] })
+                .mockResolvedValueOnce({ data: { agents: [] } });
+
+            const result = await handleBulkExportAgents(mockServer, {
+                agent_tag_filter: 'prod',
+                inline: true,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/', {
+                headers: expect.any(Object),
+                params: { tags: 'prod' },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.exports).toEqual({ 'agent-1': { agents: [] } });
+            expect(data.file_path).toBeUndefined();
+            expect(fs.readdirSync(exportDir)).toEqual([]);
+        });
+
+        it('should report failed agents and still export the rest', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: { agents: [] } })
+                .mockRejectedValueOnce(notFound());
+
+            const result = await handleBulkExportAgents(mockServer, {
+                agent_ids: ['agent-1', 'missing'],
+                output_path: 'agents.json',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(false);
+            expect(data.summary).toEqual({ total_agents: 2, success_count: 1, error_count: 1 });
+            expect(data.results[1]).toMatchObject({
+                agent_id: 'missing',
+                status: 'error',
+                error: 'Agent not found: missing',
+                error_code: 'not_found',
+            });
+            const archive = JSON.parse(fs.readFileSync(data.file_path, 'utf8'));
+            expect(Object.keys(archive.agents)).toEqual(['agent-1']);
+        });
+
+        it('should not write an archive when no agent could be exported', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+
+            const result = await handleBulkExportAgents(mockServer, { agent_name_filter: 'none' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(false);
+            expect(data.file_path).toBeUndefined();
+            expect(fs.readdirSync(exportDir)).toEqual([]);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require a filter', async () => {
+            await expect(handleBulkExportAgents(mockServer, {})).rejects.toThrow(
+                'Missing required argument: Provide agent_ids, agent_name_filter, or agent_tag_filter.',
+            );
+        });
+
+        it('should refuse archive paths outside the export directory', async () => {
+            await expect(
+                handleBulkExportAgents(mockServer, {
+                    agent_ids: ['agent-1'],
+                    output_path: '../agents.json',
+                }),
+            ).rejects.toThrow('output_path must be inside the export directory');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should fail when the agents cannot be listed', async () => {
+            mockServer.api.get.mockRejectedValueOnce(new Error('Network Error'));
+
+            await expect(
+                handleBulkExportAgents(mockServer, { agent_tag_filter: 'prod' }),
+            ).rejects.toThrow('Network Error');
+        });
+    });
+});
@@ -155,6 +155,34 @@ describe('Create Agent', () => {
     });
 
     describe('Error Handling', () => {
+        it('should reject a malformed llm_config before creating the agent', async () => {
+            await expect(
+                handleCreateAgent(mockServer, {
+                    name: 'Agent',
+                    description: 'Bad config',
+                    llm_config: { model: 'gpt-4o', model_endpoint_type: 'openai' },
+                }),
+            ).rejects.toThrow('Missing required argument: llm_config.context_window');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should reject a malformed embedding_config before creating the agent', async () => {
+            await expect(
+                handleCreateAgent(mockServer, {
+                    name: 'Agent',
+                    description: 'Bad config',
+                    embedding_config: {
+                        embedding_endpoint_type: 'openai',
+                        embedding_model: 'text-embedding-3-small',
+                        embedding_dim: '1536',
+                    },
+                }),
+            ).rejects.toThrow(
+                'Invalid argument: embedding_config.embedding_dim must be an integer',
+            );
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
         it('should throw error for missing name', async () => {
             await expect(
                 handleCreateAgent(mockServer, {
@@ -220,6 +248,70 @@ describe('Create Agent', () => {
     });
 
     describe('Agent Configuration', () => {
+        it('should fall back to the server default configs', async () => {
+            const createdAgent = { ...fixtures.agent.basic, id: 'default-agent' };
+            mockServer.api.post.mockResolvedValue({ data: createdAgent });
+            mockServer.api.get.mockResolvedValue({ data: createdAgent });
+            const defaultLlm = {
+                model: 'gpt-4o',
+                model_endpoint_type: 'openai',
+                context_window: 8000,
+            };
+            const defaultEmbedding = {
+                embedding_endpoint_type: 'openai',
+                embedding_model: 'text-embedding-3-small',
+                embedding_dim: 1536,
+            };
+            mockServer.defaultModelConfigs = {
+                llmConfig: defaultLlm,
+                embeddingConfig: defaultEmbedding,
+            };
+
+            await handleCreateAgent(mockServer, { name: 'A', description: 'Defaults' });
+            await handleCreateAgent(mockServer, {
+                name: 'B',
+                description: 'Explicit choices win',
+                llm_config: { ...defaultLlm, model: 'gpt-4.1' },
+                embedding: 'openai/text-embedding-3-large',
+            });
+
+            const [defaulted, explicit] = mockServer.api.post.mock.calls.map((call) => call[1]);
+            expect(defaulted.llm_config).toEqual(defaultLlm);
+            expect(defaulted.embedding_config).toEqual(defaultEmbedding);
+            expect(explicit.llm_config.model).toBe('gpt-4.1');
+            expect(explicit.embedding).toBe('openai/text-embedding-3-large');
+            expect(explicit).not.toHaveProperty('embedding_config');
+        });
+
+        it('should send explicit configs in place of the model handles', async () => {
+            const createdAgent = { ...fixtures.agent.basic, id: 'configured-agent' };
+            mockServer.api.post.mockResolvedValueOnce({ data: createdAgent });
+            mockServer.api.get.mockResolvedValueOnce({ data: createdAgent });
+            const llmConfig = {
+                model: 'gpt-4o',
+                model_endpoint_type: 'openai',
+                context_window: 128000,
+            };
+            const embeddingConfig = {
+                embedding_endpoint_type: 'openai',
+                embedding_model: 'text-embedding-3-small',
+                embedding_dim: 1536,
+            };
+
+            await handleCreateAgent(mockServer, {
+                name: 'Configured Agent',
+                description: 'Explicit configs',
+                llm_config: llmConfig,
+                embedding_config: embeddingConfig,
+            });
+
+            const payload = mockServer.api.post.mock.calls[0][1];
+            expect(payload.llm_config).toEqual(llmConfig);
+            expect(payload.embedding_config).toEqual(embeddingConfig);
+            expect(payload).not.toHaveProperty('model');
+            expect(payload).not.toHaveProperty('embedding');
+        });
+
         it('should create agent with correct llm_config structure', async () => {
             const createdAgent = { ...fixtures.agent.basic, id: 'config-test-agent' };
 
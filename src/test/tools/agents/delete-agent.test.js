@@ -288,5 +288,36 @@ describe('Delete Agent', () => {
                 handleDeleteAgent(mockServer, { agent_id: 'agent-to-delete' }),
             ).rejects.toThrow('Agent not found: agent-to-delete');
         });
+
+        it('should treat an already deleted agent as success with ignore_missing', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404 };
+            mockServer.api.delete.mockRejectedValueOnce(error);
+
+            const result = await handleDeleteAgent(mockServer, {
+                agent_id: 'agent-to-delete',
+                ignore_missing: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({ agent_id: 'agent-to-delete', already_absent: true });
+        });
+
+        it('should still report other errors with ignore_missing', async () => {
+            const error = new Error('Forbidden');
+            error.response = { status: 403 };
+            mockServer.api.delete.mockRejectedValueOnce(error);
+
+            await expect(
+                handleDeleteAgent(mockServer, { agent_id: 'agent-1', ignore_missing: true }),
+            ).rejects.toThrow('Forbidden');
+        });
+
+        it('should reject a non-boolean ignore_missing', async () => {
+            await expect(
+                handleDeleteAgent(mockServer, { agent_id: 'agent-1', ignore_missing: 'yes' }),
+            ).rejects.toThrow('Invalid argument: ignore_missing must be a boolean');
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+        });
     });
 });
@@ -0,0 +1,116 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleDescribeAgent,
+    describeAgentDefinition,
+} from '../../../tools/agents/describe-agent.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const responses = {
+    '/agents/agent-1': {
+        id: 'agent-1',
+        name: 'Support',
+        system: 'You are a support agent.',
+        tags: ['prod'],
+        llm_config: { model: 'gpt-4o-mini' },
+        created_at: '2025-01-01T00:00:00Z',
+    },
+    '/agents/agent-1/core-memory/blocks': [
+        { id: 'block-1', label: 'persona', value: 'Helpful', limit: 5000 },
+    ],
+    '/agents/agent-1/tools': [{ id: 'tool-1', name: 'send_message', tool_type: 'letta_core' }],
+    '/agents/agent-1/sources': [{ id: 'source-1', name: 'Docs' }],
+    '/agents/agent-1/context': {
+        num_messages: 12,
+        num_recall_memory: 40,
+        num_archival_memory: 3,
+        context_window_size_current: 2500,
+        context_window_size_max: 8000,
+    },
+};
+
+describe('Describe Agent', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.api.get.mockImplementation((url) =>
+            url in responses
+                ? Promise.resolve({ data: responses[url] })
+                : Promise.reject(new Error(`Unexpected URL ${url}`)),
+        );
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(describeAgentDefinition.name).toBe('describe_agent');
+            expect(describeAgentDefinition.inputSchema.required).toEqual(['agent_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should assemble every section', async () => {
+            const result = await handleDescribeAgent(mockServer, { agent_id: 'agent-1' });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(5);
+            const data = expectValidToolResponse(result);
+            expect(data).toMatchObject({
+                agent_id: 'agent-1',
+                name: 'Support',
+                system_prompt: 'You are a support agent.',
+                tags: ['prod'],
+                created_at: '2025-01-01T00:00:00Z',
+                memory_blocks: [
+                    { id: 'block-1', label: 'persona', chars: 7, limit: 5000, read_only: false },
+                ],
+                tools: [{ id: 'tool-1', name: 'send_message', type: 'letta_core' }],
+                sources: [{ id: 'source-1', name: 'Docs' }],
+                context: {
+                    message_count: 12,
+                    recall_message_count: 40,
+                    archival_passage_count: 3,
+                    tokens_used: 2500,
+                    tokens_max: 8000,
+                },
+            });
+            expect(data).not.toHaveProperty('errors');
+        });
+
+        it('should report sections that failed to load', async () => {
+            delete responses['/agents/agent-1/sources'];
+
+            try {
+                const result = await handleDescribeAgent(mockServer, { agent_id: 'agent-1' });
+
+                const data = expectValidToolResponse(result);
+                expect(data.sources).toBeNull();
+                expect(data.errors).toEqual({ sources: 'Unexpected URL /agents/agent-1/sources' });
+                expect(data.tools).toHaveLength(1);
+            } finally {
+                responses['/agents/agent-1/sources'] = [{ id: 'source-1', name: 'Docs' }];
+            }
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleDescribeAgent(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should report an unknown agent', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValue(error);
+
+            await expect(handleDescribeAgent(mockServer, { agent_id: 'missing' })).rejects.toThrow(
+                'Agent not found: missing',
+            );
+        });
+    });
+});
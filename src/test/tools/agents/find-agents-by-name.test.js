@@ -0,0 +1,111 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleFindAgentsByName,
+    findAgentsByNameDefinition,
+} from '../../../tools/agents/find-agents-by-name.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Find Agents By Name', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(findAgentsByNameDefinition.name).toBe('find_agents_by_name');
+            expect(findAgentsByNameDefinition.inputSchema.required).toEqual(['name']);
+            expect(findAgentsByNameDefinition.inputSchema.properties.substring.default).toBe(false);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should look up an exact name on the server', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [{ id: 'agent-1', name: 'support-bot', tags: ['prod'] }],
+            });
+
+            const result = await handleFindAgentsByName(mockServer, { name: 'support-bot' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/', {
+                headers: expect.any(Object),
+                params: { limit: 200, name: 'support-bot' },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.exists).toBe(true);
+            expect(data.count).toBe(1);
+            expect(data.agents[0]).toMatchObject({ id: 'agent-1', tags: ['prod'] });
+        });
+
+        it('should report no match with an empty result', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+
+            const result = await handleFindAgentsByName(mockServer, { name: 'missing' });
+
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({ exists: false, count: 0, agents: [] });
+        });
+
+        it('should not treat a similar name as an exact match', async () => {
+            // A server that ignores the name filter returns everything
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { id: 'agent-1', name: 'support-bot-2' },
+                    { id: 'agent-2', name: 'Support-Bot' },
+                ],
+            });
+
+            const result = await handleFindAgentsByName(mockServer, { name: 'support-bot' });
+
+            expect(expectValidToolResponse(result).exists).toBe(false);
+        });
+
+        it('should match substrings case-insensitively across pages', async () => {
+            const firstPage = Array.from({ length: 200 }, (_, i) => ({
+                id: `agent-${i}`,
+                name: i === 5 ? 'Billing Support' : `Agent ${i}`,
+            }));
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: firstPage })
+                .mockResolvedValueOnce({ data: [{ id: 'agent-200', name: 'support-bot' }] });
+
+            const result = await handleFindAgentsByName(mockServer, {
+                name: 'SUPPORT',
+                substring: true,
+            });
+
+            expect(mockServer.api.get).toHaveBeenLastCalledWith('/agents/', {
+                headers: expect.any(Object),
+                params: { limit: 200, query_text: 'SUPPORT', after: 'agent-199' },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.agents.map((a) => a.id)).toEqual(['agent-5', 'agent-200']);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require name', async () => {
+            await expect(handleFindAgentsByName(mockServer, {})).rejects.toThrow(
+                'Missing required argument: name',
+            );
+        });
+
+        it('should reject a non-boolean substring flag', async () => {
+            await expect(
+                handleFindAgentsByName(mockServer, { name: 'bot', substring: 'yes' }),
+            ).rejects.toThrow('substring must be a boolean');
+        });
+
+        it('should surface API errors', async () => {
+            mockServer.api.get.mockRejectedValueOnce(new Error('Network error'));
+
+            await expect(handleFindAgentsByName(mockServer, { name: 'bot' })).rejects.toThrow();
+        });
+    });
+});
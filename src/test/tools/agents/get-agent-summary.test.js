@@ -54,6 +54,8 @@ describe('Get Agent Summary', () => {
                 name: 'Test Agent',
                 description: 'A test agent',
                 system: 'You are a helpful assistant with extensive knowledge.',
+                created_at: '2024-01-01T00:00:00Z',
+                updated_at: '2024-01-15T12:00:00Z',
                 llm_config: {
                     handle: 'gpt-4',
                     model: 'gpt-4',
@@ -135,6 +137,8 @@ describe('Get Agent Summary', () => {
                 })),
                 attached_sources_count: 2,
                 attached_sources: mockSources.map((s) => ({ id: s.id, name: s.name })),
+                created_at: '2024-01-01T00:00:00Z',
+                updated_at: '2024-01-15T12:00:00Z',
             });
 
             // Verify all endpoints were called
@@ -46,8 +46,9 @@ describe('Import Agent Tool (LMP-94)', () => {
                 Authorization: 'Bearer test-password',
                 'Content-Type': 'application/json',
             }),
-            createErrorResponse: vi.fn((error) => {
-                throw new Error(error);
+            createErrorResponse: vi.fn((error, context) => {
+                const message = typeof error === 'string' ? error : error.message;
+                throw new Error(context ? `${context}: ${message}` : message);
             }),
         };
     });
@@ -72,8 +73,10 @@ describe('Import Agent Tool (LMP-94)', () => {
             expect(importAgentDefinition.inputSchema.properties).toHaveProperty('file_path');
         });
 
-        it('should require file_path parameter', () => {
-            expect(importAgentDefinition.inputSchema.required).toContain('file_path');
+        it('should accept file_path, export_data, or file_data as the import source', () => {
+            expect(importAgentDefinition.inputSchema.required).toEqual([]);
+            expect(importAgentDefinition.inputSchema.properties).toHaveProperty('export_data');
+            expect(importAgentDefinition.inputSchema.properties).toHaveProperty('file_data');
         });
     });
 
@@ -202,6 +205,112 @@ describe('Import Agent Tool (LMP-94)', () => {
             expect(appendCalls[0][2]).toMatch(/agent\.json$/);
         });
 
+        it('should import from an export_data object', async () => {
+            const exportData = { name: 'Exported Agent', system: 'You are helpful' };
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'agent-new', name: 'Exported Agent' },
+            });
+
+            const result = await handleImportAgent(mockServer, { export_data: exportData });
+
+            const [field, payload, filename] = mockFormDataInstance.append.mock.calls[0];
+            expect(field).toBe('file');
+            expect(JSON.parse(payload.toString())).toEqual(exportData);
+            expect(filename).toBe('agent_export.json');
+            expect(fs.existsSync).not.toHaveBeenCalled();
+
+            const responseData = JSON.parse(result.content[0].text);
+            expect(responseData.agent_id).toBe('agent-new');
+        });
+
+        it('should import from an export_data JSON string', async () => {
+            const exportData = { name: 'Exported Agent' };
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'agent-new' } });
+
+            await handleImportAgent(mockServer, { export_data: JSON.stringify(exportData) });
+
+            const payload = mockFormDataInstance.append.mock.calls[0][1];
+            expect(JSON.parse(payload.toString())).toEqual(exportData);
+        });
+
+        it('should reject export_data strings that are not valid JSON', async () => {
+            await expect(
+                handleImportAgent(mockServer, { export_data: '{not json' }),
+            ).rejects.toThrow('export_data is not valid JSON');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should import from base64 file_data', async () => {
+            const fileContent = JSON.stringify({ name: 'From AF' });
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'agent-af' } });
+
+            await handleImportAgent(mockServer, {
+                file_data: Buffer.from(fileContent).toString('base64'),
+                file_name: 'backup.af',
+            });
+
+            const [, payload, filename] = mockFormDataInstance.append.mock.calls[0];
+            expect(payload.toString()).toBe(fileContent);
+            expect(filename).toBe('backup.af');
+        });
+
+        it('should round-trip the payload produced by export_agent', async () => {
+            const exported = { name: 'Original', system: 'sys', tools: [{ name: 'tool1' }] };
+            const base64Data = Buffer.from(JSON.stringify(exported, null, 2)).toString('base64');
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'agent-clone', name: 'Original_copy' },
+            });
+
+            const result = await handleImportAgent(mockServer, { file_data: base64Data });
+
+            const payload = mockFormDataInstance.append.mock.calls[0][1];
+            expect(JSON.parse(payload.toString())).toEqual(exported);
+            const responseData = JSON.parse(result.content[0].text);
+            expect(responseData.agent_id).toBe('agent-clone');
+        });
+
+        it('should pass server validation errors on with their status and details', async () => {
+            const mockError = new Error('Request failed with status code 422');
+            mockError.response = {
+                status: 422,
+                data: { detail: 'Agent schema version 0.9 is not supported' },
+            };
+            mockServer.api.post.mockRejectedValueOnce(mockError);
+
+            await expect(
+                handleImportAgent(mockServer, { export_data: { name: 'Old Agent' } }),
+            ).rejects.toThrow('Failed to import agent from export_data');
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                expect.objectContaining({
+                    response: {
+                        status: 422,
+                        data: { detail: 'Agent schema version 0.9 is not supported' },
+                    },
+                }),
+                'Failed to import agent from export_data',
+            );
+        });
+
+        it('should reject file_data that is not valid base64', async () => {
+            const valid = Buffer.from(JSON.stringify({ name: 'From AF' })).toString('base64');
+            const corrupted = `${valid.slice(0, 8)}*%${valid.slice(8)}`;
+
+            await expect(handleImportAgent(mockServer, { file_data: corrupted })).rejects.toThrow(
+                'file_data is not valid base64 content',
+            );
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should accept line-wrapped base64 file_data', async () => {
+            const fileContent = JSON.stringify({ name: 'Wrapped' });
+            const wrapped = Buffer.from(fileContent).toString('base64').replace(/.{8}/g, '$&\n');
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'agent-af' } });
+
+            await handleImportAgent(mockServer, { file_data: wrapped });
+
+            expect(mockFormDataInstance.append.mock.calls[0][1].toString()).toBe(fileContent);
+        });
+
         it('should handle network errors', async () => {
             const mockError = new Error('Network error');
             mockError.code = 'ECONNREFUSED';
@@ -214,6 +214,242 @@ describe('List Agents', () => {
         });
     });
 
+    describe('Pagination', () => {
+        const makeAgents = (count, start = 0) =>
+            Array.from({ length: count }, (_, i) => ({
+                id: `agent-${start + i}`,
+                name: `Agent ${start + i}`,
+            }));
+
+        it('should expose cursor and offset parameters', () => {
+            const props = listAgentsToolDefinition.inputSchema.properties;
+            expect(props.limit.type).toBe('integer');
+            expect(props.after.type).toBe('string');
+            expect(props.before.type).toBe('string');
+            expect(props.offset.type).toBe('integer');
+        });
+
+        it('should pass cursors through and return the next cursor', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeAgents(3, 10) });
+
+            const result = await handleListAgents(mockServer, { limit: 2, after: 'agent-9' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/',
+                expect.objectContaining({ params: { after: 'agent-9', limit: 3 } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.agents.map((a) => a.id)).toEqual(['agent-10', 'agent-11']);
+            expect(data.has_more).toBe(true);
+            expect(data.next_cursor).toBe('agent-11');
+            expect(data.prev_cursor).toBe('agent-10');
+        });
+
+        it('should report has_more false on the last page', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeAgents(2, 20) });
+
+            const result = await handleListAgents(mockServer, { limit: 5, after: 'agent-19' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(2);
+            expect(data.has_more).toBe(false);
+            expect(data.next_cursor).toBeNull();
+        });
+
+        it('should translate offset client-side', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeAgents(6) });
+
+            const result = await handleListAgents(mockServer, { limit: 2, offset: 3 });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/',
+                expect.objectContaining({ params: { limit: 6 } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.agents.map((a) => a.id)).toEqual(['agent-3', 'agent-4']);
+            expect(data.has_more).toBe(true);
+            expect(data.next_cursor).toBe('agent-4');
+        });
+
+        it('should not paginate when no pagination arguments are given', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeAgents(4) });
+
+            const result = await handleListAgents(mockServer, {});
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/',
+                expect.objectContaining({ params: {} }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(4);
+            expect(data.has_more).toBe(false);
+            expect(data.prev_cursor).toBeNull();
+        });
+
+        it('should reject invalid limit and offset values', async () => {
+            await expect(handleListAgents(mockServer, { limit: 0 })).rejects.toThrow(
+                'limit must be a positive integer',
+            );
+            await expect(handleListAgents(mockServer, { offset: -1 })).rejects.toThrow(
+                'offset must be a non-negative integer',
+            );
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('Name and Tag Filters', () => {
+        const makeAgents = (count) =>
+            Array.from({ length: count }, (_, i) => ({ id: `agent-${i}`, name: `Agent ${i}` }));
+
+        it('should send tags and match_all_tags to the server as repeated keys', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeAgents(2) });
+
+            await handleListAgents(mockServer, { tags: ['prod', 'support'], match_all_tags: true });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/', {
+                headers: expect.any(Object),
+                params: { tags: ['prod', 'support'], match_all_tags: true },
+                paramsSerializer: { indexes: null },
+            });
+        });
+
+        it('should default match_all_tags to false', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeAgents(2) });
+
+            await handleListAgents(mockServer, { tags: ['prod'] });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/',
+                expect.objectContaining({ params: { tags: ['prod'], match_all_tags: false } }),
+            );
+        });
+
+        it('should search by name on the server and match case-insensitively', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { id: 'agent-1', name: 'Support-Bot' },
+                    { id: 'agent-2', name: 'Research Assistant' },
+                    { id: 'agent-3', name: 'billing support' },
+                ],
+            });
+
+            const result = await handleListAgents(mockServer, { name_contains: 'SUPPORT' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/',
+                expect.objectContaining({ params: { query_text: 'SUPPORT' } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.agents.map((a) => a.id)).toEqual(['agent-1', 'agent-3']);
+        });
+
+        it('should reject invalid filter arguments', async () => {
+            await expect(handleListAgents(mockServer, { tags: 'prod' })).rejects.toThrow(
+                'tags must be an array of strings',
+            );
+            await expect(handleListAgents(mockServer, { name_contains: 5 })).rejects.toThrow(
+                'name_contains must be a string',
+            );
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                expect.objectContaining({ errorCode: 'invalid_request', field: 'name_contains' }),
+            );
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should filter before the limit, paging until the page is full', async () => {
+            const page = (start, names) =>
+                names.map((name, i) => ({ id: `agent-${start + i}`, name }));
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: page(0, ['Support 1', 'Other', 'Other']) })
+                .mockResolvedValueOnce({ data: page(3, ['Other', 'Support 2', 'Support 3']) });
+
+            const result = await handleListAgents(mockServer, { filter: 'support', limit: 2 });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(2);
+            expect(mockServer.api.get).toHaveBeenLastCalledWith(
+                '/agents/',
+                expect.objectContaining({ params: { limit: 3, after: 'agent-2' } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.agents.map((a) => a.id)).toEqual(['agent-0', 'agent-4']);
+            expect(data.has_more).toBe(true);
+            expect(data.next_cursor).toBe('agent-4');
+        });
+
+        it('should match tags client-side when the server ignores them', async () => {
+            const agents = [
+                { id: 'agent-1', name: 'A', tags: ['prod', 'support'] },
+                { id: 'agent-2', name: 'B', tags: ['prod'] },
+                { id: 'agent-3', name: 'C' },
+            ];
+            mockServer.api.get.mockResolvedValue({ data: agents });
+
+            const any = expectValidToolResponse(
+                await handleListAgents(mockServer, { tags: ['support', 'prod'] }),
+            );
+            const all = expectValidToolResponse(
+                await handleListAgents(mockServer, {
+                    tags: ['support', 'prod'],
+                    match_all_tags: true,
+                }),
+            );
+
+            expect(any.agents.map((a) => a.id)).toEqual(['agent-1', 'agent-2']);
+            expect(all.agents.map((a) => a.id)).toEqual(['agent-1']);
+        });
+
+        it('should stop paging when the server ignores the after cursor', async () => {
+            mockServer.api.get.mockResolvedValue({ data: makeAgents(3) });
+
+            const result = await handleListAgents(mockServer, { filter: 'agent 1', limit: 2 });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(2);
+            const data = expectValidToolResponse(result);
+            expect(data.agents.map((a) => a.id)).toEqual(['agent-1']);
+            expect(data.has_more).toBe(false);
+        });
+
+        it('should cap the pages scanned and return a cursor to resume from', async () => {
+            let next = 0;
+            mockServer.api.get.mockImplementation(async () => {
+                const start = next;
+                next += 3;
+                return {
+                    data: Array.from({ length: 3 }, (_, i) => ({
+                        id: `agent-${start + i}`,
+                        name: 'Other',
+                    })),
+                };
+            });
+
+            const result = await handleListAgents(mockServer, { filter: 'support', limit: 2 });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(50);
+            const data = expectValidToolResponse(result);
+            expect(data.agents).toEqual([]);
+            expect(data.has_more).toBe(true);
+            expect(data.next_cursor).toBe('agent-149');
+        });
+
+        it('should combine name_contains and filter', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { id: 'agent-1', name: 'Support Bot', description: 'billing' },
+                    { id: 'agent-2', name: 'Support Bot 2', description: 'research' },
+                    { id: 'agent-3', name: 'Billing Bot', description: 'billing' },
+                ],
+            });
+
+            const result = await handleListAgents(mockServer, {
+                name_contains: 'support',
+                filter: 'billing',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.agents.map((a) => a.id)).toEqual(['agent-1']);
+        });
+    });
+
     describe('Edge Cases', () => {
         it('should handle very large agent lists', async () => {
             // Create 1000 agents
@@ -0,0 +1,262 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleMigrateAgent, migrateAgentDefinition } from '../../../tools/agents/migrate-agent.js';
+import { handleWaitForJob } from '../../../tools/jobs/wait-for-job.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const EMBEDDING_CONFIG = {
+    embedding_endpoint_type: 'openai',
+    embedding_model: 'text-embedding-3-small',
+    embedding_dim: 1536,
+};
+
+const notFound = () => Object.assign(new Error('Not found'), { response: { status: 404 } });
+
+/**
+ * Wait for the background migration recorded on the mock server to finish
+ */
+async function waitForJob(mockServer, jobId) {
+    const job = mockServer.localJobs.get(jobId);
+    for (let i = 0; i < 100 && job.status === 'running'; i++) {
+        await new Promise((resolve) => setImmediate(resolve));
+    }
+    return job;
+}
+
+describe('Migrate Agent', () => {
+    let mockServer;
+    let blocks;
+    let passages;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        blocks = [
+            { id: 'block-1', label: 'persona', value: 'helpful' },
+            {
+                id: 'block-2',
+                label: 'human',
+                value: 'Ada',
+                metadata: { preserve_on_migration: true },
+            },
+        ];
+        passages = [
+            { id: 'passage-1', text: 'first', tags: ['notes'] },
+            { id: 'passage-2', text: 'second' },
+            { id: 'passage-3', text: 'from a file', source_id: 'source-1' },
+        ];
+        mockServer.api.get.mockImplementation(async (url) => {
+            if (url.endsWith('/core-memory/blocks')) return { data: blocks };
+            if (url.endsWith('/archival-memory')) return { data: passages };
+            throw new Error(`Unexpected GET ${url}`);
+        });
+        mockServer.api.patch.mockImplementation(async (url, body) => ({
+            data: { id: 'agent-1', ...body },
+        }));
+        mockServer.api.post.mockResolvedValue({ data: [{ id: 'passage-new' }] });
+        mockServer.api.delete.mockResolvedValue({ data: {} });
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(migrateAgentDefinition.name).toBe('migrate_agent');
+            expect(migrateAgentDefinition.inputSchema.required).toEqual(['agent_id']);
+            expect(migrateAgentDefinition.inputSchema.properties).toHaveProperty(
+                'embedding_config',
+            );
+            expect(migrateAgentDefinition.inputSchema.properties).toHaveProperty(
+                'reembed_passages',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should update the agent and return a job that re-embeds its passages', async () => {
+            const result = await handleMigrateAgent(mockServer, {
+                agent_id: 'agent-1',
+                embedding_config: EMBEDDING_CONFIG,
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-1',
+                { embedding_config: EMBEDDING_CONFIG },
+                expect.any(Object),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.job_id).toMatch(/^agent_migration-/);
+            expect(data.status).toBe('running');
+            expect(data.embedding_config).toEqual(EMBEDDING_CONFIG);
+            expect(data.reembed_passages).toBe(true);
+            expect(data.preserved_blocks).toEqual(['human']);
+
+            const job = await waitForJob(mockServer, data.job_id);
+            expect(job.status).toBe('completed');
+            expect(job.metadata.completed_steps).toBe(2);
+            expect(job.metadata.total_steps).toBe(2);
+
+            // Each passage is copied before the original is removed; file passages are skipped
+            expect(mockServer.api.post).toHaveBeenCalledTimes(2);
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-1/archival-memory',
+                { text: 'first', tags: ['notes'] },
+                expect.any(Object),
+            );
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-1/archival-memory',
+                { text: 'second' },
+                expect.any(Object),
+            );
+            expect(mockServer.api.delete).toHaveBeenCalledWith(
+                '/agents/agent-1/archival-memory/passage-1',
+                expect.any(Object),
+            );
+            expect(mockServer.api.delete).not.toHaveBeenCalledWith(
+                '/agents/agent-1/archival-memory/passage-3',
+                expect.any(Object),
+            );
+        });
+
+        it('should track the running migration so shutdown waits for it', async () => {
+            const result = await handleMigrateAgent(mockServer, {
+                agent_id: 'agent-1',
+                embedding_config: EMBEDDING_CONFIG,
+            });
+            const { job_id } = expectValidToolResponse(result);
+
+            expect(mockServer.inFlight.size).toBe(1);
+            await waitForJob(mockServer, job_id);
+            await new Promise((resolve) => setImmediate(resolve));
+            expect(mockServer.inFlight.size).toBe(0);
+        });
+
+        it('should be reported by wait_for_job', async () => {
+            const result = await handleMigrateAgent(mockServer, {
+                agent_id: 'agent-1',
+                embedding: 'openai/text-embedding-3-small',
+            });
+            const { job_id } = expectValidToolResponse(result);
+
+            const waited = expectValidToolResponse(
+                await handleWaitForJob(mockServer, {
+                    job_id,
+                    wait_secs: 5,
+                    poll_interval_secs: 0.01,
+                }),
+            );
+
+            expect(waited.done).toBe(true);
+            expect(waited.job.status).toBe('completed');
+            expect(waited.job.progress_percent).toBe(100);
+        });
+
+        it('should not re-embed passages when only the model changes', async () => {
+            const result = await handleMigrateAgent(mockServer, {
+                agent_id: 'agent-1',
+                model: 'openai/gpt-4o',
+            });
+            const data = expectValidToolResponse(result);
+
+            const job = await waitForJob(mockServer, data.job_id);
+            expect(data.reembed_passages).toBe(false);
+            expect(job.status).toBe('completed');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should restore preserved blocks the migration changed', async () => {
+            mockServer.api.patch.mockImplementation(async (url, body) => {
+                if (url === '/agents/agent-1') {
+                    blocks = [blocks[0]];
+                }
+                return { data: { id: 'agent-1', ...body } };
+            });
+
+            const result = await handleMigrateAgent(mockServer, {
+                agent_id: 'agent-1',
+                embedding_config: EMBEDDING_CONFIG,
+                reembed_passages: false,
+            });
+            const job = await waitForJob(mockServer, expectValidToolResponse(result).job_id);
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-1/core-memory/blocks/attach/block-2',
+                {},
+                expect.any(Object),
+            );
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/blocks/block-2',
+                { value: 'Ada' },
+                expect.any(Object),
+            );
+            expect(job.metadata.restored_blocks).toEqual(['human']);
+        });
+
+        it('should record passages that could not be re-embedded and fail the job', async () => {
+            mockServer.api.post
+                .mockRejectedValueOnce(new Error('Embedding provider unavailable'))
+                .mockResolvedValue({ data: [{ id: 'passage-new' }] });
+
+            const result = await handleMigrateAgent(mockServer, {
+                agent_id: 'agent-1',
+                embedding_config: EMBEDDING_CONFIG,
+            });
+            const job = await waitForJob(mockServer, expectValidToolResponse(result).job_id);
+
+            expect(job.status).toBe('failed');
+            expect(job.metadata.error).toBe('1 of 2 passages could not be re-embedded');
+            expect(job.metadata.failed_passages).toEqual([
+                { id: 'passage-1', error: 'Embedding provider unavailable' },
+            ]);
+            // The original is kept when its copy could not be created
+            expect(mockServer.api.delete).not.toHaveBeenCalledWith(
+                '/agents/agent-1/archival-memory/passage-1',
+                expect.any(Object),
+            );
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleMigrateAgent(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should require something to change', async () => {
+            await expect(handleMigrateAgent(mockServer, { agent_id: 'agent-1' })).rejects.toThrow(
+                'Missing required argument: one of embedding_config, embedding, llm_config, model',
+            );
+        });
+
+        it('should validate the new configs before changing the agent', async () => {
+            await expect(
+                handleMigrateAgent(mockServer, {
+                    agent_id: 'agent-1',
+                    embedding_config: { embedding_model: 'text-embedding-3-small' },
+                }),
+            ).rejects.toThrow('embedding_config.embedding_endpoint_type');
+            await expect(
+                handleMigrateAgent(mockServer, {
+                    agent_id: 'agent-1',
+                    llm_config: { model: 'gpt-4o', model_endpoint_type: 'openai' },
+                }),
+            ).rejects.toThrow('llm_config.context_window');
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+            expect(mockServer.localJobs.size).toBe(0);
+        });
+
+        it('should report a missing agent', async () => {
+            mockServer.api.get.mockRejectedValueOnce(notFound());
+
+            await expect(
+                handleMigrateAgent(mockServer, {
+                    agent_id: 'missing',
+                    embedding_config: EMBEDDING_CONFIG,
+                }),
+            ).rejects.toThrow('Agent not found: missing');
+            expect(mockServer.localJobs.size).toBe(0);
+        });
+    });
+});
@@ -30,6 +30,9 @@ describe('Modify Agent', () => {
             expect(updateDataProp.properties).toHaveProperty('name');
             expect(updateDataProp.properties).toHaveProperty('system');
             expect(updateDataProp.properties).toHaveProperty('description');
+            expect(updateDataProp.properties).toHaveProperty('llm_config');
+            expect(updateDataProp.properties).toHaveProperty('embedding_config');
+            expect(updateDataProp.properties).toHaveProperty('tool_ids');
             expect(updateDataProp.additionalProperties).toBe(true);
         });
     });
@@ -157,25 +160,44 @@ describe('Modify Agent', () => {
             expect(data.agent.embedding_config.model).toBe('text-embedding-3-large');
         });
 
-        it('should handle empty update_data gracefully', async () => {
+        it('should treat empty update_data as a no-op', async () => {
             const unchangedAgent = fixtures.agent.basic;
-            mockServer.api.patch.mockResolvedValueOnce({ data: unchangedAgent });
+            mockServer.api.get.mockResolvedValueOnce({ data: unchangedAgent });
 
             const result = await handleModifyAgent(mockServer, {
                 agent_id: 'agent-123',
                 update_data: {},
             });
 
-            expect(mockServer.api.patch).toHaveBeenCalledWith(
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+            expect(mockServer.api.get).toHaveBeenCalledWith(
                 '/agents/agent-123',
-                {},
-                expect.any(Object),
+                expect.objectContaining({ headers: expect.any(Object) }),
             );
 
             const data = expectValidToolResponse(result);
             expect(data.agent).toEqual(unchangedAgent);
         });
 
+        it('should only send provided fields', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: fixtures.agent.basic });
+
+            await handleModifyAgent(mockServer, {
+                agent_id: 'agent-123',
+                update_data: {
+                    name: 'New Name',
+                    system: undefined,
+                    description: null,
+                },
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-123',
+                { name: 'New Name', description: null },
+                expect.any(Object),
+            );
+        });
+
         it('should update tool_ids array', async () => {
             const updatedAgent = {
                 ...fixtures.agent.basic,
@@ -231,6 +253,24 @@ describe('Modify Agent', () => {
             ).rejects.toThrow('Missing required argument: update_data');
         });
 
+        it('should throw error for non-string agent_id', async () => {
+            await expect(
+                handleModifyAgent(mockServer, {
+                    agent_id: 123,
+                    update_data: { name: 'New Name' },
+                }),
+            ).rejects.toThrow('Invalid argument: agent_id must be a string');
+        });
+
+        it('should throw error for non-object update_data', async () => {
+            await expect(
+                handleModifyAgent(mockServer, {
+                    agent_id: 'agent-123',
+                    update_data: ['name'],
+                }),
+            ).rejects.toThrow('Invalid argument: update_data must be an object');
+        });
+
         it('should handle agent not found (404)', async () => {
             const error = new Error('Not found');
             error.response = { status: 404 };
@@ -265,7 +305,7 @@ describe('Modify Agent', () => {
                         temperature: 2.0,
                     },
                 }),
-            ).rejects.toThrow(/Validation error updating agent agent-123/);
+            ).rejects.toThrow(/Failed to update agent agent-123/);
         });
 
         it('should handle server errors (500)', async () => {
@@ -2,6 +2,7 @@ import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
 import {
     handlePromptAgent,
     promptAgentToolDefinition,
+    extractUsage,
 } from '../../../tools/agents/prompt-agent.js';
 import { createMockLettaServer } from '../../utils/mock-server.js';
 import { fixtures } from '../../utils/test-fixtures.js';
@@ -381,4 +382,258 @@ describe('Prompt Agent', () => {
             expect(data.response).toContain('Response with spaces');
         });
     });
+
+    describe('Usage', () => {
+        it('should report token usage when the server includes it', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: fixtures.agent.basic });
+            const sseResponse = [
+                'data: {"message_type": "assistant_message", "content": "Done."}',
+                'data: {"message_type": "stop_reason", "stop_reason": "end_turn"}',
+                'data: {"message_type": "usage_statistics", "prompt_tokens": 812, "completion_tokens": 45, "total_tokens": 857, "step_count": 2}',
+                'data: [DONE]',
+            ].join('\n');
+            mockServer.api.post.mockResolvedValueOnce({ data: sseResponse });
+
+            const result = await handlePromptAgent(mockServer, {
+                agent_id: 'agent-123',
+                message: 'Wrap up',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.response).toBe('Done.');
+            expect(data.usage).toEqual({
+                prompt_tokens: 812,
+                completion_tokens: 45,
+                total_tokens: 857,
+                step_count: 2,
+            });
+        });
+
+        it('should omit usage when the server does not report it', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: fixtures.agent.basic });
+            mockServer.api.post.mockResolvedValueOnce({
+                data: 'data: {"message_type": "assistant_message", "content": "Hi"}\n',
+            });
+
+            const result = await handlePromptAgent(mockServer, {
+                agent_id: 'agent-123',
+                message: 'Hello',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data).not.toHaveProperty('usage');
+        });
+
+        it('should extract only token counts from usage statistics', () => {
+            expect(
+                extractUsage({
+                    message_type: 'usage_statistics',
+                    prompt_tokens: 10,
+                    completion_tokens: 5,
+                    total_tokens: 15,
+                    run_ids: ['run-1'],
+                }),
+            ).toEqual({ prompt_tokens: 10, completion_tokens: 5, total_tokens: 15 });
+            expect(extractUsage({})).toBeNull();
+            expect(extractUsage(undefined)).toBeNull();
+        });
+    });
+
+    describe('Message Roles', () => {
+        it('should send the message with the requested role', async () => {
+            for (const role of ['system', 'assistant']) {
+                mockServer.api.get.mockResolvedValueOnce({ data: fixtures.agent.basic });
+                mockServer.api.post.mockResolvedValueOnce({
+                    data: 'data: {"message_type": "assistant_message", "content": "Noted"}\n',
+                });
+
+                await handlePromptAgent(mockServer, {
+                    agent_id: 'agent-123',
+                    message: 'Always answer in French.',
+                    role,
+                });
+
+                expect(mockServer.api.post).toHaveBeenLastCalledWith(
+                    '/agents/agent-123/messages/stream',
+                    expect.objectContaining({
+                        messages: [{ role, content: 'Always answer in French.' }],
+                    }),
+                    expect.any(Object),
+                );
+            }
+        });
+
+        it('should reject an unknown role before contacting the server', async () => {
+            await expect(
+                handlePromptAgent(mockServer, {
+                    agent_id: 'agent-123',
+                    message: 'Hello',
+                    role: 'tool',
+                }),
+            ).rejects.toThrow('Invalid argument: role must be one of user, system, assistant');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('Timeouts', () => {
+        it('should apply a per-request timeout override', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: fixtures.agent.basic });
+            mockServer.api.post.mockResolvedValueOnce({
+                data: 'data: {"message_type": "assistant_message", "content": "Done"}\n',
+            });
+
+            await handlePromptAgent(mockServer, {
+                agent_id: 'agent-123',
+                message: 'Take your time',
+                timeout_secs: 600,
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/stream',
+                expect.any(Object),
+                expect.objectContaining({ timeout: 600000 }),
+            );
+        });
+
+        it('should reject an invalid timeout_secs', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: fixtures.agent.basic });
+
+            await expect(
+                handlePromptAgent(mockServer, {
+                    agent_id: 'agent-123',
+                    message: 'Hi',
+                    timeout_secs: -1,
+                }),
+            ).rejects.toThrow('timeout_secs must be a positive number');
+        });
+    });
+
+    describe('Streaming', () => {
+        const streamEvents = [
+            { message_type: 'reasoning_message', reasoning: 'Looking ' },
+            { message_type: 'reasoning_message', reasoning: 'it up' },
+            {
+                message_type: 'tool_call_message',
+                tool_call: { tool_call_id: 'call-1', name: 'web_search', arguments: '{"query":' },
+            },
+            {
+                message_type: 'tool_call_message',
+                tool_call: { tool_call_id: 'call-1', arguments: '"weather"}' },
+            },
+            { message_type: 'tool_return_message', tool_call_id: 'call-1', status: 'success' },
+            { message_type: 'assistant_message', content: 'It is ' },
+            { message_type: 'assistant_message', content: 'sunny.' },
+            { message_type: 'stop_reason', stop_reason: 'end_turn' },
+            {
+                message_type: 'usage_statistics',
+                completion_tokens: 12,
+                prompt_tokens: 30,
+                total_tokens: 42,
+                step_count: 2,
+            },
+        ];
+        const sseBody = [...streamEvents.map((e) => `data: ${JSON.stringify(e)}`), 'data: [DONE]']
+            .join('\n\n')
+            .concat('\n');
+
+        it('should expose the stream parameter in the schema', () => {
+            const streamProp = promptAgentToolDefinition.inputSchema.properties.stream;
+            expect(streamProp.type).toBe('boolean');
+            expect(streamProp.default).toBe(false);
+        });
+
+        it('should accumulate chunks when the client cannot consume a stream', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: fixtures.agent.basic });
+            mockServer.api.post.mockResolvedValueOnce({ data: sseBody });
+
+            const result = await handlePromptAgent(mockServer, {
+                agent_id: 'agent-123',
+                message: 'Weather?',
+                stream: true,
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/stream',
+                expect.objectContaining({ stream_steps: true, stream_tokens: true }),
+                expect.objectContaining({ responseType: 'text' }),
+            );
+
+            const data = expectValidToolResponse(result);
+            expect(data.streamed).toBe(false);
+            expect(data.response).toBe('It is sunny.');
+            expect(data.reasoning).toBe('Looking it up');
+            expect(data.tool_calls).toEqual([
+                { tool_call_id: 'call-1', name: 'web_search', arguments: '{"query":"weather"}' },
+            ]);
+            expect(data.stop_reason).toBe('end_turn');
+            expect(data.usage).toEqual({
+                completion_tokens: 12,
+                prompt_tokens: 30,
+                total_tokens: 42,
+                step_count: 2,
+            });
+        });
+
+        it('should forward events as progress notifications with a final completion event', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: fixtures.agent.basic });
+            // Split the body mid-line to exercise buffering across chunks
+            const chunks = [sseBody.slice(0, 57), sseBody.slice(57, 300), sseBody.slice(300)].map(
+                (part) => Buffer.from(part),
+            );
+            mockServer.api.post.mockResolvedValueOnce({ data: chunks });
+            const sendNotification = vi.fn().mockResolvedValue(undefined);
+
+            const result = await handlePromptAgent(
+                mockServer,
+                { agent_id: 'agent-123', message: 'Weather?', stream: true },
+                { _meta: { progressToken: 'tok-1' }, sendNotification },
+            );
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/stream',
+                expect.any(Object),
+                expect.objectContaining({ responseType: 'stream' }),
+            );
+
+            // One notification per event plus the completion event
+            expect(sendNotification).toHaveBeenCalledTimes(streamEvents.length + 1);
+            const messages = sendNotification.mock.calls.map(([n]) => {
+                expect(n.method).toBe('notifications/progress');
+                expect(n.params.progressToken).toBe('tok-1');
+                return JSON.parse(n.params.message);
+            });
+            expect(messages[3].tool_call.arguments).toBe('"weather"}');
+            const completion = messages[messages.length - 1];
+            expect(completion.message_type).toBe('completion');
+            expect(completion.usage.total_tokens).toBe(42);
+
+            const data = expectValidToolResponse(result);
+            expect(data.streamed).toBe(true);
+            expect(data.response).toBe('It is sunny.');
+            expect(data.tool_calls[0].arguments).toBe('{"query":"weather"}');
+        });
+
+        it('should keep multi-byte characters split across chunks intact', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: fixtures.agent.basic });
+            const event = { message_type: 'assistant_message', content: 'Héllo 👋' };
+            const body = Buffer.from(`data: ${JSON.stringify(event)}\n\n`);
+            // Split inside the emoji's four-byte sequence
+            const cut = body.indexOf(Buffer.from('👋')) + 2;
+            mockServer.api.post.mockResolvedValueOnce({
+                data: [body.subarray(0, cut), body.subarray(cut)],
+            });
+            const sendNotification = vi.fn().mockResolvedValue(undefined);
+
+            const result = await handlePromptAgent(
+                mockServer,
+                { agent_id: 'agent-123', message: 'Hi', stream: true },
+                { _meta: { progressToken: 'tok-1' }, sendNotification },
+            );
+
+            const data = expectValidToolResponse(result);
+            expect(data.response).toBe('Héllo 👋');
+            expect(sendNotification.mock.calls[0][0].params.progress).toBe(1);
+        });
+    });
 });
@@ -62,7 +62,7 @@ describe('Retrieve Agent', () => {
 
             // Verify response
             const data = expectValidToolResponse(result);
-            expect(data.agent).toEqual(fullAgentState);
+            expect(data.agent).toEqual({ ...fullAgentState, updated_at: null });
             expect(data.agent.id).toBe('agent-123');
             expect(data.agent.memory).toBeDefined();
             expect(data.agent.messages_count).toBe(42);
@@ -83,7 +83,7 @@ describe('Retrieve Agent', () => {
             );
 
             const data = expectValidToolResponse(result);
-            expect(data.agent).toEqual(agentData);
+            expect(data.agent).toEqual({ ...agentData, updated_at: null });
         });
 
         it('should retrieve minimal agent state', async () => {
@@ -95,7 +95,7 @@ describe('Retrieve Agent', () => {
             });
 
             const data = expectValidToolResponse(result);
-            expect(data.agent).toEqual(minimalAgent);
+            expect(data.agent).toEqual({ ...minimalAgent, updated_at: null });
             expect(data.agent.id).toBe('agent-456');
         });
 
@@ -127,7 +127,7 @@ describe('Retrieve Agent', () => {
             });
 
             const data = expectValidToolResponse(result);
-            expect(data.agent).toEqual(fullConfigAgent);
+            expect(data.agent).toEqual({ ...fullConfigAgent, updated_at: null });
             expect(data.agent.llm_config).toBeDefined();
             expect(data.agent.embedding_config).toBeDefined();
             expect(data.agent.memory_blocks).toHaveLength(2);
@@ -233,6 +233,18 @@ describe('Retrieve Agent', () => {
             expect(data.agent.id).toBe(unicodeId);
         });
 
+        it('should always include created_at and updated_at', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { id: 'agent-1', name: 'No Timestamps' },
+            });
+
+            const result = await handleRetrieveAgent(mockServer, { agent_id: 'agent-1' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.agent.created_at).toBeNull();
+            expect(data.agent.updated_at).toBeNull();
+        });
+
         it('should preserve all agent state fields', async () => {
             const complexAgent = {
                 id: 'complex-agent',
@@ -263,11 +275,23 @@ describe('Retrieve Agent', () => {
             });
 
             const data = expectValidToolResponse(result);
-            expect(data.agent).toEqual(complexAgent);
+            expect(data.agent).toEqual({ ...complexAgent, updated_at: null });
             // Verify nested structures are preserved
             expect(data.agent.metadata.nested.data).toBe('test');
             expect(data.agent.settings.feature_flags).toHaveLength(2);
             expect(data.agent.statistics.average_response_time).toBe(1.5);
         });
+
+        it('should name the field that cannot be serialized', async () => {
+            const agent = { id: 'agent-123', llm_config: { model: 'gpt-4o' } };
+            agent.llm_config.parent = agent;
+            mockServer.api.get.mockResolvedValueOnce({ data: agent });
+
+            await expect(
+                handleRetrieveAgent(mockServer, { agent_id: 'agent-123' }),
+            ).rejects.toThrow(
+                'Could not serialize retrieve_agent response: circular reference at agent.llm_config.parent',
+            );
+        });
     });
 });
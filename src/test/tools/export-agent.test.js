@@ -1,5 +1,6 @@
 import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
 import fs from 'fs';
+import os from 'os';
 import path from 'path';
 import { fileURLToPath } from 'url';
 import axios from 'axios';
@@ -248,6 +249,50 @@ describe('Export Agent', () => {
             );
         });
 
+        it('should report the size of the export', async () => {
+            const agentData = fixtures.agent.basic;
+            mockServer.api.get.mockResolvedValue({ data: agentData });
+
+            const result = await handleExportAgent(mockServer, { agent_id: 'agent-123' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.size_bytes).toBe(Buffer.byteLength(JSON.stringify(agentData, null, 2)));
+        });
+
+        it('should write inside the configured export directory', async () => {
+            mockServer.exportDir = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-exports-'));
+            mockServer.api.get.mockResolvedValue({ data: fixtures.agent.basic });
+
+            try {
+                const result = await handleExportAgent(mockServer, {
+                    agent_id: 'agent-123',
+                    output_path: 'ci/agent.json',
+                });
+
+                const data = expectValidToolResponse(result);
+                expect(data.file_path).toBe(path.join(mockServer.exportDir, 'ci', 'agent.json'));
+            } finally {
+                fs.rmSync(mockServer.exportDir, { recursive: true, force: true });
+            }
+        });
+
+        it('should refuse paths outside the export directory', async () => {
+            mockServer.exportDir = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-exports-'));
+
+            try {
+                await expect(
+                    handleExportAgent(mockServer, {
+                        agent_id: 'agent-123',
+                        output_path: '../agent.json',
+                    }),
+                ).rejects.toThrow('output_path must be inside the export directory');
+                expect(mockServer.api.get).not.toHaveBeenCalled();
+                expect(fs.writeFileSync).not.toHaveBeenCalled();
+            } finally {
+                fs.rmSync(mockServer.exportDir, { recursive: true, force: true });
+            }
+        });
+
         it('should handle missing agent_id', async () => {
             await expect(handleExportAgent(mockServer, {})).rejects.toThrow();
         });
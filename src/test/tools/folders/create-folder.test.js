@@ -0,0 +1,82 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleCreateFolder,
+    createFolderDefinition,
+} from '../../../tools/folders/create-folder.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Create Folder', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(createFolderDefinition.name).toBe('create_folder');
+            expect(createFolderDefinition.inputSchema.required).toEqual(['name']);
+            expect(createFolderDefinition.inputSchema.properties).toHaveProperty('description');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should create a folder and return its metadata', async () => {
+            mockServer.api.post.mockResolvedValueOnce({
+                data: {
+                    id: 'source-1',
+                    name: 'docs',
+                    description: 'Product docs',
+                    embedding_config: { embedding_model: 'text-embedding-3-small' },
+                    created_at: '2025-01-01T00:00:00Z',
+                },
+            });
+
+            const result = await handleCreateFolder(mockServer, {
+                name: 'docs',
+                description: 'Product docs',
+                embedding: 'openai/text-embedding-3-small',
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/folders/',
+                {
+                    name: 'docs',
+                    description: 'Product docs',
+                    embedding: 'openai/text-embedding-3-small',
+                },
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.folder_id).toBe('source-1');
+            expect(data.folder).toMatchObject({
+                name: 'docs',
+                description: 'Product docs',
+                embedding_model: 'text-embedding-3-small',
+            });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing name', async () => {
+            await expect(handleCreateFolder(mockServer, {})).rejects.toThrow(
+                'Missing required argument: name',
+            );
+        });
+
+        it('should report a duplicate folder name', async () => {
+            const error = new Error('Conflict');
+            error.response = { status: 409, data: { detail: 'Source already exists' } };
+            mockServer.api.post.mockRejectedValueOnce(error);
+
+            await expect(handleCreateFolder(mockServer, { name: 'docs' })).rejects.toThrow(
+                'A folder named "docs" already exists',
+            );
+        });
+    });
+});
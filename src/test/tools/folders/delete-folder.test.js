@@ -0,0 +1,102 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleDeleteFolder,
+    deleteFolderDefinition,
+} from '../../../tools/folders/delete-folder.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Delete Folder', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(deleteFolderDefinition.name).toBe('delete_folder');
+            expect(deleteFolderDefinition.inputSchema.required).toEqual(['folder_id']);
+            expect(deleteFolderDefinition.inputSchema.properties.force.default).toBe(false);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should delete a folder with no agents attached', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+            mockServer.api.delete.mockResolvedValueOnce({ data: {} });
+
+            const result = await handleDeleteFolder(mockServer, { folder_id: 'source-1' });
+
+            expect(mockServer.api.delete).toHaveBeenCalledWith(
+                '/folders/source-1',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.deleted).toBe(true);
+            expect(data.detached_agent_ids).toEqual([]);
+        });
+
+        it('should delete an attached folder when forced', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: ['agent-1', 'agent-2'] });
+            mockServer.api.delete.mockResolvedValueOnce({ data: {} });
+
+            const result = await handleDeleteFolder(mockServer, {
+                folder_id: 'source-1',
+                force: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.detached_agent_ids).toEqual(['agent-1', 'agent-2']);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing folder_id', async () => {
+            await expect(handleDeleteFolder(mockServer, {})).rejects.toThrow(
+                'Missing required argument: folder_id',
+            );
+        });
+
+        it('should refuse to delete a folder that agents still use', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: ['agent-1'] });
+
+            await expect(handleDeleteFolder(mockServer, { folder_id: 'source-1' })).rejects.toThrow(
+                'Folder source-1 is attached to 1 agent(s): agent-1',
+            );
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                expect.objectContaining({ response: { status: 409 } }),
+            );
+        });
+
+        it('should report a missing folder', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(handleDeleteFolder(mockServer, { folder_id: 'nope' })).rejects.toThrow(
+                'Folder not found: nope',
+            );
+        });
+
+        it('should treat a missing folder as deleted with ignore_missing', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            const result = await handleDeleteFolder(mockServer, {
+                folder_id: 'nope',
+                ignore_missing: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({ folder_id: 'nope', deleted: false, already_absent: true });
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+        });
+    });
+});
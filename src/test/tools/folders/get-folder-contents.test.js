@@ -0,0 +1,108 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleGetFolderContents,
+    getFolderContentsDefinition,
+} from '../../../tools/folders/get-folder-contents.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const files = [
+    {
+        id: 'file-1',
+        file_name: 'guide.pdf',
+        original_file_name: 'Guide.pdf',
+        file_size: 2048,
+        file_type: 'application/pdf',
+        processing_status: 'completed',
+        content: 'Guide text',
+    },
+    { id: 'file-2', file_name: 'notes.txt', file_size: 12, file_type: 'text/plain' },
+];
+
+describe('Get Folder Contents', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(getFolderContentsDefinition.name).toBe('get_folder_contents');
+            expect(getFolderContentsDefinition.inputSchema.required).toEqual(['folder_id']);
+            expect(getFolderContentsDefinition.inputSchema.properties).toHaveProperty(
+                'include_content',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should list file metadata without content by default', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: files });
+
+            const result = await handleGetFolderContents(mockServer, { folder_id: 'source-1' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/folders/source-1/files',
+                expect.objectContaining({ params: { limit: 100 } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(2);
+            expect(data.files[0]).toMatchObject({
+                id: 'file-1',
+                filename: 'Guide.pdf',
+                size: 2048,
+                mime_type: 'application/pdf',
+            });
+            expect(data.files[0]).not.toHaveProperty('content');
+            expect(data.files[1].filename).toBe('notes.txt');
+            expect(data.next_cursor).toBeNull();
+        });
+
+        it('should include content and page with limit and after', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [files[0]] });
+
+            const result = await handleGetFolderContents(mockServer, {
+                folder_id: 'source-1',
+                limit: 1,
+                after: 'file-0',
+                include_content: true,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/folders/source-1/files',
+                expect.objectContaining({
+                    params: { limit: 1, after: 'file-0', include_content: true },
+                }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.files[0].content).toBe('Guide text');
+            expect(data.next_cursor).toBe('file-1');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should validate arguments', async () => {
+            await expect(handleGetFolderContents(mockServer, {})).rejects.toThrow(
+                'Missing required argument: folder_id',
+            );
+            await expect(
+                handleGetFolderContents(mockServer, { folder_id: 'source-1', limit: 0 }),
+            ).rejects.toThrow('Invalid argument: limit must be a positive integer');
+        });
+
+        it('should report a missing folder', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleGetFolderContents(mockServer, { folder_id: 'nope' }),
+            ).rejects.toThrow('Folder not found: nope');
+        });
+    });
+});
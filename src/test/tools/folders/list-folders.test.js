@@ -0,0 +1,96 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleListFolders, listFoldersDefinition } from '../../../tools/folders/list-folders.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const folders = [
+    { id: 'source-1', name: 'docs', description: 'Product docs' },
+    { id: 'source-2', name: 'notes' },
+];
+
+describe('List Folders', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listFoldersDefinition.name).toBe('list_folders');
+            expect(listFoldersDefinition.inputSchema.required).toEqual([]);
+            expect(listFoldersDefinition.inputSchema.properties.include_counts.type).toBe(
+                'boolean',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should list folders without extra requests by default', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: folders });
+
+            const result = await handleListFolders(mockServer, {});
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(1);
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/folders/',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(2);
+            expect(data.folders[0]).toMatchObject({ id: 'source-1', name: 'docs' });
+            expect(data.folders[0]).not.toHaveProperty('file_count');
+            expect(data.folders[1].description).toBeNull();
+        });
+
+        it('should include file and agent counts when requested', async () => {
+            mockServer.api.get.mockImplementation(async (url) => {
+                if (url === '/folders/') return { data: folders };
+                if (url === '/folders/source-1/files') {
+                    return { data: [{ id: 'f1' }, { id: 'f2' }] };
+                }
+                if (url === '/folders/source-1/agents') return { data: ['agent-1'] };
+                if (url === '/folders/source-2/files') return { data: [] };
+                if (url === '/folders/source-2/agents') return { data: [] };
+                throw new Error(`Unexpected URL ${url}`);
+            });
+
+            const result = await handleListFolders(mockServer, { include_counts: true });
+
+            const data = expectValidToolResponse(result);
+            expect(data.folders[0]).toMatchObject({ file_count: 2, agent_count: 1 });
+            expect(data.folders[1]).toMatchObject({ file_count: 0, agent_count: 0 });
+        });
+
+        it('should report a folder whose counts could not be loaded', async () => {
+            mockServer.api.get.mockImplementation(async (url) => {
+                if (url === '/folders/') return { data: [folders[0]] };
+                if (url.endsWith('/agents')) throw new Error('Service unavailable');
+                return { data: [] };
+            });
+
+            const result = await handleListFolders(mockServer, { include_counts: true });
+
+            const data = expectValidToolResponse(result);
+            expect(data.folders[0]).toMatchObject({
+                file_count: null,
+                agent_count: null,
+                count_error: 'Service unavailable',
+            });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should handle API errors', async () => {
+            mockServer.api.get.mockRejectedValueOnce(new Error('Network error'));
+
+            await expect(handleListFolders(mockServer, {})).rejects.toThrow(
+                'Failed to list folders: Network error',
+            );
+        });
+    });
+});
@@ -0,0 +1,78 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleGetJobResult, getJobResultDefinition } from '../../../tools/jobs/get-job-result.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Get Job Result', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(getJobResultDefinition.name).toBe('get_job_result');
+            expect(getJobResultDefinition.inputSchema.required).toEqual(['job_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the output of a completed job', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: {
+                    id: 'job-1',
+                    status: 'completed',
+                    completed_at: '2025-01-01T00:01:00Z',
+                    metadata: { result: { num_passages: 42 } },
+                },
+            });
+
+            const result = await handleGetJobResult(mockServer, { job_id: 'job-1' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.status).toBe('completed');
+            expect(data.result).toEqual({ num_passages: 42 });
+        });
+
+        it('should fall back to the job metadata as its result', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { id: 'job-1', status: 'completed', metadata: { num_documents: 3 } },
+            });
+
+            const result = await handleGetJobResult(mockServer, { job_id: 'job-1' });
+
+            expect(expectValidToolResponse(result).result).toEqual({ num_documents: 3 });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should report a job that has not finished', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { id: 'job-1', status: 'running' } });
+
+            await expect(handleGetJobResult(mockServer, { job_id: 'job-1' })).rejects.toThrow(
+                'Job job-1 has no result yet (status: running)',
+            );
+        });
+
+        it('should report the error of a failed job', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { id: 'job-1', status: 'failed', metadata: { error_message: 'Parse error' } },
+            });
+
+            await expect(handleGetJobResult(mockServer, { job_id: 'job-1' })).rejects.toThrow(
+                'Job job-1 failed: Parse error',
+            );
+        });
+
+        it('should throw error for missing job_id', async () => {
+            await expect(handleGetJobResult(mockServer, {})).rejects.toThrow(
+                'Missing required argument: job_id',
+            );
+        });
+    });
+});
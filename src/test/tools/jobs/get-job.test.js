@@ -0,0 +1,97 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleGetJob, getJobDefinition } from '../../../tools/jobs/get-job.js';
+import { jobProgressPercent, jobError } from '../../../tools/jobs/job-status.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Get Job', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(getJobDefinition.name).toBe('get_job');
+            expect(getJobDefinition.inputSchema.required).toEqual(['job_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the job with progress from its step counts', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: {
+                    id: 'job-1',
+                    job_type: 'job',
+                    status: 'running',
+                    metadata: { total_chunks: 8, processed_chunks: 3 },
+                },
+            });
+
+            const result = await handleGetJob(mockServer, { job_id: 'job-1' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/jobs/job-1',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.job.status).toBe('running');
+            expect(data.job.progress_percent).toBe(37.5);
+            expect(data.job.error).toBeNull();
+        });
+
+        it('should surface the error of a failed job', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: {
+                    id: 'job-1',
+                    status: 'failed',
+                    metadata: { error: 'Unsupported file type' },
+                },
+            });
+
+            const result = await handleGetJob(mockServer, { job_id: 'job-1' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.job.error).toBe('Unsupported file type');
+            expect(data.job.progress_percent).toBeNull();
+        });
+
+        it('should compute progress only when it is reported', () => {
+            expect(jobProgressPercent({ status: 'completed' })).toBe(100);
+            expect(jobProgressPercent({ status: 'running', metadata: {} })).toBeNull();
+            expect(
+                jobProgressPercent({
+                    status: 'running',
+                    metadata: { completed_steps: 1, total_steps: 3 },
+                }),
+            ).toBe(33.3);
+            expect(jobError({ status: 'failed', stop_reason: 'error' })).toBe(
+                'Job failed (stop reason: error)',
+            );
+            expect(jobError({ status: 'completed', metadata: { error: 'stale' } })).toBeNull();
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing job_id', async () => {
+            await expect(handleGetJob(mockServer, {})).rejects.toThrow(
+                'Missing required argument: job_id',
+            );
+        });
+
+        it('should report a missing job', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(handleGetJob(mockServer, { job_id: 'job-x' })).rejects.toThrow(
+                'Job not found: job-x',
+            );
+        });
+    });
+});
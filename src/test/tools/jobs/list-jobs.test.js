@@ -0,0 +1,105 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleListJobs, listJobsDefinition } from '../../../tools/jobs/list-jobs.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const jobs = [
+    { id: 'job-1', job_type: 'job', status: 'completed' },
+    { id: 'job-2', job_type: 'job', status: 'failed', metadata: { error: 'Bad file' } },
+    { id: 'run-1', job_type: 'run', status: 'failed' },
+    { id: 'job-3', job_type: 'job', status: 'failed' },
+];
+
+describe('List Jobs', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listJobsDefinition.name).toBe('list_jobs');
+            expect(listJobsDefinition.inputSchema.required).toEqual([]);
+            expect(listJobsDefinition.inputSchema.properties.status.enum).toContain('failed');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should pass the limit through when unfiltered', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: jobs.slice(0, 2) });
+
+            const result = await handleListJobs(mockServer, { limit: 2 });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/jobs/',
+                expect.objectContaining({ params: { limit: 2 } }),
+            );
+            expect(expectValidToolResponse(result).count).toBe(2);
+        });
+
+        it('should filter by status and job_type and apply the limit', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: jobs });
+
+            const result = await handleListJobs(mockServer, {
+                status: 'failed',
+                job_type: 'job',
+                limit: 1,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/jobs/',
+                expect.objectContaining({ params: {} }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.total_matching).toBe(2);
+            expect(data.jobs).toHaveLength(1);
+            expect(data.jobs[0]).toMatchObject({ id: 'job-2', error: 'Bad file' });
+        });
+
+        it('should read active jobs for an active status', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { id: 'job-4', job_type: 'job', status: 'running' },
+                    { id: 'job-5', job_type: 'job', status: 'pending' },
+                ],
+            });
+
+            const result = await handleListJobs(mockServer, {
+                status: 'running',
+                source_id: 'source-1',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/jobs/active',
+                expect.objectContaining({ params: { source_id: 'source-1' } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.jobs.map((job) => job.id)).toEqual(['job-4']);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should reject unknown filters', async () => {
+            await expect(handleListJobs(mockServer, { status: 'done' })).rejects.toThrow(
+                'Invalid argument: status must be one of',
+            );
+            await expect(handleListJobs(mockServer, { job_type: 'cron' })).rejects.toThrow(
+                'Invalid argument: job_type must be one of',
+            );
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should handle API errors', async () => {
+            mockServer.api.get.mockRejectedValueOnce(new Error('Network error'));
+
+            await expect(handleListJobs(mockServer, {})).rejects.toThrow(
+                'Failed to list jobs: Network error',
+            );
+        });
+    });
+});
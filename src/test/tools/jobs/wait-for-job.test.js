@@ -0,0 +1,95 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleWaitForJob, waitForJobDefinition } from '../../../tools/jobs/wait-for-job.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Wait For Job', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(waitForJobDefinition.name).toBe('wait_for_job');
+            expect(waitForJobDefinition.inputSchema.required).toEqual(['job_id']);
+            expect(waitForJobDefinition.inputSchema.properties).toHaveProperty('wait_secs');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should poll until the job completes and return it', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: { id: 'job-1', status: 'running' } })
+                .mockResolvedValueOnce({
+                    data: {
+                        id: 'job-1',
+                        status: 'completed',
+                        completed_at: '2025-01-01T00:01:00Z',
+                    },
+                });
+
+            const result = await handleWaitForJob(mockServer, {
+                job_id: 'job-1',
+                poll_interval_secs: 0.001,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/jobs/job-1',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.done).toBe(true);
+            expect(data.timed_out).toBe(false);
+            expect(data.polls).toBe(2);
+            expect(data.job).toMatchObject({ status: 'completed', progress_percent: 100 });
+        });
+
+        it('should return the latest status with timed_out on timeout', async () => {
+            mockServer.api.get.mockResolvedValue({
+                data: {
+                    id: 'job-1',
+                    status: 'running',
+                    metadata: { total_steps: 4, completed_steps: 1 },
+                },
+            });
+
+            const result = await handleWaitForJob(mockServer, {
+                job_id: 'job-1',
+                wait_secs: 0.02,
+                poll_interval_secs: 0.005,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.timed_out).toBe(true);
+            expect(data.job).toMatchObject({ status: 'running', progress_percent: 25 });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should validate arguments', async () => {
+            await expect(handleWaitForJob(mockServer, {})).rejects.toThrow(
+                'Missing required argument: job_id',
+            );
+            await expect(
+                handleWaitForJob(mockServer, { job_id: 'job-1', wait_secs: -1 }),
+            ).rejects.toThrow('Invalid argument: wait_secs');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should report a missing job', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(handleWaitForJob(mockServer, { job_id: 'job-x' })).rejects.toThrow(
+                'Job not found: job-x',
+            );
+        });
+    });
+});
@@ -0,0 +1,163 @@
+import { PassThrough } from 'node:stream';
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleConnectMcpServer,
+    connectMcpServerDefinition,
+} from '../../../tools/mcp/connect-mcp-server.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const sse = (...events) => [
+    Buffer.from(events.map((event) => `data: ${JSON.stringify(event)}\n\n`).join('')),
+];
+
+describe('Connect MCP Server', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(connectMcpServerDefinition.name).toBe('connect_mcp_server');
+            expect(connectMcpServerDefinition.inputSchema.required).toEqual([
+                'server_name',
+                'server_url',
+            ]);
+            const props = connectMcpServerDefinition.inputSchema.properties;
+            expect(props.server_type.default).toBe('streamable_http');
+            expect(props.oauth_config.properties).toHaveProperty('access_token');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should connect to a streamable HTTP server and list its tools', async () => {
+            mockServer.api.post.mockResolvedValueOnce({
+                data: sse(
+                    { event: 'connection_attempt', message: 'Connecting' },
+                    { event: 'success', tools: [{ name: 'search' }] },
+                ),
+            });
+
+            const result = await handleConnectMcpServer(mockServer, {
+                server_name: 'docs',
+                server_url: 'https://mcp.example.com/mcp',
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/tools/mcp/servers/connect',
+                {
+                    server_name: 'docs',
+                    type: 'streamable_http',
+                    server_url: 'https://mcp.example.com/mcp',
+                },
+                expect.objectContaining({ responseType: 'stream' }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.status).toBe('connected');
+            expect(data.server_type).toBe('streamable_http');
+            expect(data.tools).toEqual([{ name: 'search' }]);
+        });
+
+        it('should send an OAuth access token as a bearer header', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: sse({ event: 'success' }) });
+
+            await handleConnectMcpServer(mockServer, {
+                server_name: 'docs',
+                server_url: 'https://mcp.example.com/mcp',
+                oauth_config: { access_token: 'tok-123' },
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/tools/mcp/servers/connect',
+                expect.objectContaining({
+                    auth_header: 'Authorization',
+                    auth_token: 'Bearer tok-123',
+                }),
+                expect.any(Object),
+            );
+        });
+
+        it('should return the authorization URL when OAuth needs a browser step', async () => {
+            mockServer.api.post.mockResolvedValueOnce({
+                data: sse(
+                    { event: 'oauth_required', message: 'OAuth required' },
+                    {
+                        event: 'authorization_url',
+                        url: 'https://auth.example.com/authorize?state=abc',
+                        session_id: 'session-1',
+                    },
+                    { event: 'waiting_for_auth' },
+                ),
+            });
+
+            const result = await handleConnectMcpServer(mockServer, {
+                server_name: 'docs',
+                server_url: 'https://mcp.example.com/mcp',
+                oauth_config: {},
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.status).toBe('oauth_required');
+            expect(data.authorization_url).toBe('https://auth.example.com/authorize?state=abc');
+            expect(data.session_id).toBe('session-1');
+        });
+
+        it('should not wait for the stream to end after authorization_url', async () => {
+            // Letta holds the stream open while it waits for the OAuth callback
+            const stream = new PassThrough();
+            stream.write('data: {"event":"oauth_required"}\n\ndata: {"event":"authori');
+            stream.write('zation_url","url":"https://auth.example.com/a","session_id":"s-1"}\n\n');
+            mockServer.api.post.mockResolvedValueOnce({ data: stream });
+
+            const result = await handleConnectMcpServer(mockServer, {
+                server_name: 'docs',
+                server_url: 'https://mcp.example.com/mcp',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.status).toBe('oauth_required');
+            expect(data.authorization_url).toBe('https://auth.example.com/a');
+            expect(stream.destroyed).toBe(true);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require server_name and server_url', async () => {
+            await expect(handleConnectMcpServer(mockServer, {})).rejects.toThrow(
+                'Missing required argument: server_name',
+            );
+            await expect(
+                handleConnectMcpServer(mockServer, { server_name: 'docs' }),
+            ).rejects.toThrow('Missing required argument: server_url');
+        });
+
+        it('should reject unsupported server types', async () => {
+            await expect(
+                handleConnectMcpServer(mockServer, {
+                    server_name: 'local',
+                    server_url: 'https://x',
+                    server_type: 'stdio',
+                }),
+            ).rejects.toThrow('server_type must be streamable_http or sse');
+        });
+
+        it('should surface connection errors reported in the stream', async () => {
+            mockServer.api.post.mockResolvedValueOnce({
+                data: sse({ event: 'error', message: 'Connection refused' }),
+            });
+
+            await expect(
+                handleConnectMcpServer(mockServer, {
+                    server_name: 'docs',
+                    server_url: 'https://mcp.example.com/mcp',
+                }),
+            ).rejects.toThrow('Failed to connect to MCP server docs: Connection refused');
+        });
+    });
+});
@@ -23,7 +23,11 @@ describe('List MCP Servers', () => {
             expect(listMcpServersDefinition.description).toContain(
                 'List all configured MCP servers',
             );
-            expect(listMcpServersDefinition.inputSchema.properties).toEqual({});
+            expect(Object.keys(listMcpServersDefinition.inputSchema.properties)).toEqual([
+                'page',
+                'pageSize',
+                'include_status',
+            ]);
             expect(listMcpServersDefinition.inputSchema.required).toEqual([]);
         });
     });
@@ -132,6 +136,90 @@ describe('List MCP Servers', () => {
         });
     });
 
+    describe('Pagination and Status', () => {
+        const makeServers = (count) =>
+            Object.fromEntries(
+                Array.from({ length: count }, (_, i) => [
+                    `server-${i + 1}`,
+                    { url: `http://localhost:${3000 + i}` },
+                ]),
+            );
+
+        it('should report which servers are reachable and their tool counts', async () => {
+            mockServer.api.get.mockImplementation(async (url) => {
+                if (url === '/tools/mcp/servers') return { data: makeServers(3) };
+                if (url === '/tools/mcp/servers/server-2/tools') {
+                    const error = new Error('Connection refused');
+                    error.response = { status: 500, data: { detail: 'Connection refused' } };
+                    throw error;
+                }
+                return { data: [{ name: 'a' }, { name: 'b' }] };
+            });
+
+            const result = await handleListMcpServers(mockServer, {});
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/tools/mcp/servers/server-1/tools', {
+                headers: expect.any(Object),
+                timeout: 10000,
+                retry: false,
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.status['server-1']).toEqual({ connected: true, tool_count: 2, error: null });
+            expect(data.status['server-2'].connected).toBe(false);
+            expect(data.status['server-2'].tool_count).toBeNull();
+            expect(data.status['server-2'].error).toContain('Connection refused');
+            expect(data.connected_count).toBe(2);
+            expect(data.unreachable).toEqual(['server-2']);
+        });
+
+        it('should skip the status checks when include_status is false', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeServers(2) });
+
+            const result = await handleListMcpServers(mockServer, { include_status: false });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(1);
+            const data = expectValidToolResponse(result);
+            expect(data).not.toHaveProperty('status');
+            expect(data).not.toHaveProperty('unreachable');
+        });
+
+        it('should return one page of servers and only check those', async () => {
+            mockServer.api.get.mockImplementation(async (url) =>
+                url === '/tools/mcp/servers' ? { data: makeServers(12) } : { data: [] },
+            );
+
+            const result = await handleListMcpServers(mockServer, { page: 2, pageSize: 5 });
+
+            const data = expectValidToolResponse(result);
+            expect(Object.keys(data.servers)).toEqual([
+                'server-6',
+                'server-7',
+                'server-8',
+                'server-9',
+                'server-10',
+            ]);
+            expect(data.server_count).toBe(5);
+            expect(data.pagination).toEqual({
+                page: 2,
+                pageSize: 5,
+                totalServers: 12,
+                totalPages: 3,
+                hasNextPage: true,
+                hasPreviousPage: true,
+            });
+            expect(mockServer.api.get).toHaveBeenCalledTimes(6);
+        });
+
+        it('should reject invalid pagination arguments', async () => {
+            await expect(handleListMcpServers(mockServer, { page: 0 })).rejects.toThrow(
+                'Invalid argument: page must be a positive integer',
+            );
+            await expect(handleListMcpServers(mockServer, { pageSize: 500 })).rejects.toThrow(
+                'Invalid argument: pageSize must be an integer from 1 to 100',
+            );
+        });
+    });
+
     describe('Error Handling', () => {
         it('should handle API errors gracefully', async () => {
             const error = new Error('Network error');
@@ -234,7 +234,7 @@ describe('List MCP Tools By Server', () => {
                 handleListMcpToolsByServer(mockServer, {
                     mcp_server_name: 'test-server',
                 }),
-            ).rejects.toThrow(/Error executing list_mcp_tools_by_server.*Network error/);
+            ).rejects.toThrow('Failed to list tools for MCP server test-server: Network error');
         });
 
         it('should handle API timeout', async () => {
@@ -260,7 +260,7 @@ describe('List MCP Tools By Server', () => {
 
             const data = expectValidToolResponse(result);
             expect(data.tool_count).toBe(1); // Gets 1 tool (all available)
-            expect(data.tools).toEqual([{ name: 'tool1' }]);
+            expect(data.tools).toEqual([{ name: 'tool1', description: null, input_schema: null }]);
             expect(data.pagination.pageSize).toBe(10); // Uses default
         });
     });
@@ -344,7 +344,7 @@ describe('List MCP Tools By Server', () => {
             expect(data.pagination.totalTools).toBe(0);
         });
 
-        it('should preserve tool schema and additional properties', async () => {
+        it('should preserve tool schema and additional properties when verbose', async () => {
             const complexTools = [
                 {
                     name: 'complex_tool',
@@ -374,12 +374,70 @@ describe('List MCP Tools By Server', () => {
 
             const result = await handleListMcpToolsByServer(mockServer, {
                 mcp_server_name: 'test-server',
+                verbose: true,
             });
 
             const data = expectValidToolResponse(result);
-            expect(data.tools[0]).toEqual(complexTools[0]);
+            expect(data.tools[0]).toEqual({
+                ...complexTools[0],
+                input_schema: complexTools[0].inputSchema,
+            });
             expect(data.tools[0].inputSchema.properties.param2.default).toBe(42);
             expect(data.tools[0].metadata.version).toBe('1.0.0');
         });
     });
+
+    describe('Schema Normalization', () => {
+        it('should return name, description and input_schema by default', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    {
+                        name: 'search',
+                        description: 'Search the web',
+                        inputSchema: { type: 'object', required: ['query'] },
+                        annotations: { title: 'Web Search' },
+                    },
+                ],
+            });
+
+            const result = await handleListMcpToolsByServer(mockServer, {
+                mcp_server_name: 'test-server',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.tools).toEqual([
+                {
+                    name: 'search',
+                    description: 'Search the web',
+                    input_schema: { type: 'object', required: ['query'] },
+                },
+            ]);
+        });
+
+        it('should read the schema from inputSchema, input_schema or schema', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { name: 'a', inputSchema: { title: 'a' } },
+                    { name: 'b', input_schema: { title: 'b' } },
+                    { name: 'c', schema: { title: 'c' } },
+                ],
+            });
+
+            const result = await handleListMcpToolsByServer(mockServer, {
+                mcp_server_name: 'test-server',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.tools.map((tool) => tool.input_schema.title)).toEqual(['a', 'b', 'c']);
+        });
+
+        it('should reject a non-boolean verbose', async () => {
+            await expect(
+                handleListMcpToolsByServer(mockServer, {
+                    mcp_server_name: 'test-server',
+                    verbose: 'yes',
+                }),
+            ).rejects.toThrow('Invalid argument: verbose must be a boolean');
+        });
+    });
 });
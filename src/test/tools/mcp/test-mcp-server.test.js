@@ -0,0 +1,126 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleTestMcpServer,
+    summarizeLatencies,
+    testMcpServerDefinition,
+} from '../../../tools/mcp/test-mcp-server.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Test MCP Server', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(testMcpServerDefinition.name).toBe('test_mcp_server');
+            expect(testMcpServerDefinition.inputSchema.required).toEqual(['mcp_server_name']);
+            expect(testMcpServerDefinition.inputSchema.properties).toHaveProperty('samples');
+        });
+    });
+
+    describe('summarizeLatencies', () => {
+        it('should compute min, max, avg and nearest-rank p95', () => {
+            const latencies = Array.from({ length: 20 }, (_, i) => i + 1);
+
+            expect(summarizeLatencies(latencies)).toEqual({ min: 1, max: 20, avg: 10.5, p95: 19 });
+        });
+
+        it('should return nulls without samples', () => {
+            expect(summarizeLatencies([])).toEqual({ min: null, max: null, avg: null, p95: null });
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should test the connection once by default', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [{ name: 'a' }, { name: 'b' }] });
+
+            const result = await handleTestMcpServer(mockServer, { mcp_server_name: 'search' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/tools/mcp/servers/search/tools', {
+                headers: expect.any(Object),
+                timeout: 10000,
+                retry: false,
+            });
+            const data = expectValidToolResponse(result);
+            expect(data).toMatchObject({
+                mcp_server_name: 'search',
+                connected: true,
+                samples: 1,
+                success_count: 1,
+                success_ratio: 1,
+                tool_count: 2,
+                last_error: null,
+            });
+            expect(data.latency_ms.min).toBeGreaterThanOrEqual(0);
+            expect(data.latency_ms.p95).toBe(data.latency_ms.max);
+        });
+
+        it('should report the success ratio over several samples', async () => {
+            const error = new Error('Connection reset');
+            error.response = { status: 502, data: { detail: 'Connection reset' } };
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: [] })
+                .mockRejectedValueOnce(error)
+                .mockResolvedValueOnce({ data: [] })
+                .mockResolvedValueOnce({ data: [] });
+
+            const result = await handleTestMcpServer(mockServer, {
+                mcp_server_name: 'search',
+                samples: 4,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(4);
+            const data = expectValidToolResponse(result);
+            expect(data.success_count).toBe(3);
+            expect(data.success_ratio).toBe(0.75);
+            expect(data.connected).toBe(true);
+            expect(data.last_error).toContain('Connection reset');
+        });
+
+        it('should report a server that never answers as not connected', async () => {
+            mockServer.api.get.mockRejectedValue(new Error('timeout of 10000ms exceeded'));
+
+            const result = await handleTestMcpServer(mockServer, {
+                mcp_server_name: 'down',
+                samples: 2,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.connected).toBe(false);
+            expect(data.success_ratio).toBe(0);
+            expect(data.latency_ms).toEqual({ min: null, max: null, avg: null, p95: null });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require mcp_server_name', async () => {
+            await expect(handleTestMcpServer(mockServer, {})).rejects.toThrow(
+                'Missing required argument: mcp_server_name',
+            );
+        });
+
+        it('should reject an invalid sample count', async () => {
+            await expect(
+                handleTestMcpServer(mockServer, { mcp_server_name: 'search', samples: 0 }),
+            ).rejects.toThrow('Invalid argument: samples must be an integer from 1 to 50');
+        });
+
+        it('should report an unknown server', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleTestMcpServer(mockServer, { mcp_server_name: 'missing' }),
+            ).rejects.toThrow('MCP Server not found: missing');
+        });
+    });
+});
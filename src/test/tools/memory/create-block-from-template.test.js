@@ -0,0 +1,150 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleCreateBlockFromTemplate,
+    createBlockFromTemplateToolDefinition,
+} from '../../../tools/memory/create-block-from-template.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const template = {
+    id: 'template-1',
+    template_name: 'Helpful Assistant',
+    label: 'persona',
+    value: 'I am a helpful assistant.',
+    limit: 100,
+    read_only: true,
+    description: 'Standard persona',
+    metadata: { team: 'support' },
+    is_template: true,
+};
+
+describe('Create Block From Template', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(createBlockFromTemplateToolDefinition.name).toBe('create_block_from_template');
+            expect(createBlockFromTemplateToolDefinition.inputSchema.required).toEqual([
+                'template_id',
+            ]);
+            expect(createBlockFromTemplateToolDefinition.inputSchema.properties).toHaveProperty(
+                'block_limit',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should copy the template into a new block', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: template });
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'block-new' } });
+
+            const result = await handleCreateBlockFromTemplate(mockServer, {
+                template_id: 'template-1',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/blocks/template-1', {
+                headers: expect.any(Object),
+            });
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/blocks',
+                {
+                    name: 'Helpful Assistant',
+                    label: 'persona',
+                    value: 'I am a helpful assistant.',
+                    metadata: { team: 'support' },
+                    limit: 100,
+                    read_only: true,
+                    description: 'Standard persona',
+                },
+                { headers: expect.any(Object) },
+            );
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({
+                block_id: 'block-new',
+                template_id: 'template-1',
+                name: 'Helpful Assistant',
+                label: 'persona',
+            });
+        });
+
+        it('should apply overrides and attach to an agent', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: template });
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'block-new' } });
+            mockServer.api.patch.mockResolvedValueOnce({ data: {} });
+
+            const result = await handleCreateBlockFromTemplate(mockServer, {
+                template_id: 'template-1',
+                name: 'Billing Assistant',
+                value: 'I help with billing.',
+                read_only: false,
+                agent_id: 'agent-123',
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/blocks',
+                expect.objectContaining({
+                    name: 'Billing Assistant',
+                    label: 'persona',
+                    value: 'I help with billing.',
+                    read_only: false,
+                    limit: 100,
+                }),
+                expect.any(Object),
+            );
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-123/core-memory/blocks/attach/block-new',
+                {},
+                expect.any(Object),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.agent_id).toBe('agent-123');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require template_id', async () => {
+            await expect(handleCreateBlockFromTemplate(mockServer, {})).rejects.toThrow(
+                'Missing required argument: template_id',
+            );
+        });
+
+        it('should report a missing template', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleCreateBlockFromTemplate(mockServer, { template_id: 'missing' }),
+            ).rejects.toThrow('Block template not found: missing');
+        });
+
+        it('should refuse a block that is not a template', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { ...template, is_template: false } });
+
+            await expect(
+                handleCreateBlockFromTemplate(mockServer, { template_id: 'template-1' }),
+            ).rejects.toThrow('is not a template');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it("should reject an override value longer than the template's limit", async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: template });
+
+            await expect(
+                handleCreateBlockFromTemplate(mockServer, {
+                    template_id: 'template-1',
+                    value: 'x'.repeat(101),
+                }),
+            ).rejects.toThrow('exceeds block_limit of 100');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+    });
+});
@@ -412,5 +412,105 @@ describe('Create Memory Block', () => {
                 expect(data.label).toBe(label);
             }
         });
+
+        it('should pass block_limit to the server as limit', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'limited-block' } });
+
+            await handleCreateMemoryBlock(mockServer, {
+                name: 'Limited Block',
+                label: 'human',
+                value: 'Short',
+                block_limit: 200,
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/blocks',
+                expect.objectContaining({ limit: 200 }),
+                expect.any(Object),
+            );
+        });
+
+        it('should leave limit to the server default when block_limit is omitted', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'default-block' } });
+
+            await handleCreateMemoryBlock(mockServer, {
+                name: 'Default Block',
+                label: 'human',
+                value: 'Short',
+            });
+
+            expect(mockServer.api.post.mock.calls[0][1]).not.toHaveProperty('limit');
+        });
+
+        it('should reject a value longer than block_limit before calling the API', async () => {
+            await expect(
+                handleCreateMemoryBlock(mockServer, {
+                    name: 'Too Long',
+                    label: 'human',
+                    value: 'X'.repeat(11),
+                    block_limit: 10,
+                }),
+            ).rejects.toThrow('value is 11 characters, which exceeds block_limit of 10');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should strip null bytes from value when sanitize_value is set', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'clean-block' } });
+
+            await handleCreateMemoryBlock(mockServer, {
+                name: 'Clean',
+                label: 'human',
+                value: 'Name:\u0000 Ada\u0007',
+                sanitize_value: true,
+            });
+
+            expect(mockServer.api.post.mock.calls[0][1].value).toBe('Name: Ada');
+        });
+
+        it('should pass read_only and description to the server', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'persona-block' } });
+
+            await handleCreateMemoryBlock(mockServer, {
+                name: 'Fixed Persona',
+                label: 'persona',
+                value: 'You are a careful assistant.',
+                read_only: true,
+                description: 'Core persona; not editable by the agent',
+                metadata: { owner: 'ops' },
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/blocks',
+                expect.objectContaining({
+                    read_only: true,
+                    description: 'Core persona; not editable by the agent',
+                    metadata: { owner: 'ops' },
+                }),
+                expect.any(Object),
+            );
+        });
+
+        it('should reject a non-boolean read_only', async () => {
+            await expect(
+                handleCreateMemoryBlock(mockServer, {
+                    name: 'Bad Flag',
+                    label: 'persona',
+                    value: 'Content',
+                    read_only: 'yes',
+                }),
+            ).rejects.toThrow('Invalid argument: read_only must be a boolean');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should reject a non-positive block_limit', async () => {
+            await expect(
+                handleCreateMemoryBlock(mockServer, {
+                    name: 'Bad Limit',
+                    label: 'human',
+                    value: 'Content',
+                    block_limit: 0,
+                }),
+            ).rejects.toThrow('Invalid argument: block_limit must be a positive integer');
+        });
     });
 });
@@ -0,0 +1,148 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleDeleteMemoryBlock,
+    deleteMemoryBlockToolDefinition,
+} from '../../../tools/memory/delete-memory-block.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const notFound = () => Object.assign(new Error('Not found'), { response: { status: 404 } });
+
+describe('Delete Memory Block', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(deleteMemoryBlockToolDefinition.name).toBe('delete_memory_block');
+            expect(deleteMemoryBlockToolDefinition.inputSchema.required).toEqual(['block_id']);
+            expect(deleteMemoryBlockToolDefinition.inputSchema.properties.force.default).toBe(
+                false,
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should delete a block with no agents attached', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+            mockServer.api.delete.mockResolvedValueOnce({ data: {} });
+
+            const result = await handleDeleteMemoryBlock(mockServer, { block_id: 'block-1' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/blocks/block-1/agents',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            expect(mockServer.api.delete).toHaveBeenCalledWith(
+                '/blocks/block-1',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({ block_id: 'block-1', deleted: true, detached_agent_ids: [] });
+        });
+
+        it('should detach an attached block from its agents when forced', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [{ id: 'agent-1' }, { id: 'agent-2' }],
+            });
+            mockServer.api.patch.mockResolvedValue({ data: {} });
+            mockServer.api.delete.mockResolvedValueOnce({ data: {} });
+
+            const result = await handleDeleteMemoryBlock(mockServer, {
+                block_id: 'block-1',
+                force: true,
+            });
+
+            expect(mockServer.api.patch.mock.calls.map(([url]) => url)).toEqual([
+                '/agents/agent-1/core-memory/blocks/detach/block-1',
+                '/agents/agent-2/core-memory/blocks/detach/block-1',
+            ]);
+            expect(mockServer.api.delete).toHaveBeenCalledTimes(1);
+            const data = expectValidToolResponse(result);
+            expect(data.detached_agent_ids).toEqual(['agent-1', 'agent-2']);
+        });
+
+        it('should keep the block and name each agent when forced detaches fail', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [{ id: 'agent-1' }, { id: 'agent-2' }, { id: 'agent-3' }],
+            });
+            mockServer.api.patch
+                .mockRejectedValueOnce(new Error('Detach failed'))
+                .mockResolvedValueOnce({ data: {} })
+                .mockRejectedValueOnce(new Error('Timed out'));
+
+            await expect(
+                handleDeleteMemoryBlock(mockServer, { block_id: 'block-1', force: true }),
+            ).rejects.toThrow(
+                'Block block-1 was not deleted: detaching it failed for 2 agent(s): agent-1 (Detach failed), agent-3 (Timed out); it was detached from agent-2',
+            );
+            expect(mockServer.api.patch).toHaveBeenCalledTimes(3);
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+        });
+
+        it('should not treat a failed detach as an absent block with ignore_missing', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [{ id: 'agent-1' }] });
+            mockServer.api.patch.mockRejectedValueOnce(notFound());
+
+            await expect(
+                handleDeleteMemoryBlock(mockServer, {
+                    block_id: 'block-1',
+                    force: true,
+                    ignore_missing: true,
+                }),
+            ).rejects.toThrow('detaching it failed for 1 agent(s): agent-1');
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+        });
+
+        it('should treat a missing block as deleted with ignore_missing', async () => {
+            mockServer.api.get.mockRejectedValueOnce(notFound());
+            mockServer.api.delete.mockRejectedValueOnce(notFound());
+
+            const result = await handleDeleteMemoryBlock(mockServer, {
+                block_id: 'nope',
+                ignore_missing: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({ block_id: 'nope', deleted: false, already_absent: true });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing block_id', async () => {
+            await expect(handleDeleteMemoryBlock(mockServer, {})).rejects.toThrow(
+                'Missing required argument: block_id',
+            );
+        });
+
+        it('should refuse to delete a block that agents still use', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [{ id: 'agent-1' }] });
+
+            await expect(
+                handleDeleteMemoryBlock(mockServer, { block_id: 'block-1' }),
+            ).rejects.toThrow('Block block-1 is attached to 1 agent(s): agent-1');
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                expect.objectContaining({ response: { status: 409 } }),
+            );
+        });
+
+        it('should report a missing block', async () => {
+            mockServer.api.get.mockRejectedValueOnce(notFound());
+            mockServer.api.delete.mockRejectedValueOnce(notFound());
+
+            await expect(handleDeleteMemoryBlock(mockServer, { block_id: 'nope' })).rejects.toThrow(
+                'Block not found: nope',
+            );
+        });
+    });
+});
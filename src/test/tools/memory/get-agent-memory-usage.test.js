@@ -0,0 +1,111 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleGetAgentMemoryUsage,
+    getAgentMemoryUsageToolDefinition,
+} from '../../../tools/memory/get-agent-memory-usage.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const notFound = () => {
+    const error = new Error('Not found');
+    error.response = { status: 404, data: {} };
+    return error;
+};
+
+describe('Get Agent Memory Usage', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    const mockEndpoints = (responses) => {
+        mockServer.api.get.mockImplementation((url) => {
+            const key = Object.keys(responses).find((suffix) => url.endsWith(suffix));
+            const response = responses[key];
+            return response instanceof Error
+                ? Promise.reject(response)
+                : Promise.resolve({ data: response });
+        });
+    };
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(getAgentMemoryUsageToolDefinition.name).toBe('get_agent_memory_usage');
+            expect(getAgentMemoryUsageToolDefinition.inputSchema.required).toEqual(['agent_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should aggregate core, archival, source and tool usage', async () => {
+            mockEndpoints({
+                '/core-memory/blocks': [
+                    { label: 'human', value: 'Name: Ada', limit: 5000 },
+                    { label: 'persona', value: 'Helpful', limit: 2000 },
+                ],
+                '/context': { num_archival_memory: 42 },
+                '/sources': [{ id: 'source-1' }],
+                '/tools': [{ id: 'tool-1' }, { id: 'tool-2' }, { id: 'tool-3' }],
+            });
+
+            const result = await handleGetAgentMemoryUsage(mockServer, { agent_id: 'agent-1' });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(4);
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/agent-1/context', {
+                headers: expect.any(Object),
+            });
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({
+                agent_id: 'agent-1',
+                core_memory: { block_count: 2, total_chars: 16, total_limit: 7000 },
+                archival_passage_count: 42,
+                source_count: 1,
+                tool_count: 3,
+            });
+        });
+
+        it('should report the parts that could not be fetched', async () => {
+            mockEndpoints({
+                '/core-memory/blocks': [],
+                '/context': new Error('Internal Server Error'),
+                '/sources': [],
+                '/tools': [],
+            });
+
+            const result = await handleGetAgentMemoryUsage(mockServer, { agent_id: 'agent-1' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.core_memory).toEqual({ block_count: 0, total_chars: 0, total_limit: 0 });
+            expect(data.archival_passage_count).toBeNull();
+            expect(data.errors).toEqual({ archival_passage_count: 'Internal Server Error' });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleGetAgentMemoryUsage(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should report an unknown agent', async () => {
+            mockServer.api.get.mockRejectedValue(notFound());
+
+            await expect(
+                handleGetAgentMemoryUsage(mockServer, { agent_id: 'missing' }),
+            ).rejects.toThrow('Agent not found: missing');
+        });
+
+        it('should fail when every part fails', async () => {
+            mockServer.api.get.mockRejectedValue(new Error('Network Error'));
+
+            await expect(
+                handleGetAgentMemoryUsage(mockServer, { agent_id: 'agent-1' }),
+            ).rejects.toThrow('Network Error');
+        });
+    });
+});
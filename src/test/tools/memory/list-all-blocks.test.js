@@ -0,0 +1,142 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleListAllBlocks,
+    listAllBlocksToolDefinition,
+} from '../../../tools/memory/list-all-blocks.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const blocks = [
+    { id: 'block-1', label: 'persona', value: 'Shared persona. '.repeat(20), limit: 5000 },
+    { id: 'block-2', label: 'human', value: 'The user is a developer.', limit: 2000 },
+    {
+        id: 'block-3',
+        label: 'persona',
+        value: 'Support persona',
+        template_name: 'Support',
+        is_template: true,
+    },
+    { id: 'block-4', label: 'persona', value: 'Sales persona', template_name: 'Sales' },
+];
+
+describe('List All Blocks', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listAllBlocksToolDefinition.name).toBe('list_all_blocks');
+            expect(listAllBlocksToolDefinition.inputSchema.required).toEqual([]);
+            expect(listAllBlocksToolDefinition.inputSchema.properties).not.toHaveProperty(
+                'agent_id',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should list blocks from the organization-wide endpoint', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: blocks });
+
+            const result = await handleListAllBlocks(mockServer, {});
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/blocks/', {
+                headers: expect.any(Object),
+                params: { templates_only: false },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(4);
+            expect(data.has_more).toBe(false);
+            expect(data.next_cursor).toBeNull();
+            expect(data.blocks[0]).toMatchObject({ id: 'block-1', label: 'persona', chars: 320 });
+            expect(data.blocks[0].value_preview).toHaveLength(203);
+            expect(data.blocks[0]).not.toHaveProperty('value');
+        });
+
+        it('should filter by label and template name', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: blocks });
+
+            const result = await handleListAllBlocks(mockServer, {
+                label: 'persona',
+                template_name: 'Sales',
+            });
+
+            expect(mockServer.api.get.mock.calls[0][1].params.label).toBe('persona');
+            const data = expectValidToolResponse(result);
+            expect(data.blocks.map((b) => b.id)).toEqual(['block-4']);
+        });
+
+        it('should pass templates_only to the server', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [blocks[2]] });
+
+            const result = await handleListAllBlocks(mockServer, { templates_only: true });
+
+            expect(mockServer.api.get.mock.calls[0][1].params.templates_only).toBe(true);
+            const data = expectValidToolResponse(result);
+            expect(data.blocks[0]).toMatchObject({ is_template: true, template_name: 'Support' });
+        });
+
+        it('should page with limit and return a next_cursor', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: blocks.slice(0, 3) });
+
+            const result = await handleListAllBlocks(mockServer, { limit: 2 });
+
+            expect(mockServer.api.get.mock.calls[0][1].params.limit).toBe(3);
+            const data = expectValidToolResponse(result);
+            expect(data.blocks.map((b) => b.id)).toEqual(['block-1', 'block-2']);
+            expect(data.has_more).toBe(true);
+            expect(data.next_cursor).toBe('block-2');
+        });
+
+        it('should apply the after cursor when the server returns every block', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: blocks });
+
+            const result = await handleListAllBlocks(mockServer, { after: 'block-2', limit: 1 });
+
+            expect(mockServer.api.get.mock.calls[0][1].params.after).toBe('block-2');
+            const data = expectValidToolResponse(result);
+            expect(data.blocks.map((b) => b.id)).toEqual(['block-3']);
+            expect(data.next_cursor).toBe('block-3');
+        });
+
+        it('should include full content when requested', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [blocks[1]] });
+
+            const result = await handleListAllBlocks(mockServer, { include_full_content: true });
+
+            const data = expectValidToolResponse(result);
+            expect(data.blocks[0].value).toBe('The user is a developer.');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should reject a non-positive limit', async () => {
+            await expect(handleListAllBlocks(mockServer, { limit: 0 })).rejects.toThrow(
+                'limit must be a positive integer',
+            );
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should reject a non-array response', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { blocks: [] } });
+
+            await expect(handleListAllBlocks(mockServer, {})).rejects.toThrow(
+                'Unexpected response from /blocks/',
+            );
+        });
+
+        it('should surface API errors', async () => {
+            mockServer.api.get.mockRejectedValueOnce(new Error('Server unavailable'));
+
+            await expect(handleListAllBlocks(mockServer, {})).rejects.toThrow(
+                'Server unavailable',
+            );
+        });
+    });
+});
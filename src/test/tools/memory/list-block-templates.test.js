@@ -0,0 +1,90 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleListBlockTemplates,
+    listBlockTemplatesToolDefinition,
+} from '../../../tools/memory/list-block-templates.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const templates = [
+    {
+        id: 'block-persona',
+        template_name: 'Helpful Assistant',
+        label: 'persona',
+        value: 'I am a helpful assistant. '.repeat(20),
+        limit: 5000,
+        is_template: true,
+    },
+    {
+        id: 'block-human',
+        name: 'Default Human',
+        label: 'human',
+        value: 'The user is a developer.',
+        is_template: true,
+    },
+];
+
+describe('List Block Templates', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listBlockTemplatesToolDefinition.name).toBe('list_block_templates');
+            expect(listBlockTemplatesToolDefinition.inputSchema.required).toEqual([]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should list only template blocks with value previews', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: templates });
+
+            const result = await handleListBlockTemplates(mockServer, {});
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/blocks', {
+                headers: expect.any(Object),
+                params: { templates_only: true },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(2);
+            expect(data.templates[0].name).toBe('Helpful Assistant');
+            expect(data.templates[0].value_preview).toHaveLength(203);
+            expect(data.templates[0].value).toBeUndefined();
+            expect(data.templates[1]).toMatchObject({
+                id: 'block-human',
+                name: 'Default Human',
+                label: 'human',
+                value_preview: 'The user is a developer.',
+            });
+        });
+
+        it('should filter by label and include full content when asked', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [templates[1]] });
+
+            const result = await handleListBlockTemplates(mockServer, {
+                label: 'human',
+                include_full_content: true,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/blocks', {
+                headers: expect.any(Object),
+                params: { templates_only: true, label: 'human' },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.templates[0].value).toBe('The user is a developer.');
+        });
+
+        it('should surface API errors', async () => {
+            mockServer.api.get.mockRejectedValueOnce(new Error('Network error'));
+
+            await expect(handleListBlockTemplates(mockServer, {})).rejects.toThrow();
+        });
+    });
+});
@@ -125,6 +125,9 @@ describe('Read Memory Block', () => {
             const data = expectValidToolResponse(result);
             expect(data.id).toBe('minimal-block');
             expect(data.value).toBe('Minimal content');
+            // Timestamps the server didn't report are still present, as null
+            expect(data.created_at).toBeNull();
+            expect(data.updated_at).toBeNull();
         });
 
         it('should handle template blocks', async () => {
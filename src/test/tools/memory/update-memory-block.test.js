@@ -554,5 +554,114 @@ describe('Update Memory Block', () => {
             expect(data.metadata.version).toBe(5);
             expect(data.metadata.update_count).toBe(10);
         });
+
+        it('should update block_limit on its own', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: { id: 'block-123', limit: 8000 } });
+
+            const result = await handleUpdateMemoryBlock(mockServer, {
+                block_id: 'block-123',
+                block_limit: 8000,
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/blocks/block-123',
+                { limit: 8000 },
+                expect.any(Object),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.limit).toBe(8000);
+        });
+
+        it('should mark a block read-only and update its description', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({
+                data: { id: 'block-123', read_only: true, description: 'Locked persona' },
+            });
+
+            const result = await handleUpdateMemoryBlock(mockServer, {
+                block_id: 'block-123',
+                read_only: true,
+                description: 'Locked persona',
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/blocks/block-123',
+                { read_only: true, description: 'Locked persona' },
+                expect.any(Object),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.read_only).toBe(true);
+        });
+
+        it('should reject a value longer than block_limit before calling the API', async () => {
+            await expect(
+                handleUpdateMemoryBlock(mockServer, {
+                    block_id: 'block-123',
+                    value: 'Z'.repeat(101),
+                    block_limit: 100,
+                }),
+            ).rejects.toThrow('value is 101 characters, which exceeds block_limit of 100');
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+        });
+
+        it("should reject a value longer than the block's current limit", async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { id: 'block-123', limit: 10 } });
+
+            await expect(
+                handleUpdateMemoryBlock(mockServer, { block_id: 'block-123', value: 'Z'.repeat(11) }),
+            ).rejects.toThrow("value is 11 characters, which exceeds the block's limit of 10");
+            expect(mockServer.api.get).toHaveBeenCalledWith('/blocks/block-123', expect.any(Object));
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('Value Sanitization', () => {
+        it('should strip null bytes and control characters from value', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { id: 'block-123', limit: 100 } });
+            mockServer.api.patch.mockResolvedValueOnce({ data: { id: 'block-123' } });
+
+            await handleUpdateMemoryBlock(mockServer, {
+                block_id: 'block-123',
+                value: 'a\u0000b\u001bc\td\ne',
+                sanitize_value: true,
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/blocks/block-123',
+                { value: 'abc\td\ne' },
+                expect.any(Object),
+            );
+        });
+
+        it('should leave value untouched by default', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: { id: 'block-123' } });
+
+            await handleUpdateMemoryBlock(mockServer, {
+                block_id: 'block-123',
+                value: 'a\u0000b',
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/blocks/block-123',
+                { value: 'a\u0000b' },
+                expect.any(Object),
+            );
+        });
+
+        it('should check the limit against the sanitized value', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: { id: 'block-123' } });
+
+            await handleUpdateMemoryBlock(mockServer, {
+                block_id: 'block-123',
+                value: 'abcde\u0000\u0000',
+                block_limit: 5,
+                sanitize_value: true,
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/blocks/block-123',
+                { value: 'abcde', limit: 5 },
+                expect.any(Object),
+            );
+        });
     });
 });
@@ -0,0 +1,164 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleBulkSendMessage,
+    bulkSendMessageDefinition,
+} from '../../../tools/messages/bulk-send-message.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const lettaResponse = (content) => ({
+    data: {
+        messages: [
+            { message_type: 'reasoning_message', reasoning: 'Thinking...' },
+            { message_type: 'assistant_message', content },
+        ],
+        stop_reason: { stop_reason: 'end_turn' },
+        usage: { prompt_tokens: 100, completion_tokens: 20, total_tokens: 120, step_count: 1 },
+    },
+});
+
+describe('Bulk Send Message', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(bulkSendMessageDefinition.name).toBe('bulk_send_message');
+            expect(bulkSendMessageDefinition.inputSchema.required).toEqual([
+                'agent_ids',
+                'messages',
+            ]);
+            expect(bulkSendMessageDefinition.inputSchema.properties).toHaveProperty(
+                'max_concurrency',
+            );
+            expect(bulkSendMessageDefinition.inputSchema.properties).toHaveProperty(
+                'timeout_secs',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should send the messages to every agent and collect replies', async () => {
+            mockServer.api.post.mockImplementation((url) =>
+                Promise.resolve(lettaResponse(`Reply from ${url.split('/')[2]}`)),
+            );
+
+            const result = await handleBulkSendMessage(mockServer, {
+                agent_ids: ['agent-1', 'agent-2'],
+                messages: [{ content: 'Status report, please' }],
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-1/messages',
+                { messages: [{ role: 'user', content: 'Status report, please' }] },
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(true);
+            expect(data.summary).toEqual({ total_agents: 2, success_count: 2, error_count: 0 });
+            expect(data.results[0]).toMatchObject({
+                agent_id: 'agent-1',
+                status: 'success',
+                response: 'Reply from agent-1',
+            });
+            expect(data.results[1].usage.total_tokens).toBe(120);
+        });
+
+        it('should respect max_concurrency', async () => {
+            let inFlight = 0;
+            let peak = 0;
+            mockServer.api.post.mockImplementation(async () => {
+                inFlight++;
+                peak = Math.max(peak, inFlight);
+                await new Promise((resolve) => setTimeout(resolve, 5));
+                inFlight--;
+                return lettaResponse('ok');
+            });
+
+            await handleBulkSendMessage(mockServer, {
+                agent_ids: ['a', 'b', 'c', 'd'],
+                messages: [{ role: 'system', content: 'Heads up' }],
+                max_concurrency: 2,
+            });
+
+            expect(peak).toBe(2);
+        });
+
+        it('should pass timeout_secs to each request', async () => {
+            mockServer.api.post.mockResolvedValue(lettaResponse('ok'));
+
+            await handleBulkSendMessage(mockServer, {
+                agent_ids: ['agent-1'],
+                messages: [{ content: 'Hi' }],
+                timeout_secs: 30,
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-1/messages',
+                expect.any(Object),
+                expect.objectContaining({ timeout: 30000 }),
+            );
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should report partial failures without aborting', async () => {
+            const notFound = Object.assign(new Error('Request failed with status code 404'), {
+                response: { status: 404 },
+            });
+            mockServer.api.post.mockImplementation((url) =>
+                url.includes('missing')
+                    ? Promise.reject(notFound)
+                    : Promise.resolve(lettaResponse('ok')),
+            );
+
+            const result = await handleBulkSendMessage(mockServer, {
+                agent_ids: ['agent-1', 'missing', 'agent-3'],
+                messages: [{ content: 'Hi' }],
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(false);
+            expect(data.summary).toEqual({ total_agents: 3, success_count: 2, error_count: 1 });
+            expect(data.results[1]).toEqual({
+                agent_id: 'missing',
+                status: 'error',
+                error: 'Resource not found: Request failed with status code 404',
+                error_code: 'not_found',
+                http_status: 404,
+            });
+            expect(data.results[2].status).toBe('success');
+        });
+
+        it('should throw error for missing agent_ids', async () => {
+            await expect(
+                handleBulkSendMessage(mockServer, { messages: [{ content: 'Hi' }] }),
+            ).rejects.toThrow('Missing required argument: agent_ids');
+        });
+
+        it('should throw error for missing messages', async () => {
+            await expect(
+                handleBulkSendMessage(mockServer, { agent_ids: ['agent-1'], messages: [] }),
+            ).rejects.toThrow('Missing required argument: messages');
+        });
+
+        it('should reject invalid message roles', async () => {
+            await expect(
+                handleBulkSendMessage(mockServer, {
+                    agent_ids: ['agent-1'],
+                    messages: [{ role: 'tool', content: 'Hi' }],
+                }),
+            ).rejects.toThrow(
+                'Invalid argument: messages[0].role must be one of user, system, assistant',
+            );
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+    });
+});
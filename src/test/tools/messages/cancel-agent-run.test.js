@@ -0,0 +1,121 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleCancelAgentRun,
+    cancelAgentRunDefinition,
+} from '../../../tools/messages/cancel-agent-run.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Cancel Agent Run', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(cancelAgentRunDefinition.name).toBe('cancel_agent_run');
+            expect(cancelAgentRunDefinition.inputSchema.required).toEqual(['agent_id']);
+            expect(cancelAgentRunDefinition.inputSchema.properties).toHaveProperty('agent_id');
+            expect(cancelAgentRunDefinition.inputSchema.properties.run_id.type).toBe('string');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should cancel active runs when run_id is omitted', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { 'run-1': 'cancelled' } });
+
+            const result = await handleCancelAgentRun(mockServer, { agent_id: 'agent-123' });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/cancel',
+                {},
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+
+            const data = expectValidToolResponse(result);
+            expect(data.run_id).toBeNull();
+            expect(data.cancelled).toEqual({ 'run-1': 'cancelled' });
+            expect(data.run).toBeNull();
+        });
+
+        it('should cancel a specific run and return its final status', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { 'run-42': 'cancelled' } });
+            mockServer.api.get.mockResolvedValueOnce({
+                data: {
+                    id: 'run-42',
+                    status: 'cancelled',
+                    completed_at: '2025-01-01T00:00:05Z',
+                    stop_reason: 'cancelled',
+                    metadata: { large: 'ignored' },
+                },
+            });
+
+            const result = await handleCancelAgentRun(mockServer, {
+                agent_id: 'agent-123',
+                run_id: 'run-42',
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/cancel',
+                { run_ids: ['run-42'] },
+                expect.any(Object),
+            );
+            expect(mockServer.api.get).toHaveBeenCalledWith('/runs/run-42', expect.any(Object));
+
+            const data = expectValidToolResponse(result);
+            expect(data.run_id).toBe('run-42');
+            expect(data.run).toEqual({
+                id: 'run-42',
+                status: 'cancelled',
+                completed_at: '2025-01-01T00:00:05Z',
+                stop_reason: 'cancelled',
+            });
+        });
+
+        it('should still succeed when the run status lookup fails', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { 'run-42': 'cancelled' } });
+            mockServer.api.get.mockRejectedValueOnce(new Error('Service unavailable'));
+
+            const result = await handleCancelAgentRun(mockServer, {
+                agent_id: 'agent-123',
+                run_id: 'run-42',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.cancelled).toEqual({ 'run-42': 'cancelled' });
+            expect(data.run).toBeNull();
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleCancelAgentRun(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should reject an empty run_id', async () => {
+            await expect(
+                handleCancelAgentRun(mockServer, { agent_id: 'agent-123', run_id: '' }),
+            ).rejects.toThrow('run_id must be a non-empty string');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should report a missing agent or run', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.post.mockRejectedValueOnce(error);
+
+            await expect(
+                handleCancelAgentRun(mockServer, { agent_id: 'agent-123', run_id: 'run-x' }),
+            ).rejects.toThrow('Agent or run not found');
+        });
+    });
+});
@@ -0,0 +1,120 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleCountAgentMessages,
+    countAgentMessagesDefinition,
+} from '../../../tools/messages/count-agent-messages.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const notFound = () => Object.assign(new Error('Not found'), { response: { status: 404 } });
+
+describe('Count Agent Messages', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(countAgentMessagesDefinition.name).toBe('count_agent_messages');
+            expect(countAgentMessagesDefinition.inputSchema.properties).toHaveProperty(
+                'agent_ids',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the message counts from the context overview', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { num_recall_memory: 5120, num_messages: 38, num_archival_memory: 7 },
+            });
+
+            const result = await handleCountAgentMessages(mockServer, { agent_id: 'agent-123' });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(1);
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/agent-123/context', {
+                headers: expect.any(Object),
+            });
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({ agent_id: 'agent-123', count: 5120, in_context: 38 });
+        });
+
+        it('should count several agents and total them', async () => {
+            mockServer.api.get.mockImplementation(async (url) =>
+                url === '/agents/agent-1/context'
+                    ? { data: { num_recall_memory: 10, num_messages: 4 } }
+                    : { data: { num_recall_memory: 32, num_messages: 6 } },
+            );
+
+            const result = await handleCountAgentMessages(mockServer, {
+                agent_ids: ['agent-1', 'agent-2'],
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.counts).toEqual([
+                { agent_id: 'agent-1', count: 10, in_context: 4 },
+                { agent_id: 'agent-2', count: 32, in_context: 6 },
+            ]);
+            expect(data.total).toBe(42);
+            expect(data).not.toHaveProperty('errors');
+        });
+
+        it('should report agents that fail without dropping the rest', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: { num_recall_memory: 3, num_messages: 3 } })
+                .mockRejectedValueOnce(notFound());
+
+            const result = await handleCountAgentMessages(mockServer, {
+                agent_ids: ['agent-1', 'missing'],
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.counts).toHaveLength(1);
+            expect(data.total).toBe(3);
+            expect(data.errors).toEqual([
+                { agent_id: 'missing', error: 'Agent not found: missing', error_code: 'not_found' },
+            ]);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id or agent_ids', async () => {
+            await expect(handleCountAgentMessages(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id or agent_ids',
+            );
+        });
+
+        it('should reject agent_id together with agent_ids', async () => {
+            await expect(
+                handleCountAgentMessages(mockServer, { agent_id: 'a', agent_ids: ['b'] }),
+            ).rejects.toThrow('either agent_id or agent_ids');
+        });
+
+        it('should reject an empty agent_ids list', async () => {
+            await expect(handleCountAgentMessages(mockServer, { agent_ids: [] })).rejects.toThrow(
+                'agent_ids must be a non-empty array',
+            );
+        });
+
+        it('should report a missing agent', async () => {
+            mockServer.api.get.mockRejectedValueOnce(notFound());
+
+            await expect(
+                handleCountAgentMessages(mockServer, { agent_id: 'nope' }),
+            ).rejects.toThrow('Agent not found: nope');
+        });
+
+        it('should fail when the server does not report a count', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: {} });
+
+            await expect(
+                handleCountAgentMessages(mockServer, { agent_id: 'agent-123' }),
+            ).rejects.toThrow('did not report a message count');
+        });
+    });
+});
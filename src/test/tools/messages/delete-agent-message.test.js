@@ -0,0 +1,145 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleDeleteAgentMessage,
+    deleteAgentMessageDefinition,
+} from '../../../tools/messages/delete-agent-message.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const HISTORY = [
+    { id: 'message-1', message_type: 'user_message', content: 'Look it up' },
+    { id: 'message-2', message_type: 'reasoning_message', reasoning: 'Searching.' },
+    {
+        id: 'message-2',
+        message_type: 'tool_call_message',
+        tool_call: { name: 'web_search', arguments: '{}', tool_call_id: 'call-1' },
+    },
+    {
+        id: 'message-3',
+        message_type: 'tool_return_message',
+        tool_call_id: 'call-1',
+        tool_return: 'bad output',
+    },
+    { id: 'message-4', message_type: 'assistant_message', content: 'Done.' },
+];
+
+describe('Delete Agent Message', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.api.get.mockResolvedValue({ data: HISTORY });
+        mockServer.api.delete.mockResolvedValue({ data: {} });
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(deleteAgentMessageDefinition.name).toBe('delete_agent_message');
+            expect(deleteAgentMessageDefinition.inputSchema.required).toEqual([
+                'agent_id',
+                'message_id',
+            ]);
+            expect(deleteAgentMessageDefinition.inputSchema.properties.delete_tool_pair.type).toBe(
+                'boolean',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should delete a standalone message', async () => {
+            const result = await handleDeleteAgentMessage(mockServer, {
+                agent_id: 'agent-123',
+                message_id: 'message-4',
+            });
+
+            expect(mockServer.api.delete).toHaveBeenCalledTimes(1);
+            expect(mockServer.api.delete).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/message-4',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.deleted_message_ids).toEqual(['message-4']);
+        });
+
+        it('should delete a tool return together with its tool call', async () => {
+            const result = await handleDeleteAgentMessage(mockServer, {
+                agent_id: 'agent-123',
+                message_id: 'message-3',
+                delete_tool_pair: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.deleted_message_ids).toEqual(['message-3', 'message-2']);
+            expect(mockServer.api.delete).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/message-2',
+                expect.any(Object),
+            );
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should refuse to delete half of a tool call pair', async () => {
+            await expect(
+                handleDeleteAgentMessage(mockServer, {
+                    agent_id: 'agent-123',
+                    message_id: 'message-2',
+                }),
+            ).rejects.toThrow(
+                'Message message-2 is part of tool call call-1 with message(s) message-3',
+            );
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+        });
+
+        it('should report unknown messages', async () => {
+            await expect(
+                handleDeleteAgentMessage(mockServer, {
+                    agent_id: 'agent-123',
+                    message_id: 'message-404',
+                }),
+            ).rejects.toThrow('Message not found: message-404 (agent agent-123)');
+        });
+
+        it('should treat an unknown message as deleted with ignore_missing', async () => {
+            const result = await handleDeleteAgentMessage(mockServer, {
+                agent_id: 'agent-123',
+                message_id: 'message-404',
+                ignore_missing: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.already_absent).toBe(true);
+            expect(data.deleted_message_ids).toEqual([]);
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+        });
+
+        it('should explain when the server cannot delete messages', async () => {
+            const error = new Error('Method Not Allowed');
+            error.response = { status: 405 };
+            mockServer.api.delete.mockRejectedValueOnce(error);
+
+            await expect(
+                handleDeleteAgentMessage(mockServer, {
+                    agent_id: 'agent-123',
+                    message_id: 'message-1',
+                }),
+            ).rejects.toThrow('Message deletion is not supported by this Letta server');
+        });
+
+        it('should report a missing agent', async () => {
+            const error = new Error('Not Found');
+            error.response = { status: 404 };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleDeleteAgentMessage(mockServer, {
+                    agent_id: 'missing',
+                    message_id: 'message-1',
+                }),
+            ).rejects.toThrow('Agent not found: missing');
+        });
+    });
+});
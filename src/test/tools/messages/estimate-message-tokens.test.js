@@ -0,0 +1,133 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleEstimateMessageTokens,
+    estimateMessageTokensDefinition,
+    estimateTokens,
+} from '../../../tools/messages/estimate-message-tokens.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const context = {
+    context_window_size_max: 1000,
+    context_window_size_current: 900,
+    num_tokens_system: 300,
+    num_tokens_core_memory: 200,
+    num_tokens_functions_definitions: 250,
+    num_tokens_summary_memory: 0,
+    num_tokens_external_memory_summary: 50,
+    num_tokens_messages: 100,
+};
+
+describe('Estimate Message Tokens', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(estimateMessageTokensDefinition.name).toBe('estimate_message_tokens');
+            expect(estimateMessageTokensDefinition.inputSchema.required).toEqual(['agent_id']);
+            expect(estimateMessageTokensDefinition.inputSchema.properties).toHaveProperty(
+                'messages',
+            );
+        });
+    });
+
+    describe('estimateTokens', () => {
+        it('should count characters and per-message overhead', () => {
+            expect(estimateTokens([{ role: 'user', content: 'a'.repeat(40) }])).toBe(14);
+            expect(
+                estimateTokens([
+                    { role: 'user', content: 'abc' },
+                    { role: 'assistant', content: 'abcde' },
+                ]),
+            ).toBe(11);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should report the headroom left after a message', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: context });
+
+            const result = await handleEstimateMessageTokens(mockServer, {
+                agent_id: 'agent-1',
+                message: 'a'.repeat(40),
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/agent-1/context', {
+                headers: expect.any(Object),
+            });
+            const data = expectValidToolResponse(result);
+            expect(data).toMatchObject({
+                context_window_limit: 1000,
+                current_tokens: 900,
+                message_tokens: 14,
+                total_tokens: 914,
+                remaining_tokens: 86,
+                fits: true,
+            });
+            expect(data.breakdown).toMatchObject({ system: 300, functions: 250 });
+        });
+
+        it('should flag messages that overflow the window', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: context });
+
+            const result = await handleEstimateMessageTokens(mockServer, {
+                agent_id: 'agent-1',
+                messages: [{ content: 'a'.repeat(400) }, { role: 'system', content: 'Be brief' }],
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.message_tokens).toBe(110);
+            expect(data.remaining_tokens).toBe(-10);
+            expect(data.fits).toBe(false);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(
+                handleEstimateMessageTokens(mockServer, { message: 'Hi' }),
+            ).rejects.toThrow('Missing required argument: agent_id');
+        });
+
+        it('should require exactly one of message or messages', async () => {
+            await expect(
+                handleEstimateMessageTokens(mockServer, { agent_id: 'agent-1' }),
+            ).rejects.toThrow('provide exactly one of message or messages');
+            await expect(
+                handleEstimateMessageTokens(mockServer, {
+                    agent_id: 'agent-1',
+                    message: 'Hi',
+                    messages: [{ content: 'Hi' }],
+                }),
+            ).rejects.toThrow('provide exactly one of message or messages');
+        });
+
+        it('should reject unknown roles', async () => {
+            await expect(
+                handleEstimateMessageTokens(mockServer, {
+                    agent_id: 'agent-1',
+                    message: 'Hi',
+                    role: 'tool',
+                }),
+            ).rejects.toThrow('Invalid argument: role must be one of');
+        });
+
+        it('should report an unknown agent', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleEstimateMessageTokens(mockServer, { agent_id: 'missing', message: 'Hi' }),
+            ).rejects.toThrow('Agent not found: missing');
+        });
+    });
+});
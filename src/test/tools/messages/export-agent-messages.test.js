@@ -0,0 +1,188 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import {
+    handleExportAgentMessages,
+    exportAgentMessagesDefinition,
+    normalizeMessage,
+} from '../../../tools/messages/export-agent-messages.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const history = [
+    {
+        id: 'message-1',
+        message_type: 'user_message',
+        content: [{ type: 'text', text: 'What is the weather?' }],
+        date: '2025-01-01T00:00:00Z',
+    },
+    {
+        id: 'message-2',
+        message_type: 'tool_call_message',
+        tool_call: { name: 'get_weather', arguments: '{"city":"Paris"}', tool_call_id: 'call-1' },
+        date: '2025-01-01T00:00:01Z',
+    },
+    {
+        id: 'message-3',
+        message_type: 'tool_return_message',
+        tool_return: 'Sunny',
+        tool_call_id: 'call-1',
+        status: 'success',
+        date: '2025-01-01T00:00:02Z',
+    },
+];
+
+describe('Export Agent Messages', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(exportAgentMessagesDefinition.name).toBe('export_agent_messages');
+            expect(exportAgentMessagesDefinition.inputSchema.required).toEqual(['agent_id']);
+            expect(exportAgentMessagesDefinition.inputSchema.properties).toHaveProperty(
+                'output_path',
+            );
+        });
+    });
+
+    describe('normalizeMessage', () => {
+        it('should give every message type the same keys', () => {
+            const [user, call, result] = history.map(normalizeMessage);
+
+            expect(user).toEqual({
+                id: 'message-1',
+                role: 'user',
+                type: 'user_message',
+                content: 'What is the weather?',
+                tool_calls: [],
+                tool_call_id: null,
+                status: null,
+                timestamp: '2025-01-01T00:00:00Z',
+            });
+            expect(call).toMatchObject({
+                role: 'assistant',
+                content: null,
+                tool_calls: [{ id: 'call-1', name: 'get_weather', arguments: '{"city":"Paris"}' }],
+            });
+            expect(result).toMatchObject({
+                role: 'tool',
+                content: 'Sunny',
+                tool_call_id: 'call-1',
+                status: 'success',
+            });
+        });
+
+        it('should use reasoning as the content of reasoning messages', () => {
+            expect(
+                normalizeMessage({ id: 'm', message_type: 'reasoning_message', reasoning: 'Hmm' }),
+            ).toMatchObject({ role: 'assistant', type: 'reasoning_message', content: 'Hmm' });
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should page through the whole history', async () => {
+            const firstPage = Array.from({ length: 100 }, (_, i) => ({
+                id: `message-${i}`,
+                message_type: 'assistant_message',
+                content: `Reply ${i}`,
+            }));
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: firstPage })
+                .mockResolvedValueOnce({ data: history });
+
+            const result = await handleExportAgentMessages(mockServer, { agent_id: 'agent-1' });
+
+            expect(mockServer.api.get).toHaveBeenLastCalledWith('/agents/agent-1/messages', {
+                headers: expect.any(Object),
+                params: { limit: 100, after: 'message-99' },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(103);
+            expect(data.messages[100].content).toBe('What is the weather?');
+            expect(data.next_cursor).toBeNull();
+        });
+
+        it('should start after the given cursor', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+
+            await handleExportAgentMessages(mockServer, {
+                agent_id: 'agent-1',
+                after: 'message-9',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/agent-1/messages', {
+                headers: expect.any(Object),
+                params: { limit: 100, after: 'message-9' },
+            });
+        });
+
+        it('should write the messages to a JSON Lines file', async () => {
+            mockServer.exportDir = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-exports-'));
+            mockServer.api.get.mockResolvedValueOnce({ data: history });
+
+            try {
+                const result = await handleExportAgentMessages(mockServer, {
+                    agent_id: 'agent-1',
+                    output_path: 'messages.jsonl',
+                });
+
+                const data = expectValidToolResponse(result);
+                expect(data.file_path).toBe(path.join(mockServer.exportDir, 'messages.jsonl'));
+                expect(data.count).toBe(3);
+                expect(data).not.toHaveProperty('messages');
+                const lines = fs.readFileSync(data.file_path, 'utf8').trim().split('\n');
+                expect(lines.map((line) => JSON.parse(line).id)).toEqual([
+                    'message-1',
+                    'message-2',
+                    'message-3',
+                ]);
+                expect(data.size_bytes).toBe(fs.statSync(data.file_path).size);
+            } finally {
+                fs.rmSync(mockServer.exportDir, { recursive: true, force: true });
+            }
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleExportAgentMessages(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should report an unknown agent', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleExportAgentMessages(mockServer, { agent_id: 'missing' }),
+            ).rejects.toThrow('Agent not found: missing');
+        });
+
+        it('should refuse paths outside the export directory', async () => {
+            mockServer.exportDir = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-exports-'));
+
+            try {
+                await expect(
+                    handleExportAgentMessages(mockServer, {
+                        agent_id: 'agent-1',
+                        output_path: '../messages.jsonl',
+                    }),
+                ).rejects.toThrow('output_path must be inside the export directory');
+                expect(mockServer.api.get).not.toHaveBeenCalled();
+            } finally {
+                fs.rmSync(mockServer.exportDir, { recursive: true, force: true });
+            }
+        });
+    });
+});
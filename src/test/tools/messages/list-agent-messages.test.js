@@ -0,0 +1,307 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleListAgentMessages,
+    listAgentMessagesDefinition,
+} from '../../../tools/messages/list-agent-messages.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const makeMessages = (count, start = 0) =>
+    Array.from({ length: count }, (_, i) => ({
+        id: `message-${start + i}`,
+        message_type: 'user_message',
+        content: `Message ${start + i}`,
+    }));
+
+describe('List Agent Messages', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listAgentMessagesDefinition.name).toBe('list_agent_messages');
+            expect(listAgentMessagesDefinition.inputSchema.required).toEqual(['agent_id']);
+            const props = listAgentMessagesDefinition.inputSchema.properties;
+            expect(props.message_id.type).toBe('string');
+            expect(props.limit.type).toBe('integer');
+            expect(props.after.type).toBe('string');
+            expect(props.before.type).toBe('string');
+        });
+    });
+
+    describe('Listing', () => {
+        it('should request one page and report has_more', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeMessages(4) });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                limit: 3,
+                after: 'message-x',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/agent-123/messages',
+                expect.objectContaining({ params: { limit: 4, after: 'message-x' } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(3);
+            expect(data.messages.map((m) => m.id)).toEqual(['message-0', 'message-1', 'message-2']);
+            expect(data.has_more).toBe(true);
+            expect(data.next_cursor).toBe('message-2');
+        });
+
+        it('should default to a page of 50', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeMessages(2) });
+
+            const result = await handleListAgentMessages(mockServer, { agent_id: 'agent-123' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/agent-123/messages',
+                expect.objectContaining({ params: { limit: 51 } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.has_more).toBe(false);
+            expect(data.next_cursor).toBeNull();
+        });
+    });
+
+    describe('Lookup by message_id', () => {
+        it('should return only the matching message entries', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    ...makeMessages(3),
+                    { id: 'message-target', message_type: 'reasoning_message' },
+                    { id: 'message-target', message_type: 'assistant_message' },
+                ],
+            });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                message_id: 'message-target',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.message_id).toBe('message-target');
+            expect(data.messages).toHaveLength(2);
+            expect(data.messages.every((m) => m.id === 'message-target')).toBe(true);
+        });
+
+        it('should page through history until the message is found', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: makeMessages(100) })
+                .mockResolvedValueOnce({ data: makeMessages(20, 100) });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                message_id: 'message-110',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(2);
+            expect(mockServer.api.get).toHaveBeenLastCalledWith(
+                '/agents/agent-123/messages',
+                expect.objectContaining({ params: { limit: 100, after: 'message-99' } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.messages[0].content).toBe('Message 110');
+        });
+
+        it('should report a message that does not exist', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makeMessages(5) });
+
+            await expect(
+                handleListAgentMessages(mockServer, {
+                    agent_id: 'agent-123',
+                    message_id: 'message-missing',
+                }),
+            ).rejects.toThrow('Message not found: message-missing');
+        });
+    });
+
+    describe('Filtering by role', () => {
+        const history = (count, offset = 0) =>
+            Array.from({ length: count }, (_, i) => ({
+                id: `message-${offset + i}`,
+                message_type: (offset + i) % 4 === 0 ? 'user_message' : 'tool_call_message',
+            }));
+
+        it('should return only messages with the requested role', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: history(8) });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                role: 'user',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.messages.map((m) => m.id)).toEqual(['message-0', 'message-4']);
+            expect(data.has_more).toBe(false);
+        });
+
+        it('should scan further pages until the page is full', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: history(100) })
+                .mockResolvedValueOnce({ data: history(100, 100) });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                role: 'user',
+                limit: 30,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(2);
+            expect(mockServer.api.get.mock.calls[1][1].params.after).toBe('message-99');
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(30);
+            expect(data.has_more).toBe(true);
+            expect(data.next_cursor).toBe('message-116');
+        });
+
+        it('should filter by message_type', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { id: 'message-1', message_type: 'reasoning_message' },
+                    { id: 'message-2', message_type: 'tool_call_message' },
+                    { id: 'message-3', message_type: 'assistant_message' },
+                ],
+            });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                role: 'assistant',
+                message_type: 'tool_call_message',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.messages.map((m) => m.id)).toEqual(['message-2']);
+        });
+
+        it('should reject an unknown role', async () => {
+            await expect(
+                handleListAgentMessages(mockServer, { agent_id: 'agent-123', role: 'bot' }),
+            ).rejects.toThrow('role must be one of system, user, assistant, tool');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should reject an unknown message_type', async () => {
+            await expect(
+                handleListAgentMessages(mockServer, {
+                    agent_id: 'agent-123',
+                    message_type: 'tool_message',
+                }),
+            ).rejects.toThrow('message_type must be one of');
+        });
+    });
+
+    describe('Filtering by date', () => {
+        const dated = [
+            { id: 'message-1', message_type: 'user_message', date: '2025-01-30T23:00:00Z' },
+            { id: 'message-2', message_type: 'user_message', date: '2025-01-31T09:30:00Z' },
+            { id: 'message-3', message_type: 'assistant_message', date: '2025-01-31T18:00:00Z' },
+            { id: 'message-4', message_type: 'user_message', date: '2025-02-01T00:00:01Z' },
+        ];
+
+        it('should return only messages inside the window', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: dated });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                start_date: '2025-01-31',
+                end_date: '2025-02-01T00:00:00Z',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.messages.map((m) => m.id)).toEqual(['message-2', 'message-3']);
+        });
+
+        it('should stop scanning once messages pass end_date', async () => {
+            const page = Array.from({ length: 100 }, (_, i) => ({
+                id: `message-${i}`,
+                message_type: 'user_message',
+                date: new Date(Date.UTC(2025, 0, 1 + i)).toISOString(),
+            }));
+            mockServer.api.get.mockResolvedValueOnce({ data: page });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                end_date: '2025-01-03',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(1);
+            const data = expectValidToolResponse(result);
+            expect(data.messages.map((m) => m.id)).toEqual(['message-0', 'message-1', 'message-2']);
+        });
+
+        it('should combine a date window with a role', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: dated });
+
+            const result = await handleListAgentMessages(mockServer, {
+                agent_id: 'agent-123',
+                role: 'user',
+                start_date: '2025-01-31T00:00:00+00:00',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.messages.map((m) => m.id)).toEqual(['message-2', 'message-4']);
+        });
+
+        it('should reject a date that is not ISO 8601', async () => {
+            await expect(
+                handleListAgentMessages(mockServer, {
+                    agent_id: 'agent-123',
+                    start_date: '31/01/2025',
+                }),
+            ).rejects.toThrow('start_date must be an ISO 8601 date');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should reject an impossible date', async () => {
+            await expect(
+                handleListAgentMessages(mockServer, {
+                    agent_id: 'agent-123',
+                    end_date: '2025-13-45',
+                }),
+            ).rejects.toThrow('end_date must be an ISO 8601 date');
+        });
+
+        it('should reject a window that ends before it starts', async () => {
+            await expect(
+                handleListAgentMessages(mockServer, {
+                    agent_id: 'agent-123',
+                    start_date: '2025-02-01',
+                    end_date: '2025-01-01',
+                }),
+            ).rejects.toThrow('end_date must not be before start_date');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleListAgentMessages(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should reject an invalid limit', async () => {
+            await expect(
+                handleListAgentMessages(mockServer, { agent_id: 'agent-123', limit: 0 }),
+            ).rejects.toThrow('limit must be a positive integer');
+        });
+
+        it('should report a missing agent', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleListAgentMessages(mockServer, { agent_id: 'missing' }),
+            ).rejects.toThrow('Agent not found: missing');
+        });
+    });
+});
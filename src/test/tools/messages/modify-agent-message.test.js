@@ -0,0 +1,131 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleModifyAgentMessage,
+    modifyAgentMessageDefinition,
+} from '../../../tools/messages/modify-agent-message.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const HISTORY = [
+    { id: 'message-1', message_type: 'user_message', content: 'What is 2 + 2?' },
+    { id: 'message-2', message_type: 'reasoning_message', reasoning: 'Simple sum.' },
+    { id: 'message-2', message_type: 'assistant_message', content: 'It is 5.' },
+    {
+        id: 'message-3',
+        message_type: 'tool_return_message',
+        tool_call_id: 'call-1',
+        tool_return: 'oops',
+    },
+];
+
+describe('Modify Agent Message', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.api.get.mockResolvedValue({ data: HISTORY });
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(modifyAgentMessageDefinition.name).toBe('modify_agent_message');
+            expect(modifyAgentMessageDefinition.inputSchema.required).toEqual([
+                'agent_id',
+                'message_id',
+                'content',
+            ]);
+            expect(modifyAgentMessageDefinition.inputSchema.properties.message_type.enum).toEqual([
+                'system_message',
+                'user_message',
+                'reasoning_message',
+                'assistant_message',
+            ]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should update a user message with its message_type', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({
+                data: { id: 'message-1', message_type: 'user_message', content: 'What is 2 + 3?' },
+            });
+
+            const result = await handleModifyAgentMessage(mockServer, {
+                agent_id: 'agent-123',
+                message_id: 'message-1',
+                content: 'What is 2 + 3?',
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/message-1',
+                { message_type: 'user_message', content: 'What is 2 + 3?' },
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.message_type).toBe('user_message');
+            expect(data.message.content).toBe('What is 2 + 3?');
+        });
+
+        it('should edit reasoning through the reasoning field', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: { id: 'message-2' } });
+
+            await handleModifyAgentMessage(mockServer, {
+                agent_id: 'agent-123',
+                message_id: 'message-2',
+                message_type: 'reasoning_message',
+                content: 'Add the numbers.',
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-123/messages/message-2',
+                { message_type: 'reasoning_message', reasoning: 'Add the numbers.' },
+                expect.any(Object),
+            );
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing content', async () => {
+            await expect(
+                handleModifyAgentMessage(mockServer, {
+                    agent_id: 'agent-123',
+                    message_id: 'message-1',
+                }),
+            ).rejects.toThrow('Missing required argument: content');
+        });
+
+        it('should require message_type when several parts are editable', async () => {
+            await expect(
+                handleModifyAgentMessage(mockServer, {
+                    agent_id: 'agent-123',
+                    message_id: 'message-2',
+                    content: 'It is 4.',
+                }),
+            ).rejects.toThrow('pass message_type to choose one');
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+        });
+
+        it('should refuse to edit tool returns', async () => {
+            await expect(
+                handleModifyAgentMessage(mockServer, {
+                    agent_id: 'agent-123',
+                    message_id: 'message-3',
+                    content: 'fixed',
+                }),
+            ).rejects.toThrow('Message message-3 cannot be edited: it contains tool_return_message');
+        });
+
+        it('should report unknown messages', async () => {
+            await expect(
+                handleModifyAgentMessage(mockServer, {
+                    agent_id: 'agent-123',
+                    message_id: 'message-404',
+                    content: 'x',
+                }),
+            ).rejects.toThrow('Message not found: message-404 (agent agent-123)');
+        });
+    });
+});
@@ -0,0 +1,129 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleResetAgentMessages,
+    resetAgentMessagesDefinition,
+    buildMemorySummary,
+} from '../../../tools/messages/reset-agent-messages.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const blocks = [
+    { label: 'persona', value: 'I am a support agent.' },
+    { label: 'human', value: 'Name: Sam' },
+];
+
+describe('Reset Agent Messages', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(resetAgentMessagesDefinition.name).toBe('reset_agent_messages');
+            expect(resetAgentMessagesDefinition.inputSchema.required).toEqual(['agent_id']);
+            expect(resetAgentMessagesDefinition.inputSchema.properties).toHaveProperty(
+                'soft_reset',
+            );
+        });
+    });
+
+    describe('buildMemorySummary', () => {
+        it('should list every block by label', () => {
+            const summary = buildMemorySummary(blocks);
+
+            expect(summary).toContain('The conversation history was reset.');
+            expect(summary).toContain('[persona]\nI am a support agent.');
+            expect(summary).toContain('[human]\nName: Sam');
+        });
+
+        it('should cut very long blocks', () => {
+            const summary = buildMemorySummary([{ label: 'notes', value: 'x'.repeat(5000) }]);
+
+            expect(summary).toContain(`${'x'.repeat(2000)}…`);
+            expect(summary).not.toContain('x'.repeat(2001));
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should reset the history', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: {} });
+
+            const result = await handleResetAgentMessages(mockServer, { agent_id: 'agent-1' });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-1/reset-messages',
+                null,
+                expect.objectContaining({ params: { add_default_initial_messages: false } }),
+            );
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({ agent_id: 'agent-1', reset: true, soft_reset: false });
+        });
+
+        it('should re-seed the conversation with core memory on a soft reset', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: blocks });
+            mockServer.api.patch.mockResolvedValueOnce({ data: {} });
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { messages: [{ id: 'message-1' }, { id: 'message-1' }, { id: 'message-2' }] },
+            });
+
+            const result = await handleResetAgentMessages(mockServer, {
+                agent_id: 'agent-1',
+                soft_reset: true,
+            });
+
+            const [url, body] = mockServer.api.post.mock.calls[0];
+            expect(url).toBe('/agents/agent-1/messages');
+            expect(body.messages[0].role).toBe('system');
+            expect(body.messages[0].content).toContain('[human]\nName: Sam');
+            const data = expectValidToolResponse(result);
+            expect(data.seed).toEqual({
+                block_labels: ['persona', 'human'],
+                message_ids: ['message-1', 'message-2'],
+            });
+        });
+
+        it('should report a failed seed after the reset', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: blocks });
+            mockServer.api.patch.mockResolvedValueOnce({ data: {} });
+            const error = new Error('Service Unavailable');
+            error.response = { status: 503, data: {} };
+            mockServer.api.post.mockRejectedValueOnce(error);
+
+            const result = await handleResetAgentMessages(mockServer, {
+                agent_id: 'agent-1',
+                soft_reset: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.reset).toBe(true);
+            expect(data.seed_error.error_code).toBe('service_unavailable');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleResetAgentMessages(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should not reset an unknown agent', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleResetAgentMessages(mockServer, { agent_id: 'missing', soft_reset: true }),
+            ).rejects.toThrow('Agent not found: missing');
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+        });
+    });
+});
@@ -0,0 +1,117 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleSummarizeAgentConversation,
+    summarizeAgentConversationDefinition,
+    DEFAULT_MAX_MESSAGE_LENGTH,
+    MAX_MESSAGE_LENGTH_LIMIT,
+} from '../../../tools/messages/summarize-agent-conversation.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Summarize Agent Conversation', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(summarizeAgentConversationDefinition.name).toBe('summarize_agent_conversation');
+            expect(summarizeAgentConversationDefinition.inputSchema.required).toEqual([
+                'agent_id',
+            ]);
+            const prop =
+                summarizeAgentConversationDefinition.inputSchema.properties.max_message_length;
+            expect(prop.type).toBe('integer');
+            expect(prop.minimum).toBe(1);
+            expect(prop.maximum).toBe(MAX_MESSAGE_LENGTH_LIMIT);
+            expect(prop.default).toBe(DEFAULT_MAX_MESSAGE_LENGTH);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should default max_message_length to 10', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: null });
+
+            const result = await handleSummarizeAgentConversation(mockServer, {
+                agent_id: 'agent-123',
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/summarize',
+                null,
+                expect.objectContaining({ params: { max_message_length: 10 } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.agent_id).toBe('agent-123');
+            expect(data.max_message_length).toBe(10);
+        });
+
+        it('should pass through a custom max_message_length', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { summary: 'Condensed' } });
+
+            const result = await handleSummarizeAgentConversation(mockServer, {
+                agent_id: 'agent-123',
+                max_message_length: 25,
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/summarize',
+                null,
+                expect.objectContaining({ params: { max_message_length: 25 } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.result).toEqual({ summary: 'Condensed' });
+        });
+
+        it('should apply a per-request timeout override', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: null });
+
+            await handleSummarizeAgentConversation(mockServer, {
+                agent_id: 'agent-123',
+                timeout_secs: 300,
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/summarize',
+                null,
+                expect.objectContaining({ timeout: 300000 }),
+            );
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleSummarizeAgentConversation(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should reject out-of-range max_message_length values', async () => {
+            for (const value of [0, -3, 2.5, MAX_MESSAGE_LENGTH_LIMIT + 1, '10']) {
+                await expect(
+                    handleSummarizeAgentConversation(mockServer, {
+                        agent_id: 'agent-123',
+                        max_message_length: value,
+                    }),
+                ).rejects.toThrow('max_message_length must be an integer between 1 and');
+            }
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should report a missing agent', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.post.mockRejectedValueOnce(error);
+
+            await expect(
+                handleSummarizeAgentConversation(mockServer, { agent_id: 'missing' }),
+            ).rejects.toThrow('Agent not found: missing');
+        });
+    });
+});
@@ -0,0 +1,138 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleWaitForRun, waitForRunDefinition } from '../../../tools/messages/wait-for-run.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Wait For Run', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(waitForRunDefinition.name).toBe('wait_for_run');
+            expect(waitForRunDefinition.inputSchema.required).toEqual(['run_id']);
+            expect(waitForRunDefinition.inputSchema.properties).toHaveProperty('wait_secs');
+            expect(waitForRunDefinition.inputSchema.properties).toHaveProperty(
+                'poll_interval_secs',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should poll until the run completes and return its messages', async () => {
+            const messages = [
+                { id: 'message-1', message_type: 'assistant_message', content: 'Hi' },
+            ];
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: { id: 'run-1', status: 'created' } })
+                .mockResolvedValueOnce({ data: { id: 'run-1', status: 'running' } })
+                .mockResolvedValueOnce({
+                    data: {
+                        id: 'run-1',
+                        status: 'completed',
+                        completed_at: '2025-01-01T00:00:05Z',
+                        stop_reason: 'end_turn',
+                    },
+                })
+                .mockResolvedValueOnce({ data: messages });
+
+            const result = await handleWaitForRun(mockServer, {
+                run_id: 'run-1',
+                poll_interval_secs: 0.001,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(4);
+            expect(mockServer.api.get).toHaveBeenLastCalledWith(
+                '/runs/run-1/messages',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+
+            const data = expectValidToolResponse(result);
+            expect(data.status).toBe('completed');
+            expect(data.done).toBe(true);
+            expect(data.timed_out).toBe(false);
+            expect(data.polls).toBe(3);
+            expect(data.run.stop_reason).toBe('end_turn');
+            expect(data.messages).toEqual(messages);
+        });
+
+        it('should return the current status without messages on timeout', async () => {
+            mockServer.api.get.mockResolvedValue({ data: { id: 'run-1', status: 'running' } });
+
+            const result = await handleWaitForRun(mockServer, {
+                run_id: 'run-1',
+                wait_secs: 0.02,
+                poll_interval_secs: 0.005,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.status).toBe('running');
+            expect(data.done).toBe(false);
+            expect(data.timed_out).toBe(true);
+            expect(data).not.toHaveProperty('messages');
+            expect(mockServer.api.get).not.toHaveBeenCalledWith(
+                '/runs/run-1/messages',
+                expect.anything(),
+            );
+        });
+
+        it('should check the status once when wait_secs is 0', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { id: 'run-1', status: 'running' } });
+
+            const result = await handleWaitForRun(mockServer, { run_id: 'run-1', wait_secs: 0 });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(1);
+            const data = expectValidToolResponse(result);
+            expect(data.polls).toBe(1);
+            expect(data.timed_out).toBe(true);
+        });
+
+        it('should treat failed runs as finished', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: { id: 'run-1', status: 'failed' } })
+                .mockResolvedValueOnce({ data: [] });
+
+            const result = await handleWaitForRun(mockServer, { run_id: 'run-1' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.done).toBe(true);
+            expect(data.status).toBe('failed');
+            expect(data.messages).toEqual([]);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing run_id', async () => {
+            await expect(handleWaitForRun(mockServer, {})).rejects.toThrow(
+                'Missing required argument: run_id',
+            );
+        });
+
+        it('should reject invalid wait and poll intervals', async () => {
+            await expect(
+                handleWaitForRun(mockServer, { run_id: 'run-1', wait_secs: -1 }),
+            ).rejects.toThrow('Invalid argument: wait_secs');
+            await expect(
+                handleWaitForRun(mockServer, { run_id: 'run-1', poll_interval_secs: 0 }),
+            ).rejects.toThrow('Invalid argument: poll_interval_secs');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should report a missing run', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: { detail: 'Run not found' } };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(handleWaitForRun(mockServer, { run_id: 'run-missing' })).rejects.toThrow(
+                'Run not found: run-missing',
+            );
+        });
+    });
+});
@@ -0,0 +1,130 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleBulkCreatePassages,
+    bulkCreatePassagesDefinition,
+} from '../../../tools/passages/bulk-create-passages.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Bulk Create Passages', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(bulkCreatePassagesDefinition.name).toBe('bulk_create_passages');
+            expect(bulkCreatePassagesDefinition.inputSchema.required).toEqual([
+                'agent_id',
+                'texts',
+            ]);
+            expect(bulkCreatePassagesDefinition.inputSchema.properties).toHaveProperty(
+                'max_concurrency',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should insert every text and return the created IDs in order', async () => {
+            mockServer.api.post.mockImplementation((url, body) =>
+                Promise.resolve({ data: [{ id: `passage-${body.text}`, embedding: [0.1] }] }),
+            );
+
+            const result = await handleBulkCreatePassages(mockServer, {
+                agent_id: 'agent-123',
+                texts: ['one', 'two', 'three'],
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledTimes(3);
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-123/archival-memory',
+                { text: 'one' },
+                { headers: expect.any(Object) },
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(true);
+            expect(data.summary).toEqual({ total: 3, created_count: 3, error_count: 0 });
+            expect(data.passage_ids).toEqual(['passage-one', 'passage-two', 'passage-three']);
+            expect(data.errors).toEqual([]);
+        });
+
+        it('should report failed inserts by index without aborting the rest', async () => {
+            const error = new Error('Validation failed');
+            error.response = { status: 422, data: { detail: 'bad text' } };
+            mockServer.api.post
+                .mockResolvedValueOnce({ data: [{ id: 'passage-1' }] })
+                .mockRejectedValueOnce(error)
+                .mockResolvedValueOnce({ data: [{ id: 'passage-3' }] });
+
+            const result = await handleBulkCreatePassages(mockServer, {
+                agent_id: 'agent-123',
+                texts: ['one', 'two', 'three'],
+                max_concurrency: 1,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(false);
+            expect(data.summary).toEqual({ total: 3, created_count: 2, error_count: 1 });
+            expect(data.passage_ids).toEqual(['passage-1', 'passage-3']);
+            expect(data.errors).toHaveLength(1);
+            expect(data.errors[0]).toMatchObject({ index: 1, http_status: 422 });
+        });
+
+        it('should respect max_concurrency', async () => {
+            let inFlight = 0;
+            let peak = 0;
+            mockServer.api.post.mockImplementation(async () => {
+                inFlight++;
+                peak = Math.max(peak, inFlight);
+                await new Promise((resolve) => setTimeout(resolve, 5));
+                inFlight--;
+                return { data: [{ id: 'passage' }] };
+            });
+
+            await handleBulkCreatePassages(mockServer, {
+                agent_id: 'agent-123',
+                texts: ['a', 'b', 'c', 'd', 'e'],
+                max_concurrency: 2,
+            });
+
+            expect(peak).toBe(2);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleBulkCreatePassages(mockServer, { texts: ['a'] })).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should require a non-empty texts array', async () => {
+            await expect(
+                handleBulkCreatePassages(mockServer, { agent_id: 'agent-123', texts: [] }),
+            ).rejects.toThrow('Missing required argument: texts');
+        });
+
+        it('should reject empty texts before inserting anything', async () => {
+            await expect(
+                handleBulkCreatePassages(mockServer, { agent_id: 'agent-123', texts: ['a', ''] }),
+            ).rejects.toThrow('Invalid argument: texts[1] must be a non-empty string');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should reject an invalid max_concurrency', async () => {
+            await expect(
+                handleBulkCreatePassages(mockServer, {
+                    agent_id: 'agent-123',
+                    texts: ['a'],
+                    max_concurrency: 0,
+                }),
+            ).rejects.toThrow('Invalid argument: max_concurrency must be a positive integer');
+        });
+    });
+});
@@ -0,0 +1,76 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleCountPassages,
+    countPassagesDefinition,
+} from '../../../tools/passages/count-passages.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Count Passages', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(countPassagesDefinition.name).toBe('count_passages');
+            expect(countPassagesDefinition.inputSchema.required).toEqual(['agent_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the archival memory size from the context overview', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { num_archival_memory: 42137, num_recall_memory: 12 },
+            });
+
+            const result = await handleCountPassages(mockServer, { agent_id: 'agent-123' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/agent-123/context', {
+                headers: expect.any(Object),
+            });
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({ agent_id: 'agent-123', count: 42137 });
+        });
+
+        it('should count an empty archival memory as zero', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { num_archival_memory: 0 } });
+
+            const result = await handleCountPassages(mockServer, { agent_id: 'agent-123' });
+
+            expect(expectValidToolResponse(result).count).toBe(0);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleCountPassages(mockServer, {})).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+
+        it('should report a missing agent', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleCountPassages(mockServer, { agent_id: 'missing-agent' }),
+            ).rejects.toThrow('Agent not found: missing-agent');
+        });
+
+        it('should fail when the server omits the archival memory size', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: {} });
+
+            await expect(
+                handleCountPassages(mockServer, { agent_id: 'agent-123' }),
+            ).rejects.toThrow('did not report an archival memory size');
+        });
+    });
+});
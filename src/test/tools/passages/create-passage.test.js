@@ -356,7 +356,10 @@ describe('Create Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                'Agent not found: non-existent-agent',
+                expect.objectContaining({
+                    message: 'Agent not found: non-existent-agent',
+                    response: { status: 404 },
+                }),
             );
         });
 
@@ -381,7 +384,8 @@ describe('Create Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                expect.stringContaining('Validation error creating passage'),
+                error,
+                'Failed to create passage for agent agent-123',
             );
         });
 
@@ -400,7 +404,10 @@ describe('Create Passage', () => {
                 }),
             ).rejects.toThrow();
 
-            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(error);
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                error,
+                'Failed to create passage for agent agent-123',
+            );
         });
 
         it('should handle network errors without response', async () => {
@@ -415,7 +422,10 @@ describe('Create Passage', () => {
                 }),
             ).rejects.toThrow();
 
-            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(error);
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                error,
+                'Failed to create passage for agent agent-123',
+            );
         });
 
         it('should handle malformed API response', async () => {
@@ -231,10 +231,28 @@ describe('Delete Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                `Agent or Passage not found: agent_id=${agentId}, memory_id=${memoryId}`,
+                expect.objectContaining({
+                    message: `Agent or Passage not found: agent_id=${agentId}, memory_id=${memoryId}`,
+                    response: { status: 404 },
+                }),
             );
         });
 
+        it('should treat a missing passage as deleted with ignore_missing', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: { error: 'Passage not found' } };
+            mockServer.api.delete.mockRejectedValueOnce(error);
+
+            const result = await handleDeletePassage(mockServer, {
+                agent_id: 'agent-123',
+                memory_id: 'passage-123',
+                ignore_missing: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.already_absent).toBe(true);
+        });
+
         it('should handle 401 unauthorized error', async () => {
             const error = new Error('Unauthorized');
             error.response = {
@@ -0,0 +1,86 @@
+import { describe, it, expect } from 'vitest';
+import {
+    cosineSimilarity,
+    rankByEmbedding,
+    validateEmbeddingSearch,
+} from '../../../tools/passages/embedding-search.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+
+const embeddingConfig = {
+    embedding_endpoint_type: 'openai',
+    embedding_model: 'text-embedding-3-small',
+    embedding_dim: 2,
+};
+
+describe('Embedding Search', () => {
+    describe('cosineSimilarity', () => {
+        it('should compare vector directions', () => {
+            expect(cosineSimilarity([1, 0], [2, 0])).toBeCloseTo(1);
+            expect(cosineSimilarity([1, 0], [0, 3])).toBeCloseTo(0);
+            expect(cosineSimilarity([0, 0], [1, 1])).toBe(0);
+        });
+    });
+
+    describe('rankByEmbedding', () => {
+        it('should skip passages that cannot be compared', () => {
+            const passages = [
+                { id: 'no-embedding' },
+                { id: 'wrong-dim', embedding: [1, 0, 0] },
+                {
+                    id: 'other-model',
+                    embedding: [1, 0],
+                    embedding_config: { embedding_model: 'text-embedding-ada-002' },
+                },
+                {
+                    id: 'same-model',
+                    embedding: [0, 1],
+                    embedding_config: { embedding_model: 'text-embedding-3-small' },
+                },
+            ];
+
+            expect(rankByEmbedding(passages, [1, 0], embeddingConfig).map((p) => p.id)).toEqual([
+                'same-model',
+            ]);
+            expect(rankByEmbedding(passages, [1, 0]).map((p) => p.id)).toEqual([
+                'other-model',
+                'same-model',
+            ]);
+        });
+    });
+
+    describe('validateEmbeddingSearch', () => {
+        it('should accept a query embedding with a matching config', () => {
+            const server = createMockLettaServer();
+
+            expect(() =>
+                validateEmbeddingSearch(server, {
+                    query_embedding: [0.1, 0.2],
+                    embedding_config: embeddingConfig,
+                }),
+            ).not.toThrow();
+        });
+
+        it('should reject invalid combinations', () => {
+            const server = createMockLettaServer();
+
+            expect(() => validateEmbeddingSearch(server, { query_embedding: [] })).toThrow(
+                'query_embedding must be a non-empty array of numbers',
+            );
+            expect(() =>
+                validateEmbeddingSearch(server, { embedding_config: embeddingConfig }),
+            ).toThrow('embedding_config requires query_embedding');
+            expect(() =>
+                validateEmbeddingSearch(server, {
+                    query_embedding: [0.1, 0.2, 0.3],
+                    embedding_config: embeddingConfig,
+                }),
+            ).toThrow('query_embedding has 3 dimensions but embedding_config.embedding_dim is 2');
+            expect(() =>
+                validateEmbeddingSearch(server, {
+                    query_embedding: [0.1],
+                    embedding_config: { embedding_model: 'x' },
+                }),
+            ).toThrow('Missing required argument: embedding_config.embedding_endpoint_type');
+        });
+    });
+});
@@ -3,6 +3,7 @@ import {
     handleListPassages,
     listPassagesDefinition,
 } from '../../../tools/passages/list-passages.js';
+import { MAX_RANKED_PASSAGES } from '../../../tools/passages/embedding-search.js';
 import { createMockLettaServer } from '../../utils/mock-server.js';
 import { expectValidToolResponse } from '../../utils/test-helpers.js';
 
@@ -402,7 +403,153 @@ describe('List Passages', () => {
         });
     });
 
+    describe('Pagination', () => {
+        it('should return next_cursor when a full page is returned', async () => {
+            const mockPassages = Array.from({ length: 3 }, (_, i) => ({
+                id: `passage-${i}`,
+                text: `Memory ${i}`,
+            }));
+            mockServer.api.get.mockResolvedValueOnce({ data: mockPassages });
+
+            const result = await handleListPassages(mockServer, {
+                agent_id: 'agent-123',
+                limit: 3,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(3);
+            expect(data.next_cursor).toBe('passage-2');
+            expect(data.prev_cursor).toBe('passage-0');
+        });
+
+        it('should truncate a page that exceeds LETTA_MAX_RESPONSE_BYTES', async () => {
+            const mockPassages = Array.from({ length: 10 }, (_, i) => ({
+                id: `passage-${i}`,
+                text: 'x'.repeat(500),
+            }));
+            mockServer.api.get.mockResolvedValueOnce({ data: mockPassages });
+            process.env.LETTA_MAX_RESPONSE_BYTES = '2000';
+
+            try {
+                const result = await handleListPassages(mockServer, { agent_id: 'agent-123' });
+
+                expect(result.content[0].text.length).toBeLessThanOrEqual(2000);
+                const data = expectValidToolResponse(result);
+                expect(data.truncated).toBe(true);
+                expect(data.count).toBeGreaterThan(0);
+                expect(data.count).toBeLessThan(10);
+                expect(data.next_cursor).toBe(`passage-${data.count - 1}`);
+            } finally {
+                delete process.env.LETTA_MAX_RESPONSE_BYTES;
+            }
+        });
+
+        it('should return no next_cursor on the last page', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [{ id: 'passage-9', text: 'Last memory' }],
+            });
+
+            const result = await handleListPassages(mockServer, {
+                agent_id: 'agent-123',
+                limit: 3,
+                after: 'passage-8',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.next_cursor).toBeNull();
+            expect(data.prev_cursor).toBe('passage-9');
+        });
+
+        it('should page oldest first with the next_cursor', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+
+            await handleListPassages(mockServer, {
+                agent_id: 'agent-123',
+                limit: 10,
+                after: 'passage-2',
+                ascending: true,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/agent-123/archival-memory',
+                expect.objectContaining({
+                    params: { after: 'passage-2', limit: 10, order: 'asc' },
+                }),
+            );
+        });
+
+        it('should not return cursors for an unpaginated listing', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [{ id: 'passage-1', text: 'A' }] });
+
+            const result = await handleListPassages(mockServer, { agent_id: 'agent-123' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.next_cursor).toBeNull();
+            expect(data.prev_cursor).toBeNull();
+        });
+
+        it('should rank the whole archive by a query embedding', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { id: 'passage-1', text: 'Far', embedding: [0, 1] },
+                    { id: 'passage-2', text: 'Near', embedding: [1, 0.1] },
+                    { id: 'passage-3', text: 'Other model', embedding: [1, 0, 0] },
+                ],
+            });
+
+            const result = await handleListPassages(mockServer, {
+                agent_id: 'agent-123',
+                query_embedding: [1, 0],
+                limit: 1,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/agent-123/archival-memory', {
+                headers: expect.any(Object),
+                params: { limit: 100 },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.ranked_by).toBe('query_embedding');
+            expect(data.passages).toHaveLength(1);
+            expect(data.passages[0].id).toBe('passage-2');
+            expect(data.passages[0].score).toBeCloseTo(0.995, 3);
+            expect(data.passages[0]).not.toHaveProperty('embedding');
+            expect(data).not.toHaveProperty('scan_truncated');
+        });
+
+        it('should report scan_truncated past MAX_RANKED_PASSAGES', async () => {
+            let next = 0;
+            mockServer.api.get.mockImplementation(async () => ({
+                data: Array.from({ length: 100 }, () => ({
+                    id: `passage-${next++}`,
+                    embedding: [1, 0],
+                })),
+            }));
+
+            const result = await handleListPassages(mockServer, {
+                agent_id: 'agent-123',
+                query_embedding: [1, 0],
+                limit: 1,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(MAX_RANKED_PASSAGES / 100 + 1);
+            const data = expectValidToolResponse(result);
+            expect(data.scan_truncated).toBe(true);
+            expect(data.passages).toHaveLength(1);
+        });
+    });
+
     describe('Error Handling', () => {
+        it('should not combine a query embedding with text search', async () => {
+            await expect(
+                handleListPassages(mockServer, {
+                    agent_id: 'agent-123',
+                    search: 'refund',
+                    query_embedding: [1, 0],
+                }),
+            ).rejects.toThrow('query_embedding cannot be combined with search');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
         it('should handle missing agent_id', async () => {
             await expect(handleListPassages(mockServer, {})).rejects.toThrow();
 
@@ -435,7 +582,10 @@ describe('List Passages', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                `Agent not found: ${agentId}`,
+                expect.objectContaining({
+                    message: `Agent not found: ${agentId}`,
+                    response: { status: 404 },
+                }),
             );
         });
 
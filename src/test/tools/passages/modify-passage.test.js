@@ -3,6 +3,7 @@ import {
     handleModifyPassage,
     modifyPassageDefinition,
 } from '../../../tools/passages/modify-passage.js';
+import { handleListPassages } from '../../../tools/passages/list-passages.js';
 import { createMockLettaServer } from '../../utils/mock-server.js';
 import { expectValidToolResponse } from '../../utils/test-helpers.js';
 
@@ -23,11 +24,7 @@ describe('Modify Passage', () => {
             expect(modifyPassageDefinition.description).toContain(
                 "Modify a memory in the agent's archival memory",
             );
-            expect(modifyPassageDefinition.inputSchema.required).toEqual([
-                'agent_id',
-                'memory_id',
-                'update_data',
-            ]);
+            expect(modifyPassageDefinition.inputSchema.required).toEqual(['agent_id', 'memory_id']);
             expect(modifyPassageDefinition.inputSchema.properties).toHaveProperty('agent_id');
             expect(modifyPassageDefinition.inputSchema.properties).toHaveProperty('memory_id');
             expect(modifyPassageDefinition.inputSchema.properties).toHaveProperty('update_data');
@@ -79,28 +76,24 @@ describe('Modify Passage', () => {
                 update_data: { text: newText },
             });
 
-            // Verify fetching passages with embeddings
-            expect(mockServer.api.get).toHaveBeenCalledWith(
-                `/agents/${agentId}/archival-memory`,
-                expect.objectContaining({
-                    headers: expect.any(Object),
-                    params: { include_embeddings: true },
-                }),
-            );
+            // Verify fetching passages, without downloading their embeddings
+            expect(mockServer.api.get).toHaveBeenCalledWith(`/agents/${agentId}/archival-memory`, {
+                headers: expect.any(Object),
+            });
 
-            // Verify PATCH call with full payload
+            // Verify PATCH call with full payload, minus the stale embedding
             expect(mockServer.api.patch).toHaveBeenCalledWith(
                 `/agents/${agentId}/archival-memory/${memoryId}`,
                 expect.objectContaining({
                     id: memoryId,
                     text: newText,
-                    embedding: [0.1, 0.2, 0.3],
                     embedding_config: { model: 'text-embedding-ada-002' },
                 }),
                 expect.objectContaining({
                     headers: expect.any(Object),
                 }),
             );
+            expect(mockServer.api.patch.mock.calls[0][1]).not.toHaveProperty('embedding');
 
             // Verify response without embeddings
             const data = expectValidToolResponse(result);
@@ -243,7 +236,6 @@ describe('Modify Passage', () => {
                 expect.objectContaining({
                     id: memoryId,
                     text: 'Updated text',
-                    embedding: [0.1, 0.2],
                     embedding_config: existingPassage.embedding_config,
                     metadata: existingPassage.metadata,
                     created_at: '2024-01-01T00:00:00Z',
@@ -366,6 +358,152 @@ describe('Modify Passage', () => {
         });
     });
 
+    describe('Re-embedding', () => {
+        // Minimal in-memory archival store whose embeddings are derived from the text
+        const embed = (text) => [text.length, text.charCodeAt(0) || 0];
+        const createArchivalStore = (server, passages) => {
+            let nextId = 1;
+            server.api.get.mockImplementation(async (_url, { params } = {}) => ({
+                data: passages.filter((p) => !params?.search || p.text.includes(params.search)),
+            }));
+            server.api.patch.mockRejectedValue(
+                Object.assign(new Error('Method Not Allowed'), { response: { status: 405 } }),
+            );
+            server.api.post.mockImplementation(async (_url, payload) => {
+                const passage = {
+                    id: `passage-new-${nextId++}`,
+                    text: payload.text,
+                    embedding: embed(payload.text),
+                    embedding_config: { model: 'ada' },
+                    created_at: payload.created_at,
+                    tags: payload.tags,
+                };
+                passages.push(passage);
+                return { data: [passage] };
+            });
+            server.api.delete.mockImplementation(async (url) => {
+                const id = url.split('/').pop();
+                passages.splice(passages.findIndex((p) => p.id === id), 1);
+                return { data: {} };
+            });
+            return passages;
+        };
+
+        it('should accept top-level text as shorthand for update_data.text', async () => {
+            const existingPassage = {
+                id: 'passage-1',
+                text: 'Old',
+                embedding: [0.1],
+                embedding_config: { model: 'ada' },
+            };
+            mockServer.api.get.mockResolvedValueOnce({ data: [existingPassage] });
+            mockServer.api.patch.mockResolvedValueOnce({ data: [{ ...existingPassage, text: 'New' }] });
+
+            const result = await handleModifyPassage(mockServer, {
+                agent_id: 'agent-1',
+                memory_id: 'passage-1',
+                text: 'New',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.strategy).toBe('update');
+            expect(mockServer.api.patch.mock.calls[0][1].text).toBe('New');
+        });
+
+        it('should recreate the passage when direct update is unsupported', async () => {
+            const store = createArchivalStore(mockServer, [
+                {
+                    id: 'passage-old',
+                    text: 'The sky is green',
+                    embedding: embed('The sky is green'),
+                    embedding_config: { model: 'ada' },
+                    created_at: '2024-01-01T00:00:00Z',
+                    tags: ['facts'],
+                },
+            ]);
+
+            const result = await handleModifyPassage(mockServer, {
+                agent_id: 'agent-1',
+                memory_id: 'passage-old',
+                text: 'The sky is blue',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.strategy).toBe('recreate');
+            expect(data.replaced_memory_id).toBe('passage-old');
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/agents/agent-1/archival-memory',
+                { text: 'The sky is blue', created_at: '2024-01-01T00:00:00Z', tags: ['facts'] },
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            expect(mockServer.api.delete).toHaveBeenCalledWith(
+                '/agents/agent-1/archival-memory/passage-old',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            expect(store.map((p) => p.id)).toEqual(['passage-new-1']);
+        });
+
+        it('should keep the new passage and warn when the original cannot be deleted', async () => {
+            const store = createArchivalStore(mockServer, [
+                {
+                    id: 'passage-old',
+                    text: 'The sky is green',
+                    embedding: embed('The sky is green'),
+                    embedding_config: { model: 'ada' },
+                },
+            ]);
+            mockServer.api.delete.mockRejectedValueOnce(
+                Object.assign(new Error('Request failed with status code 500'), {
+                    response: { status: 500 },
+                }),
+            );
+
+            const result = await handleModifyPassage(mockServer, {
+                agent_id: 'agent-1',
+                memory_id: 'passage-old',
+                text: 'The sky is blue',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.strategy).toBe('recreate');
+            expect(data.passages[0].id).toBe('passage-new-1');
+            expect(data.orphaned_memory_id).toBe('passage-old');
+            expect(data.warning).toContain('passage-old could not be deleted');
+            expect(store.map((p) => p.id)).toEqual(['passage-old', 'passage-new-1']);
+        });
+
+        it('should make the new text searchable with a refreshed embedding', async () => {
+            createArchivalStore(mockServer, [
+                {
+                    id: 'passage-old',
+                    text: 'Meeting on Monday',
+                    embedding: embed('Meeting on Monday'),
+                    embedding_config: { model: 'ada' },
+                },
+            ]);
+
+            await handleModifyPassage(mockServer, {
+                agent_id: 'agent-1',
+                memory_id: 'passage-old',
+                update_data: { text: 'Meeting moved to Thursday afternoon' },
+            });
+
+            const searchResult = await handleListPassages(mockServer, {
+                agent_id: 'agent-1',
+                search: 'Thursday',
+                include_embeddings: true,
+            });
+
+            const data = expectValidToolResponse(searchResult);
+            expect(data.passages).toHaveLength(1);
+            expect(data.passages[0].text).toBe('Meeting moved to Thursday afternoon');
+            expect(data.passages[0].embedding).toEqual(
+                embed('Meeting moved to Thursday afternoon'),
+            );
+            expect(data.passages[0].embedding).not.toEqual(embed('Meeting on Monday'));
+        });
+    });
+
     describe('Error Handling', () => {
         it('should handle missing agent_id', async () => {
             await expect(
@@ -455,7 +593,10 @@ describe('Modify Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                expect.stringContaining(`Could not find passage ${memoryId}`),
+                expect.objectContaining({
+                    message: `Passage not found: ${memoryId} for agent ${agentId}`,
+                    response: { status: 404 },
+                }),
             );
         });
 
@@ -501,7 +642,10 @@ describe('Modify Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                'Agent not found when listing passages: non-existent-agent',
+                expect.objectContaining({
+                    message: 'Agent not found when listing passages: non-existent-agent',
+                    response: { status: 404 },
+                }),
             );
         });
 
@@ -534,7 +678,10 @@ describe('Modify Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                expect.stringContaining('Agent or Passage not found during update'),
+                expect.objectContaining({
+                    message: expect.stringContaining('Agent or Passage not found during update'),
+                    response: { status: 404 },
+                }),
             );
         });
 
@@ -570,7 +717,8 @@ describe('Modify Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                expect.stringContaining('Validation error modifying passage'),
+                error,
+                `Failed to modify passage ${memoryId}`,
             );
         });
 
@@ -602,7 +750,10 @@ describe('Modify Passage', () => {
                 }),
             ).rejects.toThrow();
 
-            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(error);
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                error,
+                `Failed to modify passage ${memoryId}`,
+            );
         });
 
         it('should handle network errors', async () => {
@@ -618,7 +769,8 @@ describe('Modify Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                expect.stringContaining('Failed to fetch passages'),
+                error,
+                'Failed to fetch passages for agent agent-123',
             );
         });
     });
@@ -650,7 +802,10 @@ describe('Modify Passage', () => {
             ).rejects.toThrow();
 
             expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
-                expect.stringContaining(`Could not find passage ${memoryId}`),
+                expect.objectContaining({
+                    message: `Passage not found: ${memoryId} for agent ${agentId}`,
+                    response: { status: 404 },
+                }),
             );
         });
 
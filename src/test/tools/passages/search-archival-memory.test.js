@@ -0,0 +1,167 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleSearchArchivalMemory,
+    searchArchivalMemoryDefinition,
+    mergeByRank,
+} from '../../../tools/passages/search-archival-memory.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const passages = {
+    'agent-1': [
+        { id: 'passage-1a', text: 'Refund policy', embedding: [0.1] },
+        { id: 'passage-1b', text: 'Refund window' },
+    ],
+    'agent-2': [{ id: 'passage-2a', text: 'Refunds take 5 days' }],
+};
+
+describe('Search Archival Memory', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.api.get.mockImplementation((url) => {
+            if (url === '/agents/') {
+                return Promise.resolve({ data: [{ id: 'agent-1' }, { id: 'agent-2' }] });
+            }
+            return Promise.resolve({ data: passages[url.split('/')[2]] });
+        });
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(searchArchivalMemoryDefinition.name).toBe('search_archival_memory');
+            expect(searchArchivalMemoryDefinition.inputSchema.required).toEqual([]);
+            expect(searchArchivalMemoryDefinition.inputSchema.properties).toHaveProperty(
+                'agent_ids',
+            );
+        });
+    });
+
+    describe('mergeByRank', () => {
+        it('should interleave lists by rank up to the limit', () => {
+            expect(mergeByRank([['a1', 'a2', 'a3'], ['b1'], ['c1', 'c2']], 5)).toEqual([
+                'a1',
+                'b1',
+                'c1',
+                'a2',
+                'c2',
+            ]);
+            expect(mergeByRank([], 5)).toEqual([]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should search every agent and merge the hits', async () => {
+            const result = await handleSearchArchivalMemory(mockServer, { query: 'refund' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/agent-2/archival-memory', {
+                headers: expect.any(Object),
+                params: { search: 'refund', limit: 10 },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.agents_searched).toBe(2);
+            expect(data.results.map((hit) => [hit.id, hit.agent_id, hit.rank])).toEqual([
+                ['passage-1a', 'agent-1', 1],
+                ['passage-2a', 'agent-2', 1],
+                ['passage-1b', 'agent-1', 2],
+            ]);
+            expect(data.results[0]).not.toHaveProperty('embedding');
+        });
+
+        it('should respect the overall limit for the given agents', async () => {
+            const result = await handleSearchArchivalMemory(mockServer, {
+                query: 'refund',
+                agent_ids: ['agent-1', 'agent-2'],
+                limit: 2,
+            });
+
+            expect(mockServer.api.get).not.toHaveBeenCalledWith('/agents/', expect.anything());
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(2);
+            expect(data.results.map((hit) => hit.id)).toEqual(['passage-1a', 'passage-2a']);
+        });
+
+        it('should report agents that could not be searched', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockImplementation((url) =>
+                url.includes('missing')
+                    ? Promise.reject(error)
+                    : Promise.resolve({ data: passages['agent-2'] }),
+            );
+
+            const result = await handleSearchArchivalMemory(mockServer, {
+                query: 'refund',
+                agent_ids: ['missing', 'agent-2'],
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(1);
+            expect(data.errors).toEqual([
+                { agent_id: 'missing', error: 'Agent not found: missing', error_code: 'not_found' },
+            ]);
+        });
+    });
+
+    describe('Query Embedding', () => {
+        it('should merge hits from all agents by similarity score', async () => {
+            mockServer.api.get.mockImplementation((url) =>
+                Promise.resolve({
+                    data: url.includes('agent-1')
+                        ? [{ id: 'passage-1a', embedding: [0, 1] }]
+                        : [
+                              { id: 'passage-2a', embedding: [1, 0] },
+                              { id: 'passage-2b', embedding: [1, 1] },
+                          ],
+                }),
+            );
+
+            const result = await handleSearchArchivalMemory(mockServer, {
+                agent_ids: ['agent-1', 'agent-2'],
+                query_embedding: [1, 0],
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.ranked_by).toBe('query_embedding');
+            expect(data.results.map((hit) => [hit.id, hit.agent_id])).toEqual([
+                ['passage-2a', 'agent-2'],
+                ['passage-2b', 'agent-2'],
+                ['passage-1a', 'agent-1'],
+            ]);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require a query', async () => {
+            await expect(handleSearchArchivalMemory(mockServer, {})).rejects.toThrow(
+                'Missing required argument: query',
+            );
+        });
+
+        it('should require an agent filter with a query embedding', async () => {
+            await expect(
+                handleSearchArchivalMemory(mockServer, { query_embedding: [1, 0] }),
+            ).rejects.toThrow('query_embedding requires agent_ids');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should reject an invalid limit', async () => {
+            await expect(
+                handleSearchArchivalMemory(mockServer, { query: 'refund', limit: 0 }),
+            ).rejects.toThrow('Invalid argument: limit must be a positive integer');
+        });
+
+        it('should fail when every agent fails', async () => {
+            mockServer.api.get.mockRejectedValue(new Error('Network Error'));
+
+            await expect(
+                handleSearchArchivalMemory(mockServer, { query: 'refund', agent_ids: ['agent-1'] }),
+            ).rejects.toThrow('Network Error');
+        });
+    });
+});
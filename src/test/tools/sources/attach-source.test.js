@@ -0,0 +1,87 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleAttachSource,
+    attachSourceDefinition,
+} from '../../../tools/sources/attach-source.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const agentState = {
+    id: 'agent-1',
+    name: 'Support',
+    system: 'You are a support agent.',
+    sources: [{ id: 'source-1' }, { id: 'source-2' }],
+};
+
+describe('Attach Source', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(attachSourceDefinition.name).toBe('attach_source');
+            expect(attachSourceDefinition.inputSchema.required).toEqual(['agent_id', 'source_id']);
+            expect(attachSourceDefinition.inputSchema.properties).toHaveProperty('concise');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the full agent state by default', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: agentState });
+
+            const result = await handleAttachSource(mockServer, {
+                agent_id: 'agent-1',
+                source_id: 'source-2',
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-1/sources/attach/source-2',
+                {},
+                { headers: expect.any(Object) },
+            );
+            expect(expectValidToolResponse(result)).toEqual(agentState);
+        });
+
+        it('should return only the attached source IDs when concise', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: agentState });
+
+            const result = await handleAttachSource(mockServer, {
+                agent_id: 'agent-1',
+                source_id: 'source-2',
+                concise: true,
+            });
+
+            expect(expectValidToolResponse(result)).toEqual({
+                agent_id: 'agent-1',
+                source_id: 'source-2',
+                attached: true,
+                attached_source_ids: ['source-1', 'source-2'],
+            });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require source_id', async () => {
+            await expect(handleAttachSource(mockServer, { agent_id: 'agent-1' })).rejects.toThrow(
+                'Missing required argument: source_id',
+            );
+        });
+
+        it('should report an unknown agent or source', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.patch.mockRejectedValueOnce(error);
+
+            await expect(
+                handleAttachSource(mockServer, { agent_id: 'agent-1', source_id: 'missing' }),
+            ).rejects.toThrow('Agent or source not found: agent_id=agent-1, source_id=missing');
+        });
+    });
+});
@@ -0,0 +1,56 @@
+import { describe, it, expect } from 'vitest';
+import {
+    DEFAULT_CONTENT_TYPE,
+    resolveContentType,
+    sniffContentType,
+} from '../../../tools/sources/content-types.js';
+
+describe('Upload Content Types', () => {
+    describe('resolveContentType', () => {
+        it('should prefer an explicit content type', () => {
+            expect(resolveContentType({ fileName: 'notes.md', contentType: 'text/plain' })).toEqual(
+                { contentType: 'text/plain', source: 'explicit' },
+            );
+        });
+
+        it.each([
+            ['report.pdf', 'application/pdf'],
+            ['README.MD', 'text/markdown'],
+            ['notes.txt', 'text/plain'],
+            ['data.csv', 'text/csv'],
+            [
+                'letter.docx',
+                'application/vnd.openxmlformats-officedocument.wordprocessingml.document',
+            ],
+        ])('should infer %s as %s from the extension', (fileName, contentType) => {
+            expect(resolveContentType({ fileName })).toEqual({ contentType, source: 'extension' });
+        });
+
+        it('should sniff the content when the extension is unknown', () => {
+            expect(
+                resolveContentType({ fileName: 'download', bytes: Buffer.from('%PDF-1.7\n...') }),
+            ).toEqual({ contentType: 'application/pdf', source: 'content' });
+            expect(
+                resolveContentType({ fileName: 'notes.v2', bytes: Buffer.from('héllo wörld') }),
+            ).toEqual({ contentType: 'text/plain', source: 'content' });
+        });
+
+        it('should fall back to the default for unrecognised binary content', () => {
+            expect(
+                resolveContentType({ fileName: 'blob.bin', bytes: Buffer.from([0x50, 0x4b, 0, 1]) }),
+            ).toEqual({ contentType: DEFAULT_CONTENT_TYPE, source: 'default' });
+        });
+    });
+
+    describe('sniffContentType', () => {
+        it('should reject invalid UTF-8', () => {
+            expect(sniffContentType(Buffer.from([0xff, 0xfe, 0x41]))).toBeNull();
+        });
+
+        it('should not count a character split by the sample limit against the file', () => {
+            const text = Buffer.from('a'.repeat(8191) + 'é' + 'b'.repeat(10));
+
+            expect(sniffContentType(text)).toBe('text/plain');
+        });
+    });
+});
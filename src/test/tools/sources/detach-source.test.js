@@ -0,0 +1,60 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleDetachSource,
+    detachSourceDefinition,
+} from '../../../tools/sources/detach-source.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Detach Source', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(detachSourceDefinition.name).toBe('detach_source');
+            expect(detachSourceDefinition.inputSchema.required).toEqual(['agent_id', 'source_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the remaining source IDs when concise', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({
+                data: { id: 'agent-1', sources: [{ id: 'source-1' }] },
+            });
+
+            const result = await handleDetachSource(mockServer, {
+                agent_id: 'agent-1',
+                source_id: 'source-2',
+                concise: true,
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-1/sources/detach/source-2',
+                {},
+                { headers: expect.any(Object) },
+            );
+            expect(expectValidToolResponse(result)).toEqual({
+                agent_id: 'agent-1',
+                source_id: 'source-2',
+                attached: false,
+                attached_source_ids: ['source-1'],
+            });
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(handleDetachSource(mockServer, { source_id: 'source-1' })).rejects.toThrow(
+                'Missing required argument: agent_id',
+            );
+        });
+    });
+});
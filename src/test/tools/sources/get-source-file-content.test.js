@@ -0,0 +1,132 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleGetSourceFileContent,
+    getSourceFileContentDefinition,
+} from '../../../tools/sources/get-source-file-content.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const metadata = {
+    id: 'file-1',
+    source_id: 'source-1',
+    file_name: 'notes.txt',
+    file_type: 'text/plain',
+    file_size: 11,
+    created_at: '2024-01-01T00:00:00Z',
+    updated_at: '2024-01-02T00:00:00Z',
+};
+
+describe('Get Source File Content', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(getSourceFileContentDefinition.name).toBe('get_source_file_content');
+            expect(getSourceFileContentDefinition.inputSchema.required).toEqual([
+                'source_id',
+                'file_id',
+            ]);
+            expect(getSourceFileContentDefinition.inputSchema.properties.max_bytes.type).toBe(
+                'integer',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the file content base64-encoded with its metadata', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: metadata })
+                .mockResolvedValueOnce({ data: { ...metadata, content: 'hello world' } });
+
+            const result = await handleGetSourceFileContent(mockServer, {
+                source_id: 'source-1',
+                file_id: 'file-1',
+            });
+
+            expect(mockServer.api.get).toHaveBeenLastCalledWith(
+                '/sources/source-1/files/file-1',
+                expect.objectContaining({ params: { include_content: true } }),
+            );
+
+            const data = expectValidToolResponse(result);
+            expect(data.filename).toBe('notes.txt');
+            expect(data.content_type).toBe('text/plain');
+            expect(data.size_bytes).toBe(11);
+            expect(data.created_at).toBe('2024-01-01T00:00:00Z');
+            expect(data.updated_at).toBe('2024-01-02T00:00:00Z');
+            expect(Buffer.from(data.content_base64, 'base64').toString('utf8')).toBe(
+                'hello world',
+            );
+        });
+
+        it('should refuse an oversized file without downloading its content', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { ...metadata, file_size: 500_000_000 },
+            });
+
+            await expect(
+                handleGetSourceFileContent(mockServer, {
+                    source_id: 'source-1',
+                    file_id: 'file-1',
+                }),
+            ).rejects.toThrow('larger than max_bytes');
+            expect(mockServer.api.get).toHaveBeenCalledTimes(1);
+        });
+
+        it('should apply max_bytes to the downloaded content', async () => {
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: { ...metadata, file_size: undefined } })
+                .mockResolvedValueOnce({ data: { ...metadata, content: 'hello world' } });
+
+            await expect(
+                handleGetSourceFileContent(mockServer, {
+                    source_id: 'source-1',
+                    file_id: 'file-1',
+                    max_bytes: 5,
+                }),
+            ).rejects.toThrow('File file-1 is 11 bytes, larger than max_bytes (5)');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing arguments', async () => {
+            await expect(
+                handleGetSourceFileContent(mockServer, { file_id: 'file-1' }),
+            ).rejects.toThrow('Missing required argument: source_id');
+            await expect(
+                handleGetSourceFileContent(mockServer, { source_id: 'source-1' }),
+            ).rejects.toThrow('Missing required argument: file_id');
+        });
+
+        it('should report a missing source or file', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: { detail: 'File not found' } };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleGetSourceFileContent(mockServer, { source_id: 'source-1', file_id: 'nope' }),
+            ).rejects.toThrow('Source or file not found: source_id=source-1, file_id=nope');
+        });
+
+        it('should report servers without file download support', async () => {
+            const error = new Error('Method Not Allowed');
+            error.response = { status: 405, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleGetSourceFileContent(mockServer, {
+                    source_id: 'source-1',
+                    file_id: 'file-1',
+                }),
+            ).rejects.toThrow('File download is not supported by this Letta server');
+        });
+    });
+});
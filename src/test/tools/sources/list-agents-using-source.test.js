@@ -0,0 +1,185 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleListAgentsUsingSource,
+    listAgentsUsingSourceDefinition,
+} from '../../../tools/sources/list-agents-using-source.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const AGENTS = [
+    { id: 'agent-1', name: 'Alpha' },
+    { id: 'agent-2', name: 'Beta' },
+    { id: 'agent-3', name: 'Gamma' },
+];
+
+const AGENT_SOURCES = {
+    'agent-1': [{ id: 'source-1' }],
+    'agent-2': [{ id: 'source-2' }],
+    'agent-3': [{ id: 'source-2' }, { id: 'source-1' }],
+};
+
+describe('List Agents Using Source', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.api.get.mockImplementation((url) => {
+            if (url === '/agents/') {
+                return Promise.resolve({ data: AGENTS });
+            }
+            const agentId = url.match(/^\/agents\/([^/]+)\/sources$/)?.[1];
+            return Promise.resolve({ data: AGENT_SOURCES[agentId] });
+        });
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listAgentsUsingSourceDefinition.name).toBe('list_agents_using_source');
+            expect(listAgentsUsingSourceDefinition.inputSchema.required).toEqual(['source_id']);
+            const { properties } = listAgentsUsingSourceDefinition.inputSchema;
+            expect(properties.max_concurrency.type).toBe('integer');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return agents with the source attached, in list order', async () => {
+            const result = await handleListAgentsUsingSource(mockServer, {
+                source_id: 'source-1',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.source_id).toBe('source-1');
+            expect(data.agent_count).toBe(2);
+            expect(data.agents).toEqual([
+                { id: 'agent-1', name: 'Alpha' },
+                { id: 'agent-3', name: 'Gamma' },
+            ]);
+            expect(data.checked_count).toBe(3);
+            expect(data.errors).toEqual([]);
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/agent-2/sources',
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+        });
+
+        it('should respect max_concurrency', async () => {
+            let inFlight = 0;
+            let peak = 0;
+            mockServer.api.get.mockImplementation(async (url) => {
+                if (url === '/agents/') {
+                    return { data: AGENTS };
+                }
+                inFlight++;
+                peak = Math.max(peak, inFlight);
+                await new Promise((resolve) => setTimeout(resolve, 5));
+                inFlight--;
+                return { data: [] };
+            });
+
+            await handleListAgentsUsingSource(mockServer, {
+                source_id: 'source-1',
+                max_concurrency: 1,
+            });
+
+            expect(peak).toBe(1);
+        });
+
+        it('should cap max_concurrency at the configured limit', async () => {
+            mockServer.maxConcurrency = 2;
+            let inFlight = 0;
+            let peak = 0;
+            mockServer.api.get.mockImplementation(async (url) => {
+                if (url === '/agents/') {
+                    return { data: AGENTS };
+                }
+                inFlight++;
+                peak = Math.max(peak, inFlight);
+                await new Promise((resolve) => setTimeout(resolve, 5));
+                inFlight--;
+                return { data: [] };
+            });
+
+            await handleListAgentsUsingSource(mockServer, {
+                source_id: 'source-1',
+                max_concurrency: 50,
+            });
+
+            expect(peak).toBe(2);
+        });
+
+        it('should page through every agent', async () => {
+            const firstPage = Array.from({ length: 100 }, (_, i) => ({
+                id: `agent-p${i}`,
+                name: `Agent ${i}`,
+            }));
+            mockServer.api.get.mockImplementation(async (url, config) => {
+                if (url === '/agents/') {
+                    return { data: config.params.after ? [AGENTS[2]] : firstPage };
+                }
+                return { data: url === '/agents/agent-3/sources' ? [{ id: 'source-1' }] : [] };
+            });
+
+            const result = await handleListAgentsUsingSource(mockServer, {
+                source_id: 'source-1',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/agents/', {
+                headers: expect.any(Object),
+                params: { limit: 100, after: 'agent-p99' },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.checked_count).toBe(101);
+            expect(data.agents).toEqual([{ id: 'agent-3', name: 'Gamma' }]);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing source_id', async () => {
+            await expect(handleListAgentsUsingSource(mockServer, {})).rejects.toThrow(
+                'Missing required argument: source_id',
+            );
+        });
+
+        it('should reject a non-positive max_concurrency', async () => {
+            await expect(
+                handleListAgentsUsingSource(mockServer, { source_id: 's', max_concurrency: 0 }),
+            ).rejects.toThrow('Invalid argument: max_concurrency must be a positive integer');
+        });
+
+        it('should report per-agent failures without aborting the scan', async () => {
+            mockServer.api.get.mockImplementation((url) => {
+                if (url === '/agents/') {
+                    return Promise.resolve({ data: AGENTS });
+                }
+                if (url === '/agents/agent-1/sources') {
+                    return Promise.reject(new Error('Request failed with status code 500'));
+                }
+                const agentId = url.match(/^\/agents\/([^/]+)\/sources$/)?.[1];
+                return Promise.resolve({ data: AGENT_SOURCES[agentId] });
+            });
+
+            const result = await handleListAgentsUsingSource(mockServer, {
+                source_id: 'source-1',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.agents).toEqual([{ id: 'agent-3', name: 'Gamma' }]);
+            expect(data.checked_count).toBe(2);
+            expect(data.errors).toEqual([
+                { agent_id: 'agent-1', error: 'Request failed with status code 500' },
+            ]);
+        });
+
+        it('should fail when the agent list cannot be fetched', async () => {
+            mockServer.api.get.mockRejectedValueOnce(new Error('Network error'));
+
+            await expect(
+                handleListAgentsUsingSource(mockServer, { source_id: 'source-1' }),
+            ).rejects.toThrow('Failed to list agents using source source-1: Network error');
+        });
+    });
+});
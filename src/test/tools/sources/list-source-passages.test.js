@@ -0,0 +1,156 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleListSourcePassages,
+    listSourcePassagesDefinition,
+} from '../../../tools/sources/list-source-passages.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('List Source Passages', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    const makePassages = (count, fileId = 'file-1') =>
+        Array.from({ length: count }, (_, i) => ({
+            id: `passage-${i + 1}`,
+            text: `chunk ${i + 1}`,
+            source_id: 'source-1',
+            file_id: fileId,
+            embedding: [0.1, 0.2],
+        }));
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listSourcePassagesDefinition.name).toBe('list_source_passages');
+            expect(listSourcePassagesDefinition.inputSchema.required).toEqual(['source_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should list passages without embeddings', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makePassages(2) });
+
+            const result = await handleListSourcePassages(mockServer, { source_id: 'source-1' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/sources/source-1/passages', {
+                headers: expect.any(Object),
+                params: { limit: 50 },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(2);
+            expect(data.next_cursor).toBeNull();
+            expect(data.passages[0]).toEqual({
+                id: 'passage-1',
+                text: 'chunk 1',
+                source_id: 'source-1',
+                file_id: 'file-1',
+            });
+        });
+
+        it('should include embeddings when asked', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makePassages(1) });
+
+            const result = await handleListSourcePassages(mockServer, {
+                source_id: 'source-1',
+                include_embeddings: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.passages[0].embedding).toEqual([0.1, 0.2]);
+        });
+
+        it('should filter by file and pass the filters to the server', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [...makePassages(2, 'file-1'), ...makePassages(1, 'file-2')],
+            });
+
+            const result = await handleListSourcePassages(mockServer, {
+                source_id: 'source-1',
+                file_id: 'file-2',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/sources/source-1/passages', {
+                headers: expect.any(Object),
+                params: { limit: 50, file_id: 'file-2' },
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.file_id).toBe('file-2');
+            expect(data.count).toBe(1);
+            expect(data.passages[0].file_id).toBe('file-2');
+        });
+
+        it('should page through passages when the server ignores pagination', async () => {
+            mockServer.api.get.mockResolvedValue({ data: makePassages(5) });
+
+            const first = expectValidToolResponse(
+                await handleListSourcePassages(mockServer, { source_id: 'source-1', limit: 2 }),
+            );
+            expect(first.passages.map((p) => p.id)).toEqual(['passage-1', 'passage-2']);
+            expect(first.next_cursor).toBe('passage-2');
+
+            const second = expectValidToolResponse(
+                await handleListSourcePassages(mockServer, {
+                    source_id: 'source-1',
+                    limit: 2,
+                    after: first.next_cursor,
+                }),
+            );
+            expect(second.passages.map((p) => p.id)).toEqual(['passage-3', 'passage-4']);
+
+            const last = expectValidToolResponse(
+                await handleListSourcePassages(mockServer, {
+                    source_id: 'source-1',
+                    limit: 2,
+                    after: second.next_cursor,
+                }),
+            );
+            expect(last.passages.map((p) => p.id)).toEqual(['passage-5']);
+            expect(last.next_cursor).toBeNull();
+        });
+
+        it('should keep a page the server already started after the cursor', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: makePassages(5).slice(2, 4) });
+
+            const result = await handleListSourcePassages(mockServer, {
+                source_id: 'source-1',
+                limit: 2,
+                after: 'passage-2',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.passages.map((p) => p.id)).toEqual(['passage-3', 'passage-4']);
+            expect(data.next_cursor).toBe('passage-4');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require source_id', async () => {
+            await expect(handleListSourcePassages(mockServer, {})).rejects.toThrow(
+                'Missing required argument: source_id',
+            );
+        });
+
+        it('should reject an invalid limit', async () => {
+            await expect(
+                handleListSourcePassages(mockServer, { source_id: 'source-1', limit: 0 }),
+            ).rejects.toThrow('Invalid argument: limit must be a positive integer');
+        });
+
+        it('should report a missing source', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handleListSourcePassages(mockServer, { source_id: 'missing' }),
+            ).rejects.toThrow('Source not found: missing');
+        });
+    });
+});
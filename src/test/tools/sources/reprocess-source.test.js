@@ -0,0 +1,320 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleReprocessSource,
+    reprocessSourceDefinition,
+} from '../../../tools/sources/reprocess-source.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Reprocess Source', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    const mockFile = (id, content) => ({
+        data: { id, file_name: `${id}.txt`, file_type: 'text/plain', content },
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(reprocessSourceDefinition.name).toBe('reprocess_source');
+            expect(reprocessSourceDefinition.inputSchema.required).toEqual(['source_id']);
+            expect(reprocessSourceDefinition.inputSchema.properties).toHaveProperty('file_id');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        /**
+         * Route GETs by URL: the file list, each file's content and each ingestion job
+         */
+        const mockGets = ({ files = [], contents = {}, jobs = {} }) => {
+            mockServer.api.get.mockImplementation(async (url) => {
+                if (url === '/sources/source-1/files') return { data: files };
+                const fileId = /^\/sources\/source-1\/files\/(.+)$/.exec(url)?.[1];
+                if (fileId && contents[fileId]) return contents[fileId];
+                const jobId = /^\/jobs\/(.+)$/.exec(url)?.[1];
+                if (jobId && jobs[jobId]) return { data: { id: jobId, ...jobs[jobId] } };
+                throw new Error(`Unexpected GET ${url}`);
+            });
+        };
+
+        it('should re-upload every file and delete the originals once ingested', async () => {
+            mockGets({
+                files: [{ id: 'file-1' }, { id: 'file-2' }],
+                contents: {
+                    'file-1': mockFile('file-1', 'first'),
+                    'file-2': mockFile('file-2', 'second'),
+                },
+                jobs: { 'job-1': { status: 'completed' }, 'job-2': { status: 'completed' } },
+            });
+            mockServer.api.post
+                .mockImplementationOnce(async (url, form) => {
+                    expect(form.getBuffer().toString()).toContain('first');
+                    expect(form.getBuffer().toString()).toContain('filename="file-1.txt"');
+                    return { data: { id: 'job-1', status: 'created' } };
+                })
+                .mockResolvedValueOnce({ data: { id: 'job-2', status: 'created' } });
+            mockServer.api.delete.mockResolvedValue({ data: {} });
+
+            const result = await handleReprocessSource(mockServer, { source_id: 'source-1' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/sources/source-1/files', {
+                headers: expect.any(Object),
+                params: { limit: 1000 },
+            });
+            expect(mockServer.api.get).toHaveBeenCalledWith('/sources/source-1/files/file-1', {
+                headers: expect.any(Object),
+                params: { include_content: true },
+            });
+            expect(mockServer.api.get).toHaveBeenCalledWith('/jobs/job-1', {
+                headers: expect.any(Object),
+            });
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/sources/source-1/upload',
+                expect.anything(),
+                expect.objectContaining({
+                    headers: expect.objectContaining({
+                        'content-type': expect.stringContaining('multipart/form-data'),
+                    }),
+                }),
+            );
+            expect(mockServer.api.delete).toHaveBeenCalledWith('/sources/source-1/file-1', {
+                headers: expect.any(Object),
+            });
+            expect(mockServer.api.delete).toHaveBeenCalledWith('/sources/source-1/file-2', {
+                headers: expect.any(Object),
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(true);
+            expect(data.summary).toEqual({
+                total_files: 2,
+                success_count: 2,
+                error_count: 0,
+                pending_count: 0,
+                skipped_count: 0,
+            });
+            expect(data.job_ids).toEqual(['job-1', 'job-2']);
+            expect(data.results[0]).toEqual({
+                file_id: 'file-1',
+                file_name: 'file-1.txt',
+                status: 'success',
+                job_id: 'job-1',
+                job_status: 'completed',
+            });
+        });
+
+        it('should reprocess only the given file', async () => {
+            mockGets({
+                contents: { 'file-2': mockFile('file-2', 'second') },
+                jobs: { 'job-2': { status: 'completed' } },
+            });
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'job-2', status: 'created' } });
+            mockServer.api.delete.mockResolvedValueOnce({ data: {} });
+
+            const result = await handleReprocessSource(mockServer, {
+                source_id: 'source-1',
+                file_id: 'file-2',
+            });
+
+            expect(mockServer.api.get).not.toHaveBeenCalledWith(
+                '/sources/source-1/files',
+                expect.anything(),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.job_ids).toEqual(['job-2']);
+        });
+
+        it('should follow processing_status when the upload returns file metadata', async () => {
+            mockGets({
+                contents: {
+                    'file-1': mockFile('file-1', 'first'),
+                    'file-9': { data: { id: 'file-9', processing_status: 'completed' } },
+                },
+            });
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'file-9', file_name: 'file-1.txt', processing_status: 'parsing' },
+            });
+            mockServer.api.delete.mockResolvedValueOnce({ data: {} });
+
+            const result = await handleReprocessSource(mockServer, {
+                source_id: 'source-1',
+                file_id: 'file-1',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/sources/source-1/files/file-9', {
+                headers: expect.any(Object),
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.results[0]).toMatchObject({ status: 'success', job_status: 'completed' });
+            expect(mockServer.api.delete).toHaveBeenCalledWith('/sources/source-1/file-1', {
+                headers: expect.any(Object),
+            });
+        });
+
+        it('should keep the original when ingestion of the new copy fails', async () => {
+            mockGets({
+                contents: { 'file-1': mockFile('file-1', 'first') },
+                jobs: { 'job-1': { status: 'failed', metadata: { error: 'Embedding failed' } } },
+            });
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'job-1', status: 'created' } });
+
+            const result = await handleReprocessSource(mockServer, {
+                source_id: 'source-1',
+                file_id: 'file-1',
+            });
+
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(false);
+            expect(data.results[0]).toMatchObject({ status: 'error', job_status: 'failed' });
+            expect(data.results[0].error).toContain('the original was kept: Embedding failed');
+        });
+
+        it('should keep the original while ingestion is still running', async () => {
+            mockGets({
+                contents: { 'file-1': mockFile('file-1', 'first') },
+                jobs: { 'job-1': { status: 'running' } },
+            });
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'job-1', status: 'created' } });
+
+            const result = await handleReprocessSource(mockServer, {
+                source_id: 'source-1',
+                file_id: 'file-1',
+                wait_secs: 0,
+            });
+
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(true);
+            expect(data.summary.pending_count).toBe(1);
+            expect(data.results[0]).toMatchObject({ status: 'pending', job_status: 'running' });
+        });
+
+        it('should skip files whose original bytes Letta does not keep', async () => {
+            mockGets({
+                contents: {
+                    'file-1': {
+                        data: {
+                            id: 'file-1',
+                            file_name: 'report.pdf',
+                            file_type: 'application/pdf',
+                            content: 'extracted text',
+                        },
+                    },
+                },
+            });
+
+            const result = await handleReprocessSource(mockServer, {
+                source_id: 'source-1',
+                file_id: 'file-1',
+            });
+
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+            const data = expectValidToolResponse(result);
+            expect(data.summary.skipped_count).toBe(1);
+            expect(data.results[0].status).toBe('skipped');
+            expect(data.results[0].reason).toContain('application/pdf');
+        });
+
+        it('should keep the old file when its upload fails', async () => {
+            mockGets({
+                files: [{ id: 'file-1' }, { id: 'file-2' }],
+                contents: {
+                    'file-1': mockFile('file-1', 'first'),
+                    'file-2': mockFile('file-2', 'second'),
+                },
+                jobs: { 'job-2': { status: 'completed' } },
+            });
+            const error = new Error('Server error');
+            error.response = { status: 500, data: { detail: 'embedding failed' } };
+            mockServer.api.post
+                .mockRejectedValueOnce(error)
+                .mockResolvedValueOnce({ data: { id: 'job-2', status: 'created' } });
+            mockServer.api.delete.mockResolvedValue({ data: {} });
+
+            const result = await handleReprocessSource(mockServer, { source_id: 'source-1' });
+
+            expect(mockServer.api.delete).toHaveBeenCalledTimes(1);
+            expect(mockServer.api.delete).toHaveBeenCalledWith('/sources/source-1/file-2', {
+                headers: expect.any(Object),
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(false);
+            expect(data.summary).toMatchObject({
+                total_files: 2,
+                success_count: 1,
+                error_count: 1,
+            });
+            expect(data.results[0]).toMatchObject({
+                file_id: 'file-1',
+                status: 'error',
+                http_status: 500,
+            });
+        });
+
+        it('should report a file without stored content', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { id: 'file-1' } });
+
+            const result = await handleReprocessSource(mockServer, {
+                source_id: 'source-1',
+                file_id: 'file-1',
+            });
+
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+            const data = expectValidToolResponse(result);
+            expect(data.results[0].status).toBe('error');
+            expect(data.results[0].error).toContain('File content is not available');
+        });
+
+        it('should return an empty summary for a source without files', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+
+            const result = await handleReprocessSource(mockServer, { source_id: 'source-1' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(true);
+            expect(data.summary.total_files).toBe(0);
+            expect(data.job_ids).toEqual([]);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require source_id', async () => {
+            await expect(handleReprocessSource(mockServer, {})).rejects.toThrow(
+                'Missing required argument: source_id',
+            );
+        });
+
+        it('should report a missing source', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(handleReprocessSource(mockServer, { source_id: 'missing' })).rejects.toThrow(
+                'Source not found: missing',
+            );
+        });
+
+        it('should report a missing file per file', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            const result = await handleReprocessSource(mockServer, {
+                source_id: 'source-1',
+                file_id: 'missing',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.results[0].error).toBe('File not found: missing');
+        });
+    });
+});
@@ -0,0 +1,126 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleUploadFileBegin,
+    uploadFileBeginDefinition,
+} from '../../../tools/sources/upload-file-begin.js';
+import {
+    MAX_UPLOAD_SESSIONS,
+    UPLOAD_PRUNE_INTERVAL_MS,
+    UPLOAD_SESSION_TTL_MS,
+    createUploadSession,
+} from '../../../tools/sources/upload-sessions.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Upload File Begin', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        clearInterval(mockServer.uploadPruneTimer);
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(uploadFileBeginDefinition.name).toBe('upload_file_begin');
+            expect(uploadFileBeginDefinition.inputSchema.required).toEqual([
+                'source_id',
+                'file_name',
+            ]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should open an upload session', async () => {
+            const result = await handleUploadFileBegin(mockServer, {
+                source_id: 'source-1',
+                file_name: 'report.pdf',
+                content_type: 'application/pdf',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.upload_id).toMatch(/^upload-/);
+            expect(data.chunks_received).toBe(0);
+            expect(data.bytes_received).toBe(0);
+
+            const session = mockServer.uploadSessions.get(data.upload_id);
+            expect(session.sourceId).toBe('source-1');
+            expect(session.contentType).toBe('application/pdf');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should drop expired sessions when a new one starts', async () => {
+            mockServer.uploadSessions.set('upload-stale', {
+                id: 'upload-stale',
+                chunks: new Map(),
+                expiresAt: Date.now() - 1,
+            });
+
+            const result = await handleUploadFileBegin(mockServer, {
+                source_id: 'source-1',
+                file_name: 'notes.txt',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(mockServer.uploadSessions.has('upload-stale')).toBe(false);
+            expect(new Date(data.expires_at).getTime()).toBeGreaterThan(
+                Date.now() + UPLOAD_SESSION_TTL_MS - 60_000,
+            );
+        });
+
+        it('should prune expired sessions on an unref()ed timer', async () => {
+            const setIntervalSpy = vi.spyOn(globalThis, 'setInterval');
+
+            const result = await handleUploadFileBegin(mockServer, {
+                source_id: 'source-1',
+                file_name: 'notes.txt',
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(setIntervalSpy).toHaveBeenCalledTimes(1);
+            expect(setIntervalSpy.mock.calls[0][1]).toBe(UPLOAD_PRUNE_INTERVAL_MS);
+            expect(mockServer.uploadPruneTimer.hasRef()).toBe(false);
+
+            mockServer.uploadSessions.get(data.upload_id).expiresAt = Date.now() - 1;
+            setIntervalSpy.mock.calls[0][0]();
+            expect(mockServer.uploadSessions.size).toBe(0);
+            expect(mockServer.uploadPruneTimer).toBeNull();
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should throw error for missing arguments', async () => {
+            await expect(handleUploadFileBegin(mockServer, { file_name: 'a.txt' })).rejects.toThrow(
+                'Missing required argument: source_id',
+            );
+            await expect(
+                handleUploadFileBegin(mockServer, { source_id: 'source-1' }),
+            ).rejects.toThrow('Missing required argument: file_name');
+        });
+
+        it('should reject an invalid total_bytes', async () => {
+            await expect(
+                handleUploadFileBegin(mockServer, {
+                    source_id: 'source-1',
+                    file_name: 'a.txt',
+                    total_bytes: 0,
+                }),
+            ).rejects.toThrow('Invalid argument: total_bytes');
+        });
+
+        it('should refuse a new session when too many are open', async () => {
+            for (let i = 0; i < MAX_UPLOAD_SESSIONS; i++) {
+                createUploadSession(mockServer, { sourceId: 'source-1', fileName: `${i}.txt` });
+            }
+
+            await expect(
+                handleUploadFileBegin(mockServer, { source_id: 'source-1', file_name: 'a.txt' }),
+            ).rejects.toThrow('Too many open upload sessions');
+            expect(mockServer.uploadSessions.size).toBe(MAX_UPLOAD_SESSIONS);
+        });
+    });
+});
@@ -0,0 +1,115 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleUploadFileChunk,
+    uploadFileChunkDefinition,
+} from '../../../tools/sources/upload-file-chunk.js';
+import {
+    MAX_BUFFERED_UPLOAD_BYTES,
+    createUploadSession,
+} from '../../../tools/sources/upload-sessions.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const base64 = (text) => Buffer.from(text).toString('base64');
+
+describe('Upload File Chunk', () => {
+    let mockServer;
+    let session;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        session = createUploadSession(mockServer, { sourceId: 'source-1', fileName: 'a.txt' });
+    });
+
+    afterEach(() => {
+        clearInterval(mockServer.uploadPruneTimer);
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(uploadFileChunkDefinition.name).toBe('upload_file_chunk');
+            expect(uploadFileChunkDefinition.inputSchema.required).toEqual([
+                'upload_id',
+                'chunk_index',
+                'data',
+            ]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should buffer chunks in the session', async () => {
+            await handleUploadFileChunk(mockServer, {
+                upload_id: session.id,
+                chunk_index: 1,
+                data: base64('world'),
+            });
+            const result = await handleUploadFileChunk(mockServer, {
+                upload_id: session.id,
+                chunk_index: 0,
+                data: base64('hello '),
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.chunk_index).toBe(0);
+            expect(data.chunk_bytes).toBe(6);
+            expect(data.chunks_received).toBe(2);
+            expect(data.bytes_received).toBe(11);
+        });
+
+        it('should replace a re-sent chunk', async () => {
+            for (const text of ['first try', 'retry']) {
+                await handleUploadFileChunk(mockServer, {
+                    upload_id: session.id,
+                    chunk_index: 0,
+                    data: base64(text),
+                });
+            }
+
+            expect(session.chunks.size).toBe(1);
+            expect(session.chunks.get(0).toString()).toBe('retry');
+            expect(session.bytes).toBe(5);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should reject unknown or expired sessions', async () => {
+            session.expiresAt = Date.now() - 1;
+
+            await expect(
+                handleUploadFileChunk(mockServer, {
+                    upload_id: session.id,
+                    chunk_index: 0,
+                    data: base64('late'),
+                }),
+            ).rejects.toThrow(`Upload session not found: ${session.id}`);
+        });
+
+        it('should validate chunk_index and data', async () => {
+            await expect(
+                handleUploadFileChunk(mockServer, {
+                    upload_id: session.id,
+                    chunk_index: -1,
+                    data: base64('x'),
+                }),
+            ).rejects.toThrow('chunk_index');
+            await expect(
+                handleUploadFileChunk(mockServer, { upload_id: session.id, chunk_index: 0 }),
+            ).rejects.toThrow('Missing required argument: data');
+        });
+
+        it('should refuse a chunk once open uploads fill the buffer limit', async () => {
+            const other = createUploadSession(mockServer, { sourceId: 'source-1', fileName: 'b' });
+            other.bytes = MAX_BUFFERED_UPLOAD_BYTES;
+
+            await expect(
+                handleUploadFileChunk(mockServer, {
+                    upload_id: session.id,
+                    chunk_index: 0,
+                    data: base64('x'),
+                }),
+            ).rejects.toThrow('byte buffer limit');
+            expect(session.chunks.size).toBe(0);
+        });
+    });
+});
@@ -0,0 +1,228 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleUploadFileComplete,
+    uploadFileCompleteDefinition,
+} from '../../../tools/sources/upload-file-complete.js';
+import { createUploadSession } from '../../../tools/sources/upload-sessions.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Upload File Complete', () => {
+    let mockServer;
+    let session;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        session = createUploadSession(mockServer, {
+            sourceId: 'source-1',
+            fileName: 'notes.txt',
+            contentType: 'text/plain',
+        });
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    const addChunks = (...texts) => {
+        texts.forEach((text, index) => session.chunks.set(index, Buffer.from(text)));
+        session.bytes = texts.join('').length;
+    };
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(uploadFileCompleteDefinition.name).toBe('upload_file_complete');
+            expect(uploadFileCompleteDefinition.inputSchema.required).toEqual(['upload_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should assemble the chunks in order and upload the file', async () => {
+            addChunks('hello ', 'chunked ', 'world');
+            mockServer.api.post.mockImplementationOnce(async (url, form) => {
+                expect(form.getBuffer().toString()).toContain('hello chunked world');
+                expect(form.getBuffer().toString()).toContain('filename="notes.txt"');
+                return { data: { id: 'file-1', file_name: 'notes.txt' } };
+            });
+
+            const result = await handleUploadFileComplete(mockServer, { upload_id: session.id });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/sources/source-1/upload',
+                expect.anything(),
+                expect.objectContaining({
+                    headers: expect.objectContaining({
+                        'content-type': expect.stringContaining('multipart/form-data'),
+                    }),
+                }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.size_bytes).toBe(19);
+            expect(data.chunk_count).toBe(3);
+            expect(data.result.id).toBe('file-1');
+            expect(mockServer.uploadSessions.has(session.id)).toBe(false);
+        });
+
+        it('should infer the content type when none was given', async () => {
+            session.fileName = 'report.pdf';
+            session.contentType = undefined;
+            addChunks('%PDF-1.7');
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'file-1', file_name: 'report.pdf' },
+            });
+
+            const result = await handleUploadFileComplete(mockServer, { upload_id: session.id });
+
+            const data = expectValidToolResponse(result);
+            expect(data.content_type).toBe('application/pdf');
+            expect(data.content_type_source).toBe('extension');
+        });
+
+        it('should keep an explicit content type', async () => {
+            addChunks('hello');
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'file-1' } });
+
+            const result = await handleUploadFileComplete(mockServer, { upload_id: session.id });
+
+            const data = expectValidToolResponse(result);
+            expect(data.content_type).toBe('text/plain');
+            expect(data.content_type_source).toBe('explicit');
+        });
+
+        it('should return the ingestion job_id from a Job response', async () => {
+            addChunks('data');
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'job-1', job_type: 'job', status: 'created' },
+            });
+
+            const result = await handleUploadFileComplete(mockServer, { upload_id: session.id });
+
+            const data = expectValidToolResponse(result);
+            expect(data.job_id).toBe('job-1');
+            expect(data).not.toHaveProperty('ingestion');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should return the job_id from file metadata', async () => {
+            addChunks('data');
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'file-1', file_name: 'notes.txt', job_id: 'job-2' },
+            });
+
+            const result = await handleUploadFileComplete(mockServer, { upload_id: session.id });
+
+            expect(expectValidToolResponse(result).job_id).toBe('job-2');
+        });
+
+        it('should wait for ingestion to finish when wait is set', async () => {
+            addChunks('data');
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'job-1', job_type: 'job', status: 'created' },
+            });
+            mockServer.api.get
+                .mockResolvedValueOnce({ data: { id: 'job-1', status: 'running' } })
+                .mockResolvedValueOnce({ data: { id: 'job-1', status: 'completed' } });
+
+            const result = await handleUploadFileComplete(mockServer, {
+                upload_id: session.id,
+                wait: true,
+                poll_interval_secs: 0.001,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/jobs/job-1', {
+                headers: expect.any(Object),
+            });
+            const data = expectValidToolResponse(result);
+            expect(data.ingestion.done).toBe(true);
+            expect(data.ingestion.timed_out).toBe(false);
+            expect(data.ingestion.job).toMatchObject({ status: 'completed' });
+        });
+
+        it('should return the latest job state when the wait elapses', async () => {
+            addChunks('data');
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'job-1', job_type: 'job', status: 'created' },
+            });
+            mockServer.api.get.mockResolvedValue({ data: { id: 'job-1', status: 'running' } });
+
+            const result = await handleUploadFileComplete(mockServer, {
+                upload_id: session.id,
+                wait: true,
+                wait_secs: 0,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.ingestion.timed_out).toBe(true);
+            expect(data.ingestion.job.status).toBe('running');
+        });
+
+        it('should report a failed status check without failing the upload', async () => {
+            addChunks('data');
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { id: 'job-1', job_type: 'job', status: 'created' },
+            });
+            mockServer.api.get.mockRejectedValueOnce(new Error('Network error'));
+
+            const result = await handleUploadFileComplete(mockServer, {
+                upload_id: session.id,
+                wait: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.job_id).toBe('job-1');
+            expect(data.ingestion).toEqual({ done: false, error: 'Network error' });
+            expect(mockServer.uploadSessions.has(session.id)).toBe(false);
+        });
+
+        it('should keep the session when the upload fails', async () => {
+            addChunks('data');
+            const error = new Error('Service unavailable');
+            error.response = { status: 503, data: {} };
+            mockServer.api.post.mockRejectedValueOnce(error);
+
+            await expect(
+                handleUploadFileComplete(mockServer, { upload_id: session.id }),
+            ).rejects.toThrow('Failed to upload file');
+            expect(mockServer.uploadSessions.has(session.id)).toBe(true);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should reject an upload with missing chunks', async () => {
+            session.chunks.set(0, Buffer.from('a'));
+            session.chunks.set(2, Buffer.from('c'));
+
+            await expect(
+                handleUploadFileComplete(mockServer, { upload_id: session.id }),
+            ).rejects.toThrow('is missing chunk(s) 1');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should check the size against total_bytes', async () => {
+            session.totalBytes = 100;
+            addChunks('short');
+
+            await expect(
+                handleUploadFileComplete(mockServer, { upload_id: session.id }),
+            ).rejects.toThrow('has 5 bytes but total_bytes was 100');
+        });
+
+        it('should reject invalid wait options before uploading', async () => {
+            addChunks('data');
+
+            await expect(
+                handleUploadFileComplete(mockServer, { upload_id: session.id, wait: 'yes' }),
+            ).rejects.toThrow('Invalid argument: wait must be a boolean');
+            await expect(
+                handleUploadFileComplete(mockServer, { upload_id: session.id, wait_secs: -1 }),
+            ).rejects.toThrow('Invalid argument: wait_secs');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should reject an upload with no chunks', async () => {
+            await expect(
+                handleUploadFileComplete(mockServer, { upload_id: session.id }),
+            ).rejects.toThrow('has no chunks');
+        });
+    });
+});
@@ -0,0 +1,80 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleGetOperationSchema,
+    getOperationSchemaDefinition,
+} from '../../../tools/system/get-operation-schema.js';
+import { toolDefinitions } from '../../../tools/index.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Get Operation Schema', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.toolDefinitions = toolDefinitions;
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(getOperationSchemaDefinition.name).toBe('get_operation_schema');
+            expect(getOperationSchemaDefinition.inputSchema.required).toEqual(['tool_name']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return a standalone request schema', async () => {
+            const result = await handleGetOperationSchema(mockServer, {
+                tool_name: 'delete_passage',
+            });
+
+            const schema = expectValidToolResponse(result);
+            expect(schema).toMatchObject({
+                $schema: 'http://json-schema.org/draft-07/schema#',
+                $id: 'letta-mcp://operations/delete_passage/request',
+                type: 'object',
+                required: ['agent_id', 'memory_id'],
+            });
+            expect(Object.keys(schema.properties)).toContain('ignore_missing');
+            expect(schema.properties).not.toHaveProperty('query_embedding');
+        });
+
+        it('should return the response schema', async () => {
+            const result = await handleGetOperationSchema(mockServer, {
+                tool_name: 'create_agent',
+                kind: 'response',
+            });
+
+            const schema = expectValidToolResponse(result);
+            expect(schema.title).toBe('create_agent response');
+            expect(schema.required).toEqual(['agent_id']);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require tool_name', async () => {
+            await expect(handleGetOperationSchema(mockServer, {})).rejects.toThrow(
+                'Missing required argument: tool_name',
+            );
+        });
+
+        it('should report an unknown tool', async () => {
+            await expect(
+                handleGetOperationSchema(mockServer, { tool_name: 'no_such_tool' }),
+            ).rejects.toThrow('Tool not found: no_such_tool');
+        });
+
+        it('should report a tool without a response schema', async () => {
+            await expect(
+                handleGetOperationSchema(mockServer, {
+                    tool_name: 'get_operation_schema',
+                    kind: 'response',
+                }),
+            ).rejects.toThrow('No response schema is published for get_operation_schema');
+        });
+    });
+});
@@ -0,0 +1,133 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleGetServerCapabilities,
+    getServerCapabilitiesDefinition,
+    TOOL_ENDPOINTS,
+} from '../../../tools/system/get-server-capabilities.js';
+import { toolDefinitions } from '../../../tools/index.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const OPENAPI_SPEC = {
+    paths: {
+        '/v1/agents/': { get: {}, post: {} },
+        '/v1/agents/{agent_id}': { get: {}, patch: {}, delete: {} },
+        '/v1/agents/{agent_id}/export': { get: {} },
+        '/v1/blocks/': { get: {} },
+    },
+};
+
+function mockProbe(server, { version = '0.12.1', spec = OPENAPI_SPEC } = {}) {
+    server.api.get.mockImplementation((url) => {
+        if (url === '/health/') {
+            return Promise.resolve({ data: { version, status: 'ok' } });
+        }
+        if (url === 'http://localhost:8283/openapi.json') {
+            return spec
+                ? Promise.resolve({ data: spec })
+                : Promise.reject(new Error('Request failed with status code 404'));
+        }
+        return Promise.reject(new Error(`Unexpected URL ${url}`));
+    });
+}
+
+describe('Get Server Capabilities', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer({ apiBase: 'http://localhost:8283/v1' });
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(getServerCapabilitiesDefinition.name).toBe('get_server_capabilities');
+            expect(getServerCapabilitiesDefinition.inputSchema.required).toEqual([]);
+            expect(getServerCapabilitiesDefinition.inputSchema.properties.refresh.type).toBe(
+                'boolean',
+            );
+        });
+
+        it('should map every registered tool to its endpoints', () => {
+            const registered = toolDefinitions.map((tool) => tool.name).sort();
+            expect(Object.keys(TOOL_ENDPOINTS).sort()).toEqual(registered);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should report version and supported tools from the OpenAPI spec', async () => {
+            mockProbe(mockServer);
+
+            const result = await handleGetServerCapabilities(mockServer, {});
+
+            const data = expectValidToolResponse(result);
+            expect(data.version).toBe('0.12.1');
+            expect(data.openapi_available).toBe(true);
+            expect(data.cached).toBe(false);
+            expect(data.tools.list_agents).toBe('supported');
+            expect(data.tools.modify_agent).toBe('supported');
+            expect(data.tools.export_agent).toBe('supported');
+            expect(data.tools.list_memory_blocks).toBe('supported');
+            expect(data.tools.create_memory_block).toBe('unsupported');
+            expect(data.tools.clone_agent).toBe('unsupported');
+            expect(data.tools.list_prompts).toBe('local');
+            expect(data.summary.supported).toBeGreaterThan(0);
+        });
+
+        it('should cache the probe on the server instance', async () => {
+            mockProbe(mockServer);
+
+            await handleGetServerCapabilities(mockServer, {});
+            const result = await handleGetServerCapabilities(mockServer, {});
+
+            const data = expectValidToolResponse(result);
+            expect(data.cached).toBe(true);
+            expect(mockServer.api.get).toHaveBeenCalledTimes(2);
+            expect(mockServer.backendCapabilities.version).toBe('0.12.1');
+        });
+
+        it('should probe again when refresh is set', async () => {
+            mockProbe(mockServer);
+            await handleGetServerCapabilities(mockServer, {});
+
+            mockProbe(mockServer, { version: '0.13.0' });
+            const result = await handleGetServerCapabilities(mockServer, { refresh: true });
+
+            const data = expectValidToolResponse(result);
+            expect(data.cached).toBe(false);
+            expect(data.version).toBe('0.13.0');
+            expect(mockServer.api.get).toHaveBeenCalledTimes(4);
+        });
+
+        it('should report unknown support when the spec is unavailable', async () => {
+            mockProbe(mockServer, { spec: null });
+
+            const result = await handleGetServerCapabilities(mockServer, {});
+
+            const data = expectValidToolResponse(result);
+            expect(data.version).toBe('0.12.1');
+            expect(data.openapi_available).toBe(false);
+            expect(data.tools.list_agents).toBe('unknown');
+            expect(data.tools.generate_tool_schema).toBe('local');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should tolerate a failing health check', async () => {
+            mockServer.api.get.mockImplementation((url) =>
+                url === '/health/'
+                    ? Promise.reject(new Error('Network error'))
+                    : Promise.resolve({ data: OPENAPI_SPEC }),
+            );
+
+            const result = await handleGetServerCapabilities(mockServer, {});
+
+            const data = expectValidToolResponse(result);
+            expect(data.version).toBeNull();
+            expect(data.tools.list_agents).toBe('supported');
+        });
+    });
+});
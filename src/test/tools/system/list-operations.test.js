@@ -0,0 +1,91 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleListOperations,
+    listOperationsDefinition,
+} from '../../../tools/system/list-operations.js';
+import { toolDefinitions } from '../../../tools/index.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('List Operations', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.toolDefinitions = toolDefinitions;
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listOperationsDefinition.name).toBe('list_operations');
+            expect(listOperationsDefinition.inputSchema.required).toEqual([]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should list every registered tool', async () => {
+            const result = await handleListOperations(mockServer, {});
+
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(toolDefinitions.length);
+            expect(data.operations.map((op) => op.name)).toContain('list_operations');
+            expect(data.backend_checked_at).toBeNull();
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should describe parameters, annotations and endpoints', async () => {
+            const result = await handleListOperations(mockServer, { tool_name: 'list_passages' });
+
+            const [operation] = expectValidToolResponse(result).operations;
+            expect(operation).toMatchObject({
+                name: 'list_passages',
+                title: 'Search Archival Memory',
+                endpoints: ['GET /agents/{agent_id}/archival-memory'],
+                backend_status: null,
+            });
+            expect(operation.annotations.readOnly).toBe(true);
+            expect(operation.parameters).toContainEqual(
+                expect.objectContaining({ name: 'agent_id', type: 'string', required: true }),
+            );
+            expect(operation.parameters).toContainEqual(
+                expect.objectContaining({
+                    name: 'order',
+                    required: false,
+                    enum: ['asc', 'desc'],
+                    default: 'asc',
+                }),
+            );
+            expect(operation).not.toHaveProperty('input_schema');
+        });
+
+        it('should include full schemas and backend support when known', async () => {
+            mockServer.backendCapabilities = {
+                checked_at: '2025-01-01T00:00:00.000Z',
+                tools: { list_agents: 'supported' },
+            };
+
+            const result = await handleListOperations(mockServer, {
+                tool_name: 'list_agents',
+                include_schemas: true,
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.backend_checked_at).toBe('2025-01-01T00:00:00.000Z');
+            expect(data.operations[0].backend_status).toBe('supported');
+            expect(data.operations[0].input_schema.properties).toHaveProperty('project_id');
+            expect(data.operations[0]).toHaveProperty('output_schema');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should report an unknown tool', async () => {
+            await expect(
+                handleListOperations(mockServer, { tool_name: 'no_such_tool' }),
+            ).rejects.toThrow('Tool not found: no_such_tool');
+        });
+    });
+});
@@ -149,6 +149,36 @@ describe('Attach Tool', () => {
             expect(data.attachment_summary[0].tool_id).toBe('letta-tool-1');
         });
 
+        it('should attach a single tool by tool_name', async () => {
+            const mockLettaTool = { id: 'letta-tool-1', name: 'my-tool' };
+            mockServer.api.get.mockImplementation((url) => {
+                if (url === '/agents/agent-123') {
+                    return Promise.resolve({ data: { id: 'agent-123', name: 'Test Agent' } });
+                }
+                if (url === '/tools/') {
+                    return Promise.resolve({ data: [mockLettaTool] });
+                }
+                if (url === '/tools/mcp/servers') {
+                    return Promise.resolve({ data: {} });
+                }
+                return Promise.reject(new Error(`Unexpected URL: ${url}`));
+            });
+            mockServer.api.patch.mockResolvedValueOnce({ data: { tools: [mockLettaTool] } });
+
+            const result = await handleAttachTool(mockServer, {
+                agent_id: 'agent-123',
+                tool_name: 'my-tool',
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-123/tools/attach/letta-tool-1',
+                {},
+                expect.any(Object),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.attachment_summary[0].success).toBe(true);
+        });
+
         it('should register and attach MCP tool by name', async () => {
             const mockAgent = { id: 'agent-123', name: 'Test Agent' };
             const mockMcpTool = { name: 'mcp-tool', description: 'MCP Tool' };
@@ -317,6 +347,40 @@ describe('Attach Tool', () => {
             expect(data.processing_summary[0].status).toBe('not_found');
         });
 
+        it('should report an ambiguous tool name with its candidates', async () => {
+            mockServer.api.get.mockImplementation((url) => {
+                if (url === '/agents/agent-123') {
+                    return Promise.resolve({ data: { id: 'agent-123', name: 'Test Agent' } });
+                }
+                if (url === '/tools/') {
+                    return Promise.resolve({
+                        data: [
+                            { id: 'tool-a', name: 'search' },
+                            { id: 'tool-b', name: 'search' },
+                        ],
+                    });
+                }
+                if (url === '/tools/mcp/servers') {
+                    return Promise.resolve({ data: {} });
+                }
+                return Promise.reject(new Error(`Unexpected URL: ${url}`));
+            });
+
+            const result = await handleAttachTool(mockServer, {
+                agent_id: 'agent-123',
+                tool_name: 'search',
+            });
+
+            expect(result.isError).toBe(true);
+            const data = expectValidToolResponse(result);
+            expect(data.processing_summary[0]).toMatchObject({
+                status: 'ambiguous',
+                candidates: ['tool-a', 'tool-b'],
+            });
+            expect(data.processing_summary[0].error).toContain('tool-a, tool-b');
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+        });
+
         it('should handle MCP registration failure', async () => {
             const mockAgent = { id: 'agent-123', name: 'Test Agent' };
             const mockMcpTool = { name: 'mcp-tool', description: 'MCP Tool' };
@@ -0,0 +1,129 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleBulkAttachToolsToAgent,
+    bulkAttachToolsToAgentDefinition,
+} from '../../../tools/tools/bulk-attach-tools-to-agent.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Bulk Attach Tools To Agent', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(bulkAttachToolsToAgentDefinition.name).toBe('bulk_attach_tools_to_agent');
+            expect(bulkAttachToolsToAgentDefinition.inputSchema.required).toEqual([
+                'agent_id',
+                'tool_ids',
+            ]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should attach every tool to the agent', async () => {
+            mockServer.api.patch.mockResolvedValue({ data: {} });
+
+            const result = await handleBulkAttachToolsToAgent(mockServer, {
+                agent_id: 'agent-1',
+                tool_ids: ['tool-a', 'tool-b'],
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-1/tools/attach/tool-a',
+                {},
+                { headers: expect.any(Object) },
+            );
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-1/tools/attach/tool-b',
+                {},
+                { headers: expect.any(Object) },
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(true);
+            expect(data.summary).toEqual({ total_tools: 2, success_count: 2, error_count: 0 });
+        });
+
+        it('should attach duplicates once', async () => {
+            mockServer.api.patch.mockResolvedValue({ data: {} });
+
+            await handleBulkAttachToolsToAgent(mockServer, {
+                agent_id: 'agent-1',
+                tool_ids: ['tool-a', 'tool-a'],
+            });
+
+            expect(mockServer.api.patch).toHaveBeenCalledTimes(1);
+        });
+
+        it('should report failures per tool and keep going', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: { detail: 'Tool not found' } };
+            mockServer.api.patch.mockRejectedValueOnce(error).mockResolvedValueOnce({ data: {} });
+
+            const result = await handleBulkAttachToolsToAgent(mockServer, {
+                agent_id: 'agent-1',
+                tool_ids: ['tool-missing', 'tool-b'],
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.success).toBe(false);
+            expect(data.summary).toEqual({ total_tools: 2, success_count: 1, error_count: 1 });
+            expect(data.results[0]).toMatchObject({
+                tool_id: 'tool-missing',
+                status: 'error',
+                http_status: 404,
+            });
+            expect(data.results[1]).toEqual({ tool_id: 'tool-b', status: 'success' });
+        });
+
+        it('should attach one tool at a time', async () => {
+            let inFlight = 0;
+            let peak = 0;
+            mockServer.api.patch.mockImplementation(async () => {
+                inFlight++;
+                peak = Math.max(peak, inFlight);
+                await new Promise((resolve) => setTimeout(resolve, 5));
+                inFlight--;
+                return { data: {} };
+            });
+
+            await handleBulkAttachToolsToAgent(mockServer, {
+                agent_id: 'agent-1',
+                tool_ids: ['a', 'b', 'c'],
+            });
+
+            expect(peak).toBe(1);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require agent_id', async () => {
+            await expect(
+                handleBulkAttachToolsToAgent(mockServer, { tool_ids: ['tool-a'] }),
+            ).rejects.toThrow('Missing required argument: agent_id');
+        });
+
+        it('should require a non-empty tool_ids array', async () => {
+            await expect(
+                handleBulkAttachToolsToAgent(mockServer, { agent_id: 'agent-1', tool_ids: [] }),
+            ).rejects.toThrow('Missing required argument: tool_ids');
+        });
+
+        it('should reject invalid tool IDs before attaching anything', async () => {
+            await expect(
+                handleBulkAttachToolsToAgent(mockServer, {
+                    agent_id: 'agent-1',
+                    tool_ids: ['tool-a', 7],
+                }),
+            ).rejects.toThrow('Invalid argument: tool_ids[1] must be a non-empty string');
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+        });
+    });
+});
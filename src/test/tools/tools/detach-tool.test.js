@@ -0,0 +1,99 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleDetachTool, detachToolDefinition } from '../../../tools/tools/detach-tool.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Detach Tool', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(detachToolDefinition.name).toBe('detach_tool');
+            expect(detachToolDefinition.inputSchema.required).toEqual(['agent_id']);
+            expect(detachToolDefinition.inputSchema.properties).toHaveProperty('tool_id');
+            expect(detachToolDefinition.inputSchema.properties).toHaveProperty('tool_name');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should detach a tool by ID', async () => {
+            mockServer.api.patch.mockResolvedValueOnce({ data: { tools: [{ id: 'tool-2' }] } });
+
+            const result = await handleDetachTool(mockServer, {
+                agent_id: 'agent-123',
+                tool_id: 'tool-1',
+            });
+
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/agents/agent-123/tools/detach/tool-1',
+                {},
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.detached).toBe(true);
+            expect(data.remaining_tool_count).toBe(1);
+        });
+
+        it('should resolve a tool name against the agent tools', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { id: 'tool-1', name: 'web_search' },
+                    { id: 'tool-2', name: 'send_email' },
+                ],
+            });
+            mockServer.api.patch.mockResolvedValueOnce({ data: { tools: [{ id: 'tool-2' }] } });
+
+            const result = await handleDetachTool(mockServer, {
+                agent_id: 'agent-123',
+                tool_name: 'web_search',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/agents/agent-123/tools',
+                expect.any(Object),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.tool_id).toBe('tool-1');
+            expect(data.tool_name).toBe('web_search');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require a tool_id or tool_name', async () => {
+            await expect(handleDetachTool(mockServer, { agent_id: 'agent-123' })).rejects.toThrow(
+                'Missing required argument: tool_id or tool_name',
+            );
+        });
+
+        it('should list the candidates for an ambiguous name', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    { id: 'tool-a', name: 'search' },
+                    { id: 'tool-b', name: 'search' },
+                ],
+            });
+
+            await expect(
+                handleDetachTool(mockServer, { agent_id: 'agent-123', tool_name: 'search' }),
+            ).rejects.toThrow("Tool name 'search' is ambiguous: it matches 2 tools (tool-a, tool-b)");
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+        });
+
+        it('should report a name that is not attached', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+
+            await expect(
+                handleDetachTool(mockServer, { agent_id: 'agent-123', tool_name: 'missing' }),
+            ).rejects.toThrow("Tool 'missing' is not attached to agent agent-123");
+        });
+    });
+});
@@ -0,0 +1,132 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleGenerateToolFromPrompt,
+    generateToolFromPromptDefinition,
+} from '../../../tools/tools/generate-tool-from-prompt.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Generate Tool From Prompt', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(generateToolFromPromptDefinition.name).toBe('generate_tool_from_prompt');
+            expect(generateToolFromPromptDefinition.inputSchema.required).toEqual(['description']);
+            expect(generateToolFromPromptDefinition.inputSchema.properties).toHaveProperty('name');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the generated source code and schema', async () => {
+            const jsonSchema = {
+                name: 'roll_dice',
+                description: 'Roll dice',
+                parameters: { type: 'object', properties: {}, required: [] },
+            };
+            mockServer.api.post.mockResolvedValueOnce({
+                data: {
+                    tool: {
+                        name: 'roll_dice',
+                        source_code: 'def roll_dice():\n    return 4',
+                        json_schema: jsonSchema,
+                    },
+                    sample_args: {},
+                    response: '4',
+                },
+            });
+
+            const result = await handleGenerateToolFromPrompt(mockServer, {
+                description: 'Roll a six-sided die',
+                name: 'roll_dice',
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/tools/generate-tool',
+                { prompt: 'Roll a six-sided die', tool_name: 'roll_dice' },
+                expect.objectContaining({ headers: expect.any(Object) }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.name).toBe('roll_dice');
+            expect(data.source_code).toContain('def roll_dice');
+            expect(data.json_schema).toEqual(jsonSchema);
+            expect(data.sample_response).toBe('4');
+        });
+
+        it('should derive a tool name from the description when omitted', async () => {
+            mockServer.api.post.mockResolvedValueOnce({ data: { tool: {} } });
+
+            await handleGenerateToolFromPrompt(mockServer, {
+                description: 'Convert Celsius to Fahrenheit, please!',
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/tools/generate-tool',
+                expect.objectContaining({ tool_name: 'convert_celsius_to_fahrenheit_please' }),
+                expect.any(Object),
+            );
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require description', async () => {
+            await expect(handleGenerateToolFromPrompt(mockServer, {})).rejects.toThrow(
+                'Missing required argument: description',
+            );
+        });
+
+        it('should reject names that are not identifiers', async () => {
+            await expect(
+                handleGenerateToolFromPrompt(mockServer, {
+                    description: 'Something',
+                    name: 'not a name',
+                }),
+            ).rejects.toThrow('name must be a valid function identifier');
+        });
+
+        it('should explain when the server lacks tool generation', async () => {
+            const error = new Error('Not Found');
+            error.response = { status: 404, data: { detail: 'Not Found' } };
+            mockServer.api.post.mockRejectedValueOnce(error);
+
+            await expect(
+                handleGenerateToolFromPrompt(mockServer, { description: 'Roll a die' }),
+            ).rejects.toThrow('Tool generation is not supported by this Letta server');
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                expect.objectContaining({ response: { status: 501 } }),
+            );
+        });
+
+        it('should not call the server when capabilities show generation is unsupported', async () => {
+            mockServer.backendCapabilities = {
+                tools: { generate_tool_from_prompt: 'unsupported' },
+            };
+
+            await expect(
+                handleGenerateToolFromPrompt(mockServer, { description: 'Roll a die' }),
+            ).rejects.toThrow('Tool generation is not supported by this Letta server');
+            expect(mockServer.createErrorResponse).toHaveBeenCalledWith(
+                expect.objectContaining({ response: { status: 501 } }),
+            );
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should pass through other API errors', async () => {
+            const error = new Error('Internal server error');
+            error.response = { status: 500, data: {} };
+            mockServer.api.post.mockRejectedValueOnce(error);
+
+            await expect(
+                handleGenerateToolFromPrompt(mockServer, { description: 'Roll a die' }),
+            ).rejects.toThrow('Internal server error');
+        });
+    });
+});
@@ -0,0 +1,199 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleGenerateToolSchema,
+    generateToolSchemaDefinition,
+} from '../../../tools/tools/generate-tool-schema.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const pythonSource = `import requests
+
+def fetch_weather(city: str, days: int = 3, units: Optional[str] = "metric", tags: List[str] = []) -> str:
+    """
+    Fetch the weather forecast for a city.
+
+    Args:
+        city (str): The city to look up.
+        days (int): Number of days
+            to forecast.
+        units: Unit system to report in.
+
+    Returns:
+        str: The forecast.
+    """
+    return requests.get(city).text
+`;
+
+const javascriptSource = `/**
+ * Send an email to a recipient.
+ * @param {string} to - Recipient address
+ * @param {string[]} cc - Addresses to copy
+ * @param {'low'|'high'} [priority='low'] - Delivery priority
+ */
+export async function sendEmail(to, cc, priority = 'low', dryRun = false) {
+    return to;
+}`;
+
+describe('Generate Tool Schema', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(generateToolSchemaDefinition.name).toBe('generate_tool_schema');
+            expect(generateToolSchemaDefinition.inputSchema.required).toEqual(['source_code']);
+            expect(generateToolSchemaDefinition.inputSchema.properties.source_type.enum).toEqual([
+                'python',
+                'javascript',
+                'typescript',
+            ]);
+        });
+    });
+
+    describe('Python Sources', () => {
+        it('should build the schema from the signature and docstring', async () => {
+            const result = await handleGenerateToolSchema(mockServer, {
+                source_code: pythonSource,
+            });
+
+            const { json_schema: schema } = expectValidToolResponse(result);
+            expect(schema.name).toBe('fetch_weather');
+            expect(schema.description).toBe('Fetch the weather forecast for a city.');
+            expect(schema.parameters).toEqual({
+                type: 'object',
+                properties: {
+                    city: { type: 'string', description: 'The city to look up.' },
+                    days: {
+                        type: 'integer',
+                        description: 'Number of days to forecast.',
+                        default: 3,
+                    },
+                    units: {
+                        type: 'string',
+                        description: 'Unit system to report in.',
+                        default: 'metric',
+                    },
+                    tags: { type: 'array', items: { type: 'string' }, default: [] },
+                },
+                required: ['city'],
+            });
+            // Schema generation is local
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should support Literal and optional union annotations', async () => {
+            const result = await handleGenerateToolSchema(mockServer, {
+                source_code: `def pick(mode: Literal["fast", "safe"], extra: dict | None = None):\n    """Pick a mode."""\n`,
+            });
+
+            const { json_schema: schema } = expectValidToolResponse(result);
+            expect(schema.parameters.properties.mode).toEqual({
+                type: 'string',
+                enum: ['fast', 'safe'],
+            });
+            expect(schema.parameters.properties.extra).toEqual({ type: 'object' });
+            expect(schema.parameters.required).toEqual(['mode']);
+        });
+
+        it('should select a named function', async () => {
+            const source = `def first(a: int):\n    pass\n\ndef second(b: bool):\n    pass\n`;
+            const result = await handleGenerateToolSchema(mockServer, {
+                source_code: source,
+                function_name: 'second',
+            });
+
+            const { json_schema: schema } = expectValidToolResponse(result);
+            expect(schema.name).toBe('second');
+            expect(schema.parameters.properties).toEqual({ b: { type: 'boolean' } });
+        });
+    });
+
+    describe('JavaScript Sources', () => {
+        it('should build the schema from the signature and JSDoc', async () => {
+            const result = await handleGenerateToolSchema(mockServer, {
+                source_code: javascriptSource,
+            });
+
+            const { json_schema: schema } = expectValidToolResponse(result);
+            expect(schema.name).toBe('sendEmail');
+            expect(schema.description).toBe('Send an email to a recipient.');
+            expect(schema.parameters.properties).toEqual({
+                to: { type: 'string', description: 'Recipient address' },
+                cc: { type: 'array', items: { type: 'string' }, description: 'Addresses to copy' },
+                priority: {
+                    type: 'string',
+                    enum: ['low', 'high'],
+                    description: 'Delivery priority',
+                    default: 'low',
+                },
+                dryRun: { type: 'boolean', default: false },
+            });
+            expect(schema.parameters.required).toEqual(['to', 'cc']);
+        });
+
+        it('should handle typed arrow functions', async () => {
+            const result = await handleGenerateToolSchema(mockServer, {
+                source_code: 'const add = (a: number, b?: number) => a + (b ?? 0);',
+                source_type: 'typescript',
+            });
+
+            const { json_schema: schema } = expectValidToolResponse(result);
+            expect(schema.name).toBe('add');
+            expect(schema.parameters.required).toEqual(['a']);
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require source_code', async () => {
+            await expect(handleGenerateToolSchema(mockServer, {})).rejects.toThrow(
+                'Missing required argument: source_code',
+            );
+        });
+
+        it('should reject an unknown source_type', async () => {
+            await expect(
+                handleGenerateToolSchema(mockServer, { source_code: 'x', source_type: 'ruby' }),
+            ).rejects.toThrow('source_type must be one of');
+        });
+
+        it('should surface the parse error for untyped Python parameters', async () => {
+            await expect(
+                handleGenerateToolSchema(mockServer, { source_code: 'def f(x):\n    pass\n' }),
+            ).rejects.toThrow(
+                "could not parse source_code: Parameter 'x' has no type annotation",
+            );
+        });
+
+        it('should surface the parse error for unsupported types', async () => {
+            await expect(
+                handleGenerateToolSchema(mockServer, { source_code: 'def f(x: Widget): pass' }),
+            ).rejects.toThrow('Unsupported type annotation: Widget');
+        });
+
+        it('should report source without a function', async () => {
+            await expect(
+                handleGenerateToolSchema(mockServer, { source_code: 'x = 1' }),
+            ).rejects.toThrow('No function declaration found');
+        });
+
+        it('should reject a function_name that is not an identifier', async () => {
+            for (const sourceType of ['python', 'javascript']) {
+                await expect(
+                    handleGenerateToolSchema(mockServer, {
+                        source_code: pythonSource,
+                        source_type: sourceType,
+                        function_name: '.*)\\s*\\(|(x',
+                    }),
+                ).rejects.toThrow('function_name must be an identifier');
+            }
+        });
+    });
+});
@@ -0,0 +1,86 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleGetToolSource,
+    getToolSourceDefinition,
+} from '../../../tools/tools/get-tool-source.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('Get Tool Source', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(getToolSourceDefinition.name).toBe('get_tool_source');
+            expect(getToolSourceDefinition.inputSchema.required).toEqual(['tool_id']);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return only the source, schema and source type', async () => {
+            const jsonSchema = { name: 'add', parameters: { type: 'object', properties: {} } };
+            mockServer.api.get.mockResolvedValueOnce({
+                data: {
+                    id: 'tool-1',
+                    name: 'add',
+                    description: 'Add numbers',
+                    tags: ['math'],
+                    source_type: 'python',
+                    source_code: 'def add(a: int, b: int) -> int:\n    return a + b\n',
+                    json_schema: jsonSchema,
+                },
+            });
+
+            const result = await handleGetToolSource(mockServer, { tool_id: 'tool-1' });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/tools/tool-1', {
+                headers: expect.any(Object),
+            });
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({
+                tool_id: 'tool-1',
+                name: 'add',
+                source_type: 'python',
+                source_code: 'def add(a: int, b: int) -> int:\n    return a + b\n',
+                json_schema: jsonSchema,
+            });
+        });
+
+        it('should return null for fields the server omits', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { id: 'tool-2', name: 'mcp_tool' } });
+
+            const result = await handleGetToolSource(mockServer, { tool_id: 'tool-2' });
+
+            const data = expectValidToolResponse(result);
+            expect(data.source_code).toBeNull();
+            expect(data.json_schema).toBeNull();
+            expect(data.source_type).toBeNull();
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require tool_id', async () => {
+            await expect(handleGetToolSource(mockServer, {})).rejects.toThrow(
+                'Missing required argument: tool_id',
+            );
+        });
+
+        it('should report a missing tool', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(handleGetToolSource(mockServer, { tool_id: 'missing' })).rejects.toThrow(
+                'Tool not found: missing',
+            );
+        });
+    });
+});
@@ -0,0 +1,112 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleListToolRuns, listToolRunsDefinition } from '../../../tools/tools/list-tool-runs.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { runWithScope } from '../../../core/scope.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+describe('List Tool Runs', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.runHistory.record({
+            name: 'greet',
+            source_code: 'def greet(): ...',
+            args: { name: 'Ada' },
+            status: 'success',
+            success: true,
+            duration_ms: 12,
+        });
+        mockServer.runHistory.record({
+            name: 'greet',
+            source_code: 'def greet(): ...',
+            args: {},
+            status: 'error',
+            success: false,
+            stderr: "KeyError: 'name'",
+            duration_ms: 8,
+        });
+        mockServer.runHistory.record({
+            name: 'fetch',
+            source_code: 'def fetch(): ...',
+            args: { url: 'x' },
+            status: 'failed',
+            success: false,
+            error: 'Sandbox unavailable',
+            duration_ms: 3,
+        });
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listToolRunsDefinition.name).toBe('list_tool_runs');
+            expect(listToolRunsDefinition.inputSchema.required).toEqual([]);
+            expect(listToolRunsDefinition.inputSchema.properties.status.enum).toEqual([
+                'success',
+                'error',
+                'failed',
+            ]);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should list recent runs newest first without their source', async () => {
+            const data = expectValidToolResponse(await handleListToolRuns(mockServer, {}));
+
+            expect(data.count).toBe(3);
+            expect(data.total_matching).toBe(3);
+            expect(data.history_size).toBe(100);
+            expect(data.runs.map((run) => run.name)).toEqual(['fetch', 'greet', 'greet']);
+            expect(data.runs[0]).not.toHaveProperty('source_code');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should not list runs made in another project', async () => {
+            await runWithScope({ project_id: 'other' }, async () => {
+                mockServer.runHistory.record(
+                    { name: 'secret', status: 'success', success: true },
+                    { 'X-Project': 'other' },
+                );
+                const scoped = expectValidToolResponse(await handleListToolRuns(mockServer, {}));
+                expect(scoped.runs.map((run) => run.name)).toEqual(['secret']);
+            });
+
+            const data = expectValidToolResponse(await handleListToolRuns(mockServer, {}));
+            expect(data.runs.map((run) => run.name)).not.toContain('secret');
+        });
+
+        it('should filter by name and status', async () => {
+            const data = expectValidToolResponse(
+                await handleListToolRuns(mockServer, { name: 'greet', status: 'error' }),
+            );
+
+            expect(data.count).toBe(1);
+            expect(data.runs[0]).toMatchObject({ args: {}, stderr: "KeyError: 'name'" });
+        });
+
+        it('should apply the limit and include source when asked', async () => {
+            const data = expectValidToolResponse(
+                await handleListToolRuns(mockServer, { limit: 1, include_source: true }),
+            );
+
+            expect(data.count).toBe(1);
+            expect(data.total_matching).toBe(3);
+            expect(data.runs[0].source_code).toBe('def fetch(): ...');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should validate status and limit', async () => {
+            await expect(handleListToolRuns(mockServer, { status: 'ok' })).rejects.toThrow(
+                'Invalid argument: status must be one of success, error, failed',
+            );
+            await expect(handleListToolRuns(mockServer, { limit: 0 })).rejects.toThrow(
+                'Invalid argument: limit must be a positive integer',
+            );
+        });
+    });
+});
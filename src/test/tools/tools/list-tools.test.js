@@ -0,0 +1,112 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { handleListTools, listToolsDefinition } from '../../../tools/tools/list-tools.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const tool = (id, name, tags = []) => ({
+    id,
+    name,
+    tags,
+    description: `${name} tool`,
+    source_code: 'def f(): pass',
+    json_schema: { name },
+});
+
+describe('List Tools', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(listToolsDefinition.name).toBe('list_tools');
+            expect(listToolsDefinition.inputSchema.required).toEqual([]);
+            expect(listToolsDefinition.inputSchema.properties.tags.type).toBe('array');
+            expect(listToolsDefinition.inputSchema.properties).toHaveProperty('name_filter');
+            expect(listToolsDefinition.inputSchema.properties).toHaveProperty('after');
+            expect(listToolsDefinition.inputSchema.properties.limit.default).toBe(50);
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should pass limit and cursor to the server when unfiltered', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [tool('tool-1', 'web_search'), tool('tool-2', 'send_email')],
+            });
+
+            const result = await handleListTools(mockServer, { limit: 2, after: 'tool-0' });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(1);
+            expect(mockServer.api.get).toHaveBeenCalledWith(
+                '/tools/',
+                expect.objectContaining({ params: { limit: 2, after: 'tool-0' } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(2);
+            expect(data.next_cursor).toBe('tool-2');
+            expect(data.tools[0]).not.toHaveProperty('source_code');
+        });
+
+        it('should filter by tags and name substring across pages', async () => {
+            const firstPage = Array.from({ length: 200 }, (_, i) => tool(`tool-${i}`, `misc_${i}`));
+            firstPage[10] = tool('tool-10', 'Web_Search', ['web']);
+            mockServer.api.get.mockResolvedValueOnce({ data: firstPage }).mockResolvedValueOnce({
+                data: [tool('tool-200', 'web_fetch', ['web']), tool('tool-201', 'web_post')],
+            });
+
+            const result = await handleListTools(mockServer, {
+                tags: ['web'],
+                name_filter: 'web',
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledTimes(2);
+            expect(mockServer.api.get).toHaveBeenLastCalledWith(
+                '/tools/',
+                expect.objectContaining({ params: { limit: 200, after: 'tool-199' } }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.tools.map((t) => t.id)).toEqual(['tool-10', 'tool-200']);
+            expect(data.next_cursor).toBeNull();
+        });
+
+        it('should return a cursor when the limit is reached mid-page', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [
+                    tool('tool-1', 'a', ['x']),
+                    tool('tool-2', 'b', ['x']),
+                    tool('tool-3', 'c', ['x']),
+                ],
+            });
+
+            const result = await handleListTools(mockServer, { tags: ['x'], limit: 2 });
+
+            const data = expectValidToolResponse(result);
+            expect(data.count).toBe(2);
+            expect(data.next_cursor).toBe('tool-2');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should reject invalid arguments', async () => {
+            await expect(handleListTools(mockServer, { tags: 'web' })).rejects.toThrow(
+                'Invalid argument: tags must be an array of strings',
+            );
+            await expect(handleListTools(mockServer, { limit: 0 })).rejects.toThrow(
+                'Invalid argument: limit must be a positive integer',
+            );
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+
+        it('should propagate API errors', async () => {
+            mockServer.api.get.mockRejectedValueOnce(new Error('Server error'));
+
+            await expect(handleListTools(mockServer, {})).rejects.toThrow('Server error');
+        });
+    });
+});
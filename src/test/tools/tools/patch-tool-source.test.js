@@ -0,0 +1,229 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handlePatchToolSource,
+    patchToolSourceDefinition,
+} from '../../../tools/tools/patch-tool-source.js';
+import { applyLineEdits, applyUnifiedDiff } from '../../../tools/tools/source-patch.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const SOURCE = 'def add(a: int, b: int) -> int:\n    """Add two numbers"""\n    return a + b\n';
+
+const DIFF = [
+    '--- a/add.py',
+    '+++ b/add.py',
+    '@@ -1,3 +1,3 @@',
+    ' def add(a: int, b: int) -> int:',
+    '-    """Add two numbers"""',
+    '+    """Add two integers"""',
+    '     return a + b',
+    '',
+].join('\n');
+
+describe('Source Patching', () => {
+    describe('applyUnifiedDiff', () => {
+        it('should apply a diff and keep the trailing newline', () => {
+            expect(applyUnifiedDiff(SOURCE, DIFF)).toBe(SOURCE.replace('numbers', 'integers'));
+        });
+
+        it('should apply several hunks', () => {
+            const source = ['a', 'b', 'c', 'd', 'e', 'f'].join('\n');
+            const diff = ['@@ -1,2 +1,2 @@', '-a', '+A', ' b', '@@ -5,2 +5,3 @@', ' e', ' f', '+g'];
+
+            expect(applyUnifiedDiff(source, diff.join('\n'))).toBe('A\nb\nc\nd\ne\nf\ng');
+        });
+
+        it('should reject a diff whose context no longer matches', () => {
+            const drifted = SOURCE.replace('return a + b', 'return b + a');
+
+            expect(() => applyUnifiedDiff(drifted, DIFF)).toThrow(
+                'Patch does not apply: hunk 1 expected "    return a + b" at line 3',
+            );
+        });
+
+        it('should reject text without hunks', () => {
+            expect(() => applyUnifiedDiff(SOURCE, 'not a diff')).toThrow('diff contains no hunks');
+        });
+    });
+
+    describe('applyLineEdits', () => {
+        it('should replace, insert and delete lines against the original numbering', () => {
+            const source = 'a\nb\nc\nd\n';
+            const edited = applyLineEdits(source, [
+                { start_line: 4, end_line: 4, old_text: 'd', new_text: '' },
+                { start_line: 1, end_line: 0, old_text: '', new_text: 'first' },
+                { start_line: 2, end_line: 3, old_text: 'b\nc', new_text: 'B\nC\nC2' },
+            ]);
+
+            expect(edited).toBe('first\na\nB\nC\nC2\n');
+        });
+
+        it('should reject an edit whose old_text no longer matches', () => {
+            expect(() =>
+                applyLineEdits(SOURCE, [
+                    { start_line: 3, end_line: 3, old_text: '    return a - b', new_text: 'x' },
+                ]),
+            ).toThrow('Patch does not apply: edits[0] expected lines 3-3');
+        });
+
+        it('should reject overlapping edits', () => {
+            expect(() =>
+                applyLineEdits('a\nb\nc', [
+                    { start_line: 1, end_line: 2, old_text: 'a\nb', new_text: 'x' },
+                    { start_line: 2, end_line: 3, old_text: 'b\nc', new_text: 'y' },
+                ]),
+            ).toThrow('edits[1] overlaps edits[0]');
+        });
+
+        it('should reject ranges past the end of the source', () => {
+            expect(() =>
+                applyLineEdits('a', [{ start_line: 2, end_line: 2, old_text: '', new_text: 'x' }]),
+            ).toThrow('ends at line 2 but the source has 1 lines');
+        });
+    });
+});
+
+describe('Patch Tool Source', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(patchToolSourceDefinition.name).toBe('patch_tool_source');
+            expect(patchToolSourceDefinition.inputSchema.required).toEqual(['tool_id']);
+            expect(patchToolSourceDefinition.inputSchema.properties).toHaveProperty('diff');
+            expect(patchToolSourceDefinition.inputSchema.properties).toHaveProperty('edits');
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should fetch the current source, apply the diff and save it', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { id: 'tool-1', name: 'add', source_code: SOURCE },
+            });
+            mockServer.api.patch.mockResolvedValueOnce({ data: { id: 'tool-1' } });
+
+            const result = await handlePatchToolSource(mockServer, {
+                tool_id: 'tool-1',
+                diff: DIFF,
+            });
+
+            expect(mockServer.api.get).toHaveBeenCalledWith('/tools/tool-1', {
+                headers: expect.any(Object),
+            });
+            expect(mockServer.api.patch).toHaveBeenCalledWith(
+                '/tools/tool-1',
+                { source_code: SOURCE.replace('numbers', 'integers') },
+                { headers: expect.any(Object) },
+            );
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({
+                tool_id: 'tool-1',
+                name: 'add',
+                changed: true,
+                updated: true,
+                lines_before: 3,
+                lines_after: 3,
+            });
+        });
+
+        it('should apply line edits', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { id: 'tool-1', name: 'add', source_code: SOURCE },
+            });
+            mockServer.api.patch.mockResolvedValueOnce({ data: { id: 'tool-1' } });
+
+            const result = await handlePatchToolSource(mockServer, {
+                tool_id: 'tool-1',
+                edits: [
+                    {
+                        start_line: 2,
+                        end_line: 2,
+                        old_text: '    """Add two numbers"""',
+                        new_text: '',
+                    },
+                ],
+            });
+
+            const data = expectValidToolResponse(result);
+            expect(data.lines_after).toBe(2);
+            expect(mockServer.api.patch.mock.calls[0][1].source_code).toBe(
+                'def add(a: int, b: int) -> int:\n    return a + b\n',
+            );
+        });
+
+        it('should return the patched source without saving on dry_run', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: { id: 'tool-1', name: 'add', source_code: SOURCE },
+            });
+
+            const result = await handlePatchToolSource(mockServer, {
+                tool_id: 'tool-1',
+                diff: DIFF,
+                dry_run: true,
+            });
+
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+            const data = expectValidToolResponse(result);
+            expect(data.updated).toBe(false);
+            expect(data.source_code).toBe(SOURCE.replace('numbers', 'integers'));
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should require tool_id', async () => {
+            await expect(handlePatchToolSource(mockServer, { diff: DIFF })).rejects.toThrow(
+                'Missing required argument: tool_id',
+            );
+        });
+
+        it('should require exactly one of diff or edits', async () => {
+            await expect(handlePatchToolSource(mockServer, { tool_id: 'tool-1' })).rejects.toThrow(
+                'provide exactly one of diff or edits',
+            );
+            await expect(
+                handlePatchToolSource(mockServer, { tool_id: 'tool-1', diff: DIFF, edits: [] }),
+            ).rejects.toThrow('provide exactly one of diff or edits');
+        });
+
+        it('should reject a patch against drifted source without saving', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: {
+                    id: 'tool-1',
+                    name: 'add',
+                    source_code: SOURCE.replace('return a + b', 'return int(a) + int(b)'),
+                },
+            });
+
+            await expect(
+                handlePatchToolSource(mockServer, { tool_id: 'tool-1', diff: DIFF }),
+            ).rejects.toThrow('Patch does not apply');
+            expect(mockServer.api.patch).not.toHaveBeenCalled();
+        });
+
+        it('should report a missing tool', async () => {
+            const error = new Error('Not found');
+            error.response = { status: 404, data: {} };
+            mockServer.api.get.mockRejectedValueOnce(error);
+
+            await expect(
+                handlePatchToolSource(mockServer, { tool_id: 'missing', diff: DIFF }),
+            ).rejects.toThrow('Tool not found: missing');
+        });
+
+        it('should reject tools without source code', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: { id: 'tool-2', name: 'mcp_tool' } });
+
+            await expect(
+                handlePatchToolSource(mockServer, { tool_id: 'tool-2', diff: DIFF }),
+            ).rejects.toThrow('has no source code to patch');
+        });
+    });
+});
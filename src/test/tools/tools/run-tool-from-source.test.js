@@ -0,0 +1,139 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import {
+    handleRunToolFromSource,
+    runToolFromSourceDefinition,
+} from '../../../tools/tools/run-tool-from-source.js';
+import { createMockLettaServer } from '../../utils/mock-server.js';
+import { expectValidToolResponse } from '../../utils/test-helpers.js';
+
+const sourceCode = 'def greet(name: str) -> str:\n    print("greeting")\n    return f"Hi {name}"';
+
+describe('Run Tool From Source', () => {
+    let mockServer;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+    });
+
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    describe('Tool Definition', () => {
+        it('should have correct tool definition', () => {
+            expect(runToolFromSourceDefinition.name).toBe('run_tool_from_source');
+            expect(runToolFromSourceDefinition.inputSchema.required).toEqual(['source_code']);
+            expect(runToolFromSourceDefinition.inputSchema.properties).toHaveProperty('env_vars');
+            expect(runToolFromSourceDefinition.inputSchema.properties).toHaveProperty(
+                'timeout_secs',
+            );
+        });
+    });
+
+    describe('Functionality Tests', () => {
+        it('should return the result with stdout and stderr split out', async () => {
+            mockServer.api.post.mockResolvedValueOnce({
+                data: {
+                    status: 'success',
+                    tool_return: 'Hi Ada',
+                    stdout: ['greeting\n'],
+                    stderr: [],
+                },
+            });
+
+            const result = await handleRunToolFromSource(mockServer, {
+                source_code: sourceCode,
+                args: { name: 'Ada' },
+                env_vars: { API_KEY: 'secret' },
+                timeout_secs: 30,
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/tools/run',
+                { source_code: sourceCode, args: { name: 'Ada' }, env_vars: { API_KEY: 'secret' } },
+                expect.objectContaining({ timeout: 30000 }),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data).toEqual({
+                status: 'success',
+                tool_return: 'Hi Ada',
+                stdout: 'greeting\n',
+                stderr: '',
+            });
+        });
+
+        it('should return the traceback of a failing tool instead of throwing', async () => {
+            mockServer.api.post.mockResolvedValueOnce({
+                data: {
+                    status: 'error',
+                    tool_return: "Error executing function greet: KeyError: 'name'",
+                    stdout: [],
+                    stderr: ['Traceback (most recent call last):\n', "KeyError: 'name'\n"],
+                },
+            });
+
+            const result = await handleRunToolFromSource(mockServer, { source_code: sourceCode });
+
+            const data = expectValidToolResponse(result);
+            expect(data.status).toBe('error');
+            expect(data.stderr).toContain("KeyError: 'name'");
+        });
+
+        it('should record each run in the run history without env var values', async () => {
+            mockServer.api.post.mockResolvedValueOnce({
+                data: { status: 'success', tool_return: 'Hi Ada', stdout: [], stderr: [] },
+            });
+
+            await handleRunToolFromSource(mockServer, {
+                source_code: sourceCode,
+                name: 'greet',
+                args: { name: 'Ada' },
+                env_vars: { API_KEY: 'secret' },
+            });
+
+            const [run] = mockServer.runHistory.list();
+            expect(run).toMatchObject({
+                name: 'greet',
+                source_code: sourceCode,
+                args: { name: 'Ada' },
+                env_var_names: ['API_KEY'],
+                status: 'success',
+                success: true,
+                tool_return: 'Hi Ada',
+            });
+            expect(run.duration_ms).toBeGreaterThanOrEqual(0);
+            expect(JSON.stringify(run)).not.toContain('secret');
+        });
+    });
+
+    describe('Error Handling', () => {
+        it('should validate arguments before calling the API', async () => {
+            await expect(handleRunToolFromSource(mockServer, {})).rejects.toThrow(
+                'Missing required argument: source_code',
+            );
+            await expect(
+                handleRunToolFromSource(mockServer, { source_code: sourceCode, args: [] }),
+            ).rejects.toThrow('Invalid argument: args must be an object');
+            await expect(
+                handleRunToolFromSource(mockServer, {
+                    source_code: sourceCode,
+                    env_vars: { PORT: 8080 },
+                }),
+            ).rejects.toThrow('Invalid argument: env_vars must map names to string values');
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should propagate API errors', async () => {
+            mockServer.api.post.mockRejectedValueOnce(new Error('Sandbox unavailable'));
+
+            await expect(
+                handleRunToolFromSource(mockServer, { source_code: sourceCode }),
+            ).rejects.toThrow('Sandbox unavailable');
+            expect(mockServer.runHistory.list()[0]).toMatchObject({
+                status: 'failed',
+                success: false,
+                error: 'Sandbox unavailable',
+            });
+        });
+    });
+});
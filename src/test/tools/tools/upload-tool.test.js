@@ -305,6 +305,100 @@ def fetch_data(url: str, headers: Dict[str, str] = None) -> Dict:
         });
     });
 
+    describe('Validate Mode', () => {
+        it('should return a schema preview without creating or deleting anything', async () => {
+            mockServer.api.get.mockResolvedValueOnce({
+                data: [{ id: 'existing-123', name: 'add_numbers' }],
+            });
+
+            const result = await handleUploadTool(mockServer, {
+                name: 'add_numbers',
+                description: 'Add two numbers',
+                source_code: `def add_numbers(a: int, b: int = 1) -> int:
+    """Add two numbers.
+
+    Args:
+        a (int): First number
+    """
+    return a + b`,
+                validate: true,
+            });
+
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+            expect(mockServer.api.delete).not.toHaveBeenCalled();
+            const data = expectValidToolResponse(result);
+            expect(data.schema_preview).toBe(true);
+            expect(data).not.toHaveProperty('valid');
+            expect(data.json_schema.name).toBe('add_numbers');
+            expect(data.json_schema.parameters.required).toEqual(['a']);
+            expect(data.would_replace_tool_id).toBe('existing-123');
+            expect(data.warnings).toEqual(["Parameter 'b' has no description in the docstring"]);
+        });
+
+        it('should reject unparseable source before calling the API', async () => {
+            await expect(
+                handleUploadTool(mockServer, {
+                    name: 'broken',
+                    description: 'Broken tool',
+                    source_code: 'print("no function here")',
+                    validate: true,
+                }),
+            ).rejects.toThrow('Invalid argument: could not parse source_code');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+        });
+    });
+
+    describe('Pip Requirements', () => {
+        it('should send pip requirements as name/version pairs', async () => {
+            mockServer.api.get.mockResolvedValueOnce({ data: [] });
+            mockServer.api.post.mockResolvedValueOnce({ data: { id: 'tool-123' } });
+
+            const result = await handleUploadTool(mockServer, {
+                name: 'fetch_page',
+                description: 'Fetch a web page',
+                source_code: 'def fetch_page(url: str) -> str:\n    return url',
+                pip_requirements: ['requests', 'beautifulsoup4==4.12.3'],
+            });
+
+            expect(mockServer.api.post).toHaveBeenCalledWith(
+                '/tools/',
+                expect.objectContaining({
+                    pip_requirements: [
+                        { name: 'requests' },
+                        { name: 'beautifulsoup4', version: '4.12.3' },
+                    ],
+                }),
+                expect.any(Object),
+            );
+            const data = expectValidToolResponse(result);
+            expect(data.pip_requirements).toHaveLength(2);
+        });
+
+        it('should reject malformed specifiers before calling the API', async () => {
+            await expect(
+                handleUploadTool(mockServer, {
+                    name: 'fetch_page',
+                    description: 'Fetch a web page',
+                    source_code: 'def fetch_page(url: str) -> str:\n    return url',
+                    pip_requirements: ['requests; rm -rf /'],
+                }),
+            ).rejects.toThrow('Invalid pip requirement "requests; rm -rf /"');
+            expect(mockServer.api.get).not.toHaveBeenCalled();
+            expect(mockServer.api.post).not.toHaveBeenCalled();
+        });
+
+        it('should reject version ranges that Letta cannot install', async () => {
+            await expect(
+                handleUploadTool(mockServer, {
+                    name: 'fetch_page',
+                    description: 'Fetch a web page',
+                    source_code: 'def fetch_page(url: str) -> str:\n    return url',
+                    pip_requirements: ['requests>=2.0'],
+                }),
+            ).rejects.toThrow('only exact pins (name==version) are supported');
+        });
+    });
+
     describe('Error Handling', () => {
         it('should throw error for missing name', async () => {
             await expect(
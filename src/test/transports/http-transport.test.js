@@ -126,6 +126,98 @@ describe('HTTP Transport Integration', () => {
 
             expect(response.body.error.message).toBe('Forbidden: Invalid origin');
         });
+
+        it('should restrict origins to LETTA_ALLOWED_ORIGINS when set', async () => {
+            process.env.LETTA_ALLOWED_ORIGINS = 'https://app.example.com';
+            const { runHTTP } = await import('../../transports/http-transport.js');
+            const { LettaServer } = await import('../../core/server.js');
+
+            const lettaServer = new LettaServer();
+            server = await runHTTP(lettaServer);
+            delete process.env.LETTA_ALLOWED_ORIGINS;
+
+            // Wait for server to be listening
+            if (!server.listening) {
+                await new Promise((resolve) => {
+                    server.once('listening', resolve);
+                });
+            }
+            port = server.address().port;
+
+            await request(`http://localhost:${port}`)
+                .post('/mcp')
+                .set('Origin', 'http://localhost')
+                .send({ jsonrpc: '2.0', method: 'test', id: 1 })
+                .expect(403);
+            const response = await request(`http://localhost:${port}`)
+                .options('/mcp')
+                .set('Origin', 'https://app.example.com')
+                .expect(204);
+
+            expect(response.headers['access-control-allow-origin']).toBe('https://app.example.com');
+        });
+
+        it('should require the bearer token when LETTA_MCP_AUTH_TOKEN is set', async () => {
+            process.env.LETTA_MCP_AUTH_TOKEN = 'client-token';
+            const { runHTTP } = await import('../../transports/http-transport.js');
+            const { LettaServer } = await import('../../core/server.js');
+
+            const lettaServer = new LettaServer();
+            server = await runHTTP(lettaServer);
+            delete process.env.LETTA_MCP_AUTH_TOKEN;
+
+            // Wait for server to be listening
+            if (!server.listening) {
+                await new Promise((resolve) => {
+                    server.once('listening', resolve);
+                });
+            }
+            port = server.address().port;
+
+            const response = await request(`http://localhost:${port}`)
+                .post('/mcp')
+                .send({ jsonrpc: '2.0', method: 'test', id: 1 })
+                .expect(401);
+            expect(response.body.error.message).toBe(
+                'Unauthorized: missing or invalid bearer token',
+            );
+
+            // Authenticated requests reach the MCP handler, which rejects the missing session
+            await request(`http://localhost:${port}`)
+                .post('/mcp')
+                .set('Authorization', 'Bearer client-token')
+                .send({ jsonrpc: '2.0', method: 'test', id: 1 })
+                .expect(400);
+            await request(`http://localhost:${port}`).get('/health').expect(200);
+        });
+
+        it('should rate limit requests that fail auth', async () => {
+            process.env.LETTA_MCP_AUTH_TOKEN = 'client-token';
+            process.env.LETTA_RATE_LIMIT = '1';
+            const { runHTTP } = await import('../../transports/http-transport.js');
+            const { LettaServer } = await import('../../core/server.js');
+
+            const lettaServer = new LettaServer();
+            server = await runHTTP(lettaServer);
+            delete process.env.LETTA_MCP_AUTH_TOKEN;
+            delete process.env.LETTA_RATE_LIMIT;
+
+            // Wait for server to be listening
+            if (!server.listening) {
+                await new Promise((resolve) => {
+                    server.once('listening', resolve);
+                });
+            }
+            port = server.address().port;
+
+            const guess = () =>
+                request(`http://localhost:${port}`)
+                    .post('/mcp')
+                    .set('Authorization', 'Bearer wrong-token')
+                    .send({ jsonrpc: '2.0', method: 'test', id: 1 });
+            await guess().expect(401);
+            await guess().expect(429);
+        });
     });
 
     describe('Basic Endpoint Tests', () => {
@@ -244,6 +336,30 @@ describe('HTTP Transport Integration', () => {
 
             expect(response.text).toBe('Session ID required');
         });
+
+        it('should serve Prometheus metrics', async () => {
+            const { runHTTP } = await import('../../transports/http-transport.js');
+            const { LettaServer } = await import('../../core/server.js');
+
+            const lettaServer = new LettaServer();
+            lettaServer.metrics.recordToolCall('list_agents', 'success', 0.1);
+            server = await runHTTP(lettaServer);
+
+            // Wait for server to be listening
+            if (!server.listening) {
+                await new Promise((resolve) => {
+                    server.once('listening', resolve);
+                });
+            }
+            port = server.address().port;
+
+            const response = await request(`http://localhost:${port}`).get('/metrics').expect(200);
+
+            expect(response.headers['content-type']).toContain('text/plain');
+            expect(response.text).toContain(
+                'letta_mcp_tool_calls_total{tool="list_agents",outcome="success"} 1',
+            );
+        });
     });
 
     describe('Environment Configuration', () => {
@@ -116,6 +116,27 @@ describe('SSE Transport - Basic Tests', () => {
             });
     });
 
+    it('should rate limit the MCP endpoints', async () => {
+        process.env.LETTA_RATE_LIMIT = '1';
+        const limited = await runSSE(mockServer);
+        delete process.env.LETTA_RATE_LIMIT;
+        if (!limited.listening) {
+            await new Promise((resolve) => limited.once('listening', resolve));
+        }
+        const limitedPort = limited.address().port;
+
+        try {
+            await request(`http://localhost:${limitedPort}`).post('/message').send({}).expect(503);
+            const response = await request(`http://localhost:${limitedPort}`)
+                .post('/message')
+                .send({})
+                .expect(429);
+            expect(response.headers['retry-after']).toBeDefined();
+        } finally {
+            await new Promise((resolve) => limited.close(resolve));
+        }
+    });
+
     it('should have message endpoint', async () => {
         const response = await request(`http://localhost:${port}`)
             .post('/message')
@@ -0,0 +1,97 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import net from 'node:net';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import { runUDS } from '../../transports/uds-transport.js';
+import { createMockLettaServer } from '../utils/mock-server.js';
+
+vi.mock('../../core/logger.js', () => ({
+    createLogger: () => ({ info: vi.fn(), error: vi.fn(), warn: vi.fn(), debug: vi.fn() }),
+}));
+
+const listenOn = (socketPath) =>
+    new Promise((resolve) => {
+        const other = net.createServer();
+        other.listen(socketPath, () => resolve(other));
+    });
+
+// Poll until an assertion passes; socket events arrive asynchronously
+const eventually = async (assertion, timeoutMs = 1000) => {
+    const deadline = Date.now() + timeoutMs;
+    for (;;) {
+        try {
+            return assertion();
+        } catch (error) {
+            if (Date.now() > deadline) throw error;
+            await new Promise((resolve) => setTimeout(resolve, 10));
+        }
+    }
+};
+
+describe('Unix Socket Transport', () => {
+    let mockServer;
+    let socketServer;
+    let dir;
+    let socketPath;
+
+    beforeEach(() => {
+        mockServer = createMockLettaServer();
+        mockServer.server.connect = vi.fn().mockResolvedValue();
+        mockServer.server.close = vi.fn().mockResolvedValue();
+        dir = fs.mkdtempSync(path.join(os.tmpdir(), 'letta-uds-'));
+        socketPath = path.join(dir, 'mcp.sock');
+        process.env.LETTA_SOCKET_PATH = socketPath;
+    });
+
+    afterEach(async () => {
+        if (socketServer?.listening) {
+            await new Promise((resolve) => socketServer.close(resolve));
+        }
+        socketServer = undefined;
+        process.removeAllListeners('SIGINT');
+        process.removeAllListeners('SIGTERM');
+        delete process.env.LETTA_SOCKET_PATH;
+        fs.rmSync(dir, { recursive: true, force: true });
+    });
+
+    it('should listen on LETTA_SOCKET_PATH and connect a client', async () => {
+        socketServer = await runUDS(mockServer);
+
+        const client = net.connect(socketPath);
+        await new Promise((resolve) => client.once('connect', resolve));
+        await eventually(() => expect(mockServer.server.connect).toHaveBeenCalledTimes(1));
+        client.destroy();
+    });
+
+    it('should require LETTA_SOCKET_PATH', async () => {
+        delete process.env.LETTA_SOCKET_PATH;
+
+        await expect(runUDS(mockServer)).rejects.toThrow(
+            'Missing required environment variable: LETTA_SOCKET_PATH',
+        );
+    });
+
+    it('should refuse to start when another process is listening', async () => {
+        const other = await listenOn(socketPath);
+
+        await expect(runUDS(mockServer)).rejects.toThrow('is already in use by another process');
+        await new Promise((resolve) => other.close(resolve));
+    });
+
+    it('should refuse a path that is not a socket', async () => {
+        fs.writeFileSync(socketPath, 'not a socket');
+
+        await expect(runUDS(mockServer)).rejects.toThrow('exists and is not a socket');
+    });
+
+    it('should remove the socket file on shutdown', async () => {
+        socketServer = await runUDS(mockServer);
+        expect(fs.existsSync(socketPath)).toBe(true);
+
+        process.emit('SIGTERM');
+
+        await eventually(() => expect(mockServer.server.close).toHaveBeenCalled());
+        expect(fs.existsSync(socketPath)).toBe(false);
+    });
+});
@@ -1,4 +1,7 @@
 import { vi } from 'vitest';
+import { InFlightTracker } from '../../core/shutdown.js';
+import { RunHistory } from '../../core/run-history.js';
+import { DEFAULT_MAX_CONCURRENCY } from '../../core/concurrency.js';
 
 /**
  * Creates a mock LettaServer instance for testing
@@ -17,6 +20,13 @@ export function createMockLettaServer(overrides = {}) {
         api: mockApi,
         server: createMockMCPServer(),
         logger: createMockLogger(),
+        uploadSessions: new Map(),
+        uploadPruneTimer: null,
+        localJobs: new Map(),
+        maxConcurrency: DEFAULT_MAX_CONCURRENCY,
+        runHistory: new RunHistory(100),
+        inFlight: new InFlightTracker(),
+        shutdownOptions: { timeoutSecs: 25, cancelRuns: false },
         getApiHeaders: vi.fn().mockReturnValue({
             Authorization: 'Bearer test-token',
             'Content-Type': 'application/json',
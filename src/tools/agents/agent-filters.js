@@ -0,0 +1,56 @@
+/**
+ * Agent selection shared by the bulk agent tools: an explicit list of IDs, or the agents
+ * matching a name and/or tag filter.
+ */
+
+/**
+ * Whether the arguments select any agents
+ */
+export function hasAgentFilter(args) {
+    return Boolean(args?.agent_ids || args?.agent_name_filter || args?.agent_tag_filter);
+}
+
+/**
+ * Resolve the selected agents
+ * @param {Object} server - LettaServer instance
+ * @param {Object} args - Tool arguments with agent_ids, agent_name_filter or agent_tag_filter
+ * @param {Object} headers - Letta API headers
+ * @returns {Promise<Object[]>} Agents with at least id and name
+ */
+export async function findAgents(server, args, headers) {
+    // A list of IDs is used directly, with placeholder names for reporting
+    if (Array.isArray(args.agent_ids) && args.agent_ids.length > 0) {
+        return args.agent_ids.map((id) => ({ id, name: `ID: ${id}` }));
+    }
+
+    const params = {};
+    if (args.agent_name_filter) params.name = args.agent_name_filter;
+    if (args.agent_tag_filter) params.tags = args.agent_tag_filter;
+
+    const response = await server.api.get('/agents/', { headers, params });
+    return Array.isArray(response.data) ? response.data : [];
+}
+
+/**
+ * Schema properties for the agent selection arguments
+ * @param {string} action - What the tool does with the agents, e.g. "delete"
+ */
+export function agentFilterProperties(action) {
+    return {
+        agent_ids: {
+            type: 'array',
+            items: { type: 'string' },
+            description: `Optional: A specific list of agent IDs to ${action}.`,
+        },
+        agent_name_filter: {
+            type: 'string',
+            description:
+                `Optional: Filter agents to ${action} by name (exact match or substring, depending on API).`,
+        },
+        agent_tag_filter: {
+            type: 'string',
+            description:
+                `Optional: Filter agents to ${action} by tag(s). Provide a single tag or comma-separated list.`,
+        },
+    };
+}
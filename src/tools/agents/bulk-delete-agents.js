@@ -1,87 +1,133 @@
 import { createLogger } from '../../core/logger.js';
+import {
+    ignoreMissingProperty,
+    isAlreadyAbsent,
+    validateIgnoreMissing,
+} from '../../core/ignore-missing.js';
+import { callConcurrency, mapSettledWithConcurrency } from '../../core/concurrency.js';
+import { agentFilterProperties, findAgents, hasAgentFilter } from './agent-filters.js';
+import { invalidRequest } from '../../core/errors.js';
 
 // McpError and ErrorCode imported for future use
 const logger = createLogger('bulk_delete_agents');
 
+/**
+ * Report one finished deletion to the client as a progress notification, when the request
+ * carries a progress token. A failed notification is logged rather than failing the delete.
+ */
+async function reportProgress(extra, progress, total, result) {
+    const progressToken = extra?._meta?.progressToken;
+    if (progressToken === undefined || typeof extra?.sendNotification !== 'function') return;
+    try {
+        await extra.sendNotification({
+            method: 'notifications/progress',
+            params: {
+                progressToken,
+                progress,
+                total,
+                message: JSON.stringify({
+                    agent_id: result.agent_id,
+                    name: result.name,
+                    status: result.status,
+                }),
+            },
+        });
+    } catch (error) {
+        logger.warn(`Could not send progress notification: ${error.message}`);
+    }
+}
+
 /**
  * Tool handler for deleting multiple agents based on filter criteria
  */
-export async function handleBulkDeleteAgents(server, args) {
+export async function handleBulkDeleteAgents(server, args, extra) {
     // Require at least one filter criteria to prevent accidental mass deletion
-    if (!args?.agent_name_filter && !args?.agent_tag_filter && !args?.agent_ids) {
+    if (!hasAgentFilter(args)) {
         server.createErrorResponse(
             'Missing required argument: Provide agent_ids, agent_name_filter, or agent_tag_filter.',
         );
     }
-
-    const nameFilter = args.agent_name_filter;
-    const tagFilter = args.agent_tag_filter;
-    const specificAgentIds = args.agent_ids; // Allow deleting a specific list of IDs
-
-    const results = [];
-    let agentsToDelete = [];
+    validateIgnoreMissing(server, args);
+    if (
+        args.max_concurrency !== undefined &&
+        (!Number.isInteger(args.max_concurrency) || args.max_concurrency < 1)
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'max_concurrency',
+                'Invalid argument: max_concurrency must be a positive integer',
+            ),
+        );
+    }
 
     try {
         const headers = server.getApiHeaders();
 
         // Step 1: Identify agents to delete
-        if (specificAgentIds && Array.isArray(specificAgentIds) && specificAgentIds.length > 0) {
-            // If a list of IDs is provided, use that directly
-            logger.info(`Received specific list of ${specificAgentIds.length} agents to delete.`);
-            // We need agent objects (at least with 'id' and 'name') for consistent reporting
-            // Fetch details for each ID or use list_agents with multiple ID filter if supported
-            // For simplicity, we'll just use the IDs for deletion and report only IDs
-            agentsToDelete = specificAgentIds.map((id) => ({ id: id, name: `ID: ${id}` })); // Create placeholder objects
-        } else {
-            // Otherwise, list agents based on filters
-            logger.info(`Listing agents with filter: name='${nameFilter}', tags='${tagFilter}'...`);
-            const listParams = {};
-            if (nameFilter) listParams.name = nameFilter;
-            if (tagFilter) listParams.tags = tagFilter; // Adjust if API uses different param name
-
-            const listResponse = await server.api.get('/agents/', { headers, params: listParams });
-            agentsToDelete = listResponse.data; // Assuming response.data is an array of AgentState objects
-
-            if (!Array.isArray(agentsToDelete) || agentsToDelete.length === 0) {
-                return {
-                    content: [
-                        {
-                            type: 'text',
-                            text: JSON.stringify({
-                                message: 'No agents found matching the specified filter.',
-                                results: [],
-                            }),
-                        },
-                    ],
-                };
-            }
-            logger.info(`Found ${agentsToDelete.length} agents to delete.`);
+        const agentsToDelete = await findAgents(server, args, headers);
+        if (agentsToDelete.length === 0) {
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify({
+                            message: 'No agents found matching the specified filter.',
+                            results: [],
+                        }),
+                    },
+                ],
+            };
         }
+        const maxConcurrency = callConcurrency(server, args.max_concurrency);
+        logger.info(
+            `Found ${agentsToDelete.length} agents to delete (concurrency ${maxConcurrency}).`,
+        );
 
-        // Step 2: Iterate and delete each agent
-        for (const agent of agentsToDelete) {
+        // Step 2: Delete the agents, a bounded number at a time, reporting each as it finishes
+        let completed = 0;
+        const deleteAgent = async (agent) => {
             const agentId = agent.id;
             const encodedAgentId = encodeURIComponent(agentId);
             try {
                 logger.info(`Deleting agent ${agentId} (${agent.name})...`);
                 // Use the specific endpoint from the OpenAPI spec
                 await server.api.delete(`/agents/${encodedAgentId}`, { headers });
-                results.push({ agent_id: agentId, name: agent.name, status: 'success' });
                 logger.info(`Successfully deleted agent ${agentId}.`);
+                return { agent_id: agentId, name: agent.name, status: 'success' };
             } catch (deleteError) {
+                if (isAlreadyAbsent(args, deleteError)) {
+                    logger.info(`Agent ${agentId} is already gone.`);
+                    return {
+                        agent_id: agentId,
+                        name: agent.name,
+                        status: 'success',
+                        already_absent: true,
+                    };
+                }
                 let errorMessage = `Failed to delete agent ${agentId} (${agent.name}): ${deleteError.message}`;
                 if (deleteError.response) {
                     errorMessage += ` (Status: ${deleteError.response.status}, Data: ${JSON.stringify(deleteError.response.data)})`;
                 }
                 logger.error(errorMessage);
-                results.push({
+                return {
                     agent_id: agentId,
                     name: agent.name,
                     status: 'error',
                     error: errorMessage,
-                });
+                };
             }
-        }
+        };
+        const settled = await mapSettledWithConcurrency(
+            agentsToDelete,
+            maxConcurrency,
+            async (agent) => {
+                const result = await deleteAgent(agent);
+                completed += 1;
+                await reportProgress(extra, completed, agentsToDelete.length, result);
+                return result;
+            },
+        );
+        const results = settled.map((result) => result.value);
 
         // Step 3: Return summary of results
         const successCount = results.filter((r) => r.status === 'success').length;
@@ -105,7 +151,7 @@ export async function handleBulkDeleteAgents(server, args) {
     } catch (error) {
         // Handle errors during the list_agents call or unexpected issues
         logger.error('Error:', error.response?.data || error.message);
-        server.createErrorResponse(`Failed during bulk delete operation: ${error.message}`);
+        server.createErrorResponse(error, 'Failed during bulk delete operation');
     }
 }
 
@@ -115,24 +161,17 @@ export async function handleBulkDeleteAgents(server, args) {
 export const bulkDeleteAgentsDefinition = {
     name: 'bulk_delete_agents',
     description:
-        'Deletes multiple agents based on filter criteria (name or tags) or a specific list of IDs. Use list_agents first to identify agents to delete. WARNING: This action is permanent.',
+        'Deletes multiple agents based on filter criteria (name or tags) or a specific list of IDs, several at a time. Each deletion is reported as a progress notification when the request carries a progress token. Use list_agents first to identify agents to delete. WARNING: This action is permanent.',
     inputSchema: {
         type: 'object',
         properties: {
-            agent_ids: {
-                type: 'array',
-                items: { type: 'string' },
-                description: 'Optional: A specific list of agent IDs to delete.',
-            },
-            agent_name_filter: {
-                type: 'string',
-                description:
-                    'Optional: Filter agents to delete by name (exact match or substring, depending on API).',
-            },
-            agent_tag_filter: {
-                type: 'string',
+            ...agentFilterProperties('delete'),
+            ignore_missing: ignoreMissingProperty,
+            max_concurrency: {
+                type: 'integer',
                 description:
-                    'Optional: Filter agents to delete by tag(s). Provide a single tag or comma-separated list.',
+                    'Maximum number of agents to delete at once (default and maximum: LETTA_MAX_CONCURRENCY, or 16)',
+                minimum: 1,
             },
             // Could add more filters like project_id if needed
         },
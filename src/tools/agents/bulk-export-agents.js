@@ -0,0 +1,173 @@
+import fs from 'fs';
+import path from 'path';
+import { createLogger } from '../../core/logger.js';
+import { classifyError, invalidRequest } from '../../core/errors.js';
+import { callConcurrency, mapSettledWithConcurrency } from '../../core/concurrency.js';
+import { resolveExportPath } from '../../core/export-dir.js';
+import { serializeJson } from '../../core/serialize.js';
+import { agentFilterProperties, findAgents, hasAgentFilter } from './agent-filters.js';
+
+const logger = createLogger('bulk_export_agents');
+
+/**
+ * Default archive name, unique per export
+ */
+const defaultArchiveName = () =>
+    `agents_export_${new Date().toISOString().replace(/[:.]/g, '-')}.json`;
+
+/**
+ * Tool handler for exporting several agents into one archive
+ */
+export async function handleBulkExportAgents(server, args) {
+    if (!hasAgentFilter(args)) {
+        server.createErrorResponse(
+            'Missing required argument: Provide agent_ids, agent_name_filter, or agent_tag_filter.',
+        );
+    }
+    if (args.inline !== undefined && typeof args.inline !== 'boolean') {
+        server.createErrorResponse(
+            invalidRequest('inline', 'Invalid argument: inline must be a boolean'),
+        );
+    }
+    if (
+        args.max_concurrency !== undefined &&
+        (!Number.isInteger(args.max_concurrency) || args.max_concurrency < 1)
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'max_concurrency',
+                'Invalid argument: max_concurrency must be a positive integer',
+            ),
+        );
+    }
+
+    // The archive path is checked before anything is fetched, as in export_agent
+    let archivePath = null;
+    if (!args.inline) {
+        const outputPath = args.output_path || defaultArchiveName();
+        archivePath = path.resolve(outputPath);
+        if (server.exportDir) {
+            try {
+                archivePath = resolveExportPath(server.exportDir, outputPath);
+            } catch (pathError) {
+                server.createErrorResponse(pathError);
+            }
+        }
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agents = await findAgents(server, args, headers);
+        const maxConcurrency = callConcurrency(server, args.max_concurrency);
+        logger.info(`Exporting ${agents.length} agents (concurrency ${maxConcurrency})`);
+
+        // Each agent is exported on its own so one bad agent doesn't sink the backup
+        const settled = await mapSettledWithConcurrency(agents, maxConcurrency, (agent) =>
+            server.api.get(`/agents/${encodeURIComponent(agent.id)}/export`, { headers }),
+        );
+
+        const exports = {};
+        const results = settled.map((result, index) => {
+            const { id, name } = agents[index];
+            if (result.status === 'rejected') {
+                const { errorCode, status, message } = classifyError(result.reason);
+                logger.error(`Failed to export agent ${id}: ${message}`);
+                return {
+                    agent_id: id,
+                    name,
+                    status: 'error',
+                    error: status === 404 ? `Agent not found: ${id}` : message,
+                    error_code: errorCode,
+                    http_status: status,
+                };
+            }
+            exports[id] = result.value.data;
+            return {
+                agent_id: id,
+                name,
+                status: 'success',
+                size_bytes: Buffer.byteLength(
+                    serializeJson(result.value.data, `export of agent ${id}`),
+                ),
+            };
+        });
+
+        const successCount = Object.keys(exports).length;
+        const errorCount = results.length - successCount;
+        const payload = {
+            success: agents.length > 0 && errorCount === 0,
+            summary: {
+                total_agents: agents.length,
+                success_count: successCount,
+                error_count: errorCount,
+            },
+        };
+
+        if (args.inline) {
+            payload.exports = exports;
+        } else if (successCount > 0) {
+            const archive = serializeJson(
+                { exported_at: new Date().toISOString(), agents: exports },
+                'agent export archive',
+                2,
+            );
+            try {
+                fs.writeFileSync(archivePath, archive);
+            } catch (writeError) {
+                logger.error(`Error writing agent archive to ${archivePath}:`, writeError);
+                server.createErrorResponse(
+                    `Failed to save agent archive to ${archivePath}: ${writeError.message}`,
+                );
+            }
+            payload.file_path = archivePath;
+            payload.size_bytes = Buffer.byteLength(archive);
+        }
+        payload.results = results;
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: serializeJson(payload, 'bulk_export_agents response'),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed during bulk export operation');
+    }
+}
+
+/**
+ * Tool definition for bulk_export_agents
+ */
+export const bulkExportAgentsDefinition = {
+    name: 'bulk_export_agents',
+    description:
+        'Export multiple agents, chosen by a list of IDs or by name/tag filter, into one JSON archive file keyed by agent ID (inside LETTA_EXPORT_DIR when set), or return the exports inline. Failures are reported per agent and the rest are still exported. Use import_agent to restore an agent from its entry.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            ...agentFilterProperties('export'),
+            output_path: {
+                type: 'string',
+                description:
+                    'Optional: Path of the archive file. Defaults to agents_export_{timestamp}.json. When the server sets LETTA_EXPORT_DIR, the path is relative to that directory and must stay inside it.',
+            },
+            inline: {
+                type: 'boolean',
+                description:
+                    'Optional: Return the exports in the response, keyed by agent ID, instead of writing an archive. Defaults to false.',
+                default: false,
+            },
+            max_concurrency: {
+                type: 'integer',
+                description:
+                    'Maximum number of agents to export at once (default and maximum: LETTA_MAX_CONCURRENCY, or 16)',
+                minimum: 1,
+            },
+        },
+        // Custom validation in the handler ensures at least one filter is provided
+        required: [],
+    },
+};
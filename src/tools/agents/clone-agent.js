@@ -3,9 +3,99 @@ import path from 'path';
 import os from 'os'; // To get temporary directory
 import FormData from 'form-data'; // Assuming form-data is available
 import { createLogger } from '../../core/logger.js';
+import { idempotencyKeyProperty } from '../../core/idempotency.js';
+import { classifyError, httpError } from '../../core/errors.js';
+import { serializeJson } from '../../core/serialize.js';
 
 const logger = createLogger('clone_agent');
 
+// Block fields carried over when a missing memory block is copied to the clone
+const COPIED_BLOCK_FIELDS = ['label', 'value', 'limit', 'description', 'read_only', 'metadata'];
+
+/**
+ * Make sure the clone has the source agent's memory blocks, tools and sources.
+ * The export carries most of them; anything it left out is copied (blocks, by label)
+ * or attached (tools and sources, by ID). Failures are reported rather than thrown,
+ * since the clone already exists by now.
+ */
+async function copyAttachments(server, sourceAgentId, cloneId, headers) {
+    const list = (agentId, kind) =>
+        server.api.get(`/agents/${encodeURIComponent(agentId)}/${kind}`, { headers });
+    const encodedCloneId = encodeURIComponent(cloneId);
+    const result = { blocks_copied: [], tools_attached: [], sources_attached: [], errors: [] };
+
+    const parts = [
+        {
+            kind: 'core-memory/blocks',
+            key: (block) => block.label,
+            copy: async (block) => {
+                const blockData = Object.fromEntries(
+                    COPIED_BLOCK_FIELDS.filter((field) => block[field] !== undefined).map(
+                        (field) => [field, block[field]],
+                    ),
+                );
+                const created = await server.api.post('/blocks', blockData, { headers });
+                const blockId = encodeURIComponent(created.data.id);
+                await server.api.patch(
+                    `/agents/${encodedCloneId}/core-memory/blocks/attach/${blockId}`,
+                    {},
+                    { headers },
+                );
+                result.blocks_copied.push(block.label);
+            },
+        },
+        {
+            kind: 'tools',
+            key: (tool) => tool.id,
+            copy: async (tool) => {
+                await server.api.patch(
+                    `/agents/${encodedCloneId}/tools/attach/${encodeURIComponent(tool.id)}`,
+                    {},
+                    { headers },
+                );
+                result.tools_attached.push(tool.id);
+            },
+        },
+        {
+            kind: 'sources',
+            key: (source) => source.id,
+            copy: async (source) => {
+                await server.api.patch(
+                    `/agents/${encodedCloneId}/sources/attach/${encodeURIComponent(source.id)}`,
+                    {},
+                    { headers },
+                );
+                result.sources_attached.push(source.id);
+            },
+        },
+    ];
+
+    for (const { kind, key, copy } of parts) {
+        let missing;
+        try {
+            const [sourceItems, cloneItems] = await Promise.all([
+                list(sourceAgentId, kind),
+                list(cloneId, kind),
+            ]);
+            const present = new Set(cloneItems.data.map(key));
+            missing = sourceItems.data.filter((item) => !present.has(key(item)));
+        } catch (error) {
+            result.errors.push({ kind, error: classifyError(error).message });
+            continue;
+        }
+        for (const item of missing) {
+            try {
+                await copy(item);
+            } catch (error) {
+                const { message } = classifyError(error);
+                logger.error(`Failed to copy ${kind} ${key(item)} to clone ${cloneId}: ${message}`);
+                result.errors.push({ kind, id: key(item), error: message });
+            }
+        }
+    }
+    return result;
+}
+
 /**
  * Tool handler for cloning an agent
  */
@@ -50,7 +140,7 @@ export async function handleCloneAgent(server, args) {
         // agentConfig.messages = [];
         // agentConfig.message_ids = [];
 
-        const agentJsonString = JSON.stringify(agentConfig, null, 2);
+        const agentJsonString = serializeJson(agentConfig, 'cloned agent configuration', 2);
 
         // --- Step 3: Save modified config to a temporary file ---
         // Use os.tmpdir() which should work inside Docker if /tmp is writable
@@ -92,12 +182,20 @@ export async function handleCloneAgent(server, args) {
         await fs.unlink(tempFilePath);
         logger.info(`Cleaned up temporary file ${tempFilePath}.`);
 
+        // --- Step 6: Copy anything the export left out, if asked ---
+        let deepCopy;
+        if (args.deep) {
+            logger.info(`Copying memory blocks, tools and sources to ${importedAgentState.id}...`);
+            deepCopy = await copyAttachments(server, sourceAgentId, importedAgentState.id, headers);
+        }
+
         return {
             content: [
                 {
                     type: 'text',
                     text: JSON.stringify({
                         new_agent: importedAgentState,
+                        ...(deepCopy && { deep_copy: deepCopy }),
                     }),
                 },
             ],
@@ -117,15 +215,15 @@ export async function handleCloneAgent(server, args) {
         // Handle specific API errors
         if (error.response) {
             if (error.response.status === 404 && error.config.url.includes('/export')) {
-                server.createErrorResponse(`Source agent not found: ${sourceAgentId}`);
-            }
-            if (error.response.status === 422 && error.config.url.includes('/import')) {
                 server.createErrorResponse(
-                    `Validation error importing cloned agent: ${JSON.stringify(error.response.data)}`,
+                    httpError(404, `Source agent not found: ${sourceAgentId}`),
                 );
             }
+            if (error.response.status === 422 && error.config.url.includes('/import')) {
+                server.createErrorResponse(error, 'Failed to import cloned agent');
+            }
         }
-        server.createErrorResponse(`Failed to clone agent ${sourceAgentId}: ${error.message}`);
+        server.createErrorResponse(error, `Failed to clone agent ${sourceAgentId}`);
     }
 }
 
@@ -135,7 +233,7 @@ export async function handleCloneAgent(server, args) {
 export const cloneAgentDefinition = {
     name: 'clone_agent',
     description:
-        'Creates a new agent by cloning the configuration of an existing agent. Use list_agents to find source agent ID. Alternative to export_agent + import_agent workflow. Modify the clone with modify_agent afterwards.',
+        'Creates a new agent by cloning the configuration of an existing agent, including its memory blocks and tools (set deep to also carry over anything the export misses, such as attached sources). Use list_agents to find source agent ID. Alternative to export_agent + import_agent workflow. Modify the clone with modify_agent afterwards.',
     inputSchema: {
         type: 'object',
         properties: {
@@ -157,6 +255,13 @@ export const cloneAgentDefinition = {
                 type: 'string',
                 description: 'Optional: The project ID to associate the new cloned agent with.',
             },
+            deep: {
+                type: 'boolean',
+                description:
+                    "Optional: If true, also make sure the clone has all of the source agent's memory blocks, tools and sources, copying or attaching any the export left out. Reported in deep_copy. Defaults to false.",
+                default: false,
+            },
+            idempotency_key: idempotencyKeyProperty,
         },
         required: ['source_agent_id', 'new_agent_name'],
     },
@@ -1,3 +1,8 @@
+import { requestTimeout, timeoutSecsProperty } from '../../core/timeout.js';
+import { idempotencyKeyProperty } from '../../core/idempotency.js';
+import { validateEmbeddingConfig, validateLlmConfig } from '../../core/model-config.js';
+import { invalidRequest } from '../../core/errors.js';
+
 /**
  * Tool handler for creating a new agent in the Letta system
  */
@@ -10,8 +15,10 @@ export async function handleCreateAgent(server, args) {
             typeof args.name !== 'string' ||
             typeof args.description !== 'string'
         ) {
-            throw new Error('Invalid arguments: name and description must be strings');
+            throw invalidRequest('name', 'Invalid arguments: name and description must be strings');
         }
+        if (args.llm_config !== undefined) validateLlmConfig(args.llm_config);
+        if (args.embedding_config !== undefined) validateEmbeddingConfig(args.embedding_config);
 
         const model = args.model ?? 'openai/gpt-4';
         const embedding = args.embedding ?? 'openai/text-embedding-ada-002';
@@ -64,11 +71,29 @@ export async function handleCreateAgent(server, args) {
             core_memory: {},
         };
 
+        // Explicit configs replace the ones derived from the model and embedding handles. The
+        // server defaults fill in only when the call names neither a config nor a handle.
+        const defaults = server.defaultModelConfigs;
+        const llmConfig = args.llm_config ?? (args.model ? null : defaults?.llmConfig);
+        const embeddingConfig =
+            args.embedding_config ?? (args.embedding ? null : defaults?.embeddingConfig);
+        if (llmConfig) {
+            agentConfig.llm_config = llmConfig;
+            delete agentConfig.model;
+        }
+        if (embeddingConfig) {
+            agentConfig.embedding_config = embeddingConfig;
+            delete agentConfig.embedding;
+        }
+
         // Headers for API requests
         const headers = server.getApiHeaders();
 
         // Create agent
-        const createAgentResponse = await server.api.post('/agents/', agentConfig, { headers });
+        const createAgentResponse = await server.api.post('/agents/', agentConfig, {
+            headers,
+            ...requestTimeout(args),
+        });
         const agentId = createAgentResponse.data.id;
 
         // Update headers with agent ID
@@ -126,6 +151,35 @@ export const createAgentToolDefinition = {
                 description: 'The embedding model to use',
                 default: 'openai/text-embedding-ada-002',
             },
+            llm_config: {
+                type: 'object',
+                description:
+                    'Optional full LLM configuration, used instead of model (default: LETTA_DEFAULT_LLM_CONFIG when neither is given). Requires model, model_endpoint_type and context_window; checked before the agent is created.',
+                properties: {
+                    model: { type: 'string' },
+                    model_endpoint_type: { type: 'string' },
+                    context_window: { type: 'integer', minimum: 1 },
+                    model_endpoint: { type: 'string' },
+                    temperature: { type: 'number', minimum: 0, maximum: 2 },
+                    max_tokens: { type: 'integer', minimum: 1 },
+                },
+                required: ['model', 'model_endpoint_type', 'context_window'],
+            },
+            embedding_config: {
+                type: 'object',
+                description:
+                    'Optional full embedding configuration, used instead of embedding (default: LETTA_DEFAULT_EMBEDDING_CONFIG when neither is given). Requires embedding_endpoint_type, embedding_model and embedding_dim; checked before the agent is created.',
+                properties: {
+                    embedding_endpoint_type: { type: 'string' },
+                    embedding_model: { type: 'string' },
+                    embedding_dim: { type: 'integer', minimum: 1 },
+                    embedding_endpoint: { type: 'string' },
+                    embedding_chunk_size: { type: 'integer', minimum: 1 },
+                },
+                required: ['embedding_endpoint_type', 'embedding_model', 'embedding_dim'],
+            },
+            timeout_secs: timeoutSecsProperty,
+            idempotency_key: idempotencyKeyProperty,
         },
         required: ['name', 'description'],
     },
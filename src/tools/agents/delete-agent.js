@@ -1,3 +1,10 @@
+import {
+    ignoreMissingProperty,
+    isAlreadyAbsent,
+    validateIgnoreMissing,
+} from '../../core/ignore-missing.js';
+import { httpError } from '../../core/errors.js';
+
 /**
  * Tool handler for deleting a specific agent
  */
@@ -5,6 +12,7 @@ export async function handleDeleteAgent(server, args) {
     if (!args?.agent_id) {
         server.createErrorResponse('Missing required argument: agent_id');
     }
+    validateIgnoreMissing(server, args);
 
     try {
         const headers = server.getApiHeaders();
@@ -26,9 +34,19 @@ export async function handleDeleteAgent(server, args) {
             ],
         };
     } catch (error) {
+        if (isAlreadyAbsent(args, error)) {
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify({ agent_id: args.agent_id, already_absent: true }),
+                    },
+                ],
+            };
+        }
         // Handle potential 404 if agent not found, or other API errors
         if (error.response && error.response.status === 404) {
-            server.createErrorResponse(`Agent not found: ${args.agent_id}`);
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
         }
         server.createErrorResponse(error);
     }
@@ -48,6 +66,7 @@ export const deleteAgentDefinition = {
                 type: 'string',
                 description: 'The ID of the agent to delete',
             },
+            ignore_missing: ignoreMissingProperty,
         },
         required: ['agent_id'],
     },
@@ -0,0 +1,127 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, httpError } from '../../core/errors.js';
+import { serializeJson } from '../../core/serialize.js';
+import { resourceTimestamps } from '../timestamps.js';
+
+const logger = createLogger('describe_agent');
+
+/**
+ * Sections of the description besides the agent itself, each from one request
+ */
+const SECTIONS = {
+    memory_blocks: [
+        (agentId) => `/agents/${agentId}/core-memory/blocks`,
+        (blocks) =>
+            blocks.map((block) => ({
+                id: block.id,
+                label: block.label,
+                chars: block.value?.length ?? 0,
+                limit: block.limit ?? null,
+                read_only: block.read_only ?? false,
+            })),
+    ],
+    tools: [
+        (agentId) => `/agents/${agentId}/tools`,
+        (tools) => tools.map((tool) => ({ id: tool.id, name: tool.name, type: tool.tool_type })),
+    ],
+    sources: [
+        (agentId) => `/agents/${agentId}/sources`,
+        (sources) => sources.map((source) => ({ id: source.id, name: source.name })),
+    ],
+    context: [
+        (agentId) => `/agents/${agentId}/context`,
+        (context) => ({
+            message_count: context.num_messages ?? null,
+            recall_message_count: context.num_recall_memory ?? null,
+            archival_passage_count: context.num_archival_memory ?? null,
+            tokens_used: context.context_window_size_current ?? null,
+            tokens_max: context.context_window_size_max ?? null,
+        }),
+    ],
+};
+
+/**
+ * Tool handler for describing an agent in one snapshot
+ */
+export async function handleDescribeAgent(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+
+    const headers = server.getApiHeaders();
+    const agentId = encodeURIComponent(args.agent_id);
+
+    // The agent and every section are fetched at once; only the agent itself is required
+    const [agentResult, ...sectionResults] = await Promise.allSettled([
+        server.api.get(`/agents/${agentId}`, { headers }),
+        ...Object.values(SECTIONS).map(([url]) => server.api.get(url(agentId), { headers })),
+    ]);
+    if (agentResult.status === 'rejected') {
+        if (agentResult.reason?.response?.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(agentResult.reason);
+    }
+    const agent = agentResult.value.data;
+
+    const description = {
+        agent_id: agent.id,
+        name: agent.name,
+        description: agent.description ?? null,
+        agent_type: agent.agent_type ?? null,
+        tags: agent.tags ?? [],
+        system_prompt: agent.system ?? null,
+        llm_config: agent.llm_config ?? null,
+        embedding_config: agent.embedding_config ?? null,
+        ...resourceTimestamps(agent),
+    };
+    const errors = {};
+    Object.entries(SECTIONS).forEach(([key, [, summarize]], index) => {
+        const { status, value, reason } = sectionResults[index];
+        if (status === 'fulfilled') {
+            description[key] = summarize(value.data ?? []);
+            return;
+        }
+        description[key] = null;
+        errors[key] = classifyError(reason).message;
+    });
+
+    if (Object.keys(errors).length > 0) {
+        logger.warn(`Partial description of ${args.agent_id}: ${Object.keys(errors).join(', ')}`);
+        description.errors = errors;
+    }
+
+    let text;
+    try {
+        text = serializeJson(description, 'describe_agent response');
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+    return {
+        content: [
+            {
+                type: 'text',
+                text,
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for describe_agent
+ */
+export const describeAgentDefinition = {
+    name: 'describe_agent',
+    description:
+        "Get a complete snapshot of an agent in one call: its configuration and full system prompt, memory block labels and sizes, attached tools and sources, and message and archival counts. Sections that fail to load are null and listed in errors. Use get_agent_summary for a shorter overview.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent to describe',
+            },
+        },
+        required: ['agent_id'],
+    },
+};
@@ -3,6 +3,9 @@ import path from 'path';
 import axios from 'axios'; // Assuming axios is available
 import FormData from 'form-data'; // Assuming form-data is available
 import { createLogger } from '../../core/logger.js';
+import { resolveExportPath } from '../../core/export-dir.js';
+import { serializeJson } from '../../core/serialize.js';
+import { httpError } from '../../core/errors.js';
 // McpError and ErrorCode imported by framework
 
 const logger = createLogger('export_agent');
@@ -22,6 +25,16 @@ export async function handleExportAgent(server, args) {
     const xbackboneUrl = args.xbackbone_url || process.env.XBACKBONE_URL; // No hardcoded default
     const xbackboneToken = args.xbackbone_token || process.env.XBACKBONE_TOKEN; // No hardcoded default
 
+    // Exports stay inside LETTA_EXPORT_DIR when one is configured
+    let absoluteOutputPath = path.resolve(outputPath);
+    if (server.exportDir) {
+        try {
+            absoluteOutputPath = resolveExportPath(server.exportDir, outputPath);
+        } catch (pathError) {
+            server.createErrorResponse(pathError);
+        }
+    }
+
     try {
         const headers = server.getApiHeaders();
         const encodedAgentId = encodeURIComponent(agentId);
@@ -34,10 +47,9 @@ export async function handleExportAgent(server, args) {
             throw new Error('Received empty data from agent export endpoint.');
         }
 
-        const agentJsonString = JSON.stringify(agentData, null, 2);
+        const agentJsonString = serializeJson(agentData, `export of agent ${agentId}`, 2);
 
         // Step 2: Save locally
-        const absoluteOutputPath = path.resolve(outputPath);
         try {
             fs.writeFileSync(absoluteOutputPath, agentJsonString);
         } catch (writeError) {
@@ -106,6 +118,7 @@ export async function handleExportAgent(server, args) {
         const resultPayload = {
             agent_id: agentId,
             file_path: absoluteOutputPath,
+            size_bytes: Buffer.byteLength(agentJsonString),
         };
 
         if (xbackboneResult && !xbackboneResult.error) {
@@ -120,17 +133,17 @@ export async function handleExportAgent(server, args) {
             content: [
                 {
                     type: 'text',
-                    text: JSON.stringify(resultPayload),
+                    text: serializeJson(resultPayload, 'export_agent response'),
                 },
             ],
         };
     } catch (error) {
         // Handle potential 404 if agent not found, or other API errors
         if (error.response && error.response.status === 404) {
-            server.createErrorResponse(`Agent not found: ${agentId}`);
+            server.createErrorResponse(httpError(404, `Agent not found: ${agentId}`));
         }
         logger.error('Error:', error.response?.data || error.message);
-        server.createErrorResponse(`Failed to export agent ${agentId}: ${error.message}`);
+        server.createErrorResponse(error, `Failed to export agent ${agentId}`);
     }
 }
 
@@ -140,7 +153,7 @@ export async function handleExportAgent(server, args) {
 export const exportAgentDefinition = {
     name: 'export_agent',
     description:
-        "Export an agent's configuration to a JSON file and optionally upload it. Use import_agent to recreate the agent later, or clone_agent for a quick copy. Use list_agents to find agent IDs.",
+        "Export an agent's configuration to a JSON file on the server and return its path and size, optionally uploading it. Use import_agent to recreate the agent later, or clone_agent for a quick copy. Use list_agents to find agent IDs.",
     inputSchema: {
         type: 'object',
         properties: {
@@ -151,7 +164,7 @@ export const exportAgentDefinition = {
             output_path: {
                 type: 'string',
                 description:
-                    'Optional: Path to save the exported JSON file (e.g., my_agent.json). Defaults to agent_{agent_id}.json.',
+                    'Optional: Path to save the exported JSON file (e.g., my_agent.json). Defaults to agent_{agent_id}.json. When the server sets LETTA_EXPORT_DIR, the path is relative to that directory and must stay inside it.',
             },
             return_base64: {
                 type: 'boolean',
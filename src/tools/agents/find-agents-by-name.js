@@ -0,0 +1,96 @@
+import { createLogger } from '../../core/logger.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('find_agents_by_name');
+
+const SCAN_PAGE_SIZE = 200;
+
+/**
+ * Tool handler for looking up agents by name
+ */
+export async function handleFindAgentsByName(server, args) {
+    if (typeof args?.name !== 'string' || !args.name) {
+        server.createErrorResponse('Missing required argument: name');
+    }
+    if (args.substring !== undefined && typeof args.substring !== 'boolean') {
+        server.createErrorResponse(
+            invalidRequest('substring', 'Invalid argument: substring must be a boolean'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const substring = args.substring ?? false;
+        const needle = args.name.toLowerCase();
+        const matches = (agent) =>
+            substring
+                ? (agent.name || '').toLowerCase().includes(needle)
+                : agent.name === args.name;
+
+        // The server narrows by exact name or a name search; results are checked again here
+        // in case it ignores the filter, scanning pages until the listing runs out
+        const found = [];
+        let after;
+        let hasMore = true;
+        while (hasMore) {
+            const params = { limit: SCAN_PAGE_SIZE };
+            if (substring) params.query_text = args.name;
+            else params.name = args.name;
+            if (after) params.after = after;
+            const response = await server.api.get('/agents/', { headers, params });
+            const page = Array.isArray(response.data) ? response.data : [];
+
+            found.push(...page.filter(matches));
+            hasMore = page.length === SCAN_PAGE_SIZE;
+            after = page[page.length - 1]?.id;
+        }
+        logger.info(`Found ${found.length} agent(s) matching "${args.name}"`);
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        exists: found.length > 0,
+                        count: found.length,
+                        agents: found.map((agent) => ({
+                            id: agent.id,
+                            name: agent.name,
+                            description: agent.description,
+                            tags: agent.tags || [],
+                            created_at: agent.created_at ?? null,
+                        })),
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed to look up agents by name');
+    }
+}
+
+/**
+ * Tool definition for find_agents_by_name
+ */
+export const findAgentsByNameDefinition = {
+    name: 'find_agents_by_name',
+    description:
+        'Check whether agents with a given name exist and return them (exact match by default, or a case-insensitive substring match). Returns exists: false and an empty list when nothing matches. Use before create_agent to avoid duplicates, or list_agents to browse.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            name: {
+                type: 'string',
+                description: 'Agent name to look up',
+            },
+            substring: {
+                type: 'boolean',
+                description:
+                    'Match agents whose name contains the text, ignoring case, instead of the exact name (default: false)',
+                default: false,
+            },
+        },
+        required: ['name'],
+    },
+};
@@ -1,4 +1,6 @@
 import { createLogger } from '../../core/logger.js';
+import { resourceTimestamps } from '../timestamps.js';
+import { httpError } from '../../core/errors.js';
 // McpError and ErrorCode imported by framework
 
 const logger = createLogger('get_agent_summary');
@@ -39,7 +41,7 @@ export async function handleGetAgentSummary(server, args) {
                 agentStateRes.reason?.response?.status === 404 ||
                 agentStateRes.value?.status === 404
             ) {
-                server.createErrorResponse(`Agent not found: ${agentId}`);
+                server.createErrorResponse(httpError(404, `Agent not found: ${agentId}`));
             }
             server.createErrorResponse(`Failed to fetch agent state: ${JSON.stringify(errorInfo)}`);
         }
@@ -109,6 +111,7 @@ export async function handleGetAgentSummary(server, args) {
             attached_tools: attachedTools,
             attached_sources_count: attachedSources.length,
             attached_sources: attachedSources,
+            ...resourceTimestamps(agentState),
         };
 
         return {
@@ -122,7 +125,7 @@ export async function handleGetAgentSummary(server, args) {
     } catch (error) {
         // Catch any unexpected errors during processing
         logger.error(`Unexpected error for agent ${agentId}:`, error);
-        server.createErrorResponse(`Failed to get agent summary: ${error.message}`);
+        server.createErrorResponse(error, 'Failed to get agent summary');
     }
 }
 
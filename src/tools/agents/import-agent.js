@@ -2,31 +2,103 @@ import fs from 'fs';
 import path from 'path';
 import FormData from 'form-data'; // Assuming form-data is available
 import { createLogger } from '../../core/logger.js';
+import { idempotencyKeyProperty } from '../../core/idempotency.js';
+import { invalidRequest } from '../../core/errors.js';
 
 const logger = createLogger('import_agent');
 
+// Buffer.from skips characters outside the base64 alphabet, so input is checked first
+const BASE64 = /^(?:[A-Za-z0-9+/]{4})*(?:[A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$/;
+
 /**
- * Tool handler for importing an agent from a JSON file
+ * Resolve the import payload from whichever input the caller provided.
+ * Returns the data to upload, the filename to report to Letta, and a label for messages.
  */
-export async function handleImportAgent(server, args) {
-    if (!args?.file_path) {
-        server.createErrorResponse('Missing required argument: file_path');
+function resolveImportSource(server, args) {
+    if (args.export_data !== undefined && args.export_data !== null) {
+        let exportData = args.export_data;
+        // export_data may arrive as a JSON string or as an already-parsed object
+        if (typeof exportData === 'string') {
+            try {
+                exportData = JSON.parse(exportData);
+            } catch (parseError) {
+                server.createErrorResponse(
+                    invalidRequest(
+                        'export_data',
+                        `Invalid argument: export_data is not valid JSON: ${parseError.message}`,
+                    ),
+                );
+            }
+        }
+        if (!exportData || typeof exportData !== 'object' || Array.isArray(exportData)) {
+            server.createErrorResponse(
+                invalidRequest(
+                    'export_data',
+                    'Invalid argument: export_data must be a JSON object',
+                ),
+            );
+        }
+        return {
+            data: Buffer.from(JSON.stringify(exportData)),
+            filename: args.file_name || 'agent_export.json',
+            label: 'export_data',
+        };
+    }
+
+    if (args.file_data) {
+        // Line breaks are allowed, as base64 encoders often wrap their output
+        const encoded =
+            typeof args.file_data === 'string' ? args.file_data.replace(/\s+/g, '') : '';
+        if (!encoded || !BASE64.test(encoded)) {
+            server.createErrorResponse(
+                invalidRequest(
+                    'file_data',
+                    'Invalid argument: file_data is not valid base64 content',
+                ),
+            );
+        }
+        return {
+            data: Buffer.from(encoded, 'base64'),
+            filename: args.file_name || 'agent.af',
+            label: 'file_data',
+        };
     }
 
     const filePath = path.resolve(args.file_path); // Resolve to absolute path
 
     // Check if file exists
     if (!fs.existsSync(filePath)) {
-        server.createErrorResponse(`File not found at path: ${filePath}`);
+        server.createErrorResponse(
+            invalidRequest('file_path', `File not found at path: ${filePath}`),
+        );
+    }
+
+    return {
+        data: fs.createReadStream(filePath),
+        filename: path.basename(filePath),
+        label: args.file_path,
+    };
+}
+
+/**
+ * Tool handler for importing an agent from a JSON file, export data, or a base64 payload
+ */
+export async function handleImportAgent(server, args) {
+    if (!args?.file_path && !args?.file_data && !args?.export_data) {
+        server.createErrorResponse(
+            'Missing required argument: file_path (or provide export_data or file_data)',
+        );
     }
 
+    const source = resolveImportSource(server, args);
+
     try {
         const headers = server.getApiHeaders();
         // Remove content-type as axios will set it correctly for FormData
         delete headers['Content-Type'];
 
         const form = new FormData();
-        form.append('file', fs.createReadStream(filePath), path.basename(filePath));
+        form.append('file', source.data, source.filename);
 
         // Construct query parameters for optional settings
         const params = {};
@@ -63,18 +135,10 @@ export async function handleImportAgent(server, args) {
             ],
         };
     } catch (error) {
-        // Handle potential 422 for validation errors, or other API/file errors
-        if (error.response) {
-            if (error.response.status === 422) {
-                server.createErrorResponse(
-                    `Validation error importing agent from ${args.file_path}: ${JSON.stringify(error.response.data)}`,
-                );
-            }
-        }
+        // A 422 (validation) or other 4xx is reported with its status, and the server's own
+        // explanation is appended as Details
         logger.error('[import_agent] Error:', error.response?.data || error.message);
-        server.createErrorResponse(
-            `Failed to import agent from ${args.file_path}: ${error.message}`,
-        );
+        server.createErrorResponse(error, `Failed to import agent from ${source.label}`);
     }
 }
 
@@ -84,7 +148,7 @@ export async function handleImportAgent(server, args) {
 export const importAgentDefinition = {
     name: 'import_agent',
     description:
-        'Import a serialized agent JSON file and recreate the agent in the system. Use export_agent to create the JSON file, then modify_agent or attach_tool to customize the imported agent.',
+        'Import a serialized agent and recreate it in the system. Accepts a JSON file path, the export_data returned by export_agent, or a base64-encoded .af file. Use modify_agent or attach_tool to customize the imported agent.',
     inputSchema: {
         type: 'object',
         properties: {
@@ -92,6 +156,21 @@ export const importAgentDefinition = {
                 type: 'string',
                 description: 'Path to the agent JSON file to import.',
             },
+            export_data: {
+                type: ['object', 'string'],
+                description:
+                    'Optional: Agent export JSON (as an object or a JSON string), e.g. the output of export_agent. Used instead of file_path.',
+            },
+            file_data: {
+                type: 'string',
+                description:
+                    'Optional: Base64-encoded agent file (.af or .json), e.g. the base64_data returned by export_agent. Used instead of file_path.',
+            },
+            file_name: {
+                type: 'string',
+                description:
+                    'Optional: Filename to report to Letta when importing from export_data or file_data.',
+            },
             append_copy_suffix: {
                 type: 'boolean',
                 description:
@@ -108,7 +187,9 @@ export const importAgentDefinition = {
                 type: 'string',
                 description: 'Optional: The project ID to associate the uploaded agent with.',
             },
+            idempotency_key: idempotencyKeyProperty,
         },
-        required: ['file_path'],
+        // Custom validation in the handler ensures one of file_path, export_data, or file_data is provided
+        required: [],
     },
 };
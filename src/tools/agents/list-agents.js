@@ -1,7 +1,13 @@
 import { createLogger } from '../../core/logger.js';
+import { fitToResponseSize } from '../../core/response-size.js';
+import { bustCacheProperty } from '../../core/cache.js';
+import { invalidRequest } from '../../core/errors.js';
 
 const logger = createLogger('list_agents');
 
+// Most server pages a filtered listing scans before returning what it has found so far
+const MAX_FILTER_PAGES = 50;
+
 /**
  * Tool handler for listing agents in the Letta system
  */
@@ -10,36 +16,124 @@ export async function handleListAgents(server, args) {
         // Headers for API requests
         const headers = server.getApiHeaders();
 
-        // Get the list of agents
-        const response = await server.api.get('/agents/', { headers });
-        const agents = response.data;
-
-        // Apply filter if provided
-        let filteredAgents = agents;
-        if (args?.filter) {
-            const filter = args.filter.toLowerCase();
-            filteredAgents = agents.filter(
-                (agent) =>
-                    agent.name.toLowerCase().includes(filter) ||
-                    (agent.description && agent.description.toLowerCase().includes(filter)),
+        const limit = args?.limit;
+        const offset = args?.offset ?? 0;
+        if (limit !== undefined && (!Number.isInteger(limit) || limit < 1)) {
+            throw invalidRequest('limit', 'Invalid argument: limit must be a positive integer');
+        }
+        if (!Number.isInteger(offset) || offset < 0) {
+            throw invalidRequest(
+                'offset',
+                'Invalid argument: offset must be a non-negative integer',
             );
         }
+        if (
+            args?.tags !== undefined &&
+            (!Array.isArray(args.tags) || args.tags.some((tag) => typeof tag !== 'string'))
+        ) {
+            throw invalidRequest('tags', 'Invalid argument: tags must be an array of strings');
+        }
+        if (args?.name_contains !== undefined && typeof args.name_contains !== 'string') {
+            throw invalidRequest(
+                'name_contains',
+                'Invalid argument: name_contains must be a string',
+            );
+        }
+
+        // name_contains matches the name, filter the name or description, and tags the agent's
+        // tags; all are also checked here because filter has no server-side equivalent and
+        // servers without query_text or tags support ignore those parameters
+        const nameNeedle = args?.name_contains?.toLowerCase();
+        const filterNeedle = args?.filter?.toLowerCase();
+        const tags = args?.tags?.length ? args.tags : null;
+        const hasTag = (agent, tag) => Array.isArray(agent.tags) && agent.tags.includes(tag);
+        const matches = (agent) =>
+            (!nameNeedle || Boolean(agent.name?.toLowerCase().includes(nameNeedle))) &&
+            (!filterNeedle ||
+                Boolean(agent.name?.toLowerCase().includes(filterNeedle)) ||
+                Boolean(agent.description?.toLowerCase().includes(filterNeedle))) &&
+            (!tags ||
+                (args.match_all_tags
+                    ? tags.every((tag) => hasTag(agent, tag))
+                    : tags.some((tag) => hasTag(agent, tag))));
+        const filtered = Boolean(nameNeedle || filterNeedle || tags);
+
+        // The API pages with before/after cursors; offset is applied client-side for
+        // backward compatibility. One extra agent is requested to detect further pages.
+        const params = {};
+        if (args?.before) params.before = args.before;
+        if (args?.after) params.after = args.after;
+        const wanted = limit ? offset + limit + 1 : undefined;
+        if (wanted) params.limit = wanted;
+        // Name and tag filters run on the server where it supports them so pages stay full
+        if (args?.name_contains) params.query_text = args.name_contains;
+        if (tags) {
+            params.tags = tags;
+            params.match_all_tags = args.match_all_tags ?? false;
+        }
+
+        // Filter before slicing, fetching further server pages until the requested page is
+        // full. Paging backwards with before is a single request. The scan stops after
+        // MAX_FILTER_PAGES pages, returning a cursor to resume from the last agent scanned.
+        const allAgents = [];
+        let scanCursor = null;
+        for (let pages = 1; ; pages++) {
+            // Arrays are sent as repeated keys (tags=a&tags=b)
+            const response = await server.api.get('/agents/', {
+                headers,
+                params,
+                paramsSerializer: { indexes: null },
+            });
+            const batch = response.data;
+            if (!Array.isArray(batch)) {
+                throw new Error('Unexpected response from /agents/: expected an array');
+            }
+            // A server that ignores after returns the same page again
+            const lastId = batch[batch.length - 1]?.id;
+            if (pages > 1 && lastId === params.after) break;
+            allAgents.push(...batch.filter(matches));
+
+            const exhausted = !wanted || batch.length < wanted;
+            if (!filtered || exhausted || args?.before || allAgents.length >= wanted) break;
+            if (pages >= MAX_FILTER_PAGES) {
+                scanCursor = lastId;
+                break;
+            }
+            params.after = lastId;
+        }
+
+        const agents = allAgents.slice(offset, limit ? offset + limit : undefined);
+        const pageFull = limit ? allAgents.length > offset + limit : false;
+        const hasMore = pageFull || Boolean(scanCursor);
+        const paginated = Boolean(limit || offset || args?.before || args?.after);
 
         // Extract only essential details for the response
-        const summarizedAgents = filteredAgents.map((agent) => ({
+        const summarizedAgents = agents.map((agent) => ({
             id: agent.id,
             name: agent.name,
             description: agent.description,
         }));
 
+        // Oversized pages are cut short; next_cursor then continues after the last agent kept
+        const payload = fitToResponseSize(summarizedAgents, (page, truncated) => ({
+            count: page.length,
+            agents: page, // Use summarized list
+            has_more: hasMore || truncated,
+            // Pass next_cursor as `after` (or prev_cursor as `before`) to page
+            next_cursor: truncated
+                ? (page[page.length - 1]?.id ?? null)
+                : pageFull
+                  ? agents[agents.length - 1].id
+                  : scanCursor,
+            prev_cursor: paginated && agents.length > 0 ? agents[0].id : null,
+            ...(truncated && { truncated: true }),
+        }));
+
         return {
             content: [
                 {
                     type: 'text',
-                    text: JSON.stringify({
-                        count: summarizedAgents.length,
-                        agents: summarizedAgents, // Use summarized list
-                    }),
+                    text: JSON.stringify(payload),
                 },
             ],
         };
@@ -57,14 +151,51 @@ export async function handleListAgents(server, args) {
 export const listAgentsToolDefinition = {
     name: 'list_agents',
     description:
-        'List all available agents in the Letta system. Use with create_agent to add new ones, get_agent_summary for details, or prompt_agent to interact with them.',
+        'List all available agents in the Letta system, optionally narrowed by a name substring or tags. Use with create_agent to add new ones, get_agent_summary for details, or prompt_agent to interact with them.',
     inputSchema: {
         type: 'object',
         properties: {
             filter: {
                 type: 'string',
-                description: 'Optional filter to search for specific agents',
+                description:
+                    'Optional filter to search for specific agents by name or description (case-insensitive)',
+            },
+            limit: {
+                type: 'integer',
+                description: 'Maximum number of agents to return per page',
+                minimum: 1,
+            },
+            after: {
+                type: 'string',
+                description: 'Cursor: return agents after this agent ID (use next_cursor)',
+            },
+            before: {
+                type: 'string',
+                description: 'Cursor: return agents before this agent ID (use prev_cursor)',
+            },
+            offset: {
+                type: 'integer',
+                description:
+                    'Number of agents to skip. Deprecated in favor of after/before cursors; applied client-side',
+                minimum: 0,
+            },
+            name_contains: {
+                type: 'string',
+                description: 'Only return agents whose name contains this text (case-insensitive)',
+            },
+            tags: {
+                type: 'array',
+                items: { type: 'string' },
+                description:
+                    'Only return agents with these tags (any of them, or all with match_all_tags)',
+            },
+            match_all_tags: {
+                type: 'boolean',
+                description:
+                    'Require agents to have every tag in tags rather than any one (default: false)',
+                default: false,
             },
+            bust_cache: bustCacheProperty,
         },
         required: [],
     },
@@ -0,0 +1,226 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, httpError } from '../../core/errors.js';
+import { fetchAllPassages } from '../passages/embedding-search.js';
+import { createLocalJob, finishLocalJob } from '../jobs/local-jobs.js';
+import { validateEmbeddingConfig, validateLlmConfig } from '../../core/model-config.js';
+
+const logger = createLogger('migrate_agent');
+
+// Agent fields migrate_agent can change
+const MIGRATION_FIELDS = ['embedding_config', 'embedding', 'llm_config', 'model'];
+
+/**
+ * Whether a memory block is marked to survive migrations unchanged
+ */
+function isPreserved(block) {
+    return block.preserve_on_migration === true || block.metadata?.preserve_on_migration === true;
+}
+
+/**
+ * Re-embed one archival passage with the agent's current embedding config by inserting its
+ * text and tags again, then deleting the original once the copy exists. The archival memory
+ * endpoint takes no other passage metadata, so nothing else can be carried over.
+ */
+async function reembedPassage(server, agentId, passage, headers) {
+    const passagesUrl = `/agents/${agentId}/archival-memory`;
+    const body = { text: passage.text };
+    if (Array.isArray(passage.tags) && passage.tags.length > 0) body.tags = passage.tags;
+    await server.api.post(passagesUrl, body, { headers });
+    await server.api.delete(`${passagesUrl}/${encodeURIComponent(passage.id)}`, { headers });
+}
+
+/**
+ * Put preserved blocks back the way they were before the migration: re-attach any that were
+ * detached and restore any whose value changed
+ * @returns {Promise<string[]>} Labels of the blocks that had to be restored
+ */
+async function restorePreservedBlocks(server, agentId, preserved, headers) {
+    if (preserved.length === 0) return [];
+    const response = await server.api.get(`/agents/${agentId}/core-memory/blocks`, { headers });
+    const current = new Map((response.data ?? []).map((block) => [block.id, block]));
+
+    const restored = [];
+    for (const block of preserved) {
+        const blockId = encodeURIComponent(block.id);
+        const now = current.get(block.id);
+        if (!now) {
+            await server.api.patch(
+                `/agents/${agentId}/core-memory/blocks/attach/${blockId}`,
+                {},
+                { headers },
+            );
+        }
+        if (!now || now.value !== block.value) {
+            await server.api.patch(`/blocks/${blockId}`, { value: block.value }, { headers });
+            restored.push(block.label);
+        }
+    }
+    return restored;
+}
+
+/**
+ * Re-embed the agent's archival passages and check its preserved blocks, recording progress
+ * on the job. Runs after the tool has returned.
+ */
+async function runMigration(server, job, agentId, { reembed, preserved, headers }) {
+    const { metadata } = job;
+    try {
+        if (reembed) {
+            // Passages that came from a source file are re-embedded with reprocess_source
+            const passages = (await fetchAllPassages(server, headers, agentId)).filter(
+                (passage) => !passage.source_id && !passage.file_id,
+            );
+            metadata.total_steps = passages.length;
+            for (const passage of passages) {
+                try {
+                    await reembedPassage(server, agentId, passage, headers);
+                } catch (error) {
+                    const { message } = classifyError(error);
+                    logger.error(`Failed to re-embed passage ${passage.id}: ${message}`);
+                    metadata.failed_passages.push({ id: passage.id, error: message });
+                }
+                metadata.completed_steps++;
+            }
+        }
+
+        metadata.restored_blocks = await restorePreservedBlocks(
+            server,
+            agentId,
+            preserved,
+            headers,
+        );
+
+        const failed = metadata.failed_passages.length;
+        finishLocalJob(
+            job,
+            failed
+                ? `${failed} of ${metadata.total_steps} passages could not be re-embedded`
+                : null,
+        );
+    } catch (error) {
+        const { message } = classifyError(error);
+        logger.error(`Migration of agent ${agentId} failed: ${message}`);
+        finishLocalJob(job, message);
+    }
+}
+
+/**
+ * Tool handler for migrating an agent to a new embedding and/or model config
+ */
+export async function handleMigrateAgent(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    const update = Object.fromEntries(
+        MIGRATION_FIELDS.filter((field) => args[field] !== undefined).map((field) => [
+            field,
+            args[field],
+        ]),
+    );
+    if (Object.keys(update).length === 0) {
+        server.createErrorResponse(
+            `Missing required argument: one of ${MIGRATION_FIELDS.join(', ')}`,
+        );
+    }
+    // Check the configs before the agent is changed
+    try {
+        if (update.llm_config !== undefined) validateLlmConfig(update.llm_config);
+        if (update.embedding_config !== undefined) validateEmbeddingConfig(update.embedding_config);
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+
+    const headers = server.getApiHeaders();
+    const agentId = encodeURIComponent(args.agent_id);
+    const embeddingChanged = 'embedding_config' in update || 'embedding' in update;
+    const reembed = args.reembed_passages ?? embeddingChanged;
+
+    let agent;
+    let preserved;
+    try {
+        const blocks = await server.api.get(`/agents/${agentId}/core-memory/blocks`, { headers });
+        preserved = (blocks.data ?? []).filter(isPreserved);
+
+        const response = await server.api.patch(`/agents/${agentId}`, update, { headers });
+        agent = response.data;
+    } catch (error) {
+        if (error.response?.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error, `Failed to migrate agent ${args.agent_id}`);
+    }
+
+    const job = createLocalJob(server, 'agent_migration', {
+        agent_id: args.agent_id,
+        reembed_passages: reembed,
+        preserved_blocks: preserved.map((block) => block.label),
+        failed_passages: [],
+    });
+    logger.info(`Migrating agent ${args.agent_id} as job ${job.id}`);
+    // Tracked like a tool call so shutdown waits for it; jobs are held in memory, so one
+    // still running when the server stops is lost
+    server.inFlight
+        .track('migrate_agent', {}, () =>
+            runMigration(server, job, agentId, { reembed, preserved, headers }),
+        )
+        .catch((error) => finishLocalJob(job, error.message));
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    agent_id: args.agent_id,
+                    job_id: job.id,
+                    status: job.status,
+                    embedding_config: agent?.embedding_config ?? null,
+                    llm_config: agent?.llm_config ?? null,
+                    reembed_passages: reembed,
+                    preserved_blocks: job.metadata.preserved_blocks,
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for migrate_agent
+ */
+export const migrateAgentDefinition = {
+    name: 'migrate_agent',
+    description:
+        "Move an agent to a new embedding and/or model config. The agent is updated at once; when the embedding changes, its archival passages are then re-embedded in the background (each passage's text and tags are inserted again and the original deleted, so passages get new IDs and timestamps; other passage metadata is not kept). Memory blocks marked preserve_on_migration (on the block or in its metadata) are restored if the migration changed or detached them. Returns a job_id; use wait_for_job or get_job to follow progress and see failed_passages and restored_blocks. Shutdown waits for a running migration, but the job is held in memory and is lost if the server restarts before it finishes. Passages from source files are left to reprocess_source.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent to migrate',
+            },
+            embedding_config: {
+                type: 'object',
+                description: 'New embedding config for the agent',
+            },
+            embedding: {
+                type: 'string',
+                description:
+                    'New embedding model handle (e.g. openai/text-embedding-3-small), instead of a full embedding_config',
+            },
+            llm_config: {
+                type: 'object',
+                description: 'Optional: New LLM config for the agent',
+            },
+            model: {
+                type: 'string',
+                description:
+                    'Optional: New model handle (e.g. openai/gpt-4o), instead of a full llm_config',
+            },
+            reembed_passages: {
+                type: 'boolean',
+                description:
+                    'Re-embed archival passages after updating the agent (default: true when the embedding changes, false otherwise)',
+            },
+        },
+        required: ['agent_id'],
+    },
+};
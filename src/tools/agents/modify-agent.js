@@ -1,3 +1,6 @@
+import { serializeJson } from '../../core/serialize.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
 /**
  * Tool handler for modifying an existing agent
  */
@@ -5,14 +8,41 @@ export async function handleModifyAgent(server, args) {
     if (!args?.agent_id) {
         server.createErrorResponse('Missing required argument: agent_id');
     }
+    if (typeof args.agent_id !== 'string') {
+        server.createErrorResponse(
+            invalidRequest('agent_id', 'Invalid argument: agent_id must be a string'),
+        );
+    }
     if (!args?.update_data) {
         server.createErrorResponse('Missing required argument: update_data');
     }
+    if (typeof args.update_data !== 'object' || Array.isArray(args.update_data)) {
+        server.createErrorResponse(
+            invalidRequest('update_data', 'Invalid argument: update_data must be an object'),
+        );
+    }
 
     try {
         const headers = server.getApiHeaders();
         const agentId = encodeURIComponent(args.agent_id);
-        const updatePayload = args.update_data; // This should conform to the UpdateAgent schema
+
+        // Only send the fields the caller actually provided (null is kept to allow clearing)
+        const updatePayload = Object.fromEntries(
+            Object.entries(args.update_data).filter(([, value]) => value !== undefined),
+        );
+
+        // An empty update is a no-op: return the current agent state without patching
+        if (Object.keys(updatePayload).length === 0) {
+            const response = await server.api.get(`/agents/${agentId}`, { headers });
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: serializeJson({ agent: response.data }, 'modify_agent response'),
+                    },
+                ],
+            };
+        }
 
         // Use the specific endpoint from the OpenAPI spec
         const response = await server.api.patch(`/agents/${agentId}`, updatePayload, { headers });
@@ -22,25 +52,16 @@ export async function handleModifyAgent(server, args) {
             content: [
                 {
                     type: 'text',
-                    text: JSON.stringify({
-                        agent: updatedAgentState,
-                    }),
+                    text: serializeJson({ agent: updatedAgentState }, 'modify_agent response'),
                 },
             ],
         };
     } catch (error) {
-        // Handle potential 404 if agent not found, 422 for validation errors, or other API errors
-        if (error.response) {
-            if (error.response.status === 404) {
-                server.createErrorResponse(`Agent not found: ${args.agent_id}`);
-            }
-            if (error.response.status === 422) {
-                server.createErrorResponse(
-                    `Validation error updating agent ${args.agent_id}: ${JSON.stringify(error.response.data)}`,
-                );
-            }
+        // Handle potential 404 if agent not found, or other API errors (422 for validation)
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
         }
-        server.createErrorResponse(error);
+        server.createErrorResponse(error, `Failed to update agent ${args.agent_id}`);
     }
 }
 
@@ -65,14 +86,24 @@ export const modifyAgentDefinition = {
             update_data: {
                 type: 'object',
                 description:
-                    'An object containing the fields to update (e.g., name, system, description, tool_ids, etc.)',
+                    'An object containing the fields to update (e.g., name, system, description, tool_ids, etc.). Only provided fields are sent; an empty object leaves the agent unchanged.',
                 // Ideally, this would mirror the UpdateAgent schema from the API spec
                 // Example properties (add more as needed based on UpdateAgent schema):
                 properties: {
                     name: { type: 'string', description: 'New name for the agent' },
                     system: { type: 'string', description: 'New system prompt' },
                     description: { type: 'string', description: 'New description' },
-                    // Add other updatable fields like tool_ids, source_ids, block_ids, tags, etc.
+                    llm_config: { type: 'object', description: 'New LLM configuration' },
+                    embedding_config: {
+                        type: 'object',
+                        description: 'New embedding configuration',
+                    },
+                    tool_ids: {
+                        type: 'array',
+                        items: { type: 'string' },
+                        description: 'Full list of tool IDs the agent should have',
+                    },
+                    // Add other updatable fields like source_ids, block_ids, tags, etc.
                 },
                 additionalProperties: true, // Allow other properties from UpdateAgent schema
             },
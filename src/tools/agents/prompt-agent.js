@@ -1,16 +1,219 @@
 import { createLogger } from '../../core/logger.js';
+import { requestTimeout, timeoutSecsProperty } from '../../core/timeout.js';
+import { invalidRequest } from '../../core/errors.js';
 
 const logger = createLogger('prompt_agent');
 
+// Roles accepted by Letta's MessageCreate; assistant turns let callers seed few-shot examples
+export const MESSAGE_ROLES = ['user', 'system', 'assistant'];
+
+/**
+ * Parse a single SSE line into an event object, or null for blank/terminator lines
+ */
+function parseSseLine(line) {
+    const trimmed = line.trim();
+    if (!trimmed.startsWith('data: ')) {
+        return null;
+    }
+    const jsonStr = trimmed.substring(6);
+    if (jsonStr === '[DONE]') {
+        return null;
+    }
+    try {
+        return JSON.parse(jsonStr);
+    } catch (jsonError) {
+        logger.error('Error parsing SSE JSON:', jsonError);
+        return null;
+    }
+}
+
+/**
+ * Pick the token counts out of a usage_statistics event or LettaUsageStatistics object
+ * @param {Object} stats - Usage statistics reported by Letta
+ * @returns {Object|null} prompt/completion/total tokens and step count, or null if not reported
+ */
+export function extractUsage(stats) {
+    if (!stats) return null;
+    const usage = {};
+    for (const field of ['prompt_tokens', 'completion_tokens', 'total_tokens', 'step_count']) {
+        if (typeof stats[field] === 'number') usage[field] = stats[field];
+    }
+    return Object.keys(usage).length > 0 ? usage : null;
+}
+
+/**
+ * Fold streamed events into a single response. Token-level chunks for the same message are
+ * concatenated, and tool call argument deltas are merged per tool_call_id.
+ */
+export function createStreamAccumulator() {
+    const state = {
+        response: '',
+        reasoning: '',
+        toolCalls: new Map(),
+        toolReturns: [],
+        usage: null,
+        stopReason: null,
+        eventCount: 0,
+    };
+
+    return {
+        push(event) {
+            state.eventCount += 1;
+            switch (event.message_type) {
+                case 'assistant_message': {
+                    const content = Array.isArray(event.content)
+                        ? event.content.map((part) => part.text ?? '').join('')
+                        : event.content;
+                    if (content) state.response += content;
+                    break;
+                }
+                case 'reasoning_message':
+                    if (event.reasoning) state.reasoning += event.reasoning;
+                    break;
+                case 'tool_call_message': {
+                    const delta = event.tool_call || {};
+                    const key = delta.tool_call_id || event.id || `call_${state.toolCalls.size}`;
+                    const existing = state.toolCalls.get(key) || {
+                        tool_call_id: key,
+                        name: '',
+                        arguments: '',
+                    };
+                    if (delta.name) existing.name = delta.name;
+                    if (delta.arguments) existing.arguments += delta.arguments;
+                    state.toolCalls.set(key, existing);
+                    break;
+                }
+                case 'tool_return_message':
+                    state.toolReturns.push({
+                        tool_call_id: event.tool_call_id,
+                        status: event.status,
+                        tool_return: event.tool_return,
+                    });
+                    break;
+                case 'usage_statistics':
+                    state.usage = extractUsage(event);
+                    break;
+                case 'stop_reason':
+                    state.stopReason = event.stop_reason;
+                    break;
+                default:
+                    if (event.delta?.content) state.response += event.delta.content;
+            }
+        },
+        result() {
+            return {
+                response: state.response,
+                reasoning: state.reasoning || undefined,
+                tool_calls: [...state.toolCalls.values()],
+                tool_returns: state.toolReturns,
+                usage: state.usage ?? undefined,
+                stop_reason: state.stopReason,
+                event_count: state.eventCount,
+            };
+        },
+    };
+}
+
+/**
+ * Stream the agent response, forwarding each event to the client as a progress notification
+ * and accumulating the final result. Without a progress token the SSE body is buffered and
+ * folded into a single response instead.
+ */
+async function streamAgentMessage(server, args, headers, extra) {
+    const progressToken = extra?._meta?.progressToken;
+    const canForward = progressToken !== undefined && typeof extra?.sendNotification === 'function';
+    const accumulator = createStreamAccumulator();
+
+    const response = await server.api.post(
+        `/agents/${args.agent_id}/messages/stream`,
+        {
+            messages: [
+                {
+                    role: args.role || 'user',
+                    content: args.message,
+                },
+            ],
+            stream_steps: true,
+            stream_tokens: true,
+        },
+        {
+            headers,
+            responseType: canForward ? 'stream' : 'text',
+            ...requestTimeout(args),
+        },
+    );
+
+    if (!canForward) {
+        const body = typeof response.data === 'string' ? response.data : '';
+        for (const line of body.split('\n')) {
+            const event = parseSseLine(line);
+            if (event) accumulator.push(event);
+        }
+        return { ...accumulator.result(), streamed: false };
+    }
+
+    let eventCount = 0;
+    const forward = async (event) => {
+        accumulator.push(event);
+        eventCount += 1;
+        await extra.sendNotification({
+            method: 'notifications/progress',
+            params: {
+                progressToken,
+                progress: eventCount,
+                message: JSON.stringify(event),
+            },
+        });
+    };
+
+    // A streaming decoder keeps multi-byte characters split across chunks intact
+    const decoder = new TextDecoder();
+    let buffer = '';
+    for await (const chunk of response.data) {
+        buffer += typeof chunk === 'string' ? chunk : decoder.decode(chunk, { stream: true });
+        const lines = buffer.split('\n');
+        buffer = lines.pop();
+        for (const line of lines) {
+            const event = parseSseLine(line);
+            if (event) await forward(event);
+        }
+    }
+    const trailing = parseSseLine(buffer + decoder.decode());
+    if (trailing) await forward(trailing);
+
+    const result = accumulator.result();
+    await extra.sendNotification({
+        method: 'notifications/progress',
+        params: {
+            progressToken,
+            progress: result.event_count,
+            total: result.event_count,
+            message: JSON.stringify({
+                message_type: 'completion',
+                stop_reason: result.stop_reason,
+                usage: result.usage,
+            }),
+        },
+    });
+
+    return { ...result, streamed: true };
+}
+
 /**
  * Tool handler for prompting an agent in the Letta system
  */
-export async function handlePromptAgent(server, args) {
+export async function handlePromptAgent(server, args, extra) {
     try {
         // Validate arguments
         if (!args.agent_id || !args.message) {
             throw new Error('Missing required arguments: agent_id and message');
         }
+        if (args.role !== undefined && !MESSAGE_ROLES.includes(args.role)) {
+            throw invalidRequest(
+                'role',
+                `Invalid argument: role must be one of ${MESSAGE_ROLES.join(', ')}`,
+            );
+        }
 
         // Headers for API requests
         const headers = server.getApiHeaders();
@@ -19,13 +222,30 @@ export async function handlePromptAgent(server, args) {
         const agentInfoResponse = await server.api.get(`/agents/${args.agent_id}`, { headers });
         const agentName = agentInfoResponse.data.name;
 
+        if (args.stream) {
+            const streamed = await streamAgentMessage(server, args, headers, extra);
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify({
+                            agent_id: args.agent_id,
+                            agent_name: agentName,
+                            message: args.message,
+                            ...streamed,
+                        }),
+                    },
+                ],
+            };
+        }
+
         // Send message to agent using the messages/stream endpoint
         const response = await server.api.post(
             `/agents/${args.agent_id}/messages/stream`,
             {
                 messages: [
                     {
-                        role: 'user',
+                        role: args.role || 'user',
                         content: args.message,
                     },
                 ],
@@ -35,11 +255,13 @@ export async function handlePromptAgent(server, args) {
             {
                 headers,
                 responseType: 'text',
+                ...requestTimeout(args),
             },
         );
 
         // Extract the response
         let responseText = '';
+        let usage = null;
         try {
             // The response is in Server-Sent Events (SSE) format
             if (typeof response.data === 'string') {
@@ -57,10 +279,18 @@ export async function handlePromptAgent(server, args) {
                         const eventData = JSON.parse(jsonStr);
 
                         // Extract the message content based on message type
-                        if (eventData.message_type === 'assistant_message' && eventData.content) {
+                        if (eventData.message_type === 'usage_statistics') {
+                            // Token counts arrive after the response messages
+                            usage = extractUsage(eventData);
+                        } else if (responseText) {
+                            // Main response already found; keep scanning only for usage
+                            continue;
+                        } else if (
+                            eventData.message_type === 'assistant_message' &&
+                            eventData.content
+                        ) {
                             // This is the main response message
                             responseText = eventData.content;
-                            break;
                         } else if (
                             eventData.message_type === 'reasoning_message' &&
                             eventData.reasoning
@@ -90,6 +320,7 @@ export async function handlePromptAgent(server, args) {
             } else if (response.data) {
                 // Handle non-string response (unlikely with SSE)
                 responseText = JSON.stringify(response.data);
+                usage = extractUsage(response.data.usage);
             }
         } catch (error) {
             logger.error('Error parsing response:', error);
@@ -105,6 +336,8 @@ export async function handlePromptAgent(server, args) {
                         agent_name: agentName,
                         message: args.message,
                         response: responseText,
+                        // Omitted when the server doesn't report token counts
+                        ...(usage && { usage }),
                     }),
                 },
             ],
@@ -132,6 +365,20 @@ export const promptAgentToolDefinition = {
                 type: 'string',
                 description: 'Message to send to the agent',
             },
+            role: {
+                type: 'string',
+                enum: MESSAGE_ROLES,
+                description:
+                    'Role of the message (default: user). Use system or assistant to inject context or seed few-shot turns',
+                default: 'user',
+            },
+            stream: {
+                type: 'boolean',
+                description:
+                    'Stream tokens and steps from the agent. Events are forwarded as progress notifications when the request carries a progress token; otherwise they are accumulated into a single response including tool calls and usage (default: false)',
+                default: false,
+            },
+            timeout_secs: timeoutSecsProperty,
         },
         required: ['agent_id', 'message'],
     },
@@ -1,3 +1,7 @@
+import { serializeJson } from '../../core/serialize.js';
+import { withTimestamps } from '../timestamps.js';
+import { httpError } from '../../core/errors.js';
+
 /**
  * Tool handler for retrieving the state of a specific agent
  */
@@ -18,16 +22,17 @@ export async function handleRetrieveAgent(server, args) {
             content: [
                 {
                     type: 'text',
-                    text: JSON.stringify({
-                        agent: agentState,
-                    }),
+                    text: serializeJson(
+                        { agent: withTimestamps(agentState) },
+                        'retrieve_agent response',
+                    ),
                 },
             ],
         };
     } catch (error) {
         // Handle potential 404 if agent not found, or other API errors
         if (error.response && error.response.status === 404) {
-            server.createErrorResponse(`Agent not found: ${args.agent_id}`);
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
         }
         server.createErrorResponse(error);
     }
@@ -41,6 +41,14 @@ export const toolAnnotations = {
         executionTime: 'fast',
     },
 
+    find_agents_by_name: {
+        title: 'Find Agents by Name',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
     retrieve_agent: {
         title: 'Get Agent Details',
         readOnly: true,
@@ -57,6 +65,14 @@ export const toolAnnotations = {
         executionTime: 'fast',
     },
 
+    describe_agent: {
+        title: 'Describe Agent',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
     // Agent interaction - may have costs
     prompt_agent: {
         title: 'Send Message to Agent',
@@ -68,6 +84,105 @@ export const toolAnnotations = {
         rateLimit: '100/hour',
     },
 
+    bulk_send_message: {
+        title: 'Bulk Send Message',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'high',
+        executionTime: 'slow',
+        sideEffects: 'Sends messages to multiple agents, updating their memory and incurring API costs',
+        bulkOperation: true,
+    },
+
+    modify_agent_message: {
+        title: 'Modify Agent Message',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: "Rewrites the agent's conversation history",
+    },
+
+    delete_agent_message: {
+        title: 'Delete Agent Message',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: "Permanently removes messages from the agent's conversation history",
+        dangerous: true,
+    },
+
+    list_agent_messages: {
+        title: 'List Agent Messages',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    export_agent_messages: {
+        title: 'Export Agent Messages',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'medium',
+        sideEffects: 'Writes a JSON Lines file on the server when output_path is set',
+    },
+
+    count_agent_messages: {
+        title: 'Count Agent Messages',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    estimate_message_tokens: {
+        title: 'Estimate Message Tokens',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    reset_agent_messages: {
+        title: 'Reset Agent Messages',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'medium',
+        executionTime: 'variable',
+        sideEffects:
+            "Clears the agent's conversation history; soft_reset then runs one agent step to re-seed it",
+        dangerous: true,
+    },
+
+    cancel_agent_run: {
+        title: 'Cancel Agent Run',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: 'Stops in-flight agent processing',
+    },
+
+    wait_for_run: {
+        title: 'Wait For Run',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'variable',
+    },
+
+    summarize_agent_conversation: {
+        title: 'Summarize Agent Conversation',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'medium',
+        executionTime: 'slow',
+        sideEffects: 'Condenses older messages in the agent context into a summary',
+    },
+
     // Memory operations
     create_memory_block: {
         title: 'Create Memory Block',
@@ -86,6 +201,14 @@ export const toolAnnotations = {
         executionTime: 'fast',
     },
 
+    list_all_blocks: {
+        title: 'List All Memory Blocks',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
     read_memory_block: {
         title: 'Read Memory Block',
         readOnly: true,
@@ -103,6 +226,16 @@ export const toolAnnotations = {
         sideEffects: 'Modifies memory content',
     },
 
+    delete_memory_block: {
+        title: 'Delete Memory Block',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: 'Permanently deletes the block, detaching it from agents when forced',
+        dangerous: true,
+    },
+
     attach_memory_block: {
         title: 'Attach Memory to Agent',
         readOnly: false,
@@ -112,6 +245,31 @@ export const toolAnnotations = {
         sideEffects: 'Links memory block to agent',
     },
 
+    get_agent_memory_usage: {
+        title: 'Get Agent Memory Usage',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    list_block_templates: {
+        title: 'List Memory Block Templates',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    create_block_from_template: {
+        title: 'Create Memory Block from Template',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: 'Creates persistent memory block, optionally attached to an agent',
+    },
+
     // Passage operations
     create_passage: {
         title: 'Create Archival Memory',
@@ -130,6 +288,32 @@ export const toolAnnotations = {
         executionTime: 'fast',
     },
 
+    search_archival_memory: {
+        title: 'Search Archival Memory Across Agents',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'medium',
+        executionTime: 'medium',
+    },
+
+    bulk_create_passages: {
+        title: 'Bulk Create Archival Memory',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'high',
+        executionTime: 'slow',
+        sideEffects: 'Creates many searchable memories with embeddings',
+        bulkOperation: true,
+    },
+
+    count_passages: {
+        title: 'Count Archival Memory',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
     modify_passage: {
         title: 'Update Archival Memory',
         readOnly: false,
@@ -158,6 +342,32 @@ export const toolAnnotations = {
         sideEffects: 'Modifies agent capabilities',
     },
 
+    detach_tool: {
+        title: 'Detach Tool from Agent',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: 'Modifies agent capabilities',
+    },
+
+    generate_tool_schema: {
+        title: 'Generate Tool Schema',
+        readOnly: true,
+        requiresAuth: false,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    generate_tool_from_prompt: {
+        title: 'Generate Tool from Description',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'high',
+        executionTime: 'slow',
+        securityNote: 'Generated code should be reviewed before uploading',
+    },
+
     list_agent_tools: {
         title: 'List Agent Tools',
         readOnly: true,
@@ -166,6 +376,32 @@ export const toolAnnotations = {
         executionTime: 'fast',
     },
 
+    list_tools: {
+        title: 'List Tools',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    get_tool_source: {
+        title: 'Get Tool Source',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    patch_tool_source: {
+        title: 'Patch Tool Source',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: "Modifies the tool's source code",
+        securityNote: 'Executes user-provided code',
+    },
+
     upload_tool: {
         title: 'Upload Custom Tool',
         readOnly: false,
@@ -176,13 +412,31 @@ export const toolAnnotations = {
         securityNote: 'Executes user-provided code',
     },
 
+    run_tool_from_source: {
+        title: 'Run Tool From Source',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'variable',
+        sideEffects: 'Runs code in the server sandbox; nothing is saved',
+        securityNote: 'Executes user-provided code',
+    },
+
+    list_tool_runs: {
+        title: 'List Tool Runs',
+        readOnly: true,
+        requiresAuth: false,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
     // MCP operations
     list_mcp_servers: {
         title: 'List MCP Servers',
         readOnly: true,
         requiresAuth: true,
         costLevel: 'low',
-        executionTime: 'fast',
+        executionTime: 'variable',
     },
 
     list_mcp_tools_by_server: {
@@ -193,6 +447,24 @@ export const toolAnnotations = {
         executionTime: 'fast',
     },
 
+    test_mcp_server: {
+        title: 'Test MCP Server',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'variable',
+    },
+
+    connect_mcp_server: {
+        title: 'Connect MCP Server',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'medium',
+        sideEffects: 'Registers an MCP server connection on the Letta server',
+        securityNote: 'May return an OAuth authorization URL that must be opened by the user',
+    },
+
     add_mcp_tool_to_letta: {
         title: 'Import MCP Tool',
         readOnly: false,
@@ -211,6 +483,179 @@ export const toolAnnotations = {
         executionTime: 'fast',
     },
 
+    attach_source: {
+        title: 'Attach Source',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: "Adds the source's passages to the agent's searchable data",
+    },
+
+    detach_source: {
+        title: 'Detach Source',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: "Removes the source's passages from the agent's searchable data",
+    },
+
+    list_agents_using_source: {
+        title: 'List Agents Using Source',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'medium',
+        executionTime: 'variable',
+        bulkOperation: true,
+    },
+
+    get_source_file_content: {
+        title: 'Get Source File Content',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'variable',
+    },
+
+    list_source_passages: {
+        title: 'List Source Passages',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    upload_file_begin: {
+        title: 'Begin File Upload',
+        readOnly: false,
+        requiresAuth: false,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: 'Opens an upload session on this MCP server',
+    },
+
+    upload_file_chunk: {
+        title: 'Upload File Chunk',
+        readOnly: false,
+        requiresAuth: false,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: 'Buffers a chunk in the upload session',
+    },
+
+    upload_file_complete: {
+        title: 'Complete File Upload',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'medium',
+        executionTime: 'variable',
+        sideEffects: 'Adds a file to the data source and starts processing it',
+    },
+
+    reprocess_source: {
+        title: 'Reprocess Source',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'high',
+        executionTime: 'variable',
+        bulkOperation: true,
+        sideEffects: "Re-uploads the source's files and deletes the copies they replace",
+    },
+
+    // Folder operations
+    list_folders: {
+        title: 'List Folders',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'variable',
+    },
+
+    get_folder_contents: {
+        title: 'Get Folder Contents',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    create_folder: {
+        title: 'Create Folder',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: 'Creates a new folder',
+    },
+
+    delete_folder: {
+        title: 'Delete Folder',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+        sideEffects: 'Permanently deletes the folder and its files',
+        dangerous: true,
+    },
+
+    // Job operations
+    list_jobs: {
+        title: 'List Jobs',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    get_job: {
+        title: 'Get Job',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    get_job_result: {
+        title: 'Get Job Result',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    wait_for_job: {
+        title: 'Wait For Job',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'variable',
+    },
+
+    get_server_capabilities: {
+        title: 'Get Server Capabilities',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    list_operations: {
+        title: 'List Operations',
+        readOnly: true,
+        requiresAuth: false,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
+    get_operation_schema: {
+        title: 'Get Operation Schema',
+        readOnly: true,
+        requiresAuth: false,
+        costLevel: 'low',
+        executionTime: 'fast',
+    },
+
     list_embedding_models: {
         title: 'List Embedding Models',
         readOnly: true,
@@ -247,6 +692,16 @@ export const toolAnnotations = {
         sideEffects: 'Creates duplicate agent',
     },
 
+    migrate_agent: {
+        title: 'Migrate Agent Embedding/Model',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'high',
+        executionTime: 'slow',
+        sideEffects:
+            'Changes agent embedding/model config and re-embeds archival passages in the background',
+    },
+
     // Bulk operations - potentially expensive
     bulk_attach_tool_to_agents: {
         title: 'Bulk Attach Tool',
@@ -258,6 +713,26 @@ export const toolAnnotations = {
         bulkOperation: true,
     },
 
+    bulk_attach_tools_to_agent: {
+        title: 'Bulk Attach Tools to Agent',
+        readOnly: false,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'medium',
+        sideEffects: "Adds several tools to the agent's toolset",
+        bulkOperation: true,
+    },
+
+    bulk_export_agents: {
+        title: 'Bulk Export Agents',
+        readOnly: true,
+        requiresAuth: true,
+        costLevel: 'low',
+        executionTime: 'slow',
+        sideEffects: 'Writes an archive file on the server unless inline is set',
+        bulkOperation: true,
+    },
+
     bulk_delete_agents: {
         title: 'Bulk Delete Agents',
         readOnly: false,
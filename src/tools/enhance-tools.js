@@ -1,6 +1,8 @@
 import { getOutputSchema } from './output-schemas.js';
 import { getEnhancedDescription } from './enhanced-descriptions.js';
 import { getToolAnnotations } from './annotations.js';
+import { projectIdProperty } from '../core/scope.js';
+import { formatProperty, prettyJsonProperty } from '../core/json-format.js';
 
 /**
  * Enhance a tool definition with output schema and improved description
@@ -22,6 +24,20 @@ export function enhanceToolDefinition(toolDefinition) {
         enhanced.annotations = annotations;
     }
 
+    // Tools that call the Letta API can be scoped to a project per call, and any tool's
+    // response can be pretty-printed or returned as YAML
+    if (toolDefinition.inputSchema) {
+        enhanced.inputSchema = {
+            ...toolDefinition.inputSchema,
+            properties: {
+                ...toolDefinition.inputSchema.properties,
+                ...(annotations?.['x-requiresAuth'] !== false && { project_id: projectIdProperty }),
+                pretty_json: prettyJsonProperty,
+                format: formatProperty,
+            },
+        };
+    }
+
     // Add enhanced description if available
     const enhancedDesc = getEnhancedDescription(toolDefinition.name);
     if (enhancedDesc && enhancedDesc.longDescription) {
@@ -51,7 +51,12 @@ Prerequisites:
 Response includes:
 - Agent's message content
 - Tool usage information
-- Memory updates if applicable`,
+- Memory updates if applicable
+
+Streaming:
+- Set stream to true to request token-level output
+- When the call carries a progress token, each event is forwarded as a progress notification, followed by a completion event with usage stats
+- Otherwise the chunks are accumulated into a single response with merged tool-call arguments and usage`,
         examples: [
             {
                 scenario: 'Simple conversation',
@@ -68,6 +73,14 @@ Response includes:
                         'Please research the latest developments in quantum computing and summarize the key findings.',
                 },
             },
+            {
+                scenario: 'Streamed response with progress updates',
+                params: {
+                    agent_id: 'agent-123',
+                    message: 'Walk me through your plan step by step.',
+                    stream: true,
+                },
+            },
         ],
     },
 
@@ -118,7 +131,9 @@ Memory block types:
 Best practices:
 - Keep persona concise and focused
 - Update human block with user preferences
-- Use system blocks for operational guidelines`,
+- Use system blocks for operational guidelines
+- Set block_limit to cap the block's size; values longer than the limit are rejected
+- Set read_only on blocks agents must not rewrite, such as a fixed persona`,
         examples: [
             {
                 scenario: 'Create persona memory',
@@ -142,7 +157,7 @@ Best practices:
 
     list_passages: {
         description:
-            "Retrieve the memories in an agent's archival memory store (paginated query). Use create_passage to add new memories, modify_passage to edit, or delete_passage to remove them.",
+            "Retrieve the memories in an agent's archival memory store (paginated query; a full page returns next_cursor for the following page). Use create_passage to add new memories, modify_passage to edit, or delete_passage to remove them.",
         longDescription: `Retrieves archival memories (passages) for an agent with pagination and search capabilities.
 
 Use cases:
@@ -153,8 +168,8 @@ Use cases:
 
 Search tips:
 - Use keywords for targeted results
-- Combine with pagination for large datasets
-- Sort by date for recent memories`,
+- Combine with pagination for large datasets: pass next_cursor as after to fetch the next page
+- Sort by date for recent memories (order: "desc" for newest first)`,
         examples: [
             {
                 scenario: 'Get all passages',
@@ -171,13 +186,22 @@ Search tips:
                     limit: 20,
                 },
             },
+            {
+                scenario: 'Fetch the next page, oldest first',
+                params: {
+                    agent_id: 'agent-123',
+                    limit: 50,
+                    after: 'passage-456',
+                    order: 'asc',
+                },
+            },
         ],
     },
 
     // Tool Management
     list_mcp_tools_by_server: {
         description:
-            'List all available tools for a specific MCP server. Use list_mcp_servers first to see available servers, then add_mcp_tool_to_letta to import tools into Letta.',
+            'List all available tools for a specific MCP server, each with its input_schema. Use list_mcp_servers first to see available servers, then add_mcp_tool_to_letta to import tools into Letta.',
         longDescription: `Discovers available tools from a connected MCP server for potential integration with Letta agents.
 
 Workflow:
@@ -187,7 +211,7 @@ Workflow:
 4. Import selected tools with add_mcp_tool_to_letta
 5. Attach to agents as needed
 
-Filter options help find specific tool types or capabilities.`,
+Filter options help find specific tool types or capabilities. Tools are returned as name, description and input_schema; pass verbose: true for the full tool objects.`,
         examples: [
             {
                 scenario: 'List all tools from a server',
@@ -0,0 +1,76 @@
+import { createLogger } from '../../core/logger.js';
+import { toFolderMetadata } from './folder-metadata.js';
+import { idempotencyKeyProperty } from '../../core/idempotency.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('create_folder');
+
+/**
+ * Tool handler for creating a folder
+ */
+export async function handleCreateFolder(server, args) {
+    if (!args?.name) {
+        server.createErrorResponse('Missing required argument: name');
+    }
+    if (args.description !== undefined && typeof args.description !== 'string') {
+        server.createErrorResponse(
+            invalidRequest('description', 'Invalid argument: description must be a string'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const body = { name: args.name };
+        if (args.description !== undefined) body.description = args.description;
+        if (args.embedding) body.embedding = args.embedding;
+
+        const response = await server.api.post('/folders/', body, { headers });
+        logger.info(`Created folder ${response.data.id} (${args.name})`);
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        folder_id: response.data.id,
+                        folder: toFolderMetadata(response.data),
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 409) {
+            server.createErrorResponse(error, `A folder named "${args.name}" already exists`);
+        }
+        server.createErrorResponse(error, 'Failed to create folder');
+    }
+}
+
+/**
+ * Tool definition for create_folder
+ */
+export const createFolderDefinition = {
+    name: 'create_folder',
+    description:
+        'Create a folder (data source) to hold files for agents. Add files with upload_file_begin, then attach the folder to agents. Use list_folders to see existing folders.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            name: {
+                type: 'string',
+                description: 'Name of the folder (must be unique)',
+            },
+            description: {
+                type: 'string',
+                description: 'Description of what the folder contains',
+            },
+            embedding: {
+                type: 'string',
+                description:
+                    'Embedding model handle for the folder, e.g. openai/text-embedding-3-small (default: server default)',
+            },
+            idempotency_key: idempotencyKeyProperty,
+        },
+        required: ['name'],
+    },
+};
@@ -0,0 +1,98 @@
+import { createLogger } from '../../core/logger.js';
+import { httpError } from '../../core/errors.js';
+import {
+    ignoreMissingProperty,
+    isAlreadyAbsent,
+    validateIgnoreMissing,
+} from '../../core/ignore-missing.js';
+
+const logger = createLogger('delete_folder');
+
+/**
+ * Tool handler for deleting a folder
+ */
+export async function handleDeleteFolder(server, args) {
+    if (!args?.folder_id) {
+        server.createErrorResponse('Missing required argument: folder_id');
+    }
+    validateIgnoreMissing(server, args);
+
+    try {
+        const headers = server.getApiHeaders();
+        const folderId = encodeURIComponent(args.folder_id);
+
+        // Deleting a folder silently removes it from every agent it is attached to
+        const agentsResponse = await server.api.get(`/folders/${folderId}/agents`, { headers });
+        const agentIds = Array.isArray(agentsResponse.data) ? agentsResponse.data : [];
+        if (agentIds.length > 0 && !args.force) {
+            server.createErrorResponse(
+                httpError(
+                    409,
+                    `Folder ${args.folder_id} is attached to ${agentIds.length} agent(s): ${agentIds.join(', ')}. Detach it first or pass force: true to delete it anyway.`,
+                ),
+            );
+        }
+
+        logger.info(`Deleting folder ${args.folder_id}`);
+        await server.api.delete(`/folders/${folderId}`, { headers });
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        folder_id: args.folder_id,
+                        deleted: true,
+                        detached_agent_ids: agentIds,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (isAlreadyAbsent(args, error)) {
+            logger.info(`Folder ${args.folder_id} is already gone`);
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify({
+                            folder_id: args.folder_id,
+                            deleted: false,
+                            already_absent: true,
+                        }),
+                    },
+                ],
+            };
+        }
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Folder not found: ${args.folder_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for delete_folder
+ */
+export const deleteFolderDefinition = {
+    name: 'delete_folder',
+    description:
+        'Delete a folder (data source) and its files. Fails with a conflict error if agents still have the folder attached, unless force is set. WARNING: This action is permanent.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            folder_id: {
+                type: 'string',
+                description: 'ID of the folder to delete',
+            },
+            force: {
+                type: 'boolean',
+                description:
+                    'Delete the folder even if agents have it attached; it is removed from those agents (default: false)',
+                default: false,
+            },
+            ignore_missing: ignoreMissingProperty,
+        },
+        required: ['folder_id'],
+    },
+};
@@ -0,0 +1,15 @@
+/**
+ * Summarize a Letta folder (source) for tool responses
+ * @param {Object} folder - Folder returned by the Letta API
+ * @returns {Object} id, name, description, embedding model and timestamps
+ */
+export function toFolderMetadata(folder) {
+    return {
+        id: folder.id,
+        name: folder.name,
+        description: folder.description ?? null,
+        embedding_model: folder.embedding_config?.embedding_model ?? null,
+        created_at: folder.created_at,
+        updated_at: folder.updated_at,
+    };
+}
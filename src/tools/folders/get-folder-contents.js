@@ -0,0 +1,112 @@
+import { createLogger } from '../../core/logger.js';
+import { fitToResponseSize } from '../../core/response-size.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('get_folder_contents');
+
+const DEFAULT_LIMIT = 100;
+
+/**
+ * Summarize a file entry, keeping its extracted text only when it was requested
+ */
+function toFileMetadata(file, includeContent) {
+    return {
+        id: file.id,
+        filename: file.original_file_name || file.file_name,
+        size: file.file_size ?? null,
+        mime_type: file.file_type ?? null,
+        processing_status: file.processing_status,
+        created_at: file.created_at,
+        ...(includeContent && { content: file.content ?? null }),
+    };
+}
+
+/**
+ * Tool handler for listing the files in a folder
+ */
+export async function handleGetFolderContents(server, args) {
+    if (!args?.folder_id) {
+        server.createErrorResponse('Missing required argument: folder_id');
+    }
+    const limit = args.limit ?? DEFAULT_LIMIT;
+    if (!Number.isInteger(limit) || limit < 1) {
+        server.createErrorResponse(
+            invalidRequest('limit', 'Invalid argument: limit must be a positive integer'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const folderId = encodeURIComponent(args.folder_id);
+        const includeContent = Boolean(args.include_content);
+
+        const params = { limit };
+        if (args.after) params.after = args.after;
+        if (includeContent) params.include_content = true;
+
+        const response = await server.api.get(`/folders/${folderId}/files`, { headers, params });
+        if (!Array.isArray(response.data)) {
+            throw new Error('Unexpected response from folder files: expected an array');
+        }
+        const files = response.data.map((file) => toFileMetadata(file, includeContent));
+        const fullPage = files.length >= limit;
+        logger.info(`Listed ${files.length} files in folder ${args.folder_id}`);
+
+        // File text can be large; oversized pages are cut short and continue from next_cursor
+        const payload = fitToResponseSize(files, (page, truncated) => ({
+            folder_id: args.folder_id,
+            count: page.length,
+            files: page,
+            next_cursor: truncated || fullPage ? (page[page.length - 1]?.id ?? null) : null,
+            ...(truncated && { truncated: true }),
+        }));
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(payload),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Folder not found: ${args.folder_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for get_folder_contents
+ */
+export const getFolderContentsDefinition = {
+    name: 'get_folder_contents',
+    description:
+        "List the files in a folder with their id, filename, size and mime_type. Set include_content to also return each file's extracted text. A full page returns next_cursor; pass it as after to continue.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            folder_id: {
+                type: 'string',
+                description: 'ID of the folder to list',
+            },
+            limit: {
+                type: 'integer',
+                description: `Maximum number of files to return (default: ${DEFAULT_LIMIT})`,
+                minimum: 1,
+                default: DEFAULT_LIMIT,
+            },
+            after: {
+                type: 'string',
+                description: 'Cursor: return files after this file ID (use next_cursor)',
+            },
+            include_content: {
+                type: 'boolean',
+                description: "Include each file's extracted text content (default: false)",
+                default: false,
+            },
+        },
+        required: ['folder_id'],
+    },
+};
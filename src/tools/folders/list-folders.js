@@ -0,0 +1,109 @@
+import { createLogger } from '../../core/logger.js';
+import { mapSettledWithConcurrency } from '../../core/concurrency.js';
+import { toFolderMetadata } from './folder-metadata.js';
+
+const logger = createLogger('list_folders');
+
+const FILES_PAGE_SIZE = 1000;
+
+/**
+ * Count the files in a folder, paging through the listing
+ */
+async function countFolderFiles(server, headers, folderId) {
+    let count = 0;
+    let after;
+    for (;;) {
+        const params = { limit: FILES_PAGE_SIZE };
+        if (after) params.after = after;
+        const response = await server.api.get(`/folders/${folderId}/files`, { headers, params });
+        const page = Array.isArray(response.data) ? response.data : [];
+        count += page.length;
+        if (page.length < FILES_PAGE_SIZE) return count;
+        after = page[page.length - 1].id;
+    }
+}
+
+/**
+ * Tool handler for listing folders (data sources)
+ */
+export async function handleListFolders(server, args) {
+    try {
+        const headers = server.getApiHeaders();
+
+        const response = await server.api.get('/folders/', { headers });
+        if (!Array.isArray(response.data)) {
+            throw new Error('Unexpected response from /folders/: expected an array');
+        }
+
+        const folders = response.data.map(toFolderMetadata);
+
+        // Counts take two requests per folder, so they are only fetched when asked for
+        if (args?.include_counts) {
+            const maxConcurrency = server.maxConcurrency;
+            const results = await mapSettledWithConcurrency(
+                folders,
+                maxConcurrency,
+                async (folder) => {
+                    const folderId = encodeURIComponent(folder.id);
+                    const [fileCount, agentsResponse] = await Promise.all([
+                        countFolderFiles(server, headers, folderId),
+                        server.api.get(`/folders/${folderId}/agents`, { headers }),
+                    ]);
+                    const agentIds = Array.isArray(agentsResponse.data) ? agentsResponse.data : [];
+                    return { file_count: fileCount, agent_count: agentIds.length };
+                },
+            );
+            results.forEach((result, index) => {
+                if (result.status === 'fulfilled') {
+                    Object.assign(folders[index], result.value);
+                } else {
+                    logger.warn(
+                        `Failed to count contents of folder ${folders[index].id}:`,
+                        result.reason.message,
+                    );
+                    Object.assign(folders[index], {
+                        file_count: null,
+                        agent_count: null,
+                        count_error: result.reason.message,
+                    });
+                }
+            });
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        count: folders.length,
+                        folders,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed to list folders');
+    }
+}
+
+/**
+ * Tool definition for list_folders
+ */
+export const listFoldersDefinition = {
+    name: 'list_folders',
+    description:
+        "List the folders (data sources) available to attach to agents. Set include_counts to add each folder's file_count and agent_count; this makes extra requests per folder, so leave it off for quick listings.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            include_counts: {
+                type: 'boolean',
+                description:
+                    'Include file_count and agent_count for each folder (two extra requests per folder; default: false)',
+                default: false,
+            },
+        },
+        required: [],
+    },
+};
@@ -11,6 +11,13 @@ import { handleImportAgent, importAgentDefinition } from './agents/import-agent.
 import { handleCloneAgent, cloneAgentDefinition } from './agents/clone-agent.js';
 import { handleGetAgentSummary, getAgentSummaryDefinition } from './agents/get-agent-summary.js';
 import { handleBulkDeleteAgents, bulkDeleteAgentsDefinition } from './agents/bulk-delete-agents.js';
+import {
+    handleFindAgentsByName,
+    findAgentsByNameDefinition,
+} from './agents/find-agents-by-name.js';
+import { handleBulkExportAgents, bulkExportAgentsDefinition } from './agents/bulk-export-agents.js';
+import { handleDescribeAgent, describeAgentDefinition } from './agents/describe-agent.js';
+import { handleMigrateAgent, migrateAgentDefinition } from './agents/migrate-agent.js';
 
 // Memory-related imports
 import {
@@ -33,12 +40,38 @@ import {
     handleCreateMemoryBlock,
     createMemoryBlockToolDefinition,
 } from './memory/create-memory-block.js';
+import {
+    handleListBlockTemplates,
+    listBlockTemplatesToolDefinition,
+} from './memory/list-block-templates.js';
+import {
+    handleCreateBlockFromTemplate,
+    createBlockFromTemplateToolDefinition,
+} from './memory/create-block-from-template.js';
+import {
+    handleGetAgentMemoryUsage,
+    getAgentMemoryUsageToolDefinition,
+} from './memory/get-agent-memory-usage.js';
+import { handleListAllBlocks, listAllBlocksToolDefinition } from './memory/list-all-blocks.js';
+import {
+    handleDeleteMemoryBlock,
+    deleteMemoryBlockToolDefinition,
+} from './memory/delete-memory-block.js';
 
 // Passage-related imports
 import { handleListPassages, listPassagesDefinition } from './passages/list-passages.js';
 import { handleCreatePassage, createPassageDefinition } from './passages/create-passage.js';
 import { handleModifyPassage, modifyPassageDefinition } from './passages/modify-passage.js';
 import { handleDeletePassage, deletePassageDefinition } from './passages/delete-passage.js';
+import { handleCountPassages, countPassagesDefinition } from './passages/count-passages.js';
+import {
+    handleBulkCreatePassages,
+    bulkCreatePassagesDefinition,
+} from './passages/bulk-create-passages.js';
+import {
+    handleSearchArchivalMemory,
+    searchArchivalMemoryDefinition,
+} from './passages/search-archival-memory.js';
 
 // Tool-related imports
 import { handleAttachTool, attachToolToolDefinition } from './tools/attach-tool.js';
@@ -47,6 +80,27 @@ import {
     bulkAttachToolDefinition,
 } from './tools/bulk-attach-tool.js';
 import { handleUploadTool, uploadToolToolDefinition } from './tools/upload-tool.js';
+import {
+    handleGenerateToolSchema,
+    generateToolSchemaDefinition,
+} from './tools/generate-tool-schema.js';
+import {
+    handleGenerateToolFromPrompt,
+    generateToolFromPromptDefinition,
+} from './tools/generate-tool-from-prompt.js';
+import { handleDetachTool, detachToolDefinition } from './tools/detach-tool.js';
+import { handleListTools, listToolsDefinition } from './tools/list-tools.js';
+import {
+    handleRunToolFromSource,
+    runToolFromSourceDefinition,
+} from './tools/run-tool-from-source.js';
+import {
+    handleBulkAttachToolsToAgent,
+    bulkAttachToolsToAgentDefinition,
+} from './tools/bulk-attach-tools-to-agent.js';
+import { handleGetToolSource, getToolSourceDefinition } from './tools/get-tool-source.js';
+import { handlePatchToolSource, patchToolSourceDefinition } from './tools/patch-tool-source.js';
+import { handleListToolRuns, listToolRunsDefinition } from './tools/list-tool-runs.js';
 
 // MCP-related imports
 import {
@@ -58,6 +112,8 @@ import {
     handleAddMcpToolToLetta,
     addMcpToolToLettaDefinition,
 } from './mcp/add-mcp-tool-to-letta.js';
+import { handleConnectMcpServer, connectMcpServerDefinition } from './mcp/connect-mcp-server.js';
+import { handleTestMcpServer, testMcpServerDefinition } from './mcp/test-mcp-server.js';
 
 // Model-related imports
 import { handleListLlmModels, listLlmModelsDefinition } from './models/list-llm-models.js';
@@ -70,6 +126,92 @@ import {
 import { handleListPrompts, listPromptsToolDefinition } from './prompts/list-prompts.js';
 import { handleUsePrompt, usePromptToolDefinition } from './prompts/use-prompt.js';
 
+// Message-related imports
+import { handleCancelAgentRun, cancelAgentRunDefinition } from './messages/cancel-agent-run.js';
+import {
+    handleSummarizeAgentConversation,
+    summarizeAgentConversationDefinition,
+} from './messages/summarize-agent-conversation.js';
+import {
+    handleListAgentMessages,
+    listAgentMessagesDefinition,
+} from './messages/list-agent-messages.js';
+import { handleBulkSendMessage, bulkSendMessageDefinition } from './messages/bulk-send-message.js';
+import {
+    handleModifyAgentMessage,
+    modifyAgentMessageDefinition,
+} from './messages/modify-agent-message.js';
+import {
+    handleDeleteAgentMessage,
+    deleteAgentMessageDefinition,
+} from './messages/delete-agent-message.js';
+import { handleWaitForRun, waitForRunDefinition } from './messages/wait-for-run.js';
+import {
+    handleExportAgentMessages,
+    exportAgentMessagesDefinition,
+} from './messages/export-agent-messages.js';
+import {
+    handleEstimateMessageTokens,
+    estimateMessageTokensDefinition,
+} from './messages/estimate-message-tokens.js';
+import {
+    handleResetAgentMessages,
+    resetAgentMessagesDefinition,
+} from './messages/reset-agent-messages.js';
+import {
+    handleCountAgentMessages,
+    countAgentMessagesDefinition,
+} from './messages/count-agent-messages.js';
+
+// System-related imports
+import {
+    handleGetServerCapabilities,
+    getServerCapabilitiesDefinition,
+} from './system/get-server-capabilities.js';
+import { handleListOperations, listOperationsDefinition } from './system/list-operations.js';
+import {
+    handleGetOperationSchema,
+    getOperationSchemaDefinition,
+} from './system/get-operation-schema.js';
+
+// Source-related imports
+import {
+    handleListAgentsUsingSource,
+    listAgentsUsingSourceDefinition,
+} from './sources/list-agents-using-source.js';
+import {
+    handleGetSourceFileContent,
+    getSourceFileContentDefinition,
+} from './sources/get-source-file-content.js';
+import { handleUploadFileBegin, uploadFileBeginDefinition } from './sources/upload-file-begin.js';
+import { handleUploadFileChunk, uploadFileChunkDefinition } from './sources/upload-file-chunk.js';
+import {
+    handleUploadFileComplete,
+    uploadFileCompleteDefinition,
+} from './sources/upload-file-complete.js';
+import { handleReprocessSource, reprocessSourceDefinition } from './sources/reprocess-source.js';
+import {
+    handleListSourcePassages,
+    listSourcePassagesDefinition,
+} from './sources/list-source-passages.js';
+import { handleAttachSource, attachSourceDefinition } from './sources/attach-source.js';
+import { handleDetachSource, detachSourceDefinition } from './sources/detach-source.js';
+
+// Folder-related imports
+import { handleListFolders, listFoldersDefinition } from './folders/list-folders.js';
+import { handleCreateFolder, createFolderDefinition } from './folders/create-folder.js';
+import { handleDeleteFolder, deleteFolderDefinition } from './folders/delete-folder.js';
+import {
+    handleGetFolderContents,
+    getFolderContentsDefinition,
+} from './folders/get-folder-contents.js';
+
+// Job-related imports
+import { handleGetJob, getJobDefinition } from './jobs/get-job.js';
+import { handleGetJobResult, getJobResultDefinition } from './jobs/get-job-result.js';
+import { handleListJobs, listJobsDefinition } from './jobs/list-jobs.js';
+import { handleWaitForJob, waitForJobDefinition } from './jobs/wait-for-job.js';
+
 import {
     CallToolRequestSchema,
     ListToolsRequestSchema,
@@ -77,6 +219,17 @@ import {
     ErrorCode,
 } from '@modelcontextprotocol/sdk/types.js';
 import { enhanceAllTools } from './enhance-tools.js';
+import { runWithScope } from '../core/scope.js';
+import { withResponseCache } from '../core/cache.js';
+import { withIdempotency } from '../core/idempotency.js';
+import { withJsonFormat } from '../core/json-format.js';
+import { validateArguments } from '../core/argument-validation.js';
+import { metricsToolName, withMetrics } from '../core/metrics.js';
+import { createLogger } from '../core/logger.js';
+import { withSpan } from '../core/tracing.js';
+import { invalidRequest } from '../core/errors.js';
+
+const callLogger = createLogger('tool_call');
 
 /**
  * Register all tool handlers with the server
@@ -116,6 +269,60 @@ export function registerToolHandlers(server) {
         addMcpToolToLettaDefinition,
         listPromptsToolDefinition,
         usePromptToolDefinition,
+        cancelAgentRunDefinition,
+        summarizeAgentConversationDefinition,
+        listAgentMessagesDefinition,
+        generateToolSchemaDefinition,
+        generateToolFromPromptDefinition,
+        connectMcpServerDefinition,
+        getServerCapabilitiesDefinition,
+        listAgentsUsingSourceDefinition,
+        bulkSendMessageDefinition,
+        modifyAgentMessageDefinition,
+        deleteAgentMessageDefinition,
+        waitForRunDefinition,
+        getSourceFileContentDefinition,
+        uploadFileBeginDefinition,
+        uploadFileChunkDefinition,
+        uploadFileCompleteDefinition,
+        listFoldersDefinition,
+        createFolderDefinition,
+        deleteFolderDefinition,
+        getFolderContentsDefinition,
+        getJobDefinition,
+        getJobResultDefinition,
+        listJobsDefinition,
+        waitForJobDefinition,
+        detachToolDefinition,
+        listToolsDefinition,
+        runToolFromSourceDefinition,
+        countPassagesDefinition,
+        bulkCreatePassagesDefinition,
+        listBlockTemplatesToolDefinition,
+        createBlockFromTemplateToolDefinition,
+        findAgentsByNameDefinition,
+        bulkAttachToolsToAgentDefinition,
+        getToolSourceDefinition,
+        patchToolSourceDefinition,
+        reprocessSourceDefinition,
+        listSourcePassagesDefinition,
+        testMcpServerDefinition,
+        getAgentMemoryUsageToolDefinition,
+        bulkExportAgentsDefinition,
+        exportAgentMessagesDefinition,
+        estimateMessageTokensDefinition,
+        searchArchivalMemoryDefinition,
+        listOperationsDefinition,
+        getOperationSchemaDefinition,
+        resetAgentMessagesDefinition,
+        attachSourceDefinition,
+        detachSourceDefinition,
+        describeAgentDefinition,
+        listAllBlocksToolDefinition,
+        deleteMemoryBlockToolDefinition,
+        countAgentMessagesDefinition,
+        migrateAgentDefinition,
+        listToolRunsDefinition,
     ];
 
     // Enhance all tools with output schemas and improved descriptions
@@ -125,81 +332,240 @@ export function registerToolHandlers(server) {
     server.server.setRequestHandler(ListToolsRequestSchema, async () => ({
         tools: enhancedTools,
     }));
+    server.toolDefinitions = enhancedTools;
+    const inputSchemas = new Map(enhancedTools.map((tool) => [tool.name, tool.inputSchema]));
 
-    // Register tool call handler
-    server.server.setRequestHandler(CallToolRequestSchema, async (request) => {
-        switch (request.params.name) {
-            case 'list_agents':
-                return handleListAgents(server, request.params.arguments);
-            case 'prompt_agent':
-                return handlePromptAgent(server, request.params.arguments);
-            case 'list_agent_tools':
-                return handleListAgentTools(server, request.params.arguments);
-            case 'create_agent':
-                return handleCreateAgent(server, request.params.arguments);
-            case 'attach_tool':
-                return handleAttachTool(server, request.params.arguments);
-            case 'list_memory_blocks':
-                return handleListMemoryBlocks(server, request.params.arguments);
-            case 'read_memory_block':
-                return handleReadMemoryBlock(server, request.params.arguments);
-            case 'update_memory_block':
-                return handleUpdateMemoryBlock(server, request.params.arguments);
-            case 'attach_memory_block':
-                return handleAttachMemoryBlock(server, request.params.arguments);
-            case 'create_memory_block':
-                return handleCreateMemoryBlock(server, request.params.arguments);
-            case 'upload_tool':
-                return handleUploadTool(server, request.params.arguments);
-            case 'list_mcp_tools_by_server':
-                return handleListMcpToolsByServer(server, request.params.arguments);
-            case 'list_mcp_servers':
-                return handleListMcpServers(server, request.params.arguments);
-            case 'retrieve_agent':
-                return handleRetrieveAgent(server, request.params.arguments);
-            case 'modify_agent':
-                return handleModifyAgent(server, request.params.arguments);
-            case 'delete_agent':
-                return handleDeleteAgent(server, request.params.arguments);
-            case 'list_llm_models':
-                return handleListLlmModels(server, request.params.arguments);
-            case 'list_embedding_models':
-                return handleListEmbeddingModels(server, request.params.arguments);
-            case 'list_passages':
-                return handleListPassages(server, request.params.arguments);
-            case 'create_passage':
-                return handleCreatePassage(server, request.params.arguments);
-            case 'modify_passage':
-                return handleModifyPassage(server, request.params.arguments);
-            case 'delete_passage':
-                return handleDeletePassage(server, request.params.arguments);
-            case 'export_agent':
-                return handleExportAgent(server, request.params.arguments);
-            case 'import_agent':
-                return handleImportAgent(server, request.params.arguments);
-            case 'clone_agent':
-                return handleCloneAgent(server, request.params.arguments);
-            case 'bulk_attach_tool_to_agents':
-                return handleBulkAttachToolToAgents(server, request.params.arguments);
-            case 'get_agent_summary':
-                return handleGetAgentSummary(server, request.params.arguments);
-            case 'bulk_delete_agents':
-                return handleBulkDeleteAgents(server, request.params.arguments);
-            case 'add_mcp_tool_to_letta':
-                return handleAddMcpToolToLetta(server, request.params.arguments);
-            case 'list_prompts':
-                return handleListPrompts(server, request.params.arguments);
-            case 'use_prompt':
-                return handleUsePrompt(server, request.params.arguments);
-            default:
-                throw new McpError(
-                    ErrorCode.MethodNotFound,
-                    `Unknown tool: ${request.params.name}`,
+    // Register tool call handler. Every Letta request made while handling a call is scoped to
+    // its project_id argument, if given, and list results may be served from the response cache.
+    // Each call runs in a tracing span and its outcome and duration is recorded in the metrics,
+    // under `unknown` for names that aren't registered tools.
+    // Responses are compact JSON unless LETTA_JSON_COMPACT=false or the call sets pretty_json,
+    // and YAML when the call sets format: "yaml".
+    // Calls are tracked so shutdown can wait for them, and refused once shutdown has begun.
+    // Arguments are checked against the tool's input schema first, reporting every problem
+    // (including arguments the tool doesn't accept, unless LETTA_UNKNOWN_ARGUMENTS says otherwise).
+    server.server.setRequestHandler(CallToolRequestSchema, async (request, extra) => {
+        const { name, arguments: args } = request.params;
+        return withSpan(callLogger, name, args, () =>
+            withMetrics(server, metricsToolName(name, inputSchemas), async () => {
+                if (server.inFlight.draining) {
+                    server.createErrorResponse('Server is shutting down; try again shortly');
+                }
+                if (args?.project_id !== undefined && typeof args.project_id !== 'string') {
+                    server.createErrorResponse(
+                        invalidRequest(
+                            'project_id',
+                            'Invalid argument: project_id must be a string',
+                        ),
+                    );
+                }
+                if (inputSchemas.has(name)) {
+                    try {
+                        validateArguments(inputSchemas.get(name), args, {
+                            toolName: name,
+                            unknownArguments: server.unknownArguments,
+                            logger: callLogger,
+                        });
+                    } catch (error) {
+                        server.createErrorResponse(error);
+                    }
+                }
+                return server.inFlight.track(name, args, () =>
+                    withJsonFormat(server, args, () =>
+                        runWithScope(args, () =>
+                            withIdempotency(server, name, args, () =>
+                                withResponseCache(server, name, args, () =>
+                                    callTool(server, request, extra),
+                                ),
+                            ),
+                        ),
+                    ),
                 );
-        }
+            }),
+        );
     });
 }
 
+/**
+ * Dispatch a tool call to its handler
+ */
+function callTool(server, request, extra) {
+    switch (request.params.name) {
+        case 'list_agents':
+            return handleListAgents(server, request.params.arguments);
+        case 'prompt_agent':
+            return handlePromptAgent(server, request.params.arguments, extra);
+        case 'list_agent_tools':
+            return handleListAgentTools(server, request.params.arguments);
+        case 'create_agent':
+            return handleCreateAgent(server, request.params.arguments);
+        case 'attach_tool':
+            return handleAttachTool(server, request.params.arguments);
+        case 'list_memory_blocks':
+            return handleListMemoryBlocks(server, request.params.arguments);
+        case 'read_memory_block':
+            return handleReadMemoryBlock(server, request.params.arguments);
+        case 'update_memory_block':
+            return handleUpdateMemoryBlock(server, request.params.arguments);
+        case 'attach_memory_block':
+            return handleAttachMemoryBlock(server, request.params.arguments);
+        case 'create_memory_block':
+            return handleCreateMemoryBlock(server, request.params.arguments);
+        case 'upload_tool':
+            return handleUploadTool(server, request.params.arguments);
+        case 'list_mcp_tools_by_server':
+            return handleListMcpToolsByServer(server, request.params.arguments);
+        case 'list_mcp_servers':
+            return handleListMcpServers(server, request.params.arguments);
+        case 'retrieve_agent':
+            return handleRetrieveAgent(server, request.params.arguments);
+        case 'modify_agent':
+            return handleModifyAgent(server, request.params.arguments);
+        case 'delete_agent':
+            return handleDeleteAgent(server, request.params.arguments);
+        case 'list_llm_models':
+            return handleListLlmModels(server, request.params.arguments);
+        case 'list_embedding_models':
+            return handleListEmbeddingModels(server, request.params.arguments);
+        case 'list_passages':
+            return handleListPassages(server, request.params.arguments);
+        case 'create_passage':
+            return handleCreatePassage(server, request.params.arguments);
+        case 'modify_passage':
+            return handleModifyPassage(server, request.params.arguments);
+        case 'delete_passage':
+            return handleDeletePassage(server, request.params.arguments);
+        case 'export_agent':
+            return handleExportAgent(server, request.params.arguments);
+        case 'import_agent':
+            return handleImportAgent(server, request.params.arguments);
+        case 'clone_agent':
+            return handleCloneAgent(server, request.params.arguments);
+        case 'bulk_attach_tool_to_agents':
+            return handleBulkAttachToolToAgents(server, request.params.arguments);
+        case 'get_agent_summary':
+            return handleGetAgentSummary(server, request.params.arguments);
+        case 'bulk_delete_agents':
+            return handleBulkDeleteAgents(server, request.params.arguments, extra);
+        case 'add_mcp_tool_to_letta':
+            return handleAddMcpToolToLetta(server, request.params.arguments);
+        case 'list_prompts':
+            return handleListPrompts(server, request.params.arguments);
+        case 'use_prompt':
+            return handleUsePrompt(server, request.params.arguments);
+        case 'cancel_agent_run':
+            return handleCancelAgentRun(server, request.params.arguments);
+        case 'summarize_agent_conversation':
+            return handleSummarizeAgentConversation(server, request.params.arguments);
+        case 'list_agent_messages':
+            return handleListAgentMessages(server, request.params.arguments);
+        case 'generate_tool_schema':
+            return handleGenerateToolSchema(server, request.params.arguments);
+        case 'generate_tool_from_prompt':
+            return handleGenerateToolFromPrompt(server, request.params.arguments);
+        case 'connect_mcp_server':
+            return handleConnectMcpServer(server, request.params.arguments);
+        case 'get_server_capabilities':
+            return handleGetServerCapabilities(server, request.params.arguments);
+        case 'list_agents_using_source':
+            return handleListAgentsUsingSource(server, request.params.arguments);
+        case 'bulk_send_message':
+            return handleBulkSendMessage(server, request.params.arguments);
+        case 'modify_agent_message':
+            return handleModifyAgentMessage(server, request.params.arguments);
+        case 'delete_agent_message':
+            return handleDeleteAgentMessage(server, request.params.arguments);
+        case 'wait_for_run':
+            return handleWaitForRun(server, request.params.arguments);
+        case 'get_source_file_content':
+            return handleGetSourceFileContent(server, request.params.arguments);
+        case 'upload_file_begin':
+            return handleUploadFileBegin(server, request.params.arguments);
+        case 'upload_file_chunk':
+            return handleUploadFileChunk(server, request.params.arguments);
+        case 'upload_file_complete':
+            return handleUploadFileComplete(server, request.params.arguments);
+        case 'list_folders':
+            return handleListFolders(server, request.params.arguments);
+        case 'create_folder':
+            return handleCreateFolder(server, request.params.arguments);
+        case 'delete_folder':
+            return handleDeleteFolder(server, request.params.arguments);
+        case 'get_folder_contents':
+            return handleGetFolderContents(server, request.params.arguments);
+        case 'get_job':
+            return handleGetJob(server, request.params.arguments);
+        case 'get_job_result':
+            return handleGetJobResult(server, request.params.arguments);
+        case 'list_jobs':
+            return handleListJobs(server, request.params.arguments);
+        case 'wait_for_job':
+            return handleWaitForJob(server, request.params.arguments);
+        case 'detach_tool':
+            return handleDetachTool(server, request.params.arguments);
+        case 'list_tools':
+            return handleListTools(server, request.params.arguments);
+        case 'run_tool_from_source':
+            return handleRunToolFromSource(server, request.params.arguments);
+        case 'count_passages':
+            return handleCountPassages(server, request.params.arguments);
+        case 'bulk_create_passages':
+            return handleBulkCreatePassages(server, request.params.arguments);
+        case 'list_block_templates':
+            return handleListBlockTemplates(server, request.params.arguments);
+        case 'create_block_from_template':
+            return handleCreateBlockFromTemplate(server, request.params.arguments);
+        case 'find_agents_by_name':
+            return handleFindAgentsByName(server, request.params.arguments);
+        case 'bulk_attach_tools_to_agent':
+            return handleBulkAttachToolsToAgent(server, request.params.arguments);
+        case 'get_tool_source':
+            return handleGetToolSource(server, request.params.arguments);
+        case 'patch_tool_source':
+            return handlePatchToolSource(server, request.params.arguments);
+        case 'reprocess_source':
+            return handleReprocessSource(server, request.params.arguments);
+        case 'list_source_passages':
+            return handleListSourcePassages(server, request.params.arguments);
+        case 'test_mcp_server':
+            return handleTestMcpServer(server, request.params.arguments);
+        case 'get_agent_memory_usage':
+            return handleGetAgentMemoryUsage(server, request.params.arguments);
+        case 'bulk_export_agents':
+            return handleBulkExportAgents(server, request.params.arguments);
+        case 'export_agent_messages':
+            return handleExportAgentMessages(server, request.params.arguments);
+        case 'estimate_message_tokens':
+            return handleEstimateMessageTokens(server, request.params.arguments);
+        case 'search_archival_memory':
+            return handleSearchArchivalMemory(server, request.params.arguments);
+        case 'list_operations':
+            return handleListOperations(server, request.params.arguments);
+        case 'get_operation_schema':
+            return handleGetOperationSchema(server, request.params.arguments);
+        case 'reset_agent_messages':
+            return handleResetAgentMessages(server, request.params.arguments);
+        case 'attach_source':
+            return handleAttachSource(server, request.params.arguments);
+        case 'detach_source':
+            return handleDetachSource(server, request.params.arguments);
+        case 'describe_agent':
+            return handleDescribeAgent(server, request.params.arguments);
+        case 'list_all_blocks':
+            return handleListAllBlocks(server, request.params.arguments);
+        case 'delete_memory_block':
+            return handleDeleteMemoryBlock(server, request.params.arguments);
+        case 'count_agent_messages':
+            return handleCountAgentMessages(server, request.params.arguments);
+        case 'migrate_agent':
+            return handleMigrateAgent(server, request.params.arguments);
+        case 'list_tool_runs':
+            return handleListToolRuns(server, request.params.arguments);
+        default:
+            throw new McpError(ErrorCode.MethodNotFound, `Unknown tool: ${request.params.name}`);
+    }
+}
+
 // Export all tool definitions (enhanced)
 export const toolDefinitions = enhanceAllTools([
     listAgentsToolDefinition,
@@ -233,6 +599,60 @@ export const toolDefinitions = enhanceAllTools([
     addMcpToolToLettaDefinition,
     listPromptsToolDefinition,
     usePromptToolDefinition,
+    cancelAgentRunDefinition,
+    summarizeAgentConversationDefinition,
+    listAgentMessagesDefinition,
+    generateToolSchemaDefinition,
+    generateToolFromPromptDefinition,
+    connectMcpServerDefinition,
+    getServerCapabilitiesDefinition,
+    listAgentsUsingSourceDefinition,
+    bulkSendMessageDefinition,
+    modifyAgentMessageDefinition,
+    deleteAgentMessageDefinition,
+    waitForRunDefinition,
+    getSourceFileContentDefinition,
+    uploadFileBeginDefinition,
+    uploadFileChunkDefinition,
+    uploadFileCompleteDefinition,
+    listFoldersDefinition,
+    createFolderDefinition,
+    deleteFolderDefinition,
+    getFolderContentsDefinition,
+    getJobDefinition,
+    getJobResultDefinition,
+    listJobsDefinition,
+    waitForJobDefinition,
+    detachToolDefinition,
+    listToolsDefinition,
+    runToolFromSourceDefinition,
+    countPassagesDefinition,
+    bulkCreatePassagesDefinition,
+    listBlockTemplatesToolDefinition,
+    createBlockFromTemplateToolDefinition,
+    findAgentsByNameDefinition,
+    bulkAttachToolsToAgentDefinition,
+    getToolSourceDefinition,
+    patchToolSourceDefinition,
+    reprocessSourceDefinition,
+    listSourcePassagesDefinition,
+    testMcpServerDefinition,
+    getAgentMemoryUsageToolDefinition,
+    bulkExportAgentsDefinition,
+    exportAgentMessagesDefinition,
+    estimateMessageTokensDefinition,
+    searchArchivalMemoryDefinition,
+    listOperationsDefinition,
+    getOperationSchemaDefinition,
+    resetAgentMessagesDefinition,
+    attachSourceDefinition,
+    detachSourceDefinition,
+    describeAgentDefinition,
+    listAllBlocksToolDefinition,
+    deleteMemoryBlockToolDefinition,
+    countAgentMessagesDefinition,
+    migrateAgentDefinition,
+    listToolRunsDefinition,
 ]);
 
 // Export all tool handlers
@@ -266,4 +686,58 @@ export const toolHandlers = {
     handleGetAgentSummary,
     handleBulkDeleteAgents,
     handleAddMcpToolToLetta,
+    handleCancelAgentRun,
+    handleSummarizeAgentConversation,
+    handleListAgentMessages,
+    handleGenerateToolSchema,
+    handleGenerateToolFromPrompt,
+    handleConnectMcpServer,
+    handleGetServerCapabilities,
+    handleListAgentsUsingSource,
+    handleBulkSendMessage,
+    handleModifyAgentMessage,
+    handleDeleteAgentMessage,
+    handleWaitForRun,
+    handleGetSourceFileContent,
+    handleUploadFileBegin,
+    handleUploadFileChunk,
+    handleUploadFileComplete,
+    handleListFolders,
+    handleCreateFolder,
+    handleDeleteFolder,
+    handleGetFolderContents,
+    handleGetJob,
+    handleGetJobResult,
+    handleListJobs,
+    handleWaitForJob,
+    handleDetachTool,
+    handleListTools,
+    handleRunToolFromSource,
+    handleCountPassages,
+    handleBulkCreatePassages,
+    handleListBlockTemplates,
+    handleCreateBlockFromTemplate,
+    handleFindAgentsByName,
+    handleBulkAttachToolsToAgent,
+    handleGetToolSource,
+    handlePatchToolSource,
+    handleReprocessSource,
+    handleListSourcePassages,
+    handleTestMcpServer,
+    handleGetAgentMemoryUsage,
+    handleBulkExportAgents,
+    handleExportAgentMessages,
+    handleEstimateMessageTokens,
+    handleSearchArchivalMemory,
+    handleListOperations,
+    handleGetOperationSchema,
+    handleResetAgentMessages,
+    handleAttachSource,
+    handleDetachSource,
+    handleDescribeAgent,
+    handleListAllBlocks,
+    handleDeleteMemoryBlock,
+    handleCountAgentMessages,
+    handleMigrateAgent,
+    handleListToolRuns,
 };
@@ -0,0 +1,71 @@
+import { jobError } from './job-status.js';
+import { httpError } from '../../core/errors.js';
+
+/**
+ * Tool handler for getting the output of a finished job
+ */
+export async function handleGetJobResult(server, args) {
+    if (!args?.job_id) {
+        server.createErrorResponse('Missing required argument: job_id');
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const response = await server.api.get(`/jobs/${encodeURIComponent(args.job_id)}`, {
+            headers,
+        });
+        const job = response.data;
+
+        if (job.status === 'failed') {
+            server.createErrorResponse(`Job ${args.job_id} failed: ${jobError(job)}`);
+        }
+        if (job.status !== 'completed') {
+            server.createErrorResponse(
+                `Job ${args.job_id} has no result yet (status: ${job.status}). Check again with get_job.`,
+            );
+        }
+
+        // Letta records a job's output in its metadata; some jobs nest it under `result`
+        const metadata = job.metadata || {};
+        const result = metadata.result !== undefined ? metadata.result : metadata;
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        job_id: args.job_id,
+                        job_type: job.job_type,
+                        status: job.status,
+                        completed_at: job.completed_at ?? null,
+                        result,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Job not found: ${args.job_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for get_job_result
+ */
+export const getJobResultDefinition = {
+    name: 'get_job_result',
+    description:
+        "Get the output of a completed job. Fails with the job's error if it failed, or with its current status if it hasn't finished (use get_job to check progress).",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            job_id: {
+                type: 'string',
+                description: 'ID of the completed job',
+            },
+        },
+        required: ['job_id'],
+    },
+};
@@ -0,0 +1,50 @@
+import { summarizeJob } from './job-status.js';
+import { fetchJob } from './local-jobs.js';
+import { httpError } from '../../core/errors.js';
+
+/**
+ * Tool handler for getting a job's status
+ */
+export async function handleGetJob(server, args) {
+    if (!args?.job_id) {
+        server.createErrorResponse('Missing required argument: job_id');
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const job = await fetchJob(server, args.job_id, headers);
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({ job: summarizeJob(job) }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Job not found: ${args.job_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for get_job
+ */
+export const getJobDefinition = {
+    name: 'get_job',
+    description:
+        "Get a background job's status, with progress_percent when the job reports step counts and error for failed jobs. Use get_job_result for a completed job's output. Also reports jobs this server runs itself, such as migrate_agent's.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            job_id: {
+                type: 'string',
+                description: 'ID of the job',
+            },
+        },
+        required: ['job_id'],
+    },
+};
@@ -0,0 +1,76 @@
+/**
+ * Helpers for reading Letta jobs (file processing, agent runs and other background work).
+ */
+
+// Statuses after which a job will not change again
+export const TERMINAL_JOB_STATUSES = new Set(['completed', 'failed', 'cancelled', 'expired']);
+
+// Metadata fields Letta jobs use to report step progress, as [done, total] pairs
+const PROGRESS_FIELDS = [
+    ['completed_steps', 'total_steps'],
+    ['processed_chunks', 'total_chunks'],
+    ['num_processed', 'num_total'],
+];
+
+/**
+ * Progress of a job as a percentage, from its step counts when it reports them
+ * @param {Object} job - Job returned by the Letta API
+ * @returns {number|null} 0-100, or null when the job doesn't report progress
+ */
+export function jobProgressPercent(job) {
+    if (job.status === 'completed') return 100;
+    const metadata = job.metadata || {};
+    for (const [doneField, totalField] of PROGRESS_FIELDS) {
+        const done = metadata[doneField];
+        const total = metadata[totalField];
+        if (Number.isFinite(done) && Number.isFinite(total) && total > 0) {
+            return Math.min(100, Math.round((done / total) * 1000) / 10);
+        }
+    }
+    return null;
+}
+
+/**
+ * Error message of a failed job, or null
+ * @param {Object} job - Job returned by the Letta API
+ * @returns {string|null}
+ */
+export function jobError(job) {
+    if (job.status !== 'failed') return null;
+    const metadata = job.metadata || {};
+    const error = metadata.error ?? metadata.error_message ?? metadata.exception;
+    if (error) return typeof error === 'string' ? error : JSON.stringify(error);
+    return job.stop_reason ? `Job failed (stop reason: ${job.stop_reason})` : 'Job failed';
+}
+
+/**
+ * ID of the ingestion job started by a file upload
+ *
+ * Older servers answer an upload with the Job itself; newer ones return the file's metadata,
+ * which carries the job ID (if any) alongside its processing_status.
+ * @param {Object} result - Response body of POST /sources/{source_id}/upload
+ * @returns {string|null}
+ */
+export function uploadJobId(result) {
+    if (!result || typeof result !== 'object') return null;
+    if ('processing_status' in result || 'file_name' in result) return result.job_id ?? null;
+    return result.id ?? null;
+}
+
+/**
+ * Summarize a job for tool responses
+ * @param {Object} job - Job returned by the Letta API
+ * @returns {Object}
+ */
+export function summarizeJob(job) {
+    return {
+        id: job.id,
+        job_type: job.job_type,
+        status: job.status,
+        progress_percent: jobProgressPercent(job),
+        error: jobError(job),
+        created_at: job.created_at,
+        completed_at: job.completed_at ?? null,
+        metadata: job.metadata ?? null,
+    };
+}
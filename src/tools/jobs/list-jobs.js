@@ -0,0 +1,118 @@
+import { createLogger } from '../../core/logger.js';
+import { summarizeJob } from './job-status.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('list_jobs');
+
+const JOB_STATUSES = ['created', 'pending', 'running', 'completed', 'failed', 'cancelled'];
+const JOB_TYPES = ['job', 'run', 'batch'];
+const ACTIVE_STATUSES = new Set(['created', 'pending', 'running']);
+const DEFAULT_LIMIT = 50;
+
+/**
+ * Tool handler for listing jobs, optionally filtered by status and type
+ */
+export async function handleListJobs(server, args = {}) {
+    if (args.status !== undefined && !JOB_STATUSES.includes(args.status)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'status',
+                `Invalid argument: status must be one of ${JOB_STATUSES.join(', ')}`,
+            ),
+        );
+    }
+    if (args.job_type !== undefined && !JOB_TYPES.includes(args.job_type)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'job_type',
+                `Invalid argument: job_type must be one of ${JOB_TYPES.join(', ')}`,
+            ),
+        );
+    }
+    const limit = args.limit ?? DEFAULT_LIMIT;
+    if (!Number.isInteger(limit) || limit < 1) {
+        server.createErrorResponse(
+            invalidRequest('limit', 'Invalid argument: limit must be a positive integer'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const filtered = Boolean(args.status || args.job_type);
+
+        // Active statuses come from the much smaller active-jobs listing. The full listing
+        // can't filter server-side, so it is only limited there when no filter applies.
+        const params = {};
+        if (args.source_id) params.source_id = args.source_id;
+        let url = '/jobs/';
+        if (args.status && ACTIVE_STATUSES.has(args.status)) {
+            url = '/jobs/active';
+        } else if (!filtered) {
+            params.limit = limit;
+        }
+
+        const response = await server.api.get(url, { headers, params });
+        if (!Array.isArray(response.data)) {
+            throw new Error(`Unexpected response from ${url}: expected an array`);
+        }
+
+        const matches = response.data.filter(
+            (job) =>
+                (!args.status || job.status === args.status) &&
+                (!args.job_type || job.job_type === args.job_type),
+        );
+        const jobs = matches.slice(0, limit).map(summarizeJob);
+        logger.info(`Listed ${jobs.length} of ${matches.length} matching jobs`);
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        count: jobs.length,
+                        total_matching: matches.length,
+                        jobs,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed to list jobs');
+    }
+}
+
+/**
+ * Tool definition for list_jobs
+ */
+export const listJobsDefinition = {
+    name: 'list_jobs',
+    description:
+        "List background jobs, filtered by status and job_type. Filtering by an active status (created, pending, running) only reads active jobs, which is much cheaper on busy servers. Use get_job for one job's details.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            status: {
+                type: 'string',
+                enum: JOB_STATUSES,
+                description: 'Only return jobs with this status',
+            },
+            job_type: {
+                type: 'string',
+                enum: JOB_TYPES,
+                description: 'Only return jobs of this type',
+            },
+            source_id: {
+                type: 'string',
+                description: 'Only return jobs for this data source (e.g. file processing)',
+            },
+            limit: {
+                type: 'integer',
+                description: `Maximum number of jobs to return (default: ${DEFAULT_LIMIT})`,
+                minimum: 1,
+                default: DEFAULT_LIMIT,
+            },
+        },
+        required: [],
+    },
+};
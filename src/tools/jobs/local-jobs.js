@@ -0,0 +1,71 @@
+import { randomUUID } from 'node:crypto';
+
+/**
+ * Jobs this server runs itself, for multi-step work that has no Letta job (such as
+ * migrate_agent re-embedding archival memory).
+ *
+ * They are shaped like Letta jobs, so get_job and wait_for_job report them the same way,
+ * with progress from metadata.completed_steps / total_steps and metadata.error on failure.
+ * Local jobs live in memory only and finished ones are dropped after LOCAL_JOB_TTL_MS.
+ */
+
+export const LOCAL_JOB_TTL_MS = 60 * 60 * 1000;
+
+/**
+ * Drop finished jobs whose TTL has lapsed
+ * @param {Object} server - LettaServer instance
+ * @param {number} [now] - Current time in milliseconds
+ */
+export function pruneLocalJobs(server, now = Date.now()) {
+    for (const [id, job] of server.localJobs) {
+        if (job.completed_at && Date.parse(job.completed_at) + LOCAL_JOB_TTL_MS <= now) {
+            server.localJobs.delete(id);
+        }
+    }
+}
+
+/**
+ * Start tracking a local job
+ * @param {Object} server - LettaServer instance
+ * @param {string} jobType - Kind of work, also used as the job ID prefix
+ * @param {Object} metadata - Initial metadata
+ * @returns {Object} The job, in the running state
+ */
+export function createLocalJob(server, jobType, metadata = {}) {
+    pruneLocalJobs(server);
+    const job = {
+        id: `${jobType}-${randomUUID()}`,
+        job_type: jobType,
+        status: 'running',
+        created_at: new Date().toISOString(),
+        completed_at: null,
+        metadata: { completed_steps: 0, total_steps: 0, ...metadata },
+    };
+    server.localJobs.set(job.id, job);
+    return job;
+}
+
+/**
+ * Mark a local job finished
+ * @param {Object} job - Job from createLocalJob
+ * @param {string|null} [error] - Failure message; the job completes when absent
+ */
+export function finishLocalJob(job, error = null) {
+    job.status = error ? 'failed' : 'completed';
+    job.completed_at = new Date().toISOString();
+    if (error) job.metadata.error = error;
+}
+
+/**
+ * Fetch a job by ID, from this server's local jobs or else the Letta API
+ * @param {Object} server - LettaServer instance
+ * @param {string} jobId - Job ID
+ * @param {Object} headers - Letta API headers
+ * @returns {Promise<Object>} The job
+ */
+export async function fetchJob(server, jobId, headers) {
+    const local = server.localJobs.get(jobId);
+    if (local) return local;
+    const response = await server.api.get(`/jobs/${encodeURIComponent(jobId)}`, { headers });
+    return response.data;
+}
@@ -0,0 +1,70 @@
+import { createLogger } from '../../core/logger.js';
+import { pollProperties, pollUntil, readPollOptions } from '../../core/polling.js';
+import { TERMINAL_JOB_STATUSES, summarizeJob } from './job-status.js';
+import { fetchJob } from './local-jobs.js';
+import { httpError } from '../../core/errors.js';
+
+const logger = createLogger('wait_for_job');
+
+/**
+ * Tool handler for polling a job until it finishes
+ */
+export async function handleWaitForJob(server, args) {
+    if (!args?.job_id) {
+        server.createErrorResponse('Missing required argument: job_id');
+    }
+
+    try {
+        const pollOptions = readPollOptions(args);
+        const headers = server.getApiHeaders();
+
+        const fetchLatest = () => fetchJob(server, args.job_id, headers);
+        const isFinished = (latest) => TERMINAL_JOB_STATUSES.has(latest.status);
+        const poll = await pollUntil(fetchLatest, isFinished, pollOptions);
+        if (!poll.done) {
+            logger.info(`Job ${args.job_id} still ${poll.value.status} after ${pollOptions.waitSecs}s`);
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        job_id: args.job_id,
+                        done: poll.done,
+                        // On timeout the caller can call again with the same job_id
+                        timed_out: !poll.done,
+                        job: summarizeJob(poll.value),
+                        polls: poll.polls,
+                        elapsed_secs: poll.elapsedSecs,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Job not found: ${args.job_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for wait_for_job
+ */
+export const waitForJobDefinition = {
+    name: 'wait_for_job',
+    description:
+        'Poll a job until it completes, fails or is cancelled, and return the final job. If it is still running when wait_secs elapses, returns the latest status with timed_out: true instead of an error so you can keep waiting.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            job_id: {
+                type: 'string',
+                description: 'ID of the job to wait for',
+            },
+            ...pollProperties,
+        },
+        required: ['job_id'],
+    },
+};
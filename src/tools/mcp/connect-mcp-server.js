@@ -0,0 +1,198 @@
+import { createLogger } from '../../core/logger.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('connect_mcp_server');
+
+/**
+ * Build the Letta server config for a URL-based MCP server, applying OAuth settings.
+ * A pre-obtained access token is sent as a bearer header; without one, Letta runs the
+ * OAuth flow itself and reports an authorization URL for the user to visit.
+ */
+function buildServerConfig(args) {
+    const config = {
+        server_name: args.server_name,
+        type: args.server_type || 'streamable_http',
+        server_url: args.server_url,
+    };
+    if (args.custom_headers) {
+        config.custom_headers = args.custom_headers;
+    }
+
+    const oauth = args.oauth_config;
+    if (oauth?.access_token) {
+        config.auth_header = oauth.auth_header || 'Authorization';
+        config.auth_token = `${oauth.token_type || 'Bearer'} ${oauth.access_token}`;
+    }
+    return config;
+}
+
+// Events that settle the connect attempt; nothing after them is needed
+const FINAL_EVENTS = new Set(['success', 'error', 'authorization_url']);
+
+/**
+ * Parse one SSE line from Letta's connect endpoint, or null if it carries no event
+ */
+function parseConnectLine(line) {
+    const trimmed = line.trim();
+    if (!trimmed.startsWith('data: ')) return null;
+    try {
+        return JSON.parse(trimmed.substring(6));
+    } catch {
+        return null;
+    }
+}
+
+/**
+ * Read the events streamed by Letta's connect endpoint up to the first one that settles the
+ * attempt. After authorization_url Letta keeps the stream open while it waits for the OAuth
+ * callback, so the stream is closed at that point rather than read to the end.
+ */
+async function readConnectEvents(stream) {
+    const events = [];
+    const decoder = new TextDecoder();
+    let buffer = '';
+    try {
+        for await (const chunk of stream) {
+            buffer += typeof chunk === 'string' ? chunk : decoder.decode(chunk, { stream: true });
+            const lines = buffer.split('\n');
+            buffer = lines.pop();
+            for (const line of lines) {
+                const event = parseConnectLine(line);
+                if (!event) continue;
+                events.push(event);
+                if (FINAL_EVENTS.has(event.event)) return events;
+            }
+        }
+        const trailing = parseConnectLine(buffer + decoder.decode());
+        if (trailing) events.push(trailing);
+        return events;
+    } finally {
+        stream.destroy?.();
+    }
+}
+
+/**
+ * Tool handler for connecting the Letta server to an MCP server
+ */
+export async function handleConnectMcpServer(server, args) {
+    if (!args?.server_name) {
+        server.createErrorResponse('Missing required argument: server_name');
+    }
+    if (!args.server_url) {
+        server.createErrorResponse('Missing required argument: server_url');
+    }
+    if (args.server_type && !['streamable_http', 'sse'].includes(args.server_type)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'server_type',
+                'Invalid argument: server_type must be streamable_http or sse',
+            ),
+        );
+    }
+    if (args.oauth_config !== undefined && typeof args.oauth_config !== 'object') {
+        server.createErrorResponse(
+            invalidRequest('oauth_config', 'Invalid argument: oauth_config must be an object'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const config = buildServerConfig(args);
+
+        logger.info(`Connecting to MCP server ${config.server_name} at ${config.server_url}`);
+        const response = await server.api.post('/tools/mcp/servers/connect', config, {
+            headers,
+            responseType: 'stream',
+        });
+
+        const events = await readConnectEvents(response.data);
+        const authEvent = events.find((event) => event.event === 'authorization_url');
+        const successEvent = events.find((event) => event.event === 'success');
+        const errorEvent = events.find((event) => event.event === 'error');
+
+        let result;
+        if (successEvent) {
+            result = { status: 'connected', tools: successEvent.tools || [] };
+        } else if (authEvent) {
+            // The caller must open this URL in a browser; Letta completes the flow afterwards
+            result = {
+                status: 'oauth_required',
+                authorization_url: authEvent.url,
+                session_id: authEvent.session_id,
+            };
+        } else if (errorEvent) {
+            throw new Error(errorEvent.message || 'MCP server connection failed');
+        } else {
+            result = { status: 'unknown', events };
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        server_name: config.server_name,
+                        server_type: config.type,
+                        ...result,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('[connect_mcp_server] Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, `Failed to connect to MCP server ${args.server_name}`);
+    }
+}
+
+/**
+ * Tool definition for connect_mcp_server
+ */
+export const connectMcpServerDefinition = {
+    name: 'connect_mcp_server',
+    description:
+        'Connect the Letta server to a remote MCP server (streamable HTTP or SSE) and list its tools. Supports OAuth: pass an access token in oauth_config, or omit it and complete the returned authorization_url in a browser. Use list_mcp_tools_by_server and add_mcp_tool_to_letta afterwards.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            server_name: {
+                type: 'string',
+                description: 'Name to register the MCP server under',
+            },
+            server_url: {
+                type: 'string',
+                description: 'URL of the MCP server endpoint',
+            },
+            server_type: {
+                type: 'string',
+                enum: ['streamable_http', 'sse'],
+                description: 'Transport of the MCP server (default: streamable_http)',
+                default: 'streamable_http',
+            },
+            oauth_config: {
+                type: 'object',
+                description:
+                    'OAuth settings. Provide access_token to authenticate directly; without it the server starts an OAuth flow and returns an authorization_url.',
+                properties: {
+                    access_token: {
+                        type: 'string',
+                        description: 'Pre-obtained OAuth access token',
+                    },
+                    token_type: {
+                        type: 'string',
+                        description: 'Token type prefix (default: Bearer)',
+                    },
+                    auth_header: {
+                        type: 'string',
+                        description: 'Header to send the token in (default: Authorization)',
+                    },
+                },
+            },
+            custom_headers: {
+                type: 'object',
+                description: 'Additional headers to send to the MCP server',
+                additionalProperties: { type: 'string' },
+            },
+        },
+        required: ['server_name', 'server_url'],
+    },
+};
@@ -1,22 +1,108 @@
+import { createLogger } from '../../core/logger.js';
+import { mapSettledWithConcurrency } from '../../core/concurrency.js';
+import { classifyError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('list_mcp_servers');
+
+// Per-server limit on the status check, so one hung server doesn't stall the listing
+const STATUS_TIMEOUT_MS = 10000;
+
+/**
+ * Check whether Letta can reach an MCP server by listing its tools
+ */
+async function checkServerStatus(server, name, headers) {
+    try {
+        const response = await server.api.get(
+            `/tools/mcp/servers/${encodeURIComponent(name)}/tools`,
+            // Not retried, so an unreachable server is reported after one attempt
+            { headers, timeout: STATUS_TIMEOUT_MS, retry: false },
+        );
+        const tools = response.data;
+        return {
+            connected: true,
+            tool_count: Array.isArray(tools) ? tools.length : null,
+            error: null,
+        };
+    } catch (error) {
+        const { message } = classifyError(error);
+        logger.info(`MCP server ${name} is unreachable: ${message}`);
+        return { connected: false, tool_count: null, error: message };
+    }
+}
+
 /**
  * Tool handler for listing all configured MCP servers on the Letta server
  */
-export async function handleListMcpServers(server, _args) {
+export async function handleListMcpServers(server, args) {
+    const paginated = args?.page !== undefined || args?.pageSize !== undefined;
+    const page = args?.page ?? 1;
+    const pageSize = args?.pageSize ?? 10;
+    if (!Number.isInteger(page) || page < 1) {
+        server.createErrorResponse(
+            invalidRequest('page', 'Invalid argument: page must be a positive integer'),
+        );
+    }
+    if (!Number.isInteger(pageSize) || pageSize < 1 || pageSize > 100) {
+        server.createErrorResponse(
+            invalidRequest(
+                'pageSize',
+                'Invalid argument: pageSize must be an integer from 1 to 100',
+            ),
+        );
+    }
+    if (args?.include_status !== undefined && typeof args.include_status !== 'boolean') {
+        server.createErrorResponse(
+            invalidRequest('include_status', 'Invalid argument: include_status must be a boolean'),
+        );
+    }
+
     try {
         const headers = server.getApiHeaders();
 
         // Use the specific endpoint from the OpenAPI spec
         const response = await server.api.get('/tools/mcp/servers', { headers });
-        const servers = response.data; // Assuming response.data is an object mapping server names to configs
+        const allServers = response.data; // Object mapping server names to configs
+
+        let names = Object.keys(allServers);
+        const totalServers = names.length;
+        const totalPages = Math.ceil(totalServers / pageSize);
+        if (paginated) {
+            names = names.slice((page - 1) * pageSize, page * pageSize);
+        }
+        const servers = Object.fromEntries(names.map((name) => [name, allServers[name]]));
+
+        const result = {
+            server_count: names.length,
+            ...(paginated && {
+                pagination: {
+                    page,
+                    pageSize,
+                    totalServers,
+                    totalPages,
+                    hasNextPage: page < totalPages,
+                    hasPreviousPage: page > 1,
+                },
+            }),
+            servers,
+        };
+
+        // Only the servers on this page are checked
+        if (args?.include_status ?? true) {
+            const checks = await mapSettledWithConcurrency(names, server.maxConcurrency, (name) =>
+                checkServerStatus(server, name, headers),
+            );
+            result.status = Object.fromEntries(
+                names.map((name, index) => [name, checks[index].value]),
+            );
+            result.connected_count = names.filter((name) => result.status[name].connected).length;
+            result.unreachable = names.filter((name) => !result.status[name].connected);
+        }
 
         return {
             content: [
                 {
                     type: 'text',
-                    text: JSON.stringify({
-                        server_count: Object.keys(servers).length,
-                        servers: servers,
-                    }),
+                    text: JSON.stringify(result),
                 },
             ],
         };
@@ -31,10 +117,25 @@ export async function handleListMcpServers(server, _args) {
 export const listMcpServersDefinition = {
     name: 'list_mcp_servers',
     description:
-        'List all configured MCP servers on the Letta server. Use with list_mcp_tools_by_server to explore available tools from each server.',
+        'List all configured MCP servers on the Letta server, with whether each one is reachable and how many tools it offers (unreachable names are listed in unreachable). Use with list_mcp_tools_by_server to explore available tools from each server.',
     inputSchema: {
         type: 'object',
-        properties: {}, // No input arguments needed
+        properties: {
+            page: {
+                type: 'number',
+                description: 'Page number for pagination (starts at 1). Omit to list every server.',
+            },
+            pageSize: {
+                type: 'number',
+                description: 'Number of servers per page (1-100, default: 10)',
+            },
+            include_status: {
+                type: 'boolean',
+                description:
+                    'Check each listed server by fetching its tools, reporting connected, tool_count and any error (default: true)',
+                default: true,
+            },
+        },
         required: [],
     },
 };
@@ -1,7 +1,28 @@
 import { createLogger } from '../../core/logger.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
 
 const logger = createLogger('list-mcp-tools-by-server');
 
+/**
+ * Input schema of an MCP tool, whichever key the server reported it under
+ */
+function inputSchemaOf(tool) {
+    return tool.input_schema ?? tool.inputSchema ?? tool.schema ?? null;
+}
+
+/**
+ * Shape an MCP tool for the response: name, description and input_schema by default, or the
+ * full tool object with input_schema added when verbose
+ */
+function formatMcpTool(tool, verbose) {
+    if (verbose) return { ...tool, input_schema: inputSchemaOf(tool) };
+    return {
+        name: tool.name,
+        description: tool.description ?? null,
+        input_schema: inputSchemaOf(tool),
+    };
+}
+
 /**
  * Tool handler for listing all available tools for a specific MCP server
  */
@@ -9,6 +30,11 @@ export async function handleListMcpToolsByServer(server, args) {
     if (!args?.mcp_server_name) {
         server.createErrorResponse('Missing required argument: mcp_server_name');
     }
+    if (args.verbose !== undefined && typeof args.verbose !== 'boolean') {
+        server.createErrorResponse(
+            invalidRequest('verbose', 'Invalid argument: verbose must be a boolean'),
+        );
+    }
 
     try {
         const serverName = encodeURIComponent(args.mcp_server_name);
@@ -43,7 +69,10 @@ export async function handleListMcpToolsByServer(server, args) {
         const endIndex = startIndex + pageSize;
         const totalTools = tools.length;
         const totalPages = Math.ceil(totalTools / pageSize);
-        const paginatedTools = tools.slice(startIndex, endIndex);
+        const verbose = args.verbose ?? false;
+        const paginatedTools = tools
+            .slice(startIndex, endIndex)
+            .map((tool) => formatMcpTool(tool, verbose));
 
         return {
             content: [
@@ -69,11 +98,13 @@ export async function handleListMcpToolsByServer(server, args) {
         logger.error('Full error:', error); // Keep detailed logging
         // Handle potential 404 if server name not found, or other API errors
         if (error.response && error.response.status === 404) {
-            server.createErrorResponse(`MCP Server not found: ${args.mcp_server_name}`);
+            server.createErrorResponse(
+                httpError(404, `MCP Server not found: ${args.mcp_server_name}`),
+            );
         }
-        // Provide more context in the error response
         server.createErrorResponse(
-            `Error executing list_mcp_tools_by_server: ${error.message}\nResponse: ${JSON.stringify(error.response?.data || {})}`,
+            error,
+            `Failed to list tools for MCP server ${args.mcp_server_name}`,
         );
     }
 }
@@ -84,7 +115,7 @@ export async function handleListMcpToolsByServer(server, args) {
 export const listMcpToolsByServerDefinition = {
     name: 'list_mcp_tools_by_server',
     description:
-        'List all available tools for a specific MCP server. Use list_mcp_servers first to see available servers, then add_mcp_tool_to_letta to import tools into Letta.',
+        'List all available tools for a specific MCP server, each with its input_schema. Use list_mcp_servers first to see available servers, then add_mcp_tool_to_letta to import tools into Letta.',
     inputSchema: {
         type: 'object',
         properties: {
@@ -104,6 +135,12 @@ export const listMcpToolsByServerDefinition = {
                 type: 'number',
                 description: 'Number of tools per page (1-100, default: 10)',
             },
+            verbose: {
+                type: 'boolean',
+                description:
+                    'Return the full tool objects as reported by the server (e.g. annotations and title) instead of just name, description and input_schema (default: false)',
+                default: false,
+            },
         },
         required: ['mcp_server_name'], // mcp_server_name is now required
     },
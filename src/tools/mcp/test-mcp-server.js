@@ -0,0 +1,123 @@
+import { performance } from 'node:perf_hooks';
+import { createLogger } from '../../core/logger.js';
+import { classifyError, httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('test_mcp_server');
+
+export const MAX_SAMPLES = 50;
+const SAMPLE_TIMEOUT_MS = 10000;
+
+const round = (ms) => Math.round(ms * 10) / 10;
+
+/**
+ * Min, max, average and 95th percentile of a set of latencies
+ * @param {number[]} latencies - Latencies in milliseconds
+ * @returns {Object} Stats in milliseconds, all null when there are no samples
+ */
+export function summarizeLatencies(latencies) {
+    if (latencies.length === 0) {
+        return { min: null, max: null, avg: null, p95: null };
+    }
+    const sorted = [...latencies].sort((a, b) => a - b);
+    // Nearest-rank percentile
+    const p95 = sorted[Math.ceil(0.95 * sorted.length) - 1];
+    return {
+        min: round(sorted[0]),
+        max: round(sorted[sorted.length - 1]),
+        avg: round(sorted.reduce((sum, ms) => sum + ms, 0) / sorted.length),
+        p95: round(p95),
+    };
+}
+
+/**
+ * Tool handler for testing Letta's connection to an MCP server
+ */
+export async function handleTestMcpServer(server, args) {
+    if (!args?.mcp_server_name) {
+        server.createErrorResponse('Missing required argument: mcp_server_name');
+    }
+    const samples = args.samples ?? 1;
+    if (!Number.isInteger(samples) || samples < 1 || samples > MAX_SAMPLES) {
+        server.createErrorResponse(
+            invalidRequest(
+                'samples',
+                `Invalid argument: samples must be an integer from 1 to ${MAX_SAMPLES}`,
+            ),
+        );
+    }
+
+    const headers = server.getApiHeaders();
+    const url = `/tools/mcp/servers/${encodeURIComponent(args.mcp_server_name)}/tools`;
+
+    // Samples run one after another so they don't queue behind each other and skew latency
+    const latencies = [];
+    let toolCount = null;
+    let lastError = null;
+    for (let i = 0; i < samples; i++) {
+        const startedAt = performance.now();
+        try {
+            // Not retried, so each sample measures a single attempt
+            const response = await server.api.get(url, {
+                headers,
+                timeout: SAMPLE_TIMEOUT_MS,
+                retry: false,
+            });
+            latencies.push(performance.now() - startedAt);
+            if (Array.isArray(response.data)) toolCount = response.data.length;
+        } catch (error) {
+            const { status, message } = classifyError(error);
+            if (status === 404) {
+                server.createErrorResponse(
+                    httpError(404, `MCP Server not found: ${args.mcp_server_name}`),
+                );
+            }
+            lastError = message;
+        }
+    }
+
+    const successCount = latencies.length;
+    logger.info(`MCP server ${args.mcp_server_name}: ${successCount}/${samples} samples succeeded`);
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    mcp_server_name: args.mcp_server_name,
+                    connected: successCount > 0,
+                    samples,
+                    success_count: successCount,
+                    success_ratio: successCount / samples,
+                    latency_ms: summarizeLatencies(latencies),
+                    tool_count: toolCount,
+                    last_error: lastError,
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for test_mcp_server
+ */
+export const testMcpServerDefinition = {
+    name: 'test_mcp_server',
+    description:
+        "Test the Letta server's connection to a configured MCP server by listing its tools, optionally several times. Returns the success ratio and min/max/avg/p95 latency of the successful samples, which is steadier than a single check for health monitoring. Use list_mcp_servers to see configured servers.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            mcp_server_name: {
+                type: 'string',
+                description: 'Name of the MCP server to test',
+            },
+            samples: {
+                type: 'integer',
+                description: `Number of times to test the connection (1-${MAX_SAMPLES}, default: 1)`,
+                minimum: 1,
+                maximum: MAX_SAMPLES,
+            },
+        },
+        required: ['mcp_server_name'],
+    },
+};
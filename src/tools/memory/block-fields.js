@@ -0,0 +1,123 @@
+import { invalidRequest } from '../../core/errors.js';
+
+/**
+ * Optional block settings shared by create_memory_block and update_memory_block.
+ * Validation runs before anything is sent to the server so the caller gets an
+ * actionable invalid_request error.
+ */
+
+// Control characters other than tab, newline and carriage return, which break some models
+// eslint-disable-next-line no-control-regex
+const CONTROL_CHARACTERS = /[\u0000-\u0008\u000B\u000C\u000E-\u001F\u007F]/g;
+
+/**
+ * Strip null bytes and other control characters from a block value
+ * @param {string} value - Block value
+ * @returns {string}
+ */
+export function sanitizeBlockValue(value) {
+    return value.replace(CONTROL_CHARACTERS, '');
+}
+
+/**
+ * The value to send for a block: sanitized only when the call sets sanitize_value to true
+ * @param {Object} args - Tool arguments
+ * @param {*} [value] - Value to use instead of args.value (e.g. from a template)
+ * @returns {*} The value, or undefined when none was given
+ */
+export function prepareBlockValue(args, value = args.value) {
+    if (typeof value !== 'string' || args.sanitize_value !== true) return value;
+    return sanitizeBlockValue(value);
+}
+
+/**
+ * Check a value fits a block's character limit
+ * @param {string} value - Block value
+ * @param {number} limit - The block's limit
+ * @param {string} [limitName] - How the limit is referred to in the message
+ */
+export function checkValueFitsLimit(value, limit, limitName = 'block_limit') {
+    if (typeof value === 'string' && value.length > limit) {
+        throw invalidRequest(
+            'value',
+            `Invalid argument: value is ${value.length} characters, which exceeds ${limitName} of ${limit}. Shorten the value or raise block_limit.`,
+        );
+    }
+}
+
+/**
+ * Validate block_limit, read_only and description, and check the value fits the limit
+ * @param {Object} args - Tool arguments
+ */
+export function validateBlockFields(args) {
+    if (args.block_limit !== undefined) {
+        if (!Number.isInteger(args.block_limit) || args.block_limit < 1) {
+            throw invalidRequest(
+                'block_limit',
+                'Invalid argument: block_limit must be a positive integer',
+            );
+        }
+        checkValueFitsLimit(args.value, args.block_limit);
+    }
+    if (args.read_only !== undefined && typeof args.read_only !== 'boolean') {
+        throw invalidRequest('read_only', 'Invalid argument: read_only must be a boolean');
+    }
+    if (args.description !== undefined && typeof args.description !== 'string') {
+        throw invalidRequest('description', 'Invalid argument: description must be a string');
+    }
+}
+
+/**
+ * Copy the provided block settings onto a Letta block request body
+ * @param {Object} args - Tool arguments
+ * @param {Object} blockData - Request body to extend
+ * @returns {Object} The request body
+ */
+export function applyBlockFields(args, blockData) {
+    if (args.block_limit !== undefined) {
+        blockData.limit = args.block_limit;
+    }
+    if (args.read_only !== undefined) {
+        blockData.read_only = args.read_only;
+    }
+    if (args.description !== undefined) {
+        blockData.description = args.description;
+    }
+    return blockData;
+}
+
+/**
+ * Whether any of the shared block settings were provided
+ * @param {Object} args - Tool arguments
+ * @returns {boolean}
+ */
+export function hasBlockFields(args) {
+    return ['block_limit', 'read_only', 'description'].some((field) => args[field] !== undefined);
+}
+
+/**
+ * Schemas for the shared block settings
+ */
+export const blockFieldProperties = {
+    block_limit: {
+        type: 'integer',
+        minimum: 1,
+        description:
+            'Maximum number of characters the block may hold (optional; the server default applies when omitted)',
+    },
+    read_only: {
+        type: 'boolean',
+        description:
+            'Prevent agents from editing the block through their memory tools (optional; e.g. to protect a persona)',
+    },
+    description: {
+        type: 'string',
+        description: 'Description of what the block is for, shown to the agent (optional)',
+    },
+    sanitize_value: {
+        type: 'boolean',
+        description:
+            'Strip null bytes and other control characters (except tab and newlines) from value before saving (default: false, value is saved as given)',
+        default: false,
+    },
+};
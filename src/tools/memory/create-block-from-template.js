@@ -0,0 +1,134 @@
+import { createLogger } from '../../core/logger.js';
+import {
+    applyBlockFields,
+    blockFieldProperties,
+    prepareBlockValue,
+    validateBlockFields,
+} from './block-fields.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('create_block_from_template');
+
+/**
+ * Tool handler for creating a memory block from a block template
+ */
+export async function handleCreateBlockFromTemplate(server, args) {
+    try {
+        // Validate arguments
+        if (!args?.template_id) {
+            throw new Error('Missing required argument: template_id');
+        }
+        for (const field of ['name', 'label', 'value']) {
+            if (args[field] !== undefined && typeof args[field] !== 'string') {
+                throw invalidRequest(field, `Invalid argument: ${field} must be a string`);
+            }
+        }
+
+        const headers = server.getApiHeaders();
+
+        // Fetch the template
+        let template;
+        try {
+            const templateResponse = await server.api.get(`/blocks/${args.template_id}`, {
+                headers,
+            });
+            template = templateResponse.data;
+        } catch (error) {
+            if (error.response?.status === 404) {
+                throw new Error(`Block template not found: ${args.template_id}`);
+            }
+            throw error;
+        }
+        if (template.is_template === false) {
+            throw new Error(
+                `Block ${args.template_id} is not a template. Use list_block_templates to find templates.`,
+            );
+        }
+
+        // The template supplies every setting the caller didn't override
+        const value = prepareBlockValue(args, args.value ?? template.value);
+        validateBlockFields({ ...args, block_limit: args.block_limit ?? template.limit, value });
+
+        const blockData = applyBlockFields(args, {
+            name: args.name ?? template.template_name ?? template.name,
+            label: args.label ?? template.label,
+            value,
+            metadata: args.metadata ?? template.metadata ?? {},
+            ...(template.limit !== undefined && { limit: template.limit }),
+            ...(template.read_only !== undefined && { read_only: template.read_only }),
+            ...(template.description !== undefined && { description: template.description }),
+        });
+
+        logger.info(`Creating memory block from template ${args.template_id}...`);
+        const createResponse = await server.api.post('/blocks', blockData, { headers });
+        const blockId = createResponse.data.id;
+
+        const result = {
+            block_id: blockId,
+            template_id: args.template_id,
+            name: blockData.name,
+            label: blockData.label,
+        };
+
+        // If agent_id is provided, attach the new block to the agent
+        if (args.agent_id) {
+            await server.api.patch(
+                `/agents/${args.agent_id}/core-memory/blocks/attach/${blockId}`,
+                {},
+                { headers },
+            );
+            result.agent_id = args.agent_id;
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(result),
+                },
+            ],
+        };
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for create_block_from_template
+ */
+export const createBlockFromTemplateToolDefinition = {
+    name: 'create_block_from_template',
+    description:
+        "Create a new memory block from a block template, copying the template's label, value and settings. Any of them can be overridden. Use list_block_templates to find templates, or pass agent_id to attach the new block to an agent.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            template_id: {
+                type: 'string',
+                description: 'ID of the template block to copy',
+            },
+            name: {
+                type: 'string',
+                description: "Name of the new block (default: the template's name)",
+            },
+            label: {
+                type: 'string',
+                description: "Label of the new block (default: the template's label)",
+            },
+            value: {
+                type: 'string',
+                description: "Content of the new block (default: the template's value)",
+            },
+            metadata: {
+                type: 'object',
+                description: "Metadata for the new block (default: the template's metadata)",
+            },
+            agent_id: {
+                type: 'string',
+                description: 'Optional agent ID to attach the new block to',
+            },
+            ...blockFieldProperties,
+        },
+        required: ['template_id'],
+    },
+};
@@ -1,4 +1,10 @@
 import { createLogger } from '../../core/logger.js';
+import {
+    applyBlockFields,
+    blockFieldProperties,
+    prepareBlockValue,
+    validateBlockFields,
+} from './block-fields.js';
 
 const logger = createLogger('create_memory_block');
 
@@ -17,6 +23,8 @@ export async function handleCreateMemoryBlock(server, args) {
         if (!args.value || typeof args.value !== 'string') {
             throw new Error('Missing required argument: value (must be a string)');
         }
+        const value = prepareBlockValue(args);
+        validateBlockFields({ ...args, value });
 
         // Headers for API requests
         const headers = server.getApiHeaders();
@@ -34,12 +42,12 @@ export async function handleCreateMemoryBlock(server, args) {
         };
 
         // Prepare block data
-        const blockData = {
+        const blockData = applyBlockFields(args, {
             name: args.name,
             label: args.label,
-            value: args.value,
+            value,
             metadata: metadata,
-        };
+        });
 
         // Create the memory block
         logger.info(`Creating memory block "${args.name}" with label "${args.label}"...`);
@@ -119,6 +127,7 @@ export const createMemoryBlockToolDefinition = {
                 type: 'object',
                 description: 'Optional metadata for the memory block',
             },
+            ...blockFieldProperties,
         },
         required: ['name', 'label', 'value'],
     },
@@ -0,0 +1,135 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, httpError } from '../../core/errors.js';
+import {
+    ignoreMissingProperty,
+    isAlreadyAbsent,
+    validateIgnoreMissing,
+} from '../../core/ignore-missing.js';
+
+const logger = createLogger('delete_memory_block');
+
+/**
+ * Tool handler for deleting a standalone memory block
+ */
+export async function handleDeleteMemoryBlock(server, args) {
+    if (!args?.block_id) {
+        server.createErrorResponse('Missing required argument: block_id');
+    }
+    validateIgnoreMissing(server, args);
+
+    const headers = server.getApiHeaders();
+    const blockId = encodeURIComponent(args.block_id);
+
+    // A block still in an agent's core memory is only deleted when forced. A missing block
+    // has no agents; the delete below reports it.
+    let agentIds = [];
+    try {
+        const agentsResponse = await server.api.get(`/blocks/${blockId}/agents`, { headers });
+        agentIds = (Array.isArray(agentsResponse.data) ? agentsResponse.data : []).map(
+            (agent) => agent.id,
+        );
+    } catch (error) {
+        if (error.response?.status !== 404) {
+            server.createErrorResponse(error, `Failed to list agents using block ${args.block_id}`);
+        }
+    }
+    if (agentIds.length > 0 && !args.force) {
+        server.createErrorResponse(
+            httpError(
+                409,
+                `Block ${args.block_id} is attached to ${agentIds.length} agent(s): ${agentIds.join(', ')}. Detach it first or pass force: true to detach and delete it.`,
+            ),
+        );
+    }
+
+    // Detach explicitly so a failure leaves the block in place rather than half-removed
+    const detached = [];
+    const failures = [];
+    for (const agentId of agentIds) {
+        logger.info(`Detaching block ${args.block_id} from agent ${agentId}`);
+        try {
+            await server.api.patch(
+                `/agents/${encodeURIComponent(agentId)}/core-memory/blocks/detach/${blockId}`,
+                {},
+                { headers },
+            );
+            detached.push(agentId);
+        } catch (error) {
+            failures.push({ agentId, error });
+        }
+    }
+    if (failures.length > 0) {
+        const reasons = failures
+            .map(({ agentId, error }) => `${agentId} (${classifyError(error).message})`)
+            .join(', ');
+        server.createErrorResponse(
+            failures[0].error,
+            `Block ${args.block_id} was not deleted: detaching it failed for ${failures.length} agent(s): ${reasons}` +
+                (detached.length ? `; it was detached from ${detached.join(', ')}` : ''),
+        );
+    }
+
+    try {
+        logger.info(`Deleting block ${args.block_id}`);
+        await server.api.delete(`/blocks/${blockId}`, { headers });
+    } catch (error) {
+        if (isAlreadyAbsent(args, error)) {
+            logger.info(`Block ${args.block_id} is already gone`);
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify({
+                            block_id: args.block_id,
+                            deleted: false,
+                            already_absent: true,
+                        }),
+                    },
+                ],
+            };
+        }
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Block not found: ${args.block_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    block_id: args.block_id,
+                    deleted: true,
+                    detached_agent_ids: detached,
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for delete_memory_block
+ */
+export const deleteMemoryBlockToolDefinition = {
+    name: 'delete_memory_block',
+    description:
+        'Delete a memory block. Fails with a conflict error if agents still have the block attached, unless force is set, in which case it is detached from them first; if any detach fails the block is kept and the failing agents are named. Use list_all_blocks to find orphaned blocks. WARNING: This action is permanent.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            block_id: {
+                type: 'string',
+                description: 'ID of the memory block to delete',
+            },
+            force: {
+                type: 'boolean',
+                description:
+                    'Detach the block from any agents using it, then delete it (default: false)',
+                default: false,
+            },
+            ignore_missing: ignoreMissingProperty,
+        },
+        required: ['block_id'],
+    },
+};
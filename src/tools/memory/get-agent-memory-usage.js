@@ -0,0 +1,93 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, httpError } from '../../core/errors.js';
+
+const logger = createLogger('get_agent_memory_usage');
+
+/**
+ * Block count and total size of an agent's core memory
+ */
+function summarizeBlocks(blocks) {
+    return {
+        block_count: blocks.length,
+        total_chars: blocks.reduce((sum, block) => sum + (block.value?.length ?? 0), 0),
+        total_limit: blocks.reduce((sum, block) => sum + (block.limit ?? 0), 0),
+    };
+}
+
+/**
+ * Tool handler for summarizing how much memory an agent uses
+ */
+export async function handleGetAgentMemoryUsage(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+
+    const headers = server.getApiHeaders();
+    const agentId = encodeURIComponent(args.agent_id);
+
+    // Each part is reported on its own, so one failing endpoint doesn't hide the others
+    const parts = {
+        core_memory: [`/agents/${agentId}/core-memory/blocks`, summarizeBlocks],
+        archival_passage_count: [
+            `/agents/${agentId}/context`,
+            (context) => context?.num_archival_memory ?? null,
+        ],
+        source_count: [`/agents/${agentId}/sources`, (sources) => sources.length],
+        tool_count: [`/agents/${agentId}/tools`, (tools) => tools.length],
+    };
+    const settled = await Promise.allSettled(
+        Object.values(parts).map(([url]) => server.api.get(url, { headers })),
+    );
+
+    const usage = { agent_id: args.agent_id };
+    const errors = {};
+    Object.entries(parts).forEach(([key, [, summarize]], index) => {
+        const { status, value, reason } = settled[index];
+        if (status === 'fulfilled') {
+            usage[key] = summarize(value.data);
+            return;
+        }
+        if (reason?.response?.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        usage[key] = null;
+        errors[key] = classifyError(reason).message;
+    });
+
+    const failed = Object.keys(errors);
+    if (failed.length === settled.length) {
+        server.createErrorResponse(settled[0].reason);
+    }
+    if (failed.length > 0) {
+        logger.warn(`Partial memory usage for ${args.agent_id}; failed: ${failed.join(', ')}`);
+        usage.errors = errors;
+    }
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify(usage),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for get_agent_memory_usage
+ */
+export const getAgentMemoryUsageToolDefinition = {
+    name: 'get_agent_memory_usage',
+    description:
+        "Summarize an agent's memory usage in one call: core memory block count and total size (characters used and the sum of block limits), archival passage count, and the number of attached sources and tools. Parts that can't be fetched are null, with the reason in errors. Use list_memory_blocks or list_passages for the contents.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent to summarize',
+            },
+        },
+        required: ['agent_id'],
+    },
+};
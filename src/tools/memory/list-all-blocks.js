@@ -0,0 +1,159 @@
+import { fitToResponseSize } from '../../core/response-size.js';
+import { invalidRequest } from '../../core/errors.js';
+
+/**
+ * Apply before/after cursors to a block list. Servers that page /blocks/ have already
+ * applied them; older servers return every block, so they are applied here as well.
+ */
+function applyCursors(blocks, args) {
+    let page = blocks;
+    if (args.after) {
+        const index = page.findIndex((block) => block.id === args.after);
+        if (index !== -1) page = page.slice(index + 1);
+    }
+    if (args.before) {
+        const index = page.findIndex((block) => block.id === args.before);
+        if (index !== -1) page = page.slice(0, index);
+    }
+    return page;
+}
+
+/**
+ * Summarize a block for the listing
+ */
+function summarizeBlock(block, includeFullContent) {
+    const summary = {
+        id: block.id,
+        label: block.label,
+        name: block.name ?? null,
+        description: block.description ?? null,
+        is_template: block.is_template ?? false,
+        template_name: block.template_name ?? null,
+        limit: block.limit,
+        chars: typeof block.value === 'string' ? block.value.length : 0,
+        read_only: block.read_only ?? false,
+    };
+    if (includeFullContent) {
+        summary.value = block.value;
+    } else if (typeof block.value === 'string') {
+        summary.value_preview =
+            block.value.length > 200 ? block.value.substring(0, 200) + '...' : block.value;
+    }
+    return summary;
+}
+
+/**
+ * Tool handler for listing every memory block in the organization, not just one agent's
+ */
+export async function handleListAllBlocks(server, args = {}) {
+    try {
+        const limit = args.limit;
+        if (limit !== undefined && (!Number.isInteger(limit) || limit < 1)) {
+            throw invalidRequest('limit', 'Invalid argument: limit must be a positive integer');
+        }
+        if (args.template_name !== undefined && typeof args.template_name !== 'string') {
+            throw invalidRequest(
+                'template_name',
+                'Invalid argument: template_name must be a string',
+            );
+        }
+
+        const headers = server.getApiHeaders();
+
+        // One extra block is requested to detect further pages
+        const params = { templates_only: args.templates_only ?? false };
+        if (args.label) params.label = args.label;
+        if (args.after) params.after = args.after;
+        if (args.before) params.before = args.before;
+        if (limit) params.limit = limit + 1;
+
+        const response = await server.api.get('/blocks/', { headers, params });
+        if (!Array.isArray(response.data)) {
+            throw new Error('Unexpected response from /blocks/: expected an array');
+        }
+
+        let blocks = applyCursors(response.data, args);
+        // Servers without a label filter return every label; narrow them here too
+        if (args.label) {
+            blocks = blocks.filter((block) => block.label === args.label);
+        }
+        if (args.template_name) {
+            blocks = blocks.filter((block) => block.template_name === args.template_name);
+        }
+
+        const page = limit ? blocks.slice(0, limit) : blocks;
+        const hasMore = limit ? blocks.length > limit : false;
+        const summaries = page.map((block) => summarizeBlock(block, args.include_full_content));
+
+        // Oversized pages are cut short; next_cursor then continues after the last block kept
+        const payload = fitToResponseSize(summaries, (items, truncated) => ({
+            count: items.length,
+            blocks: items,
+            has_more: hasMore || truncated,
+            // Pass next_cursor as `after` to fetch the next page
+            next_cursor: truncated
+                ? (items[items.length - 1]?.id ?? null)
+                : hasMore
+                  ? page[page.length - 1].id
+                  : null,
+            ...(truncated && { truncated: true }),
+        }));
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(payload),
+                },
+            ],
+        };
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for list_all_blocks
+ */
+export const listAllBlocksToolDefinition = {
+    name: 'list_all_blocks',
+    description:
+        'List every memory block in the organization, whether or not it is attached to an agent (e.g. a shared block library). Filter by label or template and page with limit and after. Use list_memory_blocks with agent_id for one agent\'s blocks.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            label: {
+                type: 'string',
+                description: 'Only return blocks with this label (e.g., "human", "persona")',
+            },
+            templates_only: {
+                type: 'boolean',
+                description: 'Only return block templates (default: false)',
+                default: false,
+            },
+            template_name: {
+                type: 'string',
+                description: 'Only return blocks with this template name',
+            },
+            limit: {
+                type: 'integer',
+                description: 'Maximum number of blocks to return per page',
+                minimum: 1,
+            },
+            after: {
+                type: 'string',
+                description: 'Cursor: return blocks after this block ID (use next_cursor)',
+            },
+            before: {
+                type: 'string',
+                description: 'Cursor: return blocks before this block ID',
+            },
+            include_full_content: {
+                type: 'boolean',
+                description: 'Whether to include the full content of blocks (default: false)',
+                default: false,
+            },
+        },
+        required: [],
+    },
+};
@@ -0,0 +1,67 @@
+/**
+ * Tool handler for listing memory block templates
+ */
+export async function handleListBlockTemplates(server, args) {
+    try {
+        const headers = server.getApiHeaders();
+
+        const params = { templates_only: true };
+        if (args?.label) {
+            params.label = args.label;
+        }
+
+        const response = await server.api.get('/blocks', { headers, params });
+
+        const templates = response.data.map((block) => {
+            const template = {
+                id: block.id,
+                name: block.template_name || block.name || 'Unnamed Template',
+                label: block.label,
+                description: block.description,
+                limit: block.limit,
+                read_only: block.read_only,
+            };
+            if (args?.include_full_content) {
+                template.value = block.value;
+            } else if (typeof block.value === 'string') {
+                template.value_preview =
+                    block.value.length > 200 ? block.value.substring(0, 200) + '...' : block.value;
+            }
+            return template;
+        });
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({ templates, count: templates.length }),
+                },
+            ],
+        };
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for list_block_templates
+ */
+export const listBlockTemplatesToolDefinition = {
+    name: 'list_block_templates',
+    description:
+        'List memory block templates (e.g. a library of persona and human blocks). Use create_block_from_template to create a block from one.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            label: {
+                type: 'string',
+                description: 'Optional filter for template label (e.g., "human", "persona")',
+            },
+            include_full_content: {
+                type: 'boolean',
+                description: 'Whether to include the full content of templates (default: false)',
+            },
+        },
+        required: [],
+    },
+};
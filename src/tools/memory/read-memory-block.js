@@ -1,3 +1,5 @@
+import { withTimestamps } from '../timestamps.js';
+
 /**
  * Tool handler for reading a memory block in the Letta system
  */
@@ -26,7 +28,7 @@ export async function handleReadMemoryBlock(server, args) {
             content: [
                 {
                     type: 'text',
-                    text: JSON.stringify(response.data),
+                    text: JSON.stringify(withTimestamps(response.data)),
                 },
             ],
         };
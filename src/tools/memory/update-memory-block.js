@@ -1,3 +1,12 @@
+import {
+    applyBlockFields,
+    blockFieldProperties,
+    checkValueFitsLimit,
+    hasBlockFields,
+    prepareBlockValue,
+    validateBlockFields,
+} from './block-fields.js';
+
 /**
  * Tool handler for updating a memory block in the Letta system
  */
@@ -8,9 +17,13 @@ export async function handleUpdateMemoryBlock(server, args) {
             throw new Error('Missing required argument: block_id');
         }
 
-        if (!args?.value && !args?.metadata) {
-            throw new Error('Either value or metadata must be provided');
+        if (!args?.value && !args?.metadata && !hasBlockFields(args)) {
+            throw new Error(
+                'Either value or metadata must be provided, or one of block_limit, read_only or description',
+            );
         }
+        const value = prepareBlockValue(args);
+        validateBlockFields({ ...args, value });
 
         // Headers for API requests
         const headers = server.getApiHeaders();
@@ -20,14 +33,24 @@ export async function handleUpdateMemoryBlock(server, args) {
             headers['user_id'] = args.agent_id;
         }
 
+        // Without a new block_limit, check the value against the block's current limit
+        if (typeof value === 'string' && args.block_limit === undefined) {
+            const current = await server.api.get(`/blocks/${args.block_id}`, { headers });
+            const limit = current?.data?.limit;
+            if (Number.isInteger(limit)) {
+                checkValueFitsLimit(value, limit, "the block's limit");
+            }
+        }
+
         // Prepare update data
         const updateData = {};
-        if (args.value !== undefined) {
-            updateData.value = args.value;
+        if (value !== undefined) {
+            updateData.value = value;
         }
         if (args.metadata !== undefined) {
             updateData.metadata = args.metadata;
         }
+        applyBlockFields(args, updateData);
 
         // Update the memory block
         const response = await server.api.patch(`/blocks/${args.block_id}`, updateData, {
@@ -54,7 +77,7 @@ export async function handleUpdateMemoryBlock(server, args) {
 export const updateMemoryBlockToolDefinition = {
     name: 'update_memory_block',
     description:
-        'Update the contents and metadata of a memory block. Use list_memory_blocks to find block IDs, or read_memory_block to see current content before updating.',
+        'Update the contents and metadata of a memory block, or its block_limit, read_only and description settings. Use list_memory_blocks to find block IDs, or read_memory_block to see current content before updating.',
     inputSchema: {
         type: 'object',
         properties: {
@@ -70,6 +93,7 @@ export const updateMemoryBlockToolDefinition = {
                 type: 'object',
                 description: 'New metadata for the memory block (optional)',
             },
+            ...blockFieldProperties,
             agent_id: {
                 type: 'string',
                 description: 'Optional agent ID for authorization',
@@ -0,0 +1,188 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, invalidRequest } from '../../core/errors.js';
+import { callConcurrency, mapSettledWithConcurrency } from '../../core/concurrency.js';
+import { runInSpan } from '../../core/tracing.js';
+import { requestTimeout, timeoutSecsProperty } from '../../core/timeout.js';
+import { MESSAGE_ROLES, extractUsage } from '../agents/prompt-agent.js';
+
+const logger = createLogger('bulk_send_message');
+
+/**
+ * Validate the messages argument and normalize it into Letta message objects
+ */
+export function normalizeMessages(server, messages) {
+    if (!Array.isArray(messages) || messages.length === 0) {
+        server.createErrorResponse('Missing required argument: messages (non-empty array)');
+    }
+    return messages.map((message, index) => {
+        if (typeof message?.content !== 'string' || !message.content) {
+            server.createErrorResponse(
+                invalidRequest(
+                    'messages',
+                    `Invalid argument: messages[${index}].content must be a non-empty string`,
+                ),
+            );
+        }
+        const role = message.role || 'user';
+        if (!MESSAGE_ROLES.includes(role)) {
+            server.createErrorResponse(
+                invalidRequest(
+                    'messages',
+                    `Invalid argument: messages[${index}].role must be one of ${MESSAGE_ROLES.join(', ')}`,
+                ),
+            );
+        }
+        return { role, content: message.content };
+    });
+}
+
+/**
+ * Pull the assistant's reply out of a Letta response
+ */
+function extractAssistantResponse(data) {
+    const assistantMessages = (data?.messages || []).filter(
+        (message) => message.message_type === 'assistant_message',
+    );
+    return assistantMessages
+        .map((message) =>
+            typeof message.content === 'string' ? message.content : JSON.stringify(message.content),
+        )
+        .join('\n');
+}
+
+/**
+ * Tool handler for sending the same messages to several agents
+ */
+export async function handleBulkSendMessage(server, args) {
+    const agentIds = args?.agent_ids;
+    if (!Array.isArray(agentIds) || agentIds.length === 0) {
+        server.createErrorResponse('Missing required argument: agent_ids (non-empty array)');
+    }
+    const messages = normalizeMessages(server, args.messages);
+    if (
+        args.max_concurrency !== undefined &&
+        (!Number.isInteger(args.max_concurrency) || args.max_concurrency < 1)
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'max_concurrency',
+                'Invalid argument: max_concurrency must be a positive integer',
+            ),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const timeout = requestTimeout(args);
+        const maxConcurrency = callConcurrency(server, args.max_concurrency);
+
+        logger.info(
+            `Sending ${messages.length} message(s) to ${agentIds.length} agents (concurrency ${maxConcurrency})`,
+        );
+
+        // Each agent is independent; a failure is recorded in its result instead of aborting
+        // Each send runs in a child span so its logs and retries name the agent
+        const settled = await mapSettledWithConcurrency(agentIds, maxConcurrency, (agentId) =>
+            runInSpan({ agent_id: agentId }, () =>
+                server.api.post(
+                    `/agents/${encodeURIComponent(agentId)}/messages`,
+                    { messages },
+                    { headers, ...timeout },
+                ),
+            ),
+        );
+
+        const results = settled.map((result, index) => {
+            const agentId = agentIds[index];
+            if (result.status === 'rejected') {
+                const { errorCode, status, message } = classifyError(result.reason);
+                logger.error(`Failed to send message to agent ${agentId}: ${message}`);
+                return {
+                    agent_id: agentId,
+                    status: 'error',
+                    error: message,
+                    error_code: errorCode,
+                    http_status: status,
+                };
+            }
+            const data = result.value.data;
+            const usage = extractUsage(data?.usage);
+            return {
+                agent_id: agentId,
+                status: 'success',
+                response: extractAssistantResponse(data),
+                ...(data?.stop_reason && { stop_reason: data.stop_reason }),
+                ...(usage && { usage }),
+            };
+        });
+
+        const successCount = results.filter((r) => r.status === 'success').length;
+        const errorCount = results.length - successCount;
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        success: errorCount === 0,
+                        summary: {
+                            total_agents: agentIds.length,
+                            success_count: successCount,
+                            error_count: errorCount,
+                        },
+                        results,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed during bulk send operation');
+    }
+}
+
+/**
+ * Tool definition for bulk_send_message
+ */
+export const bulkSendMessageDefinition = {
+    name: 'bulk_send_message',
+    description:
+        'Send the same messages to multiple agents in parallel (bounded by max_concurrency) and collect each reply. Failures are reported per agent; success is false if any agent failed. Use prompt_agent for a single agent.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_ids: {
+                type: 'array',
+                items: { type: 'string' },
+                description: 'IDs of the agents to message',
+            },
+            messages: {
+                type: 'array',
+                description: 'Messages to send to every agent, in order',
+                items: {
+                    type: 'object',
+                    properties: {
+                        role: {
+                            type: 'string',
+                            enum: MESSAGE_ROLES,
+                            description: 'Message role (default: user)',
+                        },
+                        content: {
+                            type: 'string',
+                            description: 'Message text',
+                        },
+                    },
+                    required: ['content'],
+                },
+            },
+            max_concurrency: {
+                type: 'integer',
+                description:
+                    'Maximum number of agents to message at once (default and maximum: LETTA_MAX_CONCURRENCY, or 16)',
+                minimum: 1,
+            },
+            timeout_secs: timeoutSecsProperty,
+        },
+        required: ['agent_ids', 'messages'],
+    },
+};
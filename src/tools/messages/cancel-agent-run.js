@@ -0,0 +1,100 @@
+import { createLogger } from '../../core/logger.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('cancel_agent_run');
+
+/**
+ * Tool handler for cancelling an agent's in-flight message run(s)
+ */
+export async function handleCancelAgentRun(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    if (args.run_id !== undefined && (typeof args.run_id !== 'string' || !args.run_id)) {
+        server.createErrorResponse(
+            invalidRequest('run_id', 'Invalid argument: run_id must be a non-empty string'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        // Without run_ids the server cancels the agent's active runs
+        const payload = args.run_id ? { run_ids: [args.run_id] } : {};
+        const response = await server.api.post(`/agents/${agentId}/messages/cancel`, payload, {
+            headers,
+        });
+
+        // Look up the targeted run so callers can confirm what was actually stopped
+        let run = null;
+        if (args.run_id) {
+            try {
+                const runResponse = await server.api.get(
+                    `/runs/${encodeURIComponent(args.run_id)}`,
+                    { headers },
+                );
+                run = {
+                    id: runResponse.data.id,
+                    status: runResponse.data.status,
+                    completed_at: runResponse.data.completed_at,
+                    stop_reason: runResponse.data.stop_reason,
+                };
+            } catch (runError) {
+                logger.warn(
+                    `Could not fetch final status for run ${args.run_id}:`,
+                    runError.message,
+                );
+            }
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        agent_id: args.agent_id,
+                        run_id: args.run_id ?? null,
+                        cancelled: response.data,
+                        run,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(
+                httpError(
+                    404,
+                    args.run_id
+                        ? `Agent or run not found: agent_id=${args.agent_id}, run_id=${args.run_id}`
+                        : `Agent not found: ${args.agent_id}`,
+                ),
+            );
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for cancel_agent_run
+ */
+export const cancelAgentRunDefinition = {
+    name: 'cancel_agent_run',
+    description:
+        "Cancel an agent's in-flight message processing. Pass run_id to stop a specific run; otherwise the agent's active runs are cancelled. Returns the run's final status when run_id is given.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent whose run to cancel',
+            },
+            run_id: {
+                type: 'string',
+                description: 'Optional ID of the specific run to cancel',
+            },
+        },
+        required: ['agent_id'],
+    },
+};
@@ -0,0 +1,130 @@
+import { mapSettledWithConcurrency } from '../../core/concurrency.js';
+import { classifyError, httpError, invalidRequest } from '../../core/errors.js';
+
+/**
+ * Read an agent's message counts from its context window overview, which reports them
+ * without listing the messages
+ */
+async function countMessages(server, agentId, headers) {
+    const response = await server.api.get(`/agents/${encodeURIComponent(agentId)}/context`, {
+        headers,
+    });
+    const count = response.data?.num_recall_memory;
+    if (typeof count !== 'number') {
+        throw new Error('Letta server did not report a message count for this agent');
+    }
+    return {
+        agent_id: agentId,
+        count,
+        in_context: response.data?.num_messages ?? null,
+    };
+}
+
+/**
+ * Tool handler for counting an agent's messages without fetching them
+ */
+export async function handleCountAgentMessages(server, args) {
+    const hasIds = args?.agent_ids !== undefined;
+    if (!args?.agent_id && !hasIds) {
+        server.createErrorResponse('Missing required argument: agent_id or agent_ids');
+    }
+    if (args.agent_id && hasIds) {
+        server.createErrorResponse(
+            invalidRequest(
+                'agent_ids',
+                'Invalid argument: pass either agent_id or agent_ids, not both',
+            ),
+        );
+    }
+    if (
+        hasIds &&
+        (!Array.isArray(args.agent_ids) ||
+            args.agent_ids.length === 0 ||
+            args.agent_ids.some((id) => typeof id !== 'string' || !id))
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'agent_ids',
+                'Invalid argument: agent_ids must be a non-empty array of IDs',
+            ),
+        );
+    }
+
+    const headers = server.getApiHeaders();
+
+    if (!hasIds) {
+        try {
+            const result = await countMessages(server, args.agent_id, headers);
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify(result),
+                    },
+                ],
+            };
+        } catch (error) {
+            if (error.response && error.response.status === 404) {
+                server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+            }
+            server.createErrorResponse(error);
+        }
+    }
+
+    // Each agent is counted on its own so one missing agent doesn't hide the rest
+    const settled = await mapSettledWithConcurrency(args.agent_ids, server.maxConcurrency, (id) =>
+        countMessages(server, id, headers),
+    );
+    const counts = [];
+    const errors = [];
+    settled.forEach((result, index) => {
+        if (result.status === 'fulfilled') {
+            counts.push(result.value);
+            return;
+        }
+        const agentId = args.agent_ids[index];
+        const { errorCode, status, message } = classifyError(result.reason);
+        errors.push({
+            agent_id: agentId,
+            error: status === 404 ? `Agent not found: ${agentId}` : message,
+            error_code: errorCode,
+        });
+    });
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    counts,
+                    total: counts.reduce((sum, entry) => sum + entry.count, 0),
+                    ...(errors.length > 0 && { errors }),
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for count_agent_messages
+ */
+export const countAgentMessagesDefinition = {
+    name: 'count_agent_messages',
+    description:
+        "Count an agent's messages without fetching them: `count` is the full conversation history and `in_context` the messages currently in the context window. Pass agent_ids to count several agents at once. Use list_agent_messages to read the messages themselves.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent whose messages to count',
+            },
+            agent_ids: {
+                type: 'array',
+                items: { type: 'string' },
+                description: 'IDs of several agents to count, instead of agent_id',
+            },
+        },
+        required: [],
+    },
+};
@@ -0,0 +1,143 @@
+import { createLogger } from '../../core/logger.js';
+import {
+    ignoreMissingProperty,
+    isAlreadyAbsent,
+    validateIgnoreMissing,
+} from '../../core/ignore-missing.js';
+import { findMessageById, scanMessages, toolCallIdsOf } from './message-lookup.js';
+import { httpError } from '../../core/errors.js';
+
+const logger = createLogger('delete_agent_message');
+
+/**
+ * Response for a message that is already gone
+ */
+function alreadyAbsentResponse(args) {
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    agent_id: args.agent_id,
+                    message_id: args.message_id,
+                    deleted_message_ids: [],
+                    already_absent: true,
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool handler for deleting a message from an agent's history
+ */
+export async function handleDeleteAgentMessage(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    if (!args.message_id) {
+        server.createErrorResponse('Missing required argument: message_id');
+    }
+    validateIgnoreMissing(server, args);
+
+    let deleting = false;
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        const entries = await findMessageById(server, headers, agentId, args.message_id);
+        if (entries.length === 0) {
+            if (args.ignore_missing) return alreadyAbsentResponse(args);
+            server.createErrorResponse(
+                httpError(404, `Message not found: ${args.message_id} (agent ${args.agent_id})`),
+            );
+        }
+
+        // Deleting one half of a tool call/return pair would leave the other dangling
+        const toolCallIds = new Set(entries.flatMap(toolCallIdsOf));
+        let pairedIds = [];
+        if (toolCallIds.size > 0) {
+            const partners = await scanMessages(
+                server,
+                headers,
+                agentId,
+                (message) =>
+                    message.id !== args.message_id &&
+                    toolCallIdsOf(message).some((id) => toolCallIds.has(id)),
+            );
+            pairedIds = [...new Set(partners.map((message) => message.id))];
+        }
+        if (pairedIds.length > 0 && !args.delete_tool_pair) {
+            server.createErrorResponse(
+                `Message ${args.message_id} is part of tool call ${[...toolCallIds].join(', ')} with message(s) ${pairedIds.join(', ')}. Pass delete_tool_pair: true to delete them together.`,
+            );
+        }
+
+        const deleted = [];
+        deleting = true;
+        for (const messageId of [args.message_id, ...pairedIds]) {
+            logger.info(`Deleting message ${messageId} from agent ${args.agent_id}`);
+            const messageUrl = `/agents/${agentId}/messages/${encodeURIComponent(messageId)}`;
+            await server.api.delete(messageUrl, { headers });
+            deleted.push(messageId);
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        agent_id: args.agent_id,
+                        message_id: args.message_id,
+                        deleted_message_ids: deleted,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        // Older Letta servers can edit messages but not delete them
+        if (deleting && error.response && [404, 405, 501].includes(error.response.status)) {
+            logger.warn('Message deletion endpoint unavailable:', error.response.status);
+            server.createErrorResponse(
+                'Message deletion is not supported by this Letta server (DELETE /v1/agents/{agent_id}/messages/{message_id} is unavailable). Use modify_agent_message to correct the message instead.',
+            );
+        }
+        if (isAlreadyAbsent(args, error)) {
+            return alreadyAbsentResponse(args);
+        }
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for delete_agent_message
+ */
+export const deleteAgentMessageDefinition = {
+    name: 'delete_agent_message',
+    description:
+        "Delete a message from an agent's history. Tool calls and their returns are deleted together: deleting either half requires delete_tool_pair so no dangling reference is left. Use list_agent_messages to find message IDs. WARNING: This action is permanent.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent that owns the message',
+            },
+            message_id: {
+                type: 'string',
+                description: 'ID of the message to delete',
+            },
+            delete_tool_pair: {
+                type: 'boolean',
+                description:
+                    'Also delete the matching tool call or tool return when the message is part of one (default: false)',
+                default: false,
+            },
+            ignore_missing: ignoreMissingProperty,
+        },
+        required: ['agent_id', 'message_id'],
+    },
+};
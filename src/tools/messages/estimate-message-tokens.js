@@ -0,0 +1,163 @@
+import { createLogger } from '../../core/logger.js';
+import { MESSAGE_ROLES } from '../agents/prompt-agent.js';
+import { normalizeMessages } from './bulk-send-message.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('estimate_message_tokens');
+
+// Rough English average for GPT-style tokenizers, plus per-message framing (role, separators)
+const CHARS_PER_TOKEN = 4;
+const TOKENS_PER_MESSAGE = 4;
+const ESTIMATE_METHOD =
+    `approximate, ${CHARS_PER_TOKEN} characters per token + ${TOKENS_PER_MESSAGE} per message`;
+
+/**
+ * Estimate the tokens a set of messages adds to the prompt
+ * @param {Object[]} messages - Messages with role and content
+ * @returns {number} Approximate token count
+ */
+export function estimateTokens(messages) {
+    return messages.reduce(
+        (sum, message) =>
+            sum + TOKENS_PER_MESSAGE + Math.ceil(message.content.length / CHARS_PER_TOKEN),
+        0,
+    );
+}
+
+/**
+ * Tool handler for estimating whether messages fit in an agent's context window
+ */
+export async function handleEstimateMessageTokens(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    if ((args.message === undefined) === (args.messages === undefined)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'message',
+                'Invalid argument: provide exactly one of message or messages',
+            ),
+        );
+    }
+    if (args.message !== undefined && (typeof args.message !== 'string' || !args.message)) {
+        server.createErrorResponse(
+            invalidRequest('message', 'Invalid argument: message must be a non-empty string'),
+        );
+    }
+    const role = args.role ?? 'user';
+    if (!MESSAGE_ROLES.includes(role)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'role',
+                `Invalid argument: role must be one of ${MESSAGE_ROLES.join(', ')}`,
+            ),
+        );
+    }
+    const messages =
+        args.messages !== undefined
+            ? normalizeMessages(server, args.messages)
+            : [{ role, content: args.message }];
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        // The context window overview reports what the assembled prompt already uses
+        const response = await server.api.get(`/agents/${agentId}/context`, { headers });
+        const context = response.data ?? {};
+        const limit = context.context_window_size_max;
+        const current = context.context_window_size_current;
+        if (typeof limit !== 'number' || typeof current !== 'number') {
+            server.createErrorResponse(
+                'Letta server did not report a context window size for this agent',
+            );
+        }
+
+        const messageTokens = estimateTokens(messages);
+        const total = current + messageTokens;
+        logger.info(
+            `Agent ${args.agent_id}: ${total}/${limit} tokens with ${messages.length} message(s)`,
+        );
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        agent_id: args.agent_id,
+                        context_window_limit: limit,
+                        current_tokens: current,
+                        message_tokens: messageTokens,
+                        total_tokens: total,
+                        remaining_tokens: limit - total,
+                        fits: total <= limit,
+                        breakdown: {
+                            system: context.num_tokens_system ?? null,
+                            core_memory: context.num_tokens_core_memory ?? null,
+                            functions: context.num_tokens_functions_definitions ?? null,
+                            summary_memory: context.num_tokens_summary_memory ?? null,
+                            external_memory_summary:
+                                context.num_tokens_external_memory_summary ?? null,
+                            messages: context.num_tokens_messages ?? null,
+                        },
+                        estimate_method: ESTIMATE_METHOD,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for estimate_message_tokens
+ */
+export const estimateMessageTokensDefinition = {
+    name: 'estimate_message_tokens',
+    description:
+        "Estimate whether messages will fit in an agent's context window before sending them. Returns the window limit, the tokens the agent's prompt already uses (with a breakdown), an approximate count for the new messages and the remaining headroom. Trim the input or use summarize_agent_conversation when fits is false.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent the messages would be sent to',
+            },
+            message: {
+                type: 'string',
+                description: 'A single message, as passed to prompt_agent',
+            },
+            role: {
+                type: 'string',
+                enum: MESSAGE_ROLES,
+                description: 'Role of message (default: user)',
+                default: 'user',
+            },
+            messages: {
+                type: 'array',
+                description:
+                    'Several messages, as passed to bulk_send_message (instead of message)',
+                items: {
+                    type: 'object',
+                    properties: {
+                        role: {
+                            type: 'string',
+                            enum: MESSAGE_ROLES,
+                            description: 'Message role (default: user)',
+                        },
+                        content: {
+                            type: 'string',
+                            description: 'Message text',
+                        },
+                    },
+                    required: ['content'],
+                },
+            },
+        },
+        required: ['agent_id'],
+    },
+};
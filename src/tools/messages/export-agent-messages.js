@@ -0,0 +1,157 @@
+import fs from 'fs';
+import path from 'path';
+import { createLogger } from '../../core/logger.js';
+import { resolveExportPath } from '../../core/export-dir.js';
+import { fitToResponseSize } from '../../core/response-size.js';
+import { messageRole, scanMessages } from './message-lookup.js';
+import { httpError } from '../../core/errors.js';
+
+const logger = createLogger('export_agent_messages');
+
+/**
+ * Flatten message content, which may be a string or a list of content parts, to text
+ */
+function contentText(content) {
+    if (content === undefined || content === null) return null;
+    if (typeof content === 'string') return content;
+    if (Array.isArray(content)) {
+        return content
+            .map((part) => (typeof part === 'string' ? part : (part?.text ?? '')))
+            .join('');
+    }
+    return JSON.stringify(content);
+}
+
+/**
+ * Normalize a Letta message entry to a stable shape, the same keys for every type
+ * @param {Object} message - Message entry from GET /agents/{agent_id}/messages
+ * @returns {Object} id, role, type, content, tool_calls, tool_call_id, status and timestamp
+ */
+export function normalizeMessage(message) {
+    const calls = message.tool_calls || (message.tool_call ? [message.tool_call] : []);
+    const content =
+        message.content ??
+        message.reasoning ??
+        message.hidden_reasoning ??
+        message.tool_return ??
+        null;
+    return {
+        id: message.id ?? null,
+        role: messageRole(message),
+        type: message.message_type ?? null,
+        content: contentText(content),
+        tool_calls: calls.map((call) => ({
+            id: call.tool_call_id ?? call.id ?? null,
+            name: call.name ?? null,
+            arguments: call.arguments ?? null,
+        })),
+        tool_call_id: message.tool_call_id ?? null,
+        status: message.status ?? null,
+        timestamp: message.date ?? message.created_at ?? null,
+    };
+}
+
+/**
+ * Tool handler for exporting an agent's message history in a normalized schema
+ */
+export async function handleExportAgentMessages(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+
+    // The output path is checked before the history is fetched, as in export_agent
+    let outputPath = null;
+    if (args.output_path) {
+        outputPath = path.resolve(args.output_path);
+        if (server.exportDir) {
+            try {
+                outputPath = resolveExportPath(server.exportDir, args.output_path);
+            } catch (pathError) {
+                server.createErrorResponse(pathError);
+            }
+        }
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        const entries = await scanMessages(server, headers, agentId, () => true, {
+            after: args.after,
+        });
+        const messages = entries.map(normalizeMessage);
+        logger.info(`Exported ${messages.length} messages for agent ${args.agent_id}`);
+
+        let payload;
+        if (outputPath) {
+            // One message per line, so downstream tools can stream the file
+            const lines = messages.map((message) => `${JSON.stringify(message)}\n`).join('');
+            try {
+                fs.writeFileSync(outputPath, lines);
+            } catch (writeError) {
+                logger.error(`Error writing messages to ${outputPath}:`, writeError);
+                server.createErrorResponse(
+                    `Failed to save messages to ${outputPath}: ${writeError.message}`,
+                );
+            }
+            payload = {
+                agent_id: args.agent_id,
+                count: messages.length,
+                file_path: outputPath,
+                size_bytes: Buffer.byteLength(lines),
+            };
+        } else {
+            // Oversized histories are cut short; next_cursor then continues as after
+            payload = fitToResponseSize(messages, (page, truncated) => ({
+                agent_id: args.agent_id,
+                count: page.length,
+                messages: page,
+                next_cursor: truncated ? (page[page.length - 1]?.id ?? null) : null,
+                ...(truncated && { truncated: true }),
+            }));
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(payload),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for export_agent_messages
+ */
+export const exportAgentMessagesDefinition = {
+    name: 'export_agent_messages',
+    description:
+        "Export an agent's whole message history in a normalized schema for analytics: every message has id, role, type, content, tool_calls, tool_call_id, status and timestamp. Returns the messages, or writes them to a JSON Lines file with output_path. Use list_agent_messages to browse raw messages a page at a time.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent whose messages to export',
+            },
+            output_path: {
+                type: 'string',
+                description:
+                    'Optional: Write the messages to this JSON Lines file on the server instead of returning them. When the server sets LETTA_EXPORT_DIR, the path is relative to that directory and must stay inside it.',
+            },
+            after: {
+                type: 'string',
+                description:
+                    'Cursor: export messages after this message ID (e.g. next_cursor from a truncated export)',
+            },
+        },
+        required: ['agent_id'],
+    },
+};
@@ -0,0 +1,208 @@
+import { createLogger } from '../../core/logger.js';
+import { fitToResponseSize } from '../../core/response-size.js';
+import { invalidRequest, httpError } from '../../core/errors.js';
+import {
+    HISTORY_ROLES,
+    MESSAGE_TYPE_ROLES,
+    findMessageById,
+    messageRole,
+    parseDateArgument,
+    scanMessages,
+} from './message-lookup.js';
+
+const logger = createLogger('list_agent_messages');
+
+const DEFAULT_LIMIT = 50;
+
+/**
+ * Tool handler for listing an agent's messages, or fetching a single message by ID
+ */
+export async function handleListAgentMessages(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    const limit = args.limit ?? DEFAULT_LIMIT;
+    if (!Number.isInteger(limit) || limit < 1) {
+        server.createErrorResponse(
+            invalidRequest('limit', 'Invalid argument: limit must be a positive integer'),
+        );
+    }
+    if (args.role !== undefined && !HISTORY_ROLES.includes(args.role)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'role',
+                `Invalid argument: role must be one of ${HISTORY_ROLES.join(', ')}`,
+            ),
+        );
+    }
+    if (args.message_type !== undefined && !(args.message_type in MESSAGE_TYPE_ROLES)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'message_type',
+                `Invalid argument: message_type must be one of ${Object.keys(MESSAGE_TYPE_ROLES).join(', ')}`,
+            ),
+        );
+    }
+    let startTime;
+    let endTime;
+    try {
+        if (args.start_date !== undefined) {
+            startTime = parseDateArgument(args.start_date, 'start_date');
+        }
+        if (args.end_date !== undefined) {
+            endTime = parseDateArgument(args.end_date, 'end_date');
+        }
+    } catch (dateError) {
+        server.createErrorResponse(dateError);
+    }
+    if (startTime !== undefined && endTime !== undefined && startTime > endTime) {
+        server.createErrorResponse(
+            invalidRequest('end_date', 'Invalid argument: end_date must not be before start_date'),
+        );
+    }
+    const dated = startTime !== undefined || endTime !== undefined;
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        if (args.message_id) {
+            const matches = await findMessageById(server, headers, agentId, args.message_id);
+            if (matches.length === 0) {
+                server.createErrorResponse(
+                    httpError(
+                        404,
+                        `Message not found: ${args.message_id} (agent ${args.agent_id})`,
+                    ),
+                );
+            }
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify({
+                            agent_id: args.agent_id,
+                            message_id: args.message_id,
+                            messages: matches,
+                        }),
+                    },
+                ],
+            };
+        }
+
+        // Request one extra message to detect whether another page exists
+        let fetched;
+        if (args.role || args.message_type || dated) {
+            // The API can't filter by role or date, so pages are scanned until enough
+            // entries match. History is oldest first, so the scan ends past end_date.
+            const timeOf = (message) => Date.parse(message.date);
+            const pastEnd = (message) => endTime !== undefined && timeOf(message) > endTime;
+            fetched = await scanMessages(
+                server,
+                headers,
+                agentId,
+                (message) =>
+                    (!args.role || messageRole(message) === args.role) &&
+                    (!args.message_type || message.message_type === args.message_type) &&
+                    (!dated || !Number.isNaN(timeOf(message))) &&
+                    (startTime === undefined || timeOf(message) >= startTime),
+                { maxMatches: limit + 1, after: args.after, before: args.before, stopAt: pastEnd },
+            );
+        } else {
+            const params = { limit: limit + 1 };
+            if (args.before) params.before = args.before;
+            if (args.after) params.after = args.after;
+
+            const response = await server.api.get(`/agents/${agentId}/messages`, {
+                headers,
+                params,
+            });
+            fetched = Array.isArray(response.data) ? response.data : [];
+        }
+        const messages = fetched.slice(0, limit);
+        const hasMore = fetched.length > limit;
+        logger.info(`Fetched ${messages.length} messages for agent ${args.agent_id}`);
+
+        // Oversized pages are cut short; next_cursor then continues after the last message kept
+        const payload = fitToResponseSize(messages, (page, truncated) => ({
+            agent_id: args.agent_id,
+            count: page.length,
+            messages: page,
+            has_more: hasMore || truncated,
+            next_cursor: hasMore || truncated ? (page[page.length - 1]?.id ?? null) : null,
+            ...(truncated && { truncated: true }),
+        }));
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(payload),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for list_agent_messages
+ */
+export const listAgentMessagesDefinition = {
+    name: 'list_agent_messages',
+    description:
+        "List an agent's conversation messages a page at a time, or fetch a single message by message_id. Filter by role (e.g. just user turns), message_type (e.g. just tool calls) or a start_date/end_date window. Use next_cursor as after to page forward.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent whose messages to list',
+            },
+            message_id: {
+                type: 'string',
+                description: 'Return only the message with this ID (pagination is ignored)',
+            },
+            limit: {
+                type: 'integer',
+                description: `Maximum number of messages to return (default: ${DEFAULT_LIMIT})`,
+                minimum: 1,
+                default: DEFAULT_LIMIT,
+            },
+            after: {
+                type: 'string',
+                description: 'Cursor: return messages after this message ID',
+            },
+            before: {
+                type: 'string',
+                description: 'Cursor: return messages before this message ID',
+            },
+            role: {
+                type: 'string',
+                enum: HISTORY_ROLES,
+                description:
+                    'Only return messages with this role: assistant covers assistant text, reasoning and tool calls; tool covers tool returns',
+            },
+            message_type: {
+                type: 'string',
+                enum: Object.keys(MESSAGE_TYPE_ROLES),
+                description: 'Only return messages of this type (e.g., "tool_call_message")',
+            },
+            start_date: {
+                type: 'string',
+                description:
+                    'Only return messages sent at or after this ISO 8601 time (a date alone means midnight UTC)',
+            },
+            end_date: {
+                type: 'string',
+                description:
+                    'Only return messages sent at or before this ISO 8601 time (a date alone means midnight UTC)',
+            },
+        },
+        required: ['agent_id'],
+    },
+};
@@ -0,0 +1,118 @@
+import { invalidRequest } from '../../core/errors.js';
+
+/**
+ * Helpers for locating entries in an agent's message history. The API has no direct
+ * getter, so lookups page through GET /agents/{agent_id}/messages.
+ */
+
+const LOOKUP_PAGE_SIZE = 100;
+
+// Role of each Letta message type
+export const MESSAGE_TYPE_ROLES = {
+    system_message: 'system',
+    user_message: 'user',
+    assistant_message: 'assistant',
+    reasoning_message: 'assistant',
+    hidden_reasoning_message: 'assistant',
+    tool_call_message: 'assistant',
+    approval_request_message: 'assistant',
+    tool_return_message: 'tool',
+    approval_response_message: 'user',
+};
+
+// Roles a history entry can have, for filtering
+export const HISTORY_ROLES = ['system', 'user', 'assistant', 'tool'];
+
+/**
+ * Role of a message entry, from its type
+ * @param {Object} message - Message entry
+ * @returns {string|null}
+ */
+export function messageRole(message) {
+    return MESSAGE_TYPE_ROLES[message.message_type] ?? message.role ?? null;
+}
+
+// ISO 8601 date, optionally with a time and UTC offset (2025-01-31, 2025-01-31T09:30:00Z)
+const ISO_8601 = /^\d{4}-\d{2}-\d{2}(T\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:?\d{2})?)?$/;
+
+/**
+ * Parse an ISO 8601 date argument
+ * @param {*} value - Argument value
+ * @param {string} field - Argument name, for the error
+ * @returns {number} Milliseconds since the epoch; a date alone means midnight UTC
+ * @throws {Error} invalid_request when the value is not an ISO 8601 date
+ */
+export function parseDateArgument(value, field) {
+    const time = typeof value === 'string' && ISO_8601.test(value) ? Date.parse(value) : NaN;
+    if (Number.isNaN(time)) {
+        throw invalidRequest(
+            field,
+            `Invalid argument: ${field} must be an ISO 8601 date (e.g. 2025-01-31 or 2025-01-31T09:30:00Z)`,
+        );
+    }
+    return time;
+}
+
+/**
+ * Page through an agent's messages collecting entries that match a predicate
+ * @param {Object} server - LettaServer instance
+ * @param {Object} headers - API headers
+ * @param {string} agentId - URL-encoded agent ID
+ * @param {Function} predicate - Called with each message entry
+ * @param {Object} [options]
+ * @param {boolean} [options.stopAtFirstPage] - Stop after the first page with a match
+ * @param {number} [options.maxMatches] - Stop once this many entries have matched
+ * @param {string} [options.after] - Start after this message ID
+ * @param {string} [options.before] - Only scan messages before this message ID
+ * @param {Function} [options.stopAt] - Stop scanning at the first entry this returns true for
+ * @returns {Promise<Array>} Matching entries in history order
+ */
+export async function scanMessages(server, headers, agentId, predicate, options = {}) {
+    const matches = [];
+    let after = options.after;
+    for (;;) {
+        const params = { limit: LOOKUP_PAGE_SIZE };
+        if (after) params.after = after;
+        if (options.before) params.before = options.before;
+        const response = await server.api.get(`/agents/${agentId}/messages`, { headers, params });
+        const page = Array.isArray(response.data) ? response.data : [];
+
+        const stop = options.stopAt ? page.findIndex(options.stopAt) : -1;
+        matches.push(...(stop === -1 ? page : page.slice(0, stop)).filter(predicate));
+        if (
+            stop !== -1 ||
+            (options.stopAtFirstPage && matches.length > 0) ||
+            (options.maxMatches && matches.length >= options.maxMatches) ||
+            page.length < LOOKUP_PAGE_SIZE
+        ) {
+            return matches;
+        }
+        after = page[page.length - 1].id;
+    }
+}
+
+/**
+ * Find the entries for a message ID. A single message can appear as several entries
+ * (e.g. reasoning and assistant parts) sharing one ID.
+ */
+export function findMessageById(server, headers, agentId, messageId) {
+    return scanMessages(server, headers, agentId, (message) => message.id === messageId, {
+        stopAtFirstPage: true,
+    });
+}
+
+/**
+ * Tool call IDs referenced by a message entry, whether it makes or answers the call
+ * @param {Object} message - Message entry
+ * @returns {string[]}
+ */
+export function toolCallIdsOf(message) {
+    if (message.message_type === 'tool_return_message') {
+        return message.tool_call_id ? [message.tool_call_id] : [];
+    }
+    if (message.message_type === 'tool_call_message') {
+        const calls = message.tool_calls || (message.tool_call ? [message.tool_call] : []);
+        return calls.map((call) => call.tool_call_id).filter(Boolean);
+    }
+    return [];
+}
@@ -0,0 +1,131 @@
+import { createLogger } from '../../core/logger.js';
+import { findMessageById } from './message-lookup.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('modify_agent_message');
+
+// Message types Letta allows editing, and the field holding each one's text
+const EDITABLE_FIELDS = {
+    system_message: 'content',
+    user_message: 'content',
+    reasoning_message: 'reasoning',
+    assistant_message: 'content',
+};
+
+/**
+ * Tool handler for editing a message in an agent's history
+ */
+export async function handleModifyAgentMessage(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    if (!args.message_id) {
+        server.createErrorResponse('Missing required argument: message_id');
+    }
+    if (typeof args.content !== 'string') {
+        server.createErrorResponse('Missing required argument: content (must be a string)');
+    }
+    if (args.message_type !== undefined && !EDITABLE_FIELDS[args.message_type]) {
+        server.createErrorResponse(
+            invalidRequest(
+                'message_type',
+                `Invalid argument: message_type must be one of ${Object.keys(EDITABLE_FIELDS).join(', ')}`,
+            ),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        // The update body must name the message type, so look up the existing entries first
+        const entries = await findMessageById(server, headers, agentId, args.message_id);
+        if (entries.length === 0) {
+            server.createErrorResponse(
+                httpError(404, `Message not found: ${args.message_id} (agent ${args.agent_id})`),
+            );
+        }
+
+        const editable = entries.filter((entry) =>
+            args.message_type
+                ? entry.message_type === args.message_type
+                : EDITABLE_FIELDS[entry.message_type],
+        );
+        if (editable.length === 0) {
+            const types = entries.map((entry) => entry.message_type).join(', ');
+            server.createErrorResponse(
+                `Message ${args.message_id} cannot be edited: it contains ${types}, but only ${Object.keys(EDITABLE_FIELDS).join(', ')} can be modified`,
+            );
+        }
+        if (editable.length > 1) {
+            server.createErrorResponse(
+                `Message ${args.message_id} has several editable parts (${editable.map((entry) => entry.message_type).join(', ')}); pass message_type to choose one`,
+            );
+        }
+
+        const messageType = editable[0].message_type;
+        const body = {
+            message_type: messageType,
+            [EDITABLE_FIELDS[messageType]]: args.content,
+        };
+
+        logger.info(`Updating ${messageType} ${args.message_id} for agent ${args.agent_id}`);
+        const response = await server.api.patch(
+            `/agents/${agentId}/messages/${encodeURIComponent(args.message_id)}`,
+            body,
+            { headers },
+        );
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        agent_id: args.agent_id,
+                        message_id: args.message_id,
+                        message_type: messageType,
+                        message: response.data,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for modify_agent_message
+ */
+export const modifyAgentMessageDefinition = {
+    name: 'modify_agent_message',
+    description:
+        "Edit the text of a message in an agent's history (system, user, reasoning or assistant messages). Use list_agent_messages to find message IDs. Tool calls and tool returns cannot be edited; use delete_agent_message to remove them.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent that owns the message',
+            },
+            message_id: {
+                type: 'string',
+                description: 'ID of the message to edit',
+            },
+            content: {
+                type: 'string',
+                description: 'New text for the message',
+            },
+            message_type: {
+                type: 'string',
+                enum: Object.keys(EDITABLE_FIELDS),
+                description:
+                    'Which part to edit when the message has several (e.g. reasoning and assistant text)',
+            },
+        },
+        required: ['agent_id', 'message_id', 'content'],
+    },
+};
@@ -0,0 +1,126 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, httpError } from '../../core/errors.js';
+import { requestTimeout, timeoutSecsProperty } from '../../core/timeout.js';
+
+const logger = createLogger('reset_agent_messages');
+
+// Long blocks are cut in the seed message; the agent still has the full blocks in memory
+const MAX_BLOCK_CHARS = 2000;
+
+/**
+ * Build the system message that re-seeds a reset conversation with the core memory
+ * @param {Object[]} blocks - Core memory blocks with label and value
+ * @returns {string}
+ */
+export function buildMemorySummary(blocks) {
+    const sections = blocks.map((block) => {
+        const value = block.value ?? '';
+        const text =
+            value.length > MAX_BLOCK_CHARS ? `${value.slice(0, MAX_BLOCK_CHARS)}…` : value;
+        return `[${block.label}]\n${text}`;
+    });
+    return [
+        'The conversation history was reset. Continue from the current core memory:',
+        ...sections,
+    ].join('\n\n');
+}
+
+/**
+ * Tool handler for clearing an agent's message history
+ */
+export async function handleResetAgentMessages(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        // Memory is read first so an unknown agent fails before anything is cleared
+        let blocks = [];
+        if (args.soft_reset) {
+            const response = await server.api.get(`/agents/${agentId}/core-memory/blocks`, {
+                headers,
+            });
+            blocks = Array.isArray(response.data) ? response.data : [];
+        }
+
+        logger.info(`Resetting messages of agent ${args.agent_id}`);
+        await server.api.patch(`/agents/${agentId}/reset-messages`, null, {
+            headers,
+            params: { add_default_initial_messages: Boolean(args.add_default_initial_messages) },
+        });
+
+        const result = {
+            agent_id: args.agent_id,
+            reset: true,
+            soft_reset: Boolean(args.soft_reset),
+        };
+        if (args.soft_reset) {
+            // The history is already gone, so a failed seed is reported rather than thrown
+            try {
+                const response = await server.api.post(
+                    `/agents/${agentId}/messages`,
+                    { messages: [{ role: 'system', content: buildMemorySummary(blocks) }] },
+                    { headers, ...requestTimeout(args) },
+                );
+                const messages = response.data?.messages ?? [];
+                result.seed = {
+                    block_labels: blocks.map((block) => block.label),
+                    message_ids: [...new Set(messages.map((message) => message.id))],
+                };
+            } catch (seedError) {
+                const { errorCode, message } = classifyError(seedError);
+                logger.error(`Reset agent ${args.agent_id} but could not seed memory: ${message}`);
+                result.seed_error = { error: message, error_code: errorCode };
+            }
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(result),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for reset_agent_messages
+ */
+export const resetAgentMessagesDefinition = {
+    name: 'reset_agent_messages',
+    description:
+        "Clear an agent's conversation history. Memory blocks, tools and the system prompt are kept. With soft_reset the agent is then sent a system message summarizing its current core memory, so it stays coherent instead of starting cold (this runs one agent step). WARNING: The cleared messages cannot be recovered; use export_agent_messages first to keep a copy.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent whose messages to reset',
+            },
+            soft_reset: {
+                type: 'boolean',
+                description:
+                    'After the reset, send a system message summarizing the current core memory (default: false)',
+                default: false,
+            },
+            add_default_initial_messages: {
+                type: 'boolean',
+                description:
+                    "Let Letta add the agent's default initial messages after the reset (default: false)",
+                default: false,
+            },
+            timeout_secs: timeoutSecsProperty,
+        },
+        required: ['agent_id'],
+    },
+};
@@ -0,0 +1,91 @@
+import { createLogger } from '../../core/logger.js';
+import { requestTimeout, timeoutSecsProperty } from '../../core/timeout.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('summarize_agent_conversation');
+
+export const DEFAULT_MAX_MESSAGE_LENGTH = 10;
+export const MAX_MESSAGE_LENGTH_LIMIT = 500;
+
+/**
+ * Tool handler for summarizing an agent's conversation history
+ */
+export async function handleSummarizeAgentConversation(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+
+    const maxMessageLength = args.max_message_length ?? DEFAULT_MAX_MESSAGE_LENGTH;
+    // The Letta server errors opaquely on 0, so reject out-of-range values up front
+    if (
+        !Number.isInteger(maxMessageLength) ||
+        maxMessageLength < 1 ||
+        maxMessageLength > MAX_MESSAGE_LENGTH_LIMIT
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'max_message_length',
+                `Invalid argument: max_message_length must be an integer between 1 and ${MAX_MESSAGE_LENGTH_LIMIT}`,
+            ),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        logger.info(
+            `Summarizing conversation for agent ${args.agent_id} (max_message_length=${maxMessageLength})`,
+        );
+        const response = await server.api.post(`/agents/${agentId}/summarize`, null, {
+            headers,
+            params: { max_message_length: maxMessageLength },
+            ...requestTimeout(args),
+        });
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        agent_id: args.agent_id,
+                        max_message_length: maxMessageLength,
+                        result: response.data ?? null,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for summarize_agent_conversation
+ */
+export const summarizeAgentConversationDefinition = {
+    name: 'summarize_agent_conversation',
+    description:
+        "Summarize an agent's conversation history to free up context window space. Older messages are condensed into a summary, keeping the most recent max_message_length messages in context.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent whose conversation to summarize',
+            },
+            max_message_length: {
+                type: 'integer',
+                description: `Number of messages to keep in context after summarizing (1-${MAX_MESSAGE_LENGTH_LIMIT}, default: ${DEFAULT_MAX_MESSAGE_LENGTH})`,
+                minimum: 1,
+                maximum: MAX_MESSAGE_LENGTH_LIMIT,
+                default: DEFAULT_MAX_MESSAGE_LENGTH,
+            },
+            timeout_secs: timeoutSecsProperty,
+        },
+        required: ['agent_id'],
+    },
+};
@@ -0,0 +1,85 @@
+import { createLogger } from '../../core/logger.js';
+import { pollProperties, pollUntil, readPollOptions } from '../../core/polling.js';
+import { TERMINAL_JOB_STATUSES } from '../jobs/job-status.js';
+import { httpError } from '../../core/errors.js';
+
+const logger = createLogger('wait_for_run');
+
+/**
+ * Tool handler for polling an async run until it finishes
+ */
+export async function handleWaitForRun(server, args) {
+    if (!args?.run_id) {
+        server.createErrorResponse('Missing required argument: run_id');
+    }
+
+    try {
+        const pollOptions = readPollOptions(args);
+        const headers = server.getApiHeaders();
+        const runId = encodeURIComponent(args.run_id);
+
+        const fetchRun = async () => (await server.api.get(`/runs/${runId}`, { headers })).data;
+        const isFinished = (latest) => TERMINAL_JOB_STATUSES.has(latest.status);
+        const poll = await pollUntil(fetchRun, isFinished, pollOptions);
+        const { value: run, done } = poll;
+
+        let messages;
+        if (done) {
+            const messagesResponse = await server.api.get(`/runs/${runId}/messages`, { headers });
+            messages = Array.isArray(messagesResponse.data) ? messagesResponse.data : [];
+        } else {
+            logger.info(`Run ${args.run_id} still ${run.status} after ${pollOptions.waitSecs}s`);
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        run_id: args.run_id,
+                        status: run.status,
+                        done,
+                        // On timeout the caller can call again with the same run_id
+                        timed_out: !done,
+                        run: {
+                            id: run.id,
+                            status: run.status,
+                            created_at: run.created_at,
+                            completed_at: run.completed_at,
+                            stop_reason: run.stop_reason,
+                            metadata: run.metadata,
+                        },
+                        ...(done && { messages }),
+                        polls: poll.polls,
+                        elapsed_secs: poll.elapsedSecs,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Run not found: ${args.run_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for wait_for_run
+ */
+export const waitForRunDefinition = {
+    name: 'wait_for_run',
+    description:
+        "Poll an async run until it completes, fails or is cancelled, then return its status and messages. If the run is still going when wait_secs elapses, returns the current status with timed_out: true so you can call again. Use wait_secs: 0 to check a run's status once.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            run_id: {
+                type: 'string',
+                description: 'ID of the run to wait for',
+            },
+            ...pollProperties,
+        },
+        required: ['run_id'],
+    },
+};
@@ -1,3 +1,5 @@
+import { timestampProperties } from './timestamps.js';
+
 /**
  * Output schemas for all tools to enable structured responses
  */
@@ -106,6 +108,7 @@ export const outputSchemas = {
                 items: { type: 'string' },
             },
             last_activity: { type: 'string' },
+            ...timestampProperties,
         },
         required: ['agent_id', 'name'],
     },
@@ -178,7 +181,14 @@ export const outputSchemas = {
                 },
             },
             total: { type: 'integer' },
+            count: { type: 'integer' },
             has_more: { type: 'boolean' },
+            next_cursor: { type: ['string', 'null'] },
+            prev_cursor: { type: ['string', 'null'] },
+            truncated: {
+                type: 'boolean',
+                description: 'Set when the page was cut short to fit LETTA_MAX_RESPONSE_BYTES',
+            },
         },
         required: ['passages'],
     },
@@ -414,7 +424,7 @@ export const outputSchemas = {
             id: { type: 'string' },
             name: { type: 'string' },
             description: { type: 'string' },
-            created_at: { type: 'string' },
+            ...timestampProperties,
             model: { type: 'string' },
             embedding_model: { type: 'string' },
             memory: { type: 'object' },
@@ -455,6 +465,7 @@ export const outputSchemas = {
             label: { type: 'string' },
             value: { type: 'string' },
             metadata: { type: 'object' },
+            ...timestampProperties,
         },
         required: ['id', 'name', 'label', 'value'],
     },
@@ -0,0 +1,121 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, invalidRequest } from '../../core/errors.js';
+import { callConcurrency, mapSettledWithConcurrency } from '../../core/concurrency.js';
+
+const logger = createLogger('bulk_create_passages');
+
+/**
+ * Tool handler for inserting many passages into an agent's archival memory
+ */
+export async function handleBulkCreatePassages(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    const texts = args.texts;
+    if (!Array.isArray(texts) || texts.length === 0) {
+        server.createErrorResponse('Missing required argument: texts (non-empty array)');
+    }
+    texts.forEach((text, index) => {
+        if (typeof text !== 'string' || !text) {
+            server.createErrorResponse(
+                invalidRequest(
+                    'texts',
+                    `Invalid argument: texts[${index}] must be a non-empty string`,
+                ),
+            );
+        }
+    });
+    if (
+        args.max_concurrency !== undefined &&
+        (!Number.isInteger(args.max_concurrency) || args.max_concurrency < 1)
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'max_concurrency',
+                'Invalid argument: max_concurrency must be a positive integer',
+            ),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+        const maxConcurrency = callConcurrency(server, args.max_concurrency);
+
+        logger.info(
+            `Creating ${texts.length} passage(s) for agent ${args.agent_id} (concurrency ${maxConcurrency})`,
+        );
+
+        // Letta inserts one passage per request; a failed insert is recorded instead of
+        // aborting the rest
+        const settled = await mapSettledWithConcurrency(texts, maxConcurrency, (text) =>
+            server.api.post(`/agents/${agentId}/archival-memory`, { text }, { headers }),
+        );
+
+        const passageIds = [];
+        const errors = [];
+        settled.forEach((result, index) => {
+            if (result.status === 'rejected') {
+                const { errorCode, status, message } = classifyError(result.reason);
+                logger.error(`Failed to create passage ${index}: ${message}`);
+                errors.push({ index, error: message, error_code: errorCode, http_status: status });
+                return;
+            }
+            const created = Array.isArray(result.value.data)
+                ? result.value.data
+                : [result.value.data];
+            passageIds.push(...created.map((passage) => passage?.id).filter(Boolean));
+        });
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        success: errors.length === 0,
+                        summary: {
+                            total: texts.length,
+                            created_count: texts.length - errors.length,
+                            error_count: errors.length,
+                        },
+                        passage_ids: passageIds,
+                        errors,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed during bulk passage creation');
+    }
+}
+
+/**
+ * Tool definition for bulk_create_passages
+ */
+export const bulkCreatePassagesDefinition = {
+    name: 'bulk_create_passages',
+    description:
+        "Insert many passages into an agent's archival memory in parallel (bounded by max_concurrency), e.g. the chunks of an ingested document. Returns the created passage IDs and the index and error of each failed insert; success is false if any insert failed. Use create_passage for a single passage.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent to add the passages to',
+            },
+            texts: {
+                type: 'array',
+                items: { type: 'string' },
+                description: 'Text of each passage to write to archival memory',
+            },
+            max_concurrency: {
+                type: 'integer',
+                description:
+                    'Maximum number of passages to insert at once (default and maximum: LETTA_MAX_CONCURRENCY, or 16)',
+                minimum: 1,
+            },
+        },
+        required: ['agent_id', 'texts'],
+    },
+};
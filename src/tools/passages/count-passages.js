@@ -0,0 +1,56 @@
+import { httpError } from '../../core/errors.js';
+/**
+ * Tool handler for counting the passages in an agent's archival memory
+ */
+export async function handleCountPassages(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        // The context window overview reports the archival memory size without listing it
+        const response = await server.api.get(`/agents/${agentId}/context`, { headers });
+        const count = response.data?.num_archival_memory;
+        if (typeof count !== 'number') {
+            server.createErrorResponse(
+                'Letta server did not report an archival memory size for this agent',
+            );
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({ agent_id: args.agent_id, count }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for count_passages
+ */
+export const countPassagesDefinition = {
+    name: 'count_passages',
+    description:
+        "Count the passages in an agent's archival memory without fetching them. Use list_passages to read the passages themselves.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent whose passages to count',
+            },
+        },
+        required: ['agent_id'],
+    },
+};
@@ -1,3 +1,4 @@
+import { httpError } from '../../core/errors.js';
 /**
  * Tool handler for creating a passage in an agent's archival memory
  */
@@ -41,18 +42,11 @@ export async function handleCreatePassage(server, args) {
             ],
         };
     } catch (error) {
-        // Handle potential 404 if agent not found, 422 for validation, or other API errors
-        if (error.response) {
-            if (error.response.status === 404) {
-                server.createErrorResponse(`Agent not found: ${args.agent_id}`);
-            }
-            if (error.response.status === 422) {
-                server.createErrorResponse(
-                    `Validation error creating passage for agent ${args.agent_id}: ${JSON.stringify(error.response.data)}`,
-                );
-            }
+        // Handle potential 404 if agent not found, or other API errors (422 for validation)
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
         }
-        server.createErrorResponse(error);
+        server.createErrorResponse(error, `Failed to create passage for agent ${args.agent_id}`);
     }
 }
 
@@ -1,3 +1,10 @@
+import {
+    ignoreMissingProperty,
+    isAlreadyAbsent,
+    validateIgnoreMissing,
+} from '../../core/ignore-missing.js';
+import { httpError } from '../../core/errors.js';
+
 /**
  * Tool handler for deleting a passage from an agent's archival memory
  */
@@ -8,6 +15,7 @@ export async function handleDeletePassage(server, args) {
     if (!args?.memory_id) {
         server.createErrorResponse('Missing required argument: memory_id');
     }
+    validateIgnoreMissing(server, args);
 
     try {
         const headers = server.getApiHeaders();
@@ -30,10 +38,27 @@ export async function handleDeletePassage(server, args) {
             ],
         };
     } catch (error) {
+        if (isAlreadyAbsent(args, error)) {
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify({
+                            memory_id: args.memory_id,
+                            agent_id: args.agent_id,
+                            already_absent: true,
+                        }),
+                    },
+                ],
+            };
+        }
         // Handle potential 404 if agent or passage not found, or other API errors
         if (error.response && error.response.status === 404) {
             server.createErrorResponse(
-                `Agent or Passage not found: agent_id=${args.agent_id}, memory_id=${args.memory_id}`,
+                httpError(
+                    404,
+                    `Agent or Passage not found: agent_id=${args.agent_id}, memory_id=${args.memory_id}`,
+                ),
             );
         }
         server.createErrorResponse(error);
@@ -58,6 +83,7 @@ export const deletePassageDefinition = {
                 type: 'string',
                 description: 'ID of the passage (memory) to delete',
             },
+            ignore_missing: ignoreMissingProperty,
         },
         required: ['agent_id', 'memory_id'],
     },
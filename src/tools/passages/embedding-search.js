@@ -0,0 +1,166 @@
+import { validateEmbeddingConfig } from '../../core/model-config.js';
+import { invalidRequest } from '../../core/errors.js';
+
+/**
+ * Archival search with a caller-supplied query embedding. The archival-memory endpoint only
+ * searches by text with the agent's own embedding config, so a precomputed query_embedding
+ * is ranked here by cosine similarity against the stored passage embeddings. An
+ * embedding_config names the space the query embedding belongs to; only passages embedded
+ * with the same model are compared. It has no effect on text search. Ranking reads every
+ * passage, so at most MAX_RANKED_PASSAGES per agent are compared and larger archives are
+ * reported as truncated.
+ */
+
+const PAGE_SIZE = 100;
+
+export const MAX_RANKED_PASSAGES = 10000;
+
+/**
+ * Validate the query_embedding and embedding_config arguments
+ * @param {Object} server - LettaServer instance
+ * @param {Object} args - Tool arguments
+ */
+export function validateEmbeddingSearch(server, args) {
+    if (args.embedding_config !== undefined) {
+        try {
+            validateEmbeddingConfig(args.embedding_config);
+        } catch (error) {
+            server.createErrorResponse(error);
+        }
+        if (args.query_embedding === undefined) {
+            server.createErrorResponse(
+                invalidRequest(
+                    'embedding_config',
+                    "Invalid argument: embedding_config requires query_embedding (text queries are embedded with the agent's own config)",
+                ),
+            );
+        }
+    }
+    if (args.query_embedding === undefined) return;
+
+    const embedding = args.query_embedding;
+    if (
+        !Array.isArray(embedding) ||
+        embedding.length === 0 ||
+        !embedding.every((value) => typeof value === 'number' && Number.isFinite(value))
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'query_embedding',
+                'Invalid argument: query_embedding must be a non-empty array of numbers',
+            ),
+        );
+    }
+    const dim = args.embedding_config?.embedding_dim;
+    if (dim !== undefined && embedding.length !== dim) {
+        server.createErrorResponse(
+            invalidRequest(
+                'query_embedding',
+                `Invalid argument: query_embedding has ${embedding.length} dimensions but embedding_config.embedding_dim is ${dim}`,
+            ),
+        );
+    }
+}
+
+/**
+ * Cosine similarity of two vectors of the same length
+ */
+export function cosineSimilarity(a, b) {
+    let dot = 0;
+    let normA = 0;
+    let normB = 0;
+    for (let i = 0; i < a.length; i++) {
+        dot += a[i] * b[i];
+        normA += a[i] * a[i];
+        normB += b[i] * b[i];
+    }
+    return normA && normB ? dot / Math.sqrt(normA * normB) : 0;
+}
+
+/**
+ * Rank passages by similarity to a query embedding
+ * @param {Object[]} passages - Passages with their embeddings
+ * @param {number[]} queryEmbedding - Query vector
+ * @param {Object} [embeddingConfig] - Only passages embedded with this model are compared
+ * @returns {Object[]} Comparable passages with a score, best first
+ */
+export function rankByEmbedding(passages, queryEmbedding, embeddingConfig) {
+    const model = embeddingConfig?.embedding_model;
+    return passages
+        .filter(
+            (passage) =>
+                Array.isArray(passage.embedding) &&
+                passage.embedding.length === queryEmbedding.length &&
+                (!model ||
+                    !passage.embedding_config?.embedding_model ||
+                    passage.embedding_config.embedding_model === model),
+        )
+        .map((passage) => ({
+            ...passage,
+            score: cosineSimilarity(queryEmbedding, passage.embedding),
+        }))
+        .sort((a, b) => b.score - a.score);
+}
+
+/**
+ * Fetch every passage in an agent's archival memory
+ * @param {Object} server - LettaServer instance
+ * @param {Object} headers - API headers
+ * @param {string} agentId - URL-encoded agent ID
+ * @param {number} [maxPassages] - Stop once at least this many passages have been read
+ * @returns {Promise<Object[]>}
+ */
+export async function fetchAllPassages(server, headers, agentId, maxPassages = Infinity) {
+    const passages = [];
+    let after;
+    for (;;) {
+        const params = { limit: PAGE_SIZE };
+        if (after) params.after = after;
+        const response = await server.api.get(`/agents/${agentId}/archival-memory`, {
+            headers,
+            params,
+        });
+        const page = Array.isArray(response.data) ? response.data : [];
+        passages.push(...page);
+        if (page.length < PAGE_SIZE || passages.length >= maxPassages) return passages;
+        after = page[page.length - 1].id;
+    }
+}
+
+/**
+ * Fetch the passages an embedding search compares, at most MAX_RANKED_PASSAGES
+ * @param {Object} server - LettaServer instance
+ * @param {Object} headers - API headers
+ * @param {string} agentId - URL-encoded agent ID
+ * @returns {Promise<{passages: Object[], truncated: boolean}>}
+ */
+export async function fetchPassagesToRank(server, headers, agentId) {
+    const passages = await fetchAllPassages(server, headers, agentId, MAX_RANKED_PASSAGES + 1);
+    return {
+        passages: passages.slice(0, MAX_RANKED_PASSAGES),
+        truncated: passages.length > MAX_RANKED_PASSAGES,
+    };
+}
+
+/**
+ * Schema properties for the query_embedding and embedding_config arguments
+ */
+export const embeddingSearchProperties = {
+    query_embedding: {
+        type: 'array',
+        items: { type: 'number' },
+        description:
+            `Optional: Precomputed query embedding. Passages are ranked by cosine similarity to it (each hit gets a score) instead of the server text search; passages with a different dimension are skipped. At most ${MAX_RANKED_PASSAGES} passages per agent are compared (scan_truncated is set when an archive is larger).`,
+    },
+    embedding_config: {
+        type: 'object',
+        description:
+            "Optional: Embedding config query_embedding was computed with. Its embedding_dim must match query_embedding and only passages embedded with the same embedding_model are compared. Requires query_embedding; it does not change text search, which always uses the agent's own embedding config.",
+        properties: {
+            embedding_endpoint_type: { type: 'string' },
+            embedding_model: { type: 'string' },
+            embedding_dim: { type: 'integer', minimum: 1 },
+        },
+        required: ['embedding_endpoint_type', 'embedding_model', 'embedding_dim'],
+    },
+};
@@ -1,3 +1,23 @@
+import { fitToResponseSize } from '../../core/response-size.js';
+import {
+    embeddingSearchProperties,
+    fetchPassagesToRank,
+    rankByEmbedding,
+    validateEmbeddingSearch,
+} from './embedding-search.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+/**
+ * Drop the embedding vectors from passages
+ */
+function withoutEmbeddings(passages) {
+    return passages.map((passage) => {
+        // eslint-disable-next-line no-unused-vars
+        const { embedding, ...rest } = passage; // Destructure to remove embedding
+        return rest;
+    });
+}
+
 /**
  * Tool handler for listing passages in an agent's archival memory
  */
@@ -5,11 +25,48 @@ export async function handleListPassages(server, args) {
     if (!args?.agent_id) {
         server.createErrorResponse('Missing required argument: agent_id');
     }
+    validateEmbeddingSearch(server, args);
+    if (args.query_embedding !== undefined && (args.search || args.after || args.before)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'query_embedding',
+                'Invalid argument: query_embedding cannot be combined with search, after or before',
+            ),
+        );
+    }
 
     try {
         const headers = server.getApiHeaders();
         const agentId = encodeURIComponent(args.agent_id);
 
+        // A precomputed query embedding ranks the whole archive locally, best match first
+        if (args.query_embedding !== undefined) {
+            const scan = await fetchPassagesToRank(server, headers, agentId);
+            let ranked = rankByEmbedding(
+                scan.passages,
+                args.query_embedding,
+                args.embedding_config,
+            );
+            if (args.limit) ranked = ranked.slice(0, args.limit);
+            if (!args.include_embeddings) ranked = withoutEmbeddings(ranked);
+
+            const payload = fitToResponseSize(ranked, (page, truncated) => ({
+                passages: page,
+                count: page.length,
+                ranked_by: 'query_embedding',
+                ...(scan.truncated && { scan_truncated: true }),
+                ...(truncated && { truncated: true }),
+            }));
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify(payload),
+                    },
+                ],
+            };
+        }
+
         // Construct query parameters based on optional args
         const params = {};
         if (args.after) params.after = args.after;
@@ -34,27 +91,34 @@ export async function handleListPassages(server, args) {
         // Optionally remove embeddings from the response
         const includeEmbeddings = args?.include_embeddings ?? false;
         if (!includeEmbeddings) {
-            passages = passages.map((passage) => {
-                // eslint-disable-next-line no-unused-vars
-                const { embedding, ...rest } = passage; // Destructure to remove embedding
-                return rest;
-            });
+            passages = withoutEmbeddings(passages);
         }
 
+        // A full page means there may be more; pass next_cursor as `after` to continue
+        const fullPage = args.limit > 0 && passages.length >= args.limit;
+        const paginated = Boolean(args.limit || args.after || args.before);
+
+        // Oversized pages are cut short; next_cursor then continues after the last passage kept
+        const payload = fitToResponseSize(passages, (page, truncated) => ({
+            passages: page,
+            count: page.length,
+            next_cursor: truncated || fullPage ? (page[page.length - 1]?.id ?? null) : null,
+            prev_cursor: paginated && passages.length > 0 ? passages[0].id : null,
+            ...(truncated && { truncated: true }),
+        }));
+
         return {
             content: [
                 {
                     type: 'text',
-                    text: JSON.stringify({
-                        passages: passages,
-                    }),
+                    text: JSON.stringify(payload),
                 },
             ],
         };
     } catch (error) {
         // Handle potential 404 if agent not found, or other API errors
         if (error.response && error.response.status === 404) {
-            server.createErrorResponse(`Agent not found: ${args.agent_id}`);
+            server.createErrorResponse(httpError(404, `Agent not found: ${args.agent_id}`));
         }
         server.createErrorResponse(error);
     }
@@ -66,7 +130,7 @@ export async function handleListPassages(server, args) {
 export const listPassagesDefinition = {
     name: 'list_passages',
     description:
-        "Retrieve the memories in an agent's archival memory store (paginated query). Use create_passage to add new memories, modify_passage to edit, or delete_passage to remove them.",
+        "Retrieve the memories in an agent's archival memory store (paginated query; a full page returns next_cursor for the following page). Use create_passage to add new memories, modify_passage to edit, or delete_passage to remove them.",
     inputSchema: {
         type: 'object',
         properties: {
@@ -77,11 +141,12 @@ export const listPassagesDefinition = {
             after: {
                 type: 'string',
                 description:
-                    'Unique ID of the memory to start the query range at (for pagination).',
+                    'Unique ID of the memory to start the query range at (for pagination). Pass the next_cursor from a previous page.',
             },
             before: {
                 type: 'string',
-                description: 'Unique ID of the memory to end the query range at (for pagination).',
+                description:
+                    'Unique ID of the memory to end the query range at (for pagination). Pass the prev_cursor from a previous page.',
             },
             limit: {
                 type: 'integer',
@@ -110,6 +175,7 @@ export const listPassagesDefinition = {
                     'Whether to include the full embedding vectors in the response (default: false).',
                 default: false,
             },
+            ...embeddingSearchProperties,
         },
         required: ['agent_id'],
     },
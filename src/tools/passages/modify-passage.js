@@ -1,7 +1,42 @@
 import { createLogger } from '../../core/logger.js';
+import { classifyError, httpError } from '../../core/errors.js';
 
 const logger = createLogger('modify_passage');
 
+/**
+ * Replace a passage by creating one with the new text and then deleting the original.
+ * The original timestamp and tags are carried over so the passage keeps its place in
+ * time-ordered listings. If the original can't be deleted the new passage is kept and a
+ * warning names the original, so the caller can remove it rather than lose the update.
+ * @returns {Promise<{passages: Object[], warning?: string}>}
+ */
+async function recreatePassage(server, headers, agentId, existingPassage, text) {
+    const payload = { text };
+    if (existingPassage.created_at) payload.created_at = existingPassage.created_at;
+    if (Array.isArray(existingPassage.tags)) payload.tags = existingPassage.tags;
+
+    const createResponse = await server.api.post(`/agents/${agentId}/archival-memory`, payload, {
+        headers,
+    });
+    const created = createResponse.data;
+    const passages = Array.isArray(created) ? created : [created];
+
+    try {
+        await server.api.delete(
+            `/agents/${agentId}/archival-memory/${encodeURIComponent(existingPassage.id)}`,
+            { headers },
+        );
+    } catch (error) {
+        const { message } = classifyError(error);
+        logger.warn(`Could not delete original passage ${existingPassage.id}: ${message}`);
+        return {
+            passages,
+            warning: `The original passage ${existingPassage.id} could not be deleted (${message}) and is still in archival memory; remove it with delete_passage`,
+        };
+    }
+    return { passages };
+}
+
 /**
  * Tool handler for modifying a passage in an agent's archival memory
  */
@@ -12,7 +47,9 @@ export async function handleModifyPassage(server, args) {
     if (!args?.memory_id) {
         server.createErrorResponse('Missing required argument: memory_id');
     }
-    if (!args?.update_data || typeof args.update_data.text !== 'string') {
+    // Top-level text is shorthand for update_data.text
+    const newText = args?.text ?? args?.update_data?.text;
+    if (typeof newText !== 'string') {
         // Ensure text is provided and is a string
         server.createErrorResponse(
             "Missing or invalid required argument: update_data must contain a 'text' field (string).",
@@ -25,70 +62,86 @@ export async function handleModifyPassage(server, args) {
         const memoryId = args.memory_id; // Use for finding, encode for URL
 
         // Step 1: Fetch ALL passages for the agent to find the target one
-        let existingPassage = null;
+        let allPassages;
         try {
-            // Use list_passages logic internally, ensuring embeddings are included for the PATCH
             const listResponse = await server.api.get(`/agents/${agentId}/archival-memory`, {
                 headers,
-                params: { include_embeddings: true }, // Ensure we get embeddings
             });
-            const allPassages = listResponse.data;
-            if (Array.isArray(allPassages)) {
-                existingPassage = allPassages.find((p) => p.id === memoryId);
-            }
-            if (!existingPassage) {
-                throw new Error(`Could not find passage ${memoryId} for agent ${agentId}.`);
-            }
-            // Basic check for required fields based on the schema provided by the user
-            if (
-                !existingPassage.embedding_config ||
-                !existingPassage.id ||
-                existingPassage.text === undefined
-            ) {
-                logger.error('Fetched passage object is missing required fields:', existingPassage);
-                throw new Error(
-                    `Fetched passage ${memoryId} is missing required fields (embedding_config, id, text).`,
-                );
-            }
+            allPassages = listResponse.data;
         } catch (fetchError) {
             if (fetchError.response && fetchError.response.status === 404) {
                 server.createErrorResponse(
-                    `Agent not found when listing passages: ${args.agent_id}`,
+                    httpError(404, `Agent not found when listing passages: ${args.agent_id}`),
                 );
             }
             logger.error('Error fetching passages:', fetchError);
             server.createErrorResponse(
-                `Failed to fetch passages for agent ${args.agent_id}: ${fetchError.message}`,
+                fetchError,
+                `Failed to fetch passages for agent ${args.agent_id}`,
+            );
+        }
+        const existingPassage = Array.isArray(allPassages)
+            ? allPassages.find((p) => p.id === memoryId)
+            : undefined;
+        if (!existingPassage) {
+            server.createErrorResponse(
+                httpError(404, `Passage not found: ${memoryId} for agent ${args.agent_id}`),
+            );
+        }
+        // Basic check for required fields based on the schema provided by the user
+        if (
+            !existingPassage.embedding_config ||
+            !existingPassage.id ||
+            existingPassage.text === undefined
+        ) {
+            logger.error('Fetched passage object is missing required fields:', existingPassage);
+            server.createErrorResponse(
+                `Fetched passage ${memoryId} is missing required fields (embedding_config, id, text).`,
             );
         }
 
-        // Step 2: Construct the full update payload based on the fetched passage, modifying only the text
+        // Step 2: Construct the full update payload based on the fetched passage, modifying only the text.
+        // The stale embedding is dropped so the server re-embeds the new text.
+        // eslint-disable-next-line no-unused-vars
+        const { embedding: staleEmbedding, ...existingFields } = existingPassage;
         const updatePayload = {
-            ...existingPassage, // Copy all fields from the fetched passage
-            text: args.update_data.text, // Update the text field
+            ...existingFields, // Copy all other fields from the fetched passage
+            text: newText, // Update the text field
         };
 
-        // Remove fields that shouldn't be sent in PATCH body if necessary (adjust based on API behavior)
-        // delete updatePayload.created_at;
-        // delete updatePayload.updated_at;
-        // delete updatePayload.created_by_id;
-        // delete updatePayload.last_updated_by_id;
-        // delete updatePayload.organization_id;
-        // delete updatePayload.agent_id; // agent_id is in the URL path
-
         logger.info(
             `[modify_passage] Sending payload for memory_id ${memoryId}:`,
             JSON.stringify(updatePayload),
         );
 
-        // Step 3: Send the PATCH request with the complete payload
-        const patchResponse = await server.api.patch(
-            `/agents/${agentId}/archival-memory/${encodeURIComponent(memoryId)}`,
-            updatePayload,
-            { headers },
-        );
-        let modifiedPassages = patchResponse.data; // API returns an array of modified Passage objects
-
+        // Step 3: Send the PATCH request with the complete payload. Servers without a direct
+        // update endpoint get a delete + recreate instead, which always re-embeds.
+        let modifiedPassages;
+        let warning;
+        let strategy = 'update';
+        try {
+            const patchResponse = await server.api.patch(
+                `/agents/${agentId}/archival-memory/${encodeURIComponent(memoryId)}`,
+                updatePayload,
+                { headers },
+            );
+            modifiedPassages = patchResponse.data; // API returns an array of modified Passage objects
+        } catch (patchError) {
+            if (![405, 501].includes(patchError.response?.status)) {
+                throw patchError;
+            }
+            logger.info(
+                `[modify_passage] Direct update unsupported (${patchError.response.status}), recreating passage ${memoryId}`,
+            );
+            ({ passages: modifiedPassages, warning } = await recreatePassage(
+                server,
+                headers,
+                agentId,
+                existingPassage,
+                newText,
+            ));
+            strategy = 'recreate';
+        }
         // Optionally remove embeddings from the response based on the flag
         const includeEmbeddings = args?.include_embeddings ?? false;
         if (!includeEmbeddings && Array.isArray(modifiedPassages)) {
@@ -105,6 +158,9 @@ export async function handleModifyPassage(server, args) {
                     type: 'text',
                     text: JSON.stringify({
                         passages: modifiedPassages,
+                        strategy,
+                        ...(strategy === 'recreate' && { replaced_memory_id: memoryId }),
+                        ...(warning && { warning, orphaned_memory_id: memoryId }),
                     }),
                 },
             ],
@@ -114,16 +170,14 @@ export async function handleModifyPassage(server, args) {
         if (error.response) {
             if (error.response.status === 404) {
                 server.createErrorResponse(
-                    `Agent or Passage not found during update: agent_id=${args.agent_id}, memory_id=${args.memory_id}`,
-                );
-            }
-            if (error.response.status === 422) {
-                server.createErrorResponse(
-                    `Validation error modifying passage ${args.memory_id}: ${JSON.stringify(error.response.data)}`,
+                    httpError(
+                        404,
+                        `Agent or Passage not found during update: agent_id=${args.agent_id}, memory_id=${args.memory_id}`,
+                    ),
                 );
             }
         }
-        server.createErrorResponse(error);
+        server.createErrorResponse(error, `Failed to modify passage ${args.memory_id}`);
     }
 }
 
@@ -133,7 +187,7 @@ export async function handleModifyPassage(server, args) {
 export const modifyPassageDefinition = {
     name: 'modify_passage',
     description:
-        "Modify a memory in the agent's archival memory store. Use list_passages to find memory IDs. Currently only supports updating the text content.",
+        "Modify a memory in the agent's archival memory store. Use list_passages to find memory IDs. Currently only supports updating the text content; the passage is re-embedded (via recreate and delete if the server has no direct update; if the original then can't be deleted, the response has a warning and its orphaned_memory_id).",
     inputSchema: {
         type: 'object',
         properties: {
@@ -158,6 +212,10 @@ export const modifyPassageDefinition = {
                 },
                 required: ['text'], // Require 'text' within the update_data object
             },
+            text: {
+                type: 'string',
+                description: 'Shorthand for update_data.text: the new text content for the passage.',
+            },
             include_embeddings: {
                 type: 'boolean',
                 description:
@@ -165,6 +223,7 @@ export const modifyPassageDefinition = {
                 default: false,
             },
         },
-        required: ['agent_id', 'memory_id', 'update_data'],
+        // Custom validation in the handler ensures text is provided via update_data or text
+        required: ['agent_id', 'memory_id'],
     },
 };
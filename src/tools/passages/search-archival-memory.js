@@ -0,0 +1,198 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, invalidRequest } from '../../core/errors.js';
+import { callConcurrency, mapSettledWithConcurrency } from '../../core/concurrency.js';
+import { fitToResponseSize } from '../../core/response-size.js';
+import { agentFilterProperties, findAgents, hasAgentFilter } from '../agents/agent-filters.js';
+import {
+    embeddingSearchProperties,
+    fetchPassagesToRank,
+    rankByEmbedding,
+    validateEmbeddingSearch,
+} from './embedding-search.js';
+
+const logger = createLogger('search_archival_memory');
+
+const DEFAULT_LIMIT = 10;
+
+/**
+ * Merge per-agent result lists into one ranking. Letta returns search hits best first
+ * without a comparable score, so hits are interleaved by rank: every agent's best hit,
+ * then every agent's second best, and so on.
+ * @param {Array<Object[]>} lists - Hits per agent, best first, each with a rank
+ * @param {number} limit - Maximum number of hits to keep
+ * @returns {Object[]}
+ */
+export function mergeByRank(lists, limit) {
+    const merged = [];
+    const depth = Math.max(0, ...lists.map((list) => list.length));
+    for (let rank = 0; rank < depth && merged.length < limit; rank++) {
+        for (const list of lists) {
+            if (rank < list.length && merged.length < limit) merged.push(list[rank]);
+        }
+    }
+    return merged;
+}
+
+/**
+ * Tool handler for searching archival memory across several agents
+ */
+export async function handleSearchArchivalMemory(server, args) {
+    const byEmbedding = args?.query_embedding !== undefined;
+    if (!byEmbedding && (typeof args?.query !== 'string' || !args.query.trim())) {
+        server.createErrorResponse('Missing required argument: query');
+    }
+    validateEmbeddingSearch(server, args);
+    // Embedding search reads whole archives, so it isn't run across every agent at once
+    if (byEmbedding && !hasAgentFilter(args)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'agent_ids',
+                'Invalid argument: query_embedding requires agent_ids, agent_name_filter or agent_tag_filter',
+            ),
+        );
+    }
+    if (args.limit !== undefined && (!Number.isInteger(args.limit) || args.limit < 1)) {
+        server.createErrorResponse(
+            invalidRequest('limit', 'Invalid argument: limit must be a positive integer'),
+        );
+    }
+    if (
+        args.max_concurrency !== undefined &&
+        (!Number.isInteger(args.max_concurrency) || args.max_concurrency < 1)
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'max_concurrency',
+                'Invalid argument: max_concurrency must be a positive integer',
+            ),
+        );
+    }
+    const limit = args.limit ?? DEFAULT_LIMIT;
+
+    try {
+        const headers = server.getApiHeaders();
+        // Without a filter every agent is searched
+        const agents = await findAgents(server, args, headers);
+        const maxConcurrency = callConcurrency(server, args.max_concurrency);
+        logger.info(`Searching archival memory of ${agents.length} agents`);
+
+        // Each agent can contribute at most `limit` hits to the merged set
+        const settled = await mapSettledWithConcurrency(agents, maxConcurrency, async (agent) => {
+            const agentId = encodeURIComponent(agent.id);
+            if (byEmbedding) {
+                const scan = await fetchPassagesToRank(server, headers, agentId);
+                const ranked = rankByEmbedding(
+                    scan.passages,
+                    args.query_embedding,
+                    args.embedding_config,
+                );
+                return { data: ranked.slice(0, limit), scanTruncated: scan.truncated };
+            }
+            return server.api.get(`/agents/${agentId}/archival-memory`, {
+                headers,
+                params: { search: args.query, limit },
+            });
+        });
+
+        const lists = [];
+        const errors = [];
+        const scanTruncated = [];
+        settled.forEach((result, index) => {
+            const agentId = agents[index].id;
+            if (result.status === 'rejected') {
+                const { errorCode, status, message } = classifyError(result.reason);
+                logger.warn(`Archival search failed for agent ${agentId}: ${message}`);
+                errors.push({
+                    agent_id: agentId,
+                    error: status === 404 ? `Agent not found: ${agentId}` : message,
+                    error_code: errorCode,
+                });
+                return;
+            }
+            if (result.value.scanTruncated) scanTruncated.push(agentId);
+            const passages = Array.isArray(result.value.data) ? result.value.data : [];
+            lists.push(
+                passages.map((passage, rank) => {
+                    // eslint-disable-next-line no-unused-vars
+                    const { embedding, ...rest } = passage;
+                    const hit = args.include_embeddings ? passage : rest;
+                    return { ...hit, agent_id: agentId, rank: rank + 1 };
+                }),
+            );
+        });
+
+        if (agents.length > 0 && errors.length === agents.length) {
+            server.createErrorResponse(settled[0].reason);
+        }
+
+        // Similarity scores are comparable across agents; server text search ranks are not
+        const hits = byEmbedding
+            ? lists
+                  .flat()
+                  .sort((a, b) => b.score - a.score)
+                  .slice(0, limit)
+            : mergeByRank(lists, limit);
+        const payload = fitToResponseSize(hits, (page, truncated) => ({
+            query: args.query ?? null,
+            ranked_by: byEmbedding ? 'query_embedding' : 'rank',
+            agents_searched: agents.length,
+            count: page.length,
+            results: page,
+            ...(errors.length > 0 && { errors }),
+            ...(scanTruncated.length > 0 && { scan_truncated_agent_ids: scanTruncated }),
+            ...(truncated && { truncated: true }),
+        }));
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(payload),
+                },
+            ],
+        };
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for search_archival_memory
+ */
+export const searchArchivalMemoryDefinition = {
+    name: 'search_archival_memory',
+    description:
+        "Search archival memory across several agents at once (all agents unless agent_ids or a name/tag filter is given). Agents are searched concurrently and the hits merged by rank (or by similarity score with query_embedding, which needs agent_ids or a name/tag filter), each tagged with the agent_id it came from; limit caps the merged set. Agents that fail are listed in errors. Use list_passages to search a single agent's archive.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            query: {
+                type: 'string',
+                description:
+                    'Text to search archival memory for (required unless query_embedding is given)',
+            },
+            ...agentFilterProperties('search'),
+            limit: {
+                type: 'integer',
+                description: `Maximum number of hits across all agents (default: ${DEFAULT_LIMIT})`,
+                minimum: 1,
+                default: DEFAULT_LIMIT,
+            },
+            max_concurrency: {
+                type: 'integer',
+                description:
+                    'Maximum number of agents to search at once (default and maximum: LETTA_MAX_CONCURRENCY, or 16)',
+                minimum: 1,
+            },
+            include_embeddings: {
+                type: 'boolean',
+                description:
+                    'Whether to include the full embedding vectors in the response (default: false).',
+                default: false,
+            },
+            ...embeddingSearchProperties,
+        },
+        // query is optional when query_embedding is given
+        required: [],
+    },
+};
@@ -0,0 +1,22 @@
+import { changeSourceAttachment, sourceAttachmentProperties } from './source-attachment.js';
+
+/**
+ * Tool handler for attaching a source to an agent
+ */
+export function handleAttachSource(server, args) {
+    return changeSourceAttachment(server, args, 'attach');
+}
+
+/**
+ * Tool definition for attach_source
+ */
+export const attachSourceDefinition = {
+    name: 'attach_source',
+    description:
+        "Attach a data source (folder) to an agent so its passages become searchable by the agent. Returns the updated agent state, or with concise just the agent's attached source IDs. Use detach_source to remove it.",
+    inputSchema: {
+        type: 'object',
+        properties: sourceAttachmentProperties('attach'),
+        required: ['agent_id', 'source_id'],
+    },
+};
@@ -0,0 +1,79 @@
+/**
+ * Content type inference for source file uploads.
+ *
+ * Letta classifies an upload by its content type, and guesses (sometimes wrongly) when none
+ * is sent. When the caller gives no content_type it is taken from the file name's extension,
+ * falling back to the file's leading bytes for unknown extensions.
+ */
+
+export const DEFAULT_CONTENT_TYPE = 'application/octet-stream';
+
+const SNIFF_BYTES = 8192;
+
+const EXTENSION_CONTENT_TYPES = {
+    pdf: 'application/pdf',
+    txt: 'text/plain',
+    text: 'text/plain',
+    log: 'text/plain',
+    md: 'text/markdown',
+    markdown: 'text/markdown',
+    csv: 'text/csv',
+    tsv: 'text/tab-separated-values',
+    json: 'application/json',
+    jsonl: 'application/jsonl',
+    html: 'text/html',
+    htm: 'text/html',
+    xml: 'application/xml',
+    rtf: 'application/rtf',
+    doc: 'application/msword',
+    docx: 'application/vnd.openxmlformats-officedocument.wordprocessingml.document',
+    xlsx: 'application/vnd.openxmlformats-officedocument.spreadsheetml.sheet',
+    pptx: 'application/vnd.openxmlformats-officedocument.presentationml.presentation',
+};
+
+/**
+ * Guess a content type from a file's leading bytes
+ * @param {Buffer} bytes - File content
+ * @returns {string|null} The content type, or null if the bytes aren't recognised
+ */
+export function sniffContentType(bytes) {
+    if (!bytes || bytes.length === 0) return null;
+    if (bytes.subarray(0, 5).toString('latin1') === '%PDF-') return 'application/pdf';
+    if (bytes.subarray(0, 5).toString('latin1') === '{\\rtf') return 'application/rtf';
+    // A NUL byte means binary; anything else that is valid UTF-8 is treated as plain text
+    let sample = bytes.subarray(0, SNIFF_BYTES);
+    if (sample.includes(0)) return null;
+    if (bytes.length > SNIFF_BYTES) {
+        // Don't let a multi-byte character cut off by the sample count against the file
+        let end = sample.length;
+        while (end > sample.length - 4 && (sample[end - 1] & 0xc0) === 0x80) end--;
+        if (end > 0 && sample[end - 1] >= 0xc0) end--;
+        sample = sample.subarray(0, end);
+    }
+    try {
+        new TextDecoder('utf-8', { fatal: true }).decode(sample);
+        return 'text/plain';
+    } catch {
+        return null;
+    }
+}
+
+/**
+ * Content type for an upload: the explicit one if given, else inferred
+ * @param {Object} options
+ * @param {string} options.fileName - File name, including its extension
+ * @param {string} [options.contentType] - Content type given by the caller
+ * @param {Buffer} [options.bytes] - File content, sniffed when the extension is unknown
+ * @returns {{contentType: string, source: string}} The type and how it was chosen
+ *   ('explicit', 'extension', 'content' or 'default')
+ */
+export function resolveContentType({ fileName, contentType, bytes }) {
+    if (contentType) return { contentType, source: 'explicit' };
+    const extension = /\.([^./\\]+)$/.exec(fileName || '')?.[1]?.toLowerCase();
+    if (extension && EXTENSION_CONTENT_TYPES[extension]) {
+        return { contentType: EXTENSION_CONTENT_TYPES[extension], source: 'extension' };
+    }
+    const sniffed = sniffContentType(bytes);
+    if (sniffed) return { contentType: sniffed, source: 'content' };
+    return { contentType: DEFAULT_CONTENT_TYPE, source: 'default' };
+}
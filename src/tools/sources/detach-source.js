@@ -0,0 +1,22 @@
+import { changeSourceAttachment, sourceAttachmentProperties } from './source-attachment.js';
+
+/**
+ * Tool handler for detaching a source from an agent
+ */
+export function handleDetachSource(server, args) {
+    return changeSourceAttachment(server, args, 'detach');
+}
+
+/**
+ * Tool definition for detach_source
+ */
+export const detachSourceDefinition = {
+    name: 'detach_source',
+    description:
+        "Detach a data source (folder) from an agent. Returns the updated agent state, or with concise just the agent's remaining source IDs. Use list_agents_using_source to see which agents use a source.",
+    inputSchema: {
+        type: 'object',
+        properties: sourceAttachmentProperties('detach'),
+        required: ['agent_id', 'source_id'],
+    },
+};
@@ -0,0 +1,123 @@
+import { createLogger } from '../../core/logger.js';
+import { resourceTimestamps } from '../timestamps.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('get_source_file_content');
+
+export const DEFAULT_MAX_FILE_BYTES = 5_000_000;
+
+/**
+ * Reject a file larger than the size cap before it is encoded into the response
+ */
+function checkSize(server, args, size, maxBytes) {
+    if (size > maxBytes) {
+        server.createErrorResponse(
+            `File ${args.file_id} is ${size} bytes, larger than max_bytes (${maxBytes}). Pass a larger max_bytes to download it.`,
+        );
+    }
+}
+
+/**
+ * Tool handler for downloading a file from a data source
+ */
+export async function handleGetSourceFileContent(server, args) {
+    if (!args?.source_id) {
+        server.createErrorResponse('Missing required argument: source_id');
+    }
+    if (!args.file_id) {
+        server.createErrorResponse('Missing required argument: file_id');
+    }
+    const maxBytes = args.max_bytes ?? DEFAULT_MAX_FILE_BYTES;
+    if (!Number.isInteger(maxBytes) || maxBytes < 1) {
+        server.createErrorResponse(
+            invalidRequest('max_bytes', 'Invalid argument: max_bytes must be a positive integer'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const fileUrl = `/sources/${encodeURIComponent(args.source_id)}/files/${encodeURIComponent(args.file_id)}`;
+
+        // Check the recorded size first so an oversized file is never downloaded
+        const metadataResponse = await server.api.get(fileUrl, { headers });
+        const metadata = metadataResponse.data;
+        if (typeof metadata?.file_size === 'number') {
+            checkSize(server, args, metadata.file_size, maxBytes);
+        }
+
+        const contentResponse = await server.api.get(fileUrl, {
+            headers,
+            params: { include_content: true },
+        });
+        const content = contentResponse.data?.content;
+        if (typeof content !== 'string') {
+            server.createErrorResponse(
+                `File content is not available for ${args.file_id}; the Letta server did not return it`,
+            );
+        }
+
+        const bytes = Buffer.from(content, 'utf8');
+        checkSize(server, args, bytes.length, maxBytes);
+        logger.info(`Downloaded ${bytes.length} bytes of ${metadata.file_name || args.file_id}`);
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        source_id: args.source_id,
+                        file_id: args.file_id,
+                        filename: metadata.file_name,
+                        content_type: metadata.file_type || 'application/octet-stream',
+                        size_bytes: bytes.length,
+                        ...resourceTimestamps(metadata),
+                        content_base64: bytes.toString('base64'),
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && [405, 501].includes(error.response.status)) {
+            server.createErrorResponse(
+                'File download is not supported by this Letta server (GET /v1/sources/{source_id}/files/{file_id} is unavailable)',
+            );
+        }
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(
+                httpError(
+                    404,
+                    `Source or file not found: source_id=${args.source_id}, file_id=${args.file_id}`,
+                ),
+            );
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for get_source_file_content
+ */
+export const getSourceFileContentDefinition = {
+    name: 'get_source_file_content',
+    description:
+        "Download a file from a data source as base64, with its filename and content type. Files larger than max_bytes are refused before download. Letta returns the file's extracted text, so binary uploads such as PDFs come back as text.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            source_id: {
+                type: 'string',
+                description: 'ID of the source that holds the file',
+            },
+            file_id: {
+                type: 'string',
+                description: 'ID of the file to download',
+            },
+            max_bytes: {
+                type: 'integer',
+                description: `Largest file to download, in bytes (default: ${DEFAULT_MAX_FILE_BYTES})`,
+                minimum: 1,
+            },
+        },
+        required: ['source_id', 'file_id'],
+    },
+};
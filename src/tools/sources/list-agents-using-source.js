@@ -0,0 +1,125 @@
+import { createLogger } from '../../core/logger.js';
+import { callConcurrency, mapSettledWithConcurrency } from '../../core/concurrency.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('list_agents_using_source');
+
+const AGENTS_PAGE_SIZE = 100;
+
+/**
+ * List every agent, paging through /agents/ until a short page comes back
+ */
+async function listAllAgents(server, headers) {
+    const agents = [];
+    let after;
+    for (;;) {
+        const params = { limit: AGENTS_PAGE_SIZE };
+        if (after) params.after = after;
+        const response = await server.api.get('/agents/', { headers, params });
+        const page = response.data;
+        if (!Array.isArray(page)) {
+            throw new Error('Unexpected response format from /agents/: expected an array');
+        }
+        agents.push(...page);
+        // A server that ignores the cursor would return the same page forever
+        const last = page[page.length - 1]?.id;
+        if (page.length < AGENTS_PAGE_SIZE || last === after) return agents;
+        after = last;
+    }
+}
+
+/**
+ * Tool handler for finding the agents that have a data source attached
+ */
+export async function handleListAgentsUsingSource(server, args) {
+    if (!args?.source_id) {
+        server.createErrorResponse('Missing required argument: source_id');
+    }
+    if (
+        args.max_concurrency !== undefined &&
+        (!Number.isInteger(args.max_concurrency) || args.max_concurrency < 1)
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'max_concurrency',
+                'Invalid argument: max_concurrency must be a positive integer',
+            ),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const sourceId = args.source_id;
+        const maxConcurrency = callConcurrency(server, args.max_concurrency);
+
+        const agents = await listAllAgents(server, headers);
+
+        logger.info(
+            `Checking ${agents.length} agents for source ${sourceId} (concurrency ${maxConcurrency})`,
+        );
+
+        // One failing agent shouldn't abort the scan; its error is reported alongside the matches
+        const results = await mapSettledWithConcurrency(agents, maxConcurrency, async (agent) => {
+            const response = await server.api.get(
+                `/agents/${encodeURIComponent(agent.id)}/sources`,
+                { headers },
+            );
+            return (response.data || []).some((source) => source.id === sourceId);
+        });
+
+        const matches = [];
+        const errors = [];
+        results.forEach((result, index) => {
+            const agent = agents[index];
+            if (result.status === 'rejected') {
+                logger.warn(`Failed to list sources for agent ${agent.id}:`, result.reason.message);
+                errors.push({ agent_id: agent.id, error: result.reason.message });
+            } else if (result.value) {
+                matches.push({ id: agent.id, name: agent.name });
+            }
+        });
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        source_id: sourceId,
+                        agent_count: matches.length,
+                        agents: matches,
+                        checked_count: agents.length - errors.length,
+                        errors,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, `Failed to list agents using source ${args.source_id}`);
+    }
+}
+
+/**
+ * Tool definition for list_agents_using_source
+ */
+export const listAgentsUsingSourceDefinition = {
+    name: 'list_agents_using_source',
+    description:
+        'List the agents that have a data source attached. Every agent is checked, in parallel (bounded by max_concurrency); agents that fail to load are reported in errors instead of aborting the scan.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            source_id: {
+                type: 'string',
+                description: 'ID of the source to look for',
+            },
+            max_concurrency: {
+                type: 'integer',
+                description:
+                    'Maximum number of agents to check at once (default and maximum: LETTA_MAX_CONCURRENCY, or 16)',
+                minimum: 1,
+            },
+        },
+        required: ['source_id'],
+    },
+};
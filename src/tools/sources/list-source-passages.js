@@ -0,0 +1,116 @@
+import { fitToResponseSize } from '../../core/response-size.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+export const DEFAULT_SOURCE_PASSAGES_LIMIT = 50;
+
+/**
+ * Tool handler for listing the passages a data source's files were split into
+ */
+export async function handleListSourcePassages(server, args) {
+    if (!args?.source_id) {
+        server.createErrorResponse('Missing required argument: source_id');
+    }
+    const limit = args.limit ?? DEFAULT_SOURCE_PASSAGES_LIMIT;
+    if (!Number.isInteger(limit) || limit < 1) {
+        server.createErrorResponse(
+            invalidRequest('limit', 'Invalid argument: limit must be a positive integer'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const sourceId = encodeURIComponent(args.source_id);
+
+        const params = { limit };
+        if (args.after) params.after = args.after;
+        if (args.file_id) params.file_id = args.file_id;
+
+        const response = await server.api.get(`/sources/${sourceId}/passages`, {
+            headers,
+            params,
+        });
+        let passages = Array.isArray(response.data) ? response.data : [];
+
+        // Older servers return every passage in the source and ignore the filters, so the
+        // file filter and page window are applied here as well
+        if (args.file_id) {
+            passages = passages.filter((passage) => passage.file_id === args.file_id);
+        }
+        if (args.after) {
+            const cursorIndex = passages.findIndex((passage) => passage.id === args.after);
+            if (cursorIndex !== -1) passages = passages.slice(cursorIndex + 1);
+        }
+        const fullPage = passages.length >= limit;
+        passages = passages.slice(0, limit);
+
+        const includeEmbeddings = args.include_embeddings ?? false;
+        if (!includeEmbeddings) {
+            passages = passages.map((passage) => {
+                // eslint-disable-next-line no-unused-vars
+                const { embedding, ...rest } = passage;
+                return rest;
+            });
+        }
+
+        const payload = fitToResponseSize(passages, (page, truncated) => ({
+            source_id: args.source_id,
+            ...(args.file_id && { file_id: args.file_id }),
+            passages: page,
+            count: page.length,
+            next_cursor: truncated || fullPage ? (page[page.length - 1]?.id ?? null) : null,
+            ...(truncated && { truncated: true }),
+        }));
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(payload),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Source not found: ${args.source_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for list_source_passages
+ */
+export const listSourcePassagesDefinition = {
+    name: 'list_source_passages',
+    description:
+        "List the passages (chunks) a data source's files were split into during ingestion, optionally for one file, e.g. to check chunking before attaching the source to agents. Paginated: a full page returns next_cursor for the following page. For an agent's archival memory use list_passages instead.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            source_id: {
+                type: 'string',
+                description: 'ID of the source whose passages to list',
+            },
+            file_id: {
+                type: 'string',
+                description: 'Only list passages from this file',
+            },
+            after: {
+                type: 'string',
+                description: 'Pass the next_cursor from a previous page to continue after it',
+            },
+            limit: {
+                type: 'integer',
+                description: `Maximum passages to return (default: ${DEFAULT_SOURCE_PASSAGES_LIMIT})`,
+                minimum: 1,
+            },
+            include_embeddings: {
+                type: 'boolean',
+                description:
+                    'Whether to include the full embedding vectors in the response (default: false).',
+                default: false,
+            },
+        },
+        required: ['source_id'],
+    },
+};
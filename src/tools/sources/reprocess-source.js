@@ -0,0 +1,252 @@
+import FormData from 'form-data';
+import { createLogger } from '../../core/logger.js';
+import { classifyError, httpError, invalidRequest } from '../../core/errors.js';
+import { pollProperties, pollUntil, readPollOptions } from '../../core/polling.js';
+import { TERMINAL_JOB_STATUSES, jobError, uploadJobId } from '../jobs/job-status.js';
+import { resolveContentType } from './content-types.js';
+
+const logger = createLogger('reprocess_source');
+
+const FILES_PAGE_SIZE = 1000;
+
+// Non-text/* types whose stored content is the file itself. For anything else (PDF, DOCX...)
+// Letta only keeps the extracted text, and uploading that would replace the original
+const TEXT_APPLICATION_TYPES = new Set([
+    'application/json',
+    'application/jsonl',
+    'application/x-ndjson',
+    'application/xml',
+]);
+
+/**
+ * Whether a file of this content type can be re-uploaded from its stored content
+ * @param {string} contentType
+ * @returns {boolean}
+ */
+export function isTextContentType(contentType) {
+    const type = (contentType || '').split(';')[0].trim().toLowerCase();
+    return type.startsWith('text/') || TEXT_APPLICATION_TYPES.has(type);
+}
+
+/**
+ * Wait for the ingestion of an uploaded file to finish, following its job when the server
+ * returned one and the file's processing_status otherwise
+ * @returns {Promise<{done: boolean, status: string|null, error: string|null}>}
+ */
+async function waitForIngestion(server, sourceId, uploaded, headers, pollOptions) {
+    const jobId = uploadJobId(uploaded);
+    if (jobId) {
+        const jobUrl = `/jobs/${encodeURIComponent(jobId)}`;
+        const fetchJob = async () => (await server.api.get(jobUrl, { headers })).data;
+        const poll = await pollUntil(
+            fetchJob,
+            (job) => TERMINAL_JOB_STATUSES.has(job?.status),
+            pollOptions,
+        );
+        return { done: poll.done, status: poll.value?.status ?? null, error: jobError(poll.value) };
+    }
+    if (uploaded?.id && 'processing_status' in uploaded) {
+        const fileUrl = `/sources/${sourceId}/files/${encodeURIComponent(uploaded.id)}`;
+        const fetchFile = async () => (await server.api.get(fileUrl, { headers })).data;
+        const poll = await pollUntil(
+            fetchFile,
+            (file) => ['completed', 'error'].includes(file?.processing_status),
+            pollOptions,
+        );
+        const status = poll.value?.processing_status ?? null;
+        const error = status === 'error' ? (poll.value.error_message ?? 'Ingestion failed') : null;
+        return { done: poll.done, status, error };
+    }
+    // Nothing to follow, so the new copy can't be confirmed
+    return { done: false, status: null, error: null };
+}
+
+/**
+ * List every file in a source, following the pagination cursor
+ */
+async function listSourceFiles(server, sourceId, headers) {
+    const files = [];
+    let after;
+    let hasMore = true;
+    while (hasMore) {
+        const params = { limit: FILES_PAGE_SIZE };
+        if (after) params.after = after;
+        const response = await server.api.get(`/sources/${sourceId}/files`, { headers, params });
+        const page = Array.isArray(response.data) ? response.data : [];
+        files.push(...page);
+        hasMore = page.length === FILES_PAGE_SIZE;
+        after = page[page.length - 1]?.id;
+    }
+    return files;
+}
+
+/**
+ * Upload a file's stored content again, then remove the copy it replaces once the new one
+ * has been ingested
+ */
+async function reprocessFile(server, sourceId, fileId, headers, pollOptions) {
+    const fileUrl = `/sources/${sourceId}/files/${encodeURIComponent(fileId)}`;
+    const { data: file } = await server.api.get(fileUrl, {
+        headers,
+        params: { include_content: true },
+    });
+    if (typeof file?.content !== 'string') {
+        throw new Error('File content is not available; the Letta server did not return it');
+    }
+    const bytes = Buffer.from(file.content, 'utf8');
+    const fileName = file.file_name || fileId;
+    const { contentType } = resolveContentType({ fileName, contentType: file.file_type, bytes });
+    if (!isTextContentType(contentType)) {
+        return {
+            file_id: fileId,
+            file_name: file.file_name ?? null,
+            status: 'skipped',
+            reason: `Only text files can be reprocessed: Letta keeps just the extracted text of ${contentType} files. Upload the original file again instead.`,
+        };
+    }
+
+    const uploadHeaders = { ...headers };
+    // Remove content-type as axios will set it correctly for FormData
+    delete uploadHeaders['Content-Type'];
+    const form = new FormData();
+    form.append('file', bytes, { filename: fileName, contentType });
+    const { data: uploaded } = await server.api.post(`/sources/${sourceId}/upload`, form, {
+        headers: { ...uploadHeaders, ...form.getHeaders() },
+    });
+
+    const result = {
+        file_id: fileId,
+        file_name: file.file_name ?? null,
+        job_id: uploadJobId(uploaded),
+    };
+
+    // The old copy (and the passages embedded from it) is only removed once the new one has
+    // been ingested, so a failed or unfinished ingestion loses nothing
+    const ingestion = await waitForIngestion(server, sourceId, uploaded, headers, pollOptions);
+    if (ingestion.error || (ingestion.done && ingestion.status !== 'completed')) {
+        return {
+            ...result,
+            status: 'error',
+            job_status: ingestion.status,
+            error: `Ingestion of the new copy failed, so the original was kept: ${ingestion.error ?? ingestion.status}`,
+        };
+    }
+    if (!ingestion.done) {
+        return {
+            ...result,
+            status: 'pending',
+            job_status: ingestion.status,
+            note: 'Ingestion of the new copy has not finished, so the original was kept; delete it once the job completes',
+        };
+    }
+
+    await server.api.delete(`/sources/${sourceId}/${encodeURIComponent(fileId)}`, { headers });
+    return { ...result, status: 'success', job_status: ingestion.status };
+}
+
+/**
+ * Tool handler for re-running ingestion on a source's files
+ */
+export async function handleReprocessSource(server, args) {
+    if (!args?.source_id) {
+        server.createErrorResponse('Missing required argument: source_id');
+    }
+    if (args.file_id !== undefined && (typeof args.file_id !== 'string' || !args.file_id)) {
+        server.createErrorResponse(
+            invalidRequest('file_id', 'Invalid argument: file_id must be a non-empty string'),
+        );
+    }
+
+    let pollOptions;
+    try {
+        pollOptions = readPollOptions(args);
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+    const headers = server.getApiHeaders();
+    const sourceId = encodeURIComponent(args.source_id);
+
+    let fileIds;
+    if (args.file_id) {
+        fileIds = [args.file_id];
+    } else {
+        try {
+            const files = await listSourceFiles(server, sourceId, headers);
+            fileIds = files.map((file) => file.id);
+        } catch (error) {
+            if (error.response && error.response.status === 404) {
+                server.createErrorResponse(httpError(404, `Source not found: ${args.source_id}`));
+            }
+            server.createErrorResponse(error, 'Failed to list source files');
+        }
+    }
+
+    logger.info(`Reprocessing ${fileIds.length} file(s) in source ${args.source_id}`);
+
+    // Files are reprocessed one at a time to avoid queueing many embedding jobs at once
+    const results = [];
+    for (const fileId of fileIds) {
+        try {
+            results.push(await reprocessFile(server, sourceId, fileId, headers, pollOptions));
+        } catch (error) {
+            const { errorCode, status, message } = classifyError(error);
+            logger.error(`Failed to reprocess file ${fileId}: ${message}`);
+            results.push({
+                file_id: fileId,
+                status: 'error',
+                error: status === 404 ? `File not found: ${fileId}` : message,
+                error_code: errorCode,
+                http_status: status,
+            });
+        }
+    }
+
+    const countOf = (status) => results.filter((r) => r.status === status).length;
+    const successCount = countOf('success');
+    const errorCount = countOf('error');
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    success: errorCount === 0,
+                    source_id: args.source_id,
+                    summary: {
+                        total_files: results.length,
+                        success_count: successCount,
+                        error_count: errorCount,
+                        pending_count: countOf('pending'),
+                        skipped_count: countOf('skipped'),
+                    },
+                    job_ids: results.map((r) => r.job_id).filter(Boolean),
+                    results,
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for reprocess_source
+ */
+export const reprocessSourceDefinition = {
+    name: 'reprocess_source',
+    description:
+        "Re-run ingestion for a data source's text files, or one file, e.g. after changing the source's embedding config. Letta has no reprocess endpoint, so each file's stored text is uploaded again and the old copy (with its passages) is removed only once the new copy's ingestion job completes; if it fails, or is still running after wait_secs, the original is kept (status error or pending). Non-text files such as PDF or DOCX are skipped, since Letta stores only their extracted text. Returns the job_id of each ingestion job. Failures are reported per file.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            source_id: {
+                type: 'string',
+                description: 'ID of the source to reprocess',
+            },
+            file_id: {
+                type: 'string',
+                description: 'Reprocess only this file (default: every file in the source)',
+            },
+            ...pollProperties,
+        },
+        required: ['source_id'],
+    },
+};
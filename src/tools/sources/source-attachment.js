@@ -0,0 +1,87 @@
+import { createLogger } from '../../core/logger.js';
+import { httpError } from '../../core/errors.js';
+
+const logger = createLogger('source_attachment');
+
+/**
+ * Attach a source to or detach it from an agent. Letta answers with the whole AgentState;
+ * with `concise` only the agent's attached source IDs are returned.
+ * @param {Object} server - LettaServer instance
+ * @param {Object} args - Tool arguments with agent_id, source_id and concise
+ * @param {string} action - "attach" or "detach"
+ */
+export async function changeSourceAttachment(server, args, action) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    if (!args.source_id) {
+        server.createErrorResponse('Missing required argument: source_id');
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+        const sourceId = encodeURIComponent(args.source_id);
+
+        logger.info(`${action} source ${args.source_id} for agent ${args.agent_id}`);
+        const response = await server.api.patch(
+            `/agents/${agentId}/sources/${action}/${sourceId}`,
+            {},
+            { headers },
+        );
+
+        let payload = response.data;
+        if (args.concise) {
+            const sourceIds = (response.data?.sources ?? []).map((source) => source.id);
+            payload = {
+                agent_id: args.agent_id,
+                source_id: args.source_id,
+                attached: sourceIds.includes(args.source_id),
+                attached_source_ids: sourceIds,
+            };
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(payload),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(
+                httpError(
+                    404,
+                    `Agent or source not found: agent_id=${args.agent_id}, source_id=${args.source_id}`,
+                ),
+            );
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Schema properties shared by attach_source and detach_source
+ * @param {string} action - "attach" or "detach"
+ */
+export function sourceAttachmentProperties(action) {
+    const preposition = action === 'attach' ? 'to' : 'from';
+    return {
+        agent_id: {
+            type: 'string',
+            description: `ID of the agent to ${action} the source ${preposition}`,
+        },
+        source_id: {
+            type: 'string',
+            description: `ID of the source (folder) to ${action}`,
+        },
+        concise: {
+            type: 'boolean',
+            description:
+                "Return only the agent's attached source IDs instead of the full agent state (default: false)",
+            default: false,
+        },
+    };
+}
@@ -0,0 +1,86 @@
+import { createLogger } from '../../core/logger.js';
+import {
+    MAX_UPLOAD_BYTES,
+    MAX_UPLOAD_SESSIONS,
+    createUploadSession,
+    describeUploadSession,
+} from './upload-sessions.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('upload_file_begin');
+
+/**
+ * Tool handler for starting a chunked upload to a data source
+ */
+export async function handleUploadFileBegin(server, args) {
+    if (!args?.source_id) {
+        server.createErrorResponse('Missing required argument: source_id');
+    }
+    if (!args.file_name) {
+        server.createErrorResponse('Missing required argument: file_name');
+    }
+    if (
+        args.total_bytes !== undefined &&
+        (!Number.isInteger(args.total_bytes) ||
+            args.total_bytes < 1 ||
+            args.total_bytes > MAX_UPLOAD_BYTES)
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'total_bytes',
+                `Invalid argument: total_bytes must be a positive integer no larger than ${MAX_UPLOAD_BYTES}`,
+            ),
+        );
+    }
+
+    const session = createUploadSession(server, {
+        sourceId: args.source_id,
+        fileName: args.file_name,
+        contentType: args.content_type,
+        totalBytes: args.total_bytes,
+    });
+    logger.info(`Started upload ${session.id} of ${args.file_name} to source ${args.source_id}`);
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify(describeUploadSession(session)),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for upload_file_begin
+ */
+export const uploadFileBeginDefinition = {
+    name: 'upload_file_begin',
+    description: `Start a chunked upload of a large file to a data source. Returns an upload_id; send the file with upload_file_chunk, then call upload_file_complete. Unfinished uploads expire after 30 minutes without activity; at most ${MAX_UPLOAD_SESSIONS} uploads may be open at once.`,
+    inputSchema: {
+        type: 'object',
+        properties: {
+            source_id: {
+                type: 'string',
+                description: 'ID of the source to upload the file to',
+            },
+            file_name: {
+                type: 'string',
+                description: 'Name of the file, including its extension',
+            },
+            content_type: {
+                type: 'string',
+                description:
+                    'MIME type of the file (e.g. application/pdf). Inferred from the file_name extension, or failing that the content, when omitted',
+            },
+            total_bytes: {
+                type: 'integer',
+                description:
+                    'Expected size of the whole file in bytes, checked when the upload completes (optional)',
+                minimum: 1,
+                maximum: MAX_UPLOAD_BYTES,
+            },
+        },
+        required: ['source_id', 'file_name'],
+    },
+};
@@ -0,0 +1,94 @@
+import {
+    MAX_BUFFERED_UPLOAD_BYTES,
+    MAX_UPLOAD_BYTES,
+    bufferedUploadBytes,
+    describeUploadSession,
+    getUploadSession,
+} from './upload-sessions.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+/**
+ * Tool handler for adding a chunk to a chunked upload
+ */
+export async function handleUploadFileChunk(server, args) {
+    if (!args?.upload_id) {
+        server.createErrorResponse('Missing required argument: upload_id');
+    }
+    if (!Number.isInteger(args.chunk_index) || args.chunk_index < 0) {
+        server.createErrorResponse(
+            'Missing required argument: chunk_index (must be a non-negative integer)',
+        );
+    }
+    if (typeof args.data !== 'string' || !args.data) {
+        server.createErrorResponse('Missing required argument: data (base64 string)');
+    }
+
+    const session = getUploadSession(server, args.upload_id);
+    const chunk = Buffer.from(args.data, 'base64');
+    if (chunk.length === 0) {
+        server.createErrorResponse(
+            invalidRequest('data', 'Invalid argument: data is not valid base64 content'),
+        );
+    }
+
+    // Re-sending an index replaces the earlier chunk, so a failed chunk can be retried
+    const previous = session.chunks.get(args.chunk_index);
+    const added = chunk.length - (previous?.length ?? 0);
+    const bytes = session.bytes + added;
+    if (bytes > MAX_UPLOAD_BYTES) {
+        server.createErrorResponse(
+            `Upload ${session.id} would exceed the ${MAX_UPLOAD_BYTES} byte limit`,
+        );
+    }
+    if (bufferedUploadBytes(server) + added > MAX_BUFFERED_UPLOAD_BYTES) {
+        server.createErrorResponse(
+            httpError(
+                429,
+                `Open uploads would exceed the ${MAX_BUFFERED_UPLOAD_BYTES} byte buffer limit; complete one or wait for idle ones to expire`,
+            ),
+        );
+    }
+    session.chunks.set(args.chunk_index, chunk);
+    session.bytes = bytes;
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    ...describeUploadSession(session),
+                    chunk_index: args.chunk_index,
+                    chunk_bytes: chunk.length,
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for upload_file_chunk
+ */
+export const uploadFileChunkDefinition = {
+    name: 'upload_file_chunk',
+    description:
+        'Add a base64-encoded chunk to an upload started with upload_file_begin. Chunks are assembled in chunk_index order and may arrive in any order; re-sending an index replaces that chunk.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            upload_id: {
+                type: 'string',
+                description: 'Upload ID returned by upload_file_begin',
+            },
+            chunk_index: {
+                type: 'integer',
+                description: 'Position of this chunk in the file, starting at 0',
+                minimum: 0,
+            },
+            data: {
+                type: 'string',
+                description: 'Chunk contents, base64-encoded',
+            },
+        },
+        required: ['upload_id', 'chunk_index', 'data'],
+    },
+};
@@ -0,0 +1,164 @@
+import FormData from 'form-data';
+import { createLogger } from '../../core/logger.js';
+import { pollProperties, pollUntil, readPollOptions } from '../../core/polling.js';
+import { TERMINAL_JOB_STATUSES, summarizeJob, uploadJobId } from '../jobs/job-status.js';
+import { resolveContentType } from './content-types.js';
+import { getUploadSession } from './upload-sessions.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('upload_file_complete');
+
+/**
+ * Poll the ingestion job until it finishes or the wait elapses
+ */
+async function waitForIngestion(server, jobId, pollOptions) {
+    const headers = server.getApiHeaders();
+    const fetchJob = async () =>
+        (await server.api.get(`/jobs/${encodeURIComponent(jobId)}`, { headers })).data;
+    const isFinished = (job) => TERMINAL_JOB_STATUSES.has(job.status);
+    const poll = await pollUntil(fetchJob, isFinished, pollOptions);
+    if (!poll.done) {
+        logger.info(`Ingestion job ${jobId} still ${poll.value.status} after ${pollOptions.waitSecs}s`);
+    }
+    return {
+        done: poll.done,
+        timed_out: !poll.done,
+        job: summarizeJob(poll.value),
+        elapsed_secs: poll.elapsedSecs,
+    };
+}
+
+/**
+ * Tool handler for assembling a chunked upload and sending it to the data source
+ */
+export async function handleUploadFileComplete(server, args) {
+    if (!args?.upload_id) {
+        server.createErrorResponse('Missing required argument: upload_id');
+    }
+    if (args.wait !== undefined && typeof args.wait !== 'boolean') {
+        server.createErrorResponse(
+            invalidRequest('wait', 'Invalid argument: wait must be a boolean'),
+        );
+    }
+    let pollOptions;
+    try {
+        pollOptions = readPollOptions(args);
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+
+    const session = getUploadSession(server, args.upload_id);
+    const chunkCount = session.chunks.size;
+    if (chunkCount === 0) {
+        server.createErrorResponse(`Upload ${session.id} has no chunks`);
+    }
+    const missing = [];
+    for (let index = 0; index < chunkCount; index++) {
+        if (!session.chunks.has(index)) missing.push(index);
+    }
+    if (missing.length > 0) {
+        server.createErrorResponse(
+            `Upload ${session.id} is missing chunk(s) ${missing.join(', ')}; chunk indexes must run from 0 without gaps`,
+        );
+    }
+    if (session.totalBytes !== undefined && session.bytes !== session.totalBytes) {
+        server.createErrorResponse(
+            `Upload ${session.id} has ${session.bytes} bytes but total_bytes was ${session.totalBytes}`,
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        // Remove content-type as axios will set it correctly for FormData
+        delete headers['Content-Type'];
+
+        const file = Buffer.concat([...Array(chunkCount).keys()].map((i) => session.chunks.get(i)));
+        const { contentType, source: contentTypeSource } = resolveContentType({
+            fileName: session.fileName,
+            contentType: session.contentType,
+            bytes: file,
+        });
+        const form = new FormData();
+        form.append('file', file, { filename: session.fileName, contentType });
+
+        logger.info(
+            `Uploading ${file.length} bytes (${chunkCount} chunks) to source ${session.sourceId}`,
+        );
+        const response = await server.api.post(
+            `/sources/${encodeURIComponent(session.sourceId)}/upload`,
+            form,
+            {
+                headers: {
+                    ...headers,
+                    ...form.getHeaders(), // Let FormData set the Content-Type with boundary
+                },
+            },
+        );
+
+        // Only a successful upload closes the session, so a failed one can be retried
+        server.uploadSessions.delete(session.id);
+
+        const jobId = uploadJobId(response.data);
+        let ingestion;
+        if (args.wait && jobId) {
+            // The file is already uploaded, so a failed status check is reported, not thrown
+            try {
+                ingestion = await waitForIngestion(server, jobId, pollOptions);
+            } catch (error) {
+                logger.error(`Failed to check ingestion job ${jobId}: ${error.message}`);
+                ingestion = { done: false, error: error.message };
+            }
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        upload_id: session.id,
+                        source_id: session.sourceId,
+                        file_name: session.fileName,
+                        content_type: contentType,
+                        content_type_source: contentTypeSource,
+                        size_bytes: file.length,
+                        chunk_count: chunkCount,
+                        job_id: jobId,
+                        ...(ingestion && { ingestion }),
+                        result: response.data,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Source not found: ${session.sourceId}`));
+        }
+        server.createErrorResponse(error, 'Failed to upload file');
+    }
+}
+
+/**
+ * Tool definition for upload_file_complete
+ */
+export const uploadFileCompleteDefinition = {
+    name: 'upload_file_complete',
+    description:
+        'Finish a chunked upload: assemble the chunks sent with upload_file_chunk and upload the file to its data source for processing. Returns the ingestion job_id to monitor with wait_for_job or get_job, or pass wait: true to block until the file is processed and queryable. If the upload fails the session is kept so it can be retried.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            upload_id: {
+                type: 'string',
+                description: 'Upload ID returned by upload_file_begin',
+            },
+            wait: {
+                type: 'boolean',
+                description:
+                    'Wait for the ingestion job to finish (up to wait_secs) and include its final state (default: false)',
+                default: false,
+            },
+            ...pollProperties,
+        },
+        required: ['upload_id'],
+    },
+};
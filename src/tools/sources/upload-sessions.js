@@ -0,0 +1,128 @@
+import { randomUUID } from 'node:crypto';
+import { httpError } from '../../core/errors.js';
+
+/**
+ * Chunked upload sessions for large source files.
+ *
+ * upload_file_begin opens a session on the server, upload_file_chunk adds base64 chunks
+ * by index (in any order, re-sending an index replaces it), and upload_file_complete
+ * assembles them and uploads the file. Sessions idle for longer than the TTL are dropped
+ * the next time any upload tool runs, and by a timer that runs while any session is open.
+ * Chunks are held in memory, so the number of open sessions and the bytes buffered across
+ * all of them are capped.
+ */
+
+export const UPLOAD_SESSION_TTL_MS = 30 * 60 * 1000;
+export const UPLOAD_PRUNE_INTERVAL_MS = 60 * 1000;
+export const MAX_UPLOAD_BYTES = 100_000_000;
+export const MAX_UPLOAD_SESSIONS = 20;
+export const MAX_BUFFERED_UPLOAD_BYTES = 500_000_000;
+
+/**
+ * Drop sessions whose TTL has lapsed
+ * @param {Object} server - LettaServer instance
+ * @param {number} [now] - Current time in milliseconds
+ * @returns {number} Number of sessions removed
+ */
+export function pruneUploadSessions(server, now = Date.now()) {
+    let removed = 0;
+    for (const [id, session] of server.uploadSessions) {
+        if (session.expiresAt <= now) {
+            server.uploadSessions.delete(id);
+            removed++;
+        }
+    }
+    return removed;
+}
+
+/**
+ * Prune sessions periodically while any are open; the timer stops once none are left and
+ * never keeps the process alive
+ * @param {Object} server - LettaServer instance
+ */
+function schedulePruning(server) {
+    if (server.uploadPruneTimer) return;
+    server.uploadPruneTimer = setInterval(() => {
+        pruneUploadSessions(server);
+        if (server.uploadSessions.size === 0) {
+            clearInterval(server.uploadPruneTimer);
+            server.uploadPruneTimer = null;
+        }
+    }, UPLOAD_PRUNE_INTERVAL_MS);
+    server.uploadPruneTimer.unref();
+}
+
+/**
+ * Bytes buffered across all open sessions
+ * @param {Object} server - LettaServer instance
+ * @returns {number}
+ */
+export function bufferedUploadBytes(server) {
+    let total = 0;
+    for (const session of server.uploadSessions.values()) total += session.bytes ?? 0;
+    return total;
+}
+
+/**
+ * Open a new upload session, failing if too many are already open
+ * @returns {Object} The session
+ */
+export function createUploadSession(server, { sourceId, fileName, contentType, totalBytes }) {
+    pruneUploadSessions(server);
+    if (server.uploadSessions.size >= MAX_UPLOAD_SESSIONS) {
+        server.createErrorResponse(
+            httpError(
+                429,
+                `Too many open upload sessions (limit ${MAX_UPLOAD_SESSIONS}); complete one or wait for idle ones to expire`,
+            ),
+        );
+    }
+    const now = Date.now();
+    const session = {
+        id: `upload-${randomUUID()}`,
+        sourceId,
+        fileName,
+        contentType,
+        totalBytes,
+        chunks: new Map(),
+        bytes: 0,
+        createdAt: now,
+        expiresAt: now + UPLOAD_SESSION_TTL_MS,
+    };
+    server.uploadSessions.set(session.id, session);
+    schedulePruning(server);
+    return session;
+}
+
+/**
+ * Look up an open session and extend its TTL, failing if it is unknown or expired
+ * @returns {Object} The session
+ */
+export function getUploadSession(server, uploadId) {
+    pruneUploadSessions(server);
+    const session = server.uploadSessions.get(uploadId);
+    if (!session) {
+        server.createErrorResponse(
+            httpError(
+                404,
+                `Upload session not found: ${uploadId} (it may have expired; start again with upload_file_begin)`,
+            ),
+        );
+    }
+    session.expiresAt = Date.now() + UPLOAD_SESSION_TTL_MS;
+    return session;
+}
+
+/**
+ * Summarize a session for tool responses
+ */
+export function describeUploadSession(session) {
+    return {
+        upload_id: session.id,
+        source_id: session.sourceId,
+        file_name: session.fileName,
+        chunks_received: session.chunks.size,
+        bytes_received: session.bytes,
+        expires_at: new Date(session.expiresAt).toISOString(),
+    };
+}
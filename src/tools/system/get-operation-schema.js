@@ -0,0 +1,74 @@
+import { httpError, invalidRequest } from '../../core/errors.js';
+const JSON_SCHEMA_DRAFT = 'http://json-schema.org/draft-07/schema#';
+
+/**
+ * Wrap a tool's schema as a standalone JSON Schema document
+ */
+function schemaDocument(tool, schema, kind) {
+    return {
+        $schema: JSON_SCHEMA_DRAFT,
+        $id: `letta-mcp://operations/${tool.name}/${kind}`,
+        title: `${tool.name} ${kind}`,
+        ...(kind === 'request' && { description: tool.description }),
+        ...schema,
+    };
+}
+
+/**
+ * Tool handler for fetching the JSON Schema of one operation
+ */
+export async function handleGetOperationSchema(server, args) {
+    if (!args?.tool_name) {
+        server.createErrorResponse('Missing required argument: tool_name');
+    }
+    const kind = args.kind ?? 'request';
+    if (!['request', 'response'].includes(kind)) {
+        server.createErrorResponse(
+            invalidRequest('kind', 'Invalid argument: kind must be "request" or "response"'),
+        );
+    }
+
+    const tool = (server.toolDefinitions ?? []).find(({ name }) => name === args.tool_name);
+    if (!tool) {
+        server.createErrorResponse(httpError(404, `Tool not found: ${args.tool_name}`));
+    }
+    const schema = kind === 'request' ? tool.inputSchema : tool.outputSchema;
+    if (!schema) {
+        server.createErrorResponse(`No ${kind} schema is published for ${args.tool_name}`);
+    }
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify(schemaDocument(tool, schema, kind)),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for get_operation_schema
+ */
+export const getOperationSchemaDefinition = {
+    name: 'get_operation_schema',
+    description:
+        "Return the JSON Schema (draft-07) for one tool's arguments, or for its response, as a standalone document that clients can validate against before calling. Only the fields that tool accepts are included. Use list_operations to find tool names.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            tool_name: {
+                type: 'string',
+                description: 'Name of the tool to describe',
+            },
+            kind: {
+                type: 'string',
+                enum: ['request', 'response'],
+                description:
+                    'Which schema to return: the arguments or the response (default: request)',
+                default: 'request',
+            },
+        },
+        required: ['tool_name'],
+    },
+};
@@ -0,0 +1,295 @@
+import { createLogger } from '../../core/logger.js';
+
+const logger = createLogger('get_server_capabilities');
+
+/**
+ * Letta API endpoints each tool depends on, as [method, path] pairs relative to /v1.
+ * Tools with no entries run locally and are always available.
+ */
+export const TOOL_ENDPOINTS = {
+    list_agents: [['get', '/agents/']],
+    find_agents_by_name: [['get', '/agents/']],
+    prompt_agent: [['post', '/agents/{agent_id}/messages/stream']],
+    list_agent_tools: [['get', '/agents/{agent_id}']],
+    create_agent: [['post', '/agents/']],
+    attach_tool: [['patch', '/agents/{agent_id}/tools/attach/{tool_id}']],
+    detach_tool: [
+        ['get', '/agents/{agent_id}/tools'],
+        ['patch', '/agents/{agent_id}/tools/detach/{tool_id}'],
+    ],
+    list_memory_blocks: [['get', '/blocks/']],
+    list_all_blocks: [['get', '/blocks/']],
+    read_memory_block: [['get', '/blocks/{block_id}']],
+    update_memory_block: [
+        ['get', '/blocks/{block_id}'],
+        ['patch', '/blocks/{block_id}'],
+    ],
+    delete_memory_block: [
+        ['get', '/blocks/{block_id}/agents'],
+        ['patch', '/agents/{agent_id}/core-memory/blocks/detach/{block_id}'],
+        ['delete', '/blocks/{block_id}'],
+    ],
+    attach_source: [['patch', '/agents/{agent_id}/sources/attach/{source_id}']],
+    detach_source: [['patch', '/agents/{agent_id}/sources/detach/{source_id}']],
+    attach_memory_block: [['patch', '/agents/{agent_id}/core-memory/blocks/attach/{block_id}']],
+    create_memory_block: [['post', '/blocks/']],
+    list_block_templates: [['get', '/blocks/']],
+    get_agent_memory_usage: [
+        ['get', '/agents/{agent_id}/core-memory/blocks'],
+        ['get', '/agents/{agent_id}/context'],
+        ['get', '/agents/{agent_id}/sources'],
+        ['get', '/agents/{agent_id}/tools'],
+    ],
+    create_block_from_template: [
+        ['get', '/blocks/{block_id}'],
+        ['post', '/blocks/'],
+    ],
+    list_tools: [['get', '/tools/']],
+    get_tool_source: [['get', '/tools/{tool_id}']],
+    upload_tool: [['post', '/tools/']],
+    patch_tool_source: [
+        ['get', '/tools/{tool_id}'],
+        ['patch', '/tools/{tool_id}'],
+    ],
+    run_tool_from_source: [['post', '/tools/run']],
+    list_tool_runs: [],
+    list_mcp_tools_by_server: [['get', '/tools/mcp/servers/{server_name}/tools']],
+    test_mcp_server: [['get', '/tools/mcp/servers/{server_name}/tools']],
+    list_mcp_servers: [
+        ['get', '/tools/mcp/servers'],
+        ['get', '/tools/mcp/servers/{server_name}/tools'],
+    ],
+    connect_mcp_server: [['post', '/tools/mcp/servers/connect']],
+    retrieve_agent: [['get', '/agents/{agent_id}']],
+    modify_agent: [['patch', '/agents/{agent_id}']],
+    delete_agent: [['delete', '/agents/{agent_id}']],
+    list_llm_models: [['get', '/models/']],
+    list_embedding_models: [['get', '/models/embedding']],
+    list_passages: [['get', '/agents/{agent_id}/archival-memory']],
+    search_archival_memory: [
+        ['get', '/agents/'],
+        ['get', '/agents/{agent_id}/archival-memory'],
+    ],
+    count_passages: [['get', '/agents/{agent_id}/context']],
+    create_passage: [['post', '/agents/{agent_id}/archival-memory']],
+    bulk_create_passages: [['post', '/agents/{agent_id}/archival-memory']],
+    modify_passage: [['patch', '/agents/{agent_id}/archival-memory/{memory_id}']],
+    delete_passage: [['delete', '/agents/{agent_id}/archival-memory/{memory_id}']],
+    export_agent: [['get', '/agents/{agent_id}/export']],
+    import_agent: [['post', '/agents/import']],
+    clone_agent: [
+        ['get', '/agents/{agent_id}/export'],
+        ['post', '/agents/import'],
+    ],
+    migrate_agent: [
+        ['patch', '/agents/{agent_id}'],
+        ['get', '/agents/{agent_id}/archival-memory'],
+        ['post', '/agents/{agent_id}/archival-memory'],
+        ['delete', '/agents/{agent_id}/archival-memory/{memory_id}'],
+    ],
+    bulk_attach_tool_to_agents: [['patch', '/agents/{agent_id}/tools/attach/{tool_id}']],
+    bulk_attach_tools_to_agent: [['patch', '/agents/{agent_id}/tools/attach/{tool_id}']],
+    get_agent_summary: [['get', '/agents/{agent_id}/core-memory/blocks']],
+    describe_agent: [
+        ['get', '/agents/{agent_id}'],
+        ['get', '/agents/{agent_id}/core-memory/blocks'],
+        ['get', '/agents/{agent_id}/tools'],
+        ['get', '/agents/{agent_id}/sources'],
+        ['get', '/agents/{agent_id}/context'],
+    ],
+    bulk_delete_agents: [['delete', '/agents/{agent_id}']],
+    bulk_export_agents: [['get', '/agents/{agent_id}/export']],
+    add_mcp_tool_to_letta: [['post', '/tools/mcp/servers/{server_name}/{tool_name}']],
+    list_prompts: [],
+    use_prompt: [],
+    list_agent_messages: [['get', '/agents/{agent_id}/messages']],
+    export_agent_messages: [['get', '/agents/{agent_id}/messages']],
+    count_agent_messages: [['get', '/agents/{agent_id}/context']],
+    estimate_message_tokens: [['get', '/agents/{agent_id}/context']],
+    reset_agent_messages: [
+        ['get', '/agents/{agent_id}/core-memory/blocks'],
+        ['patch', '/agents/{agent_id}/reset-messages'],
+        ['post', '/agents/{agent_id}/messages'],
+    ],
+    cancel_agent_run: [['post', '/agents/{agent_id}/messages/cancel']],
+    wait_for_run: [
+        ['get', '/runs/{run_id}'],
+        ['get', '/runs/{run_id}/messages'],
+    ],
+    summarize_agent_conversation: [['post', '/agents/{agent_id}/summarize']],
+    generate_tool_schema: [],
+    generate_tool_from_prompt: [['post', '/tools/generate-tool']],
+    get_server_capabilities: [],
+    list_operations: [],
+    get_operation_schema: [],
+    bulk_send_message: [['post', '/agents/{agent_id}/messages']],
+    modify_agent_message: [
+        ['get', '/agents/{agent_id}/messages'],
+        ['patch', '/agents/{agent_id}/messages/{message_id}'],
+    ],
+    delete_agent_message: [
+        ['get', '/agents/{agent_id}/messages'],
+        ['delete', '/agents/{agent_id}/messages/{message_id}'],
+    ],
+    list_agents_using_source: [
+        ['get', '/agents/'],
+        ['get', '/agents/{agent_id}/sources'],
+    ],
+    get_source_file_content: [['get', '/sources/{source_id}/files/{file_id}']],
+    list_source_passages: [['get', '/sources/{source_id}/passages']],
+    upload_file_begin: [],
+    upload_file_chunk: [],
+    upload_file_complete: [
+        ['post', '/sources/{source_id}/upload'],
+        ['get', '/jobs/{job_id}'],
+    ],
+    reprocess_source: [
+        ['get', '/sources/{source_id}/files'],
+        ['get', '/sources/{source_id}/files/{file_id}'],
+        ['post', '/sources/{source_id}/upload'],
+        ['delete', '/sources/{source_id}/{file_id}'],
+    ],
+    list_folders: [
+        ['get', '/folders/'],
+        ['get', '/folders/{folder_id}/files'],
+        ['get', '/folders/{folder_id}/agents'],
+    ],
+    get_folder_contents: [['get', '/folders/{folder_id}/files']],
+    create_folder: [['post', '/folders/']],
+    delete_folder: [
+        ['get', '/folders/{folder_id}/agents'],
+        ['delete', '/folders/{folder_id}'],
+    ],
+    list_jobs: [
+        ['get', '/jobs/'],
+        ['get', '/jobs/active'],
+    ],
+    get_job: [['get', '/jobs/{job_id}']],
+    get_job_result: [['get', '/jobs/{job_id}']],
+    wait_for_job: [['get', '/jobs/{job_id}']],
+};
+
+/**
+ * Normalize an API path for comparison: drop the /v1 prefix, trailing slashes and
+ * path parameter names
+ */
+function normalizePath(path) {
+    return (
+        path
+            .replace(/^\/v1(?=\/)/, '')
+            .replace(/\{[^}]+\}/g, '{}')
+            .replace(/\/+$/, '') || '/'
+    );
+}
+
+/**
+ * Build a lookup of "METHOD path" keys from an OpenAPI document
+ */
+function indexOpenApiPaths(spec) {
+    const available = new Set();
+    for (const [path, operations] of Object.entries(spec?.paths || {})) {
+        for (const method of Object.keys(operations)) {
+            available.add(`${method.toLowerCase()} ${normalizePath(path)}`);
+        }
+    }
+    return available;
+}
+
+/**
+ * Probe the Letta server for its version and OpenAPI spec and work out which tools it supports
+ */
+async function probeCapabilities(server) {
+    const headers = server.getApiHeaders();
+
+    let version = null;
+    try {
+        const health = await server.api.get('/health/', { headers });
+        version = health.data?.version ?? null;
+    } catch (error) {
+        logger.warn('Could not read Letta server version:', error.message);
+    }
+
+    // The OpenAPI document is served from the server root, outside the /v1 prefix
+    let available = null;
+    try {
+        const root = server.apiBase.replace(/\/v1\/?$/, '');
+        const spec = await server.api.get(`${root}/openapi.json`, { headers });
+        available = indexOpenApiPaths(spec.data);
+    } catch (error) {
+        logger.warn('Could not fetch Letta OpenAPI spec:', error.message);
+    }
+
+    const tools = {};
+    for (const [toolName, endpoints] of Object.entries(TOOL_ENDPOINTS)) {
+        if (endpoints.length === 0) {
+            tools[toolName] = 'local';
+        } else if (!available) {
+            tools[toolName] = 'unknown';
+        } else {
+            const missing = endpoints.filter(
+                ([method, path]) => !available.has(`${method} ${normalizePath(path)}`),
+            );
+            tools[toolName] = missing.length === 0 ? 'supported' : 'unsupported';
+        }
+    }
+
+    const summary = Object.values(tools).reduce((counts, status) => {
+        counts[status] = (counts[status] || 0) + 1;
+        return counts;
+    }, {});
+
+    return {
+        version,
+        openapi_available: Boolean(available),
+        checked_at: new Date().toISOString(),
+        summary,
+        tools,
+    };
+}
+
+/**
+ * Tool handler for reporting the connected Letta server's version and supported tools
+ */
+export async function handleGetServerCapabilities(server, args) {
+    try {
+        // Probing is a few requests, so the result is cached on the server instance
+        const cached = Boolean(server.backendCapabilities) && !args?.refresh;
+        if (!cached) {
+            server.backendCapabilities = await probeCapabilities(server);
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        ...server.backendCapabilities,
+                        cached,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for get_server_capabilities
+ */
+export const getServerCapabilitiesDefinition = {
+    name: 'get_server_capabilities',
+    description:
+        "Report the connected Letta server's version and which tools it supports, based on its OpenAPI spec. Results are cached; pass refresh to probe again (e.g. after a server upgrade).",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            refresh: {
+                type: 'boolean',
+                description: 'Ignore the cached result and probe the server again',
+                default: false,
+            },
+        },
+        required: [],
+    },
+};
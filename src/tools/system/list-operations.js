@@ -0,0 +1,103 @@
+import { TOOL_ENDPOINTS } from './get-server-capabilities.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+/**
+ * Summarize a tool's arguments from its input schema
+ */
+function describeParameters(inputSchema) {
+    const required = new Set(inputSchema?.required ?? []);
+    return Object.entries(inputSchema?.properties ?? {}).map(([name, property]) => ({
+        name,
+        type: property.type ?? null,
+        required: required.has(name),
+        description: property.description ?? null,
+        ...(property.enum && { enum: property.enum }),
+        ...(property.default !== undefined && { default: property.default }),
+        ...(property.deprecated && { deprecated: true }),
+    }));
+}
+
+/**
+ * Catalog entry for one tool
+ */
+function describeOperation(tool, backendTools, includeSchemas) {
+    const endpoints = TOOL_ENDPOINTS[tool.name];
+    return {
+        name: tool.name,
+        title: tool.annotations?.title ?? null,
+        description: tool.description,
+        parameters: describeParameters(tool.inputSchema),
+        annotations: tool.annotations ?? {},
+        endpoints: endpoints
+            ? endpoints.map(([method, path]) => `${method.toUpperCase()} ${path}`)
+            : null,
+        // From the last get_server_capabilities probe; null until one has run
+        backend_status: backendTools?.[tool.name] ?? null,
+        ...(includeSchemas && {
+            input_schema: tool.inputSchema,
+            output_schema: tool.outputSchema ?? null,
+        }),
+    };
+}
+
+/**
+ * Tool handler for listing the server's operations in machine-readable form
+ */
+export async function handleListOperations(server, args) {
+    if (args?.tool_name !== undefined && typeof args.tool_name !== 'string') {
+        server.createErrorResponse(
+            invalidRequest('tool_name', 'Invalid argument: tool_name must be a string'),
+        );
+    }
+
+    const tools = server.toolDefinitions ?? [];
+    const selected = args?.tool_name
+        ? tools.filter((tool) => tool.name === args.tool_name)
+        : tools;
+    if (args?.tool_name && selected.length === 0) {
+        server.createErrorResponse(httpError(404, `Tool not found: ${args.tool_name}`));
+    }
+
+    const backend = server.backendCapabilities;
+    const operations = selected.map((tool) =>
+        describeOperation(tool, backend?.tools, Boolean(args?.include_schemas)),
+    );
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    count: operations.length,
+                    backend_checked_at: backend?.checked_at ?? null,
+                    operations,
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for list_operations
+ */
+export const listOperationsDefinition = {
+    name: 'list_operations',
+    description:
+        "Return a machine-readable catalog of this server's tools: each tool's parameters (name, type, required, enum, default), annotations, the Letta endpoints it calls and, once get_server_capabilities has run, whether the connected Letta server supports it. Set include_schemas for the full input and output JSON Schemas, e.g. to generate client bindings, or use get_operation_schema for one tool.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            tool_name: {
+                type: 'string',
+                description: 'Optional: Describe only this tool',
+            },
+            include_schemas: {
+                type: 'boolean',
+                description:
+                    'Include the full input_schema and output_schema of each tool (default: false)',
+                default: false,
+            },
+        },
+        required: [],
+    },
+};
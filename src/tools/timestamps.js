@@ -0,0 +1,44 @@
+/**
+ * Timestamps for single-resource responses.
+ *
+ * Letta returns created_at/updated_at for some resources (agents, sources, files) but not
+ * others (blocks and tools on older servers). Single-resource tools always include both keys
+ * so clients can track changes without special-casing each response; a timestamp the
+ * server didn't report is null.
+ */
+
+/**
+ * Pick a resource's creation and last-update times
+ * @param {Object} resource - Resource returned by the Letta API
+ * @returns {{created_at: string|null, updated_at: string|null}}
+ */
+export function resourceTimestamps(resource) {
+    return {
+        created_at: resource?.created_at ?? null,
+        updated_at: resource?.updated_at ?? null,
+    };
+}
+
+/**
+ * Add the normalized timestamps to a resource returned as-is
+ * @param {*} resource - Resource returned by the Letta API
+ * @returns {*} The resource with created_at and updated_at, or the input if not an object
+ */
+export function withTimestamps(resource) {
+    if (!resource || typeof resource !== 'object') return resource;
+    return { ...resource, ...resourceTimestamps(resource) };
+}
+
+/**
+ * Output schema properties for the timestamps added by resourceTimestamps
+ */
+export const timestampProperties = {
+    created_at: {
+        type: ['string', 'null'],
+        description: 'When the resource was created (ISO 8601), or null if not reported',
+    },
+    updated_at: {
+        type: ['string', 'null'],
+        description: 'When the resource was last updated (ISO 8601), or null if not reported',
+    },
+};
@@ -1,4 +1,5 @@
 import { createLogger } from '../../core/logger.js';
+import { invalidRequest } from '../../core/errors.js';
 
 const logger = createLogger('attach_tool');
 
@@ -20,13 +21,13 @@ export async function handleAttachTool(server, args) {
         const agent_id = args.agent_id;
 
         const toolIdsInput = args.tool_ids || (args.tool_id ? [args.tool_id] : []);
-        const toolNamesInput = args.tool_names || [];
+        const toolNamesInput = args.tool_names || (args.tool_name ? [args.tool_name] : []);
 
         if (!Array.isArray(toolIdsInput)) {
-            throw new Error('Invalid argument: tool_ids must be an array.');
+            throw invalidRequest('tool_ids', 'Invalid argument: tool_ids must be an array.');
         }
         if (!Array.isArray(toolNamesInput)) {
-            throw new Error('Invalid argument: tool_names must be an array.');
+            throw invalidRequest('tool_names', 'Invalid argument: tool_names must be an array.');
         }
         if (toolIdsInput.length === 0 && toolNamesInput.length === 0) {
             throw new Error(
@@ -162,8 +163,23 @@ export async function handleAttachTool(server, args) {
                     continue; // Skip further processing for this name
                 }
 
-                // Try finding as existing Letta tool
-                const existingLettaTool = lettaTools.find((t) => t.name === toolName);
+                // Try finding as existing Letta tool; a name shared by several tools is ambiguous
+                const lettaMatches = lettaTools.filter((t) => t.name === toolName);
+                if (lettaMatches.length > 1) {
+                    const candidates = lettaMatches.map((t) => t.id);
+                    const message = `Tool name '${toolName}' is ambiguous: it matches ${candidates.length} tools (${candidates.join(', ')}). Pass the tool ID instead.`;
+                    logger.error(message);
+                    processingResults.push({
+                        input: toolName,
+                        type: 'name',
+                        success: false,
+                        status: 'ambiguous',
+                        error: message,
+                        candidates,
+                    });
+                    continue;
+                }
+                const existingLettaTool = lettaMatches[0];
                 if (existingLettaTool) {
                     logger.info(
                         `Found existing Letta tool: ${toolName} (ID: ${existingLettaTool.id})`,
@@ -346,6 +362,11 @@ export const attachToolToolDefinition = {
                 items: { type: 'string' },
                 description: 'Optional array of existing Letta tool IDs to attach.',
             },
+            tool_name: {
+                type: 'string',
+                description:
+                    'The name of a single tool to attach, resolved to its ID (alternative to tool_names).',
+            },
             tool_names: {
                 type: 'array',
                 items: { type: 'string' },
@@ -108,7 +108,7 @@ export async function handleBulkAttachToolToAgents(server, args) {
     } catch (error) {
         // Handle errors during the list_agents call or unexpected issues
         logger.error('[bulk_attach_tool] Error:', error.response?.data || error.message);
-        server.createErrorResponse(`Failed during bulk attach operation: ${error.message}`);
+        server.createErrorResponse(error, 'Failed during bulk attach operation');
     }
 }
 
@@ -0,0 +1,107 @@
+import { createLogger } from '../../core/logger.js';
+import { classifyError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('bulk_attach_tools_to_agent');
+
+/**
+ * Tool handler for attaching several tools to one agent
+ */
+export async function handleBulkAttachToolsToAgent(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    const toolIds = args.tool_ids;
+    if (!Array.isArray(toolIds) || toolIds.length === 0) {
+        server.createErrorResponse('Missing required argument: tool_ids (non-empty array)');
+    }
+    toolIds.forEach((toolId, index) => {
+        if (typeof toolId !== 'string' || !toolId) {
+            server.createErrorResponse(
+                invalidRequest(
+                    'tool_ids',
+                    `Invalid argument: tool_ids[${index}] must be a non-empty string`,
+                ),
+            );
+        }
+    });
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+        const uniqueToolIds = [...new Set(toolIds)];
+
+        logger.info(`Attaching ${uniqueToolIds.length} tool(s) to agent ${args.agent_id}`);
+
+        // Attaches run one at a time: each one rewrites the agent's tool list, so parallel
+        // requests against the same agent could drop each other's changes
+        const results = [];
+        for (const toolId of uniqueToolIds) {
+            try {
+                await server.api.patch(
+                    `/agents/${agentId}/tools/attach/${encodeURIComponent(toolId)}`,
+                    {},
+                    { headers },
+                );
+                results.push({ tool_id: toolId, status: 'success' });
+            } catch (error) {
+                const { errorCode, status, message } = classifyError(error);
+                logger.error(`Failed to attach tool ${toolId}: ${message}`);
+                results.push({
+                    tool_id: toolId,
+                    status: 'error',
+                    error: message,
+                    error_code: errorCode,
+                    http_status: status,
+                });
+            }
+        }
+
+        const successCount = results.filter((r) => r.status === 'success').length;
+        const errorCount = results.length - successCount;
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        success: errorCount === 0,
+                        agent_id: args.agent_id,
+                        summary: {
+                            total_tools: results.length,
+                            success_count: successCount,
+                            error_count: errorCount,
+                        },
+                        results,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed during bulk attach operation');
+    }
+}
+
+/**
+ * Tool definition for bulk_attach_tools_to_agent
+ */
+export const bulkAttachToolsToAgentDefinition = {
+    name: 'bulk_attach_tools_to_agent',
+    description:
+        "Attach several tools to one agent in a single call, e.g. to set up a new agent's toolset. Failures are reported per tool; success is false if any attach failed. Use bulk_attach_tool_to_agents for the reverse (one tool, many agents), or attach_tool for a single tool.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent to attach the tools to',
+            },
+            tool_ids: {
+                type: 'array',
+                items: { type: 'string' },
+                description: 'IDs of the tools to attach',
+            },
+        },
+        required: ['agent_id', 'tool_ids'],
+    },
+};
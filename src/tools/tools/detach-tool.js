@@ -0,0 +1,100 @@
+import { createLogger } from '../../core/logger.js';
+import { httpError } from '../../core/errors.js';
+
+const logger = createLogger('detach_tool');
+
+/**
+ * Tool handler for detaching a tool (by ID or name) from an agent
+ */
+export async function handleDetachTool(server, args) {
+    if (!args?.agent_id) {
+        server.createErrorResponse('Missing required argument: agent_id');
+    }
+    if (!args.tool_id && !args.tool_name) {
+        server.createErrorResponse('Missing required argument: tool_id or tool_name');
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const agentId = encodeURIComponent(args.agent_id);
+
+        // Names are resolved against the agent's own tools, so only attached tools can match
+        let toolId = args.tool_id;
+        if (!toolId) {
+            const toolsResponse = await server.api.get(`/agents/${agentId}/tools`, { headers });
+            const attached = Array.isArray(toolsResponse.data) ? toolsResponse.data : [];
+            const matches = attached.filter((tool) => tool.name === args.tool_name);
+            if (matches.length === 0) {
+                server.createErrorResponse(
+                    `Tool '${args.tool_name}' is not attached to agent ${args.agent_id}`,
+                );
+            }
+            if (matches.length > 1) {
+                server.createErrorResponse(
+                    `Tool name '${args.tool_name}' is ambiguous: it matches ${matches.length} tools (${matches.map((tool) => tool.id).join(', ')}). Pass tool_id instead.`,
+                );
+            }
+            toolId = matches[0].id;
+        }
+
+        logger.info(`Detaching tool ${args.tool_name || toolId} from agent ${args.agent_id}`);
+        const response = await server.api.patch(
+            `/agents/${agentId}/tools/detach/${encodeURIComponent(toolId)}`,
+            {},
+            { headers },
+        );
+        const remaining = response.data?.tools || [];
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        agent_id: args.agent_id,
+                        tool_id: toolId,
+                        tool_name: args.tool_name ?? null,
+                        detached: !remaining.some((tool) => tool.id === toolId),
+                        remaining_tool_count: remaining.length,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(
+                httpError(
+                    404,
+                    `Agent or tool not found: agent_id=${args.agent_id}, tool=${args.tool_id || args.tool_name}`,
+                ),
+            );
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for detach_tool
+ */
+export const detachToolDefinition = {
+    name: 'detach_tool',
+    description:
+        "Detach a tool from an agent by tool_id or tool_name. Names are matched against the agent's attached tools; if several share the name, the candidates are listed so you can pass tool_id. Use list_agent_tools to see what is attached.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            agent_id: {
+                type: 'string',
+                description: 'ID of the agent to detach the tool from',
+            },
+            tool_id: {
+                type: 'string',
+                description: 'ID of the tool to detach',
+            },
+            tool_name: {
+                type: 'string',
+                description: 'Name of the tool to detach (alternative to tool_id)',
+            },
+        },
+        required: ['agent_id'],
+    },
+};
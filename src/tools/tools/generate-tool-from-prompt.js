@@ -0,0 +1,101 @@
+import { createLogger } from '../../core/logger.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('generate_tool_from_prompt');
+
+const UNSUPPORTED_MESSAGE =
+    'Tool generation is not supported by this Letta server (POST /v1/tools/generate-tool is unavailable). Upgrade the server, or write the source yourself and use generate_tool_schema and upload_tool.';
+
+/**
+ * Derive a snake_case function name from a natural-language description
+ */
+function deriveToolName(description) {
+    const words = description
+        .toLowerCase()
+        .replace(/[^a-z0-9\s]/g, ' ')
+        .split(/\s+/)
+        .filter(Boolean)
+        .slice(0, 5);
+    const name = words.join('_') || 'generated_tool';
+    return /^[a-z_]/.test(name) ? name : `tool_${name}`;
+}
+
+/**
+ * Tool handler for generating a tool's source code and schema from a description
+ */
+export async function handleGenerateToolFromPrompt(server, args) {
+    if (!args?.description || typeof args.description !== 'string') {
+        server.createErrorResponse('Missing required argument: description (must be a string)');
+    }
+    if (args.name !== undefined && !/^[A-Za-z_][A-Za-z0-9_]*$/.test(args.name)) {
+        server.createErrorResponse(
+            invalidRequest('name', 'Invalid argument: name must be a valid function identifier'),
+        );
+    }
+
+    // Skip the request when get_server_capabilities already found the endpoint missing
+    if (server.backendCapabilities?.tools?.generate_tool_from_prompt === 'unsupported') {
+        server.createErrorResponse(httpError(501, UNSUPPORTED_MESSAGE));
+    }
+
+    const toolName = args.name || deriveToolName(args.description);
+
+    try {
+        const headers = server.getApiHeaders();
+        const response = await server.api.post(
+            '/tools/generate-tool',
+            {
+                prompt: args.description,
+                tool_name: toolName,
+            },
+            { headers },
+        );
+
+        const generated = response.data?.tool || {};
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        name: generated.name || toolName,
+                        source_code: generated.source_code,
+                        json_schema: generated.json_schema,
+                        sample_args: response.data?.sample_args,
+                        sample_response: response.data?.response,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        // Older Letta servers don't expose tool generation at all
+        if (error.response && [404, 405, 501].includes(error.response.status)) {
+            logger.warn('Tool generation endpoint unavailable:', error.response.status);
+            server.createErrorResponse(httpError(501, UNSUPPORTED_MESSAGE));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for generate_tool_from_prompt
+ */
+export const generateToolFromPromptDefinition = {
+    name: 'generate_tool_from_prompt',
+    description:
+        'Ask the Letta server to write a tool from a natural-language description. Returns the generated source_code and json_schema without creating the tool; review it, then use upload_tool to save it. Requires a Letta server with tool generation support.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            description: {
+                type: 'string',
+                description: 'What the tool should do, in plain language',
+            },
+            name: {
+                type: 'string',
+                description:
+                    'Optional function name for the tool (derived from the description when omitted)',
+            },
+        },
+        required: ['description'],
+    },
+};
@@ -0,0 +1,85 @@
+import { generateSchemaFromSource, isValidFunctionName } from './source-schema.js';
+import { invalidRequest } from '../../core/errors.js';
+
+/**
+ * Tool handler for generating a tool's JSON schema locally from its source code
+ */
+export async function handleGenerateToolSchema(server, args) {
+    if (!args?.source_code || typeof args.source_code !== 'string') {
+        server.createErrorResponse('Missing required argument: source_code (must be a string)');
+    }
+    if (
+        args.source_type !== undefined &&
+        !['python', 'javascript', 'typescript'].includes(args.source_type)
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'source_type',
+                'Invalid argument: source_type must be one of python, javascript, typescript',
+            ),
+        );
+    }
+
+    if (args.function_name !== undefined && !isValidFunctionName(args.function_name)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'function_name',
+                'Invalid argument: function_name must be an identifier (letters, digits, _ or $)',
+            ),
+        );
+    }
+
+    let jsonSchema;
+    try {
+        jsonSchema = generateSchemaFromSource(args.source_code, {
+            sourceType: args.source_type,
+            functionName: args.function_name,
+        });
+    } catch (error) {
+        server.createErrorResponse(
+            invalidRequest(
+                'source_code',
+                `Invalid argument: could not parse source_code: ${error.message}`,
+            ),
+        );
+    }
+
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify({
+                    json_schema: jsonSchema,
+                }),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for generate_tool_schema
+ */
+export const generateToolSchemaDefinition = {
+    name: 'generate_tool_schema',
+    description:
+        'Generate the JSON schema Letta expects for a tool from its Python or JavaScript source, using the function signature and docstring. Runs locally, so you can preview the schema before calling upload_tool.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            source_code: {
+                type: 'string',
+                description: 'Source code containing the tool function',
+            },
+            source_type: {
+                type: 'string',
+                enum: ['python', 'javascript', 'typescript'],
+                description: 'Language of the source (detected automatically when omitted)',
+            },
+            function_name: {
+                type: 'string',
+                description: 'Function to describe (defaults to the first function in the source)',
+            },
+        },
+        required: ['source_code'],
+    },
+};
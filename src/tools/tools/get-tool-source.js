@@ -0,0 +1,56 @@
+import { httpError } from '../../core/errors.js';
+/**
+ * Tool handler for reading a tool's source code and schema
+ */
+export async function handleGetToolSource(server, args) {
+    if (!args?.tool_id) {
+        server.createErrorResponse('Missing required argument: tool_id');
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const response = await server.api.get(`/tools/${encodeURIComponent(args.tool_id)}`, {
+            headers,
+        });
+        const tool = response.data;
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        tool_id: tool.id,
+                        name: tool.name,
+                        source_type: tool.source_type ?? null,
+                        source_code: tool.source_code ?? null,
+                        json_schema: tool.json_schema ?? null,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Tool not found: ${args.tool_id}`));
+        }
+        server.createErrorResponse(error);
+    }
+}
+
+/**
+ * Tool definition for get_tool_source
+ */
+export const getToolSourceDefinition = {
+    name: 'get_tool_source',
+    description:
+        "Get just a tool's source code, JSON schema and source type, for inspecting or editing its implementation. Use list_tools to find tool IDs, and upload_tool to save a changed version.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            tool_id: {
+                type: 'string',
+                description: 'ID of the tool',
+            },
+        },
+        required: ['tool_id'],
+    },
+};
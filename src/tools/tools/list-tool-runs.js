@@ -0,0 +1,85 @@
+import { fitToResponseSize } from '../../core/response-size.js';
+import { scopeHeaders } from '../../core/scope.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const DEFAULT_LIMIT = 20;
+const RUN_STATUSES = ['success', 'error', 'failed'];
+
+/**
+ * Tool handler for listing recent run_tool_from_source executions
+ */
+export async function handleListToolRuns(server, args = {}) {
+    if (args.status !== undefined && !RUN_STATUSES.includes(args.status)) {
+        server.createErrorResponse(
+            invalidRequest(
+                'status',
+                `Invalid argument: status must be one of ${RUN_STATUSES.join(', ')}`,
+            ),
+        );
+    }
+    if (args.limit !== undefined && (!Number.isInteger(args.limit) || args.limit < 1)) {
+        server.createErrorResponse(
+            invalidRequest('limit', 'Invalid argument: limit must be a positive integer'),
+        );
+    }
+
+    const matches = server.runHistory.list(
+        { name: args.name, status: args.status },
+        scopeHeaders(server.scope),
+    );
+    const runs = matches.slice(0, args.limit ?? DEFAULT_LIMIT).map((run) => {
+        if (args.include_source) return run;
+        // eslint-disable-next-line no-unused-vars
+        const { source_code, ...rest } = run;
+        return rest;
+    });
+
+    const payload = fitToResponseSize(runs, (page, truncated) => ({
+        runs: page,
+        count: page.length,
+        total_matching: matches.length,
+        history_size: server.runHistory.size,
+        ...(truncated && { truncated: true }),
+    }));
+    return {
+        content: [
+            {
+                type: 'text',
+                text: JSON.stringify(payload),
+            },
+        ],
+    };
+}
+
+/**
+ * Tool definition for list_tool_runs
+ */
+export const listToolRunsDefinition = {
+    name: 'list_tool_runs',
+    description:
+        'List recent run_tool_from_source executions, newest first, with the args, result, stdout/stderr, duration_ms and success of each, to see which inputs made a tool fail. status is "success", "error" (the tool raised) or "failed" (the run request itself failed). The history is kept in memory by this server (last LETTA_RUN_HISTORY_SIZE runs, default 100), only runs made in the same project and user scope are listed, and env_vars are recorded by name only.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            name: {
+                type: 'string',
+                description: 'Only runs of this function name',
+            },
+            status: {
+                type: 'string',
+                enum: RUN_STATUSES,
+                description: 'Only runs with this outcome',
+            },
+            limit: {
+                type: 'integer',
+                minimum: 1,
+                description: `Maximum number of runs to return (default ${DEFAULT_LIMIT})`,
+            },
+            include_source: {
+                type: 'boolean',
+                description: "Include each run's source_code (default: false)",
+            },
+        },
+        required: [],
+    },
+};
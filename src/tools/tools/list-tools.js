@@ -0,0 +1,123 @@
+import { createLogger } from '../../core/logger.js';
+import { fitToResponseSize } from '../../core/response-size.js';
+import { bustCacheProperty } from '../../core/cache.js';
+import { invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('list_tools');
+
+const DEFAULT_LIMIT = 50;
+const SCAN_PAGE_SIZE = 200;
+
+const summarizeTool = (tool) => ({
+    id: tool.id,
+    name: tool.name,
+    description: tool.description,
+    tags: tool.tags || [],
+    tool_type: tool.tool_type,
+    source_type: tool.source_type,
+});
+
+/**
+ * Tool handler for listing tools registered on the server, with tag and name filters
+ */
+export async function handleListTools(server, args = {}) {
+    if (args.tags !== undefined && !Array.isArray(args.tags)) {
+        server.createErrorResponse(
+            invalidRequest('tags', 'Invalid argument: tags must be an array of strings'),
+        );
+    }
+    const limit = args.limit ?? DEFAULT_LIMIT;
+    if (!Number.isInteger(limit) || limit < 1) {
+        server.createErrorResponse(
+            invalidRequest('limit', 'Invalid argument: limit must be a positive integer'),
+        );
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        const tags = args.tags || [];
+        const nameFilter = args.name_filter?.toLowerCase();
+        const matchesFilters = (tool) =>
+            tags.every((tag) => (tool.tags || []).includes(tag)) &&
+            (!nameFilter || (tool.name || '').toLowerCase().includes(nameFilter));
+
+        // The server only filters by exact name, so tags and substrings are matched here,
+        // scanning pages until `limit` matches are found or the listing runs out
+        const scanPageSize = tags.length || nameFilter ? SCAN_PAGE_SIZE : limit;
+        const matches = [];
+        let after = args.after;
+        let hasMore = true;
+        while (matches.length < limit && hasMore) {
+            const params = { limit: scanPageSize };
+            if (after) params.after = after;
+            const response = await server.api.get('/tools/', { headers, params });
+            const page = Array.isArray(response.data) ? response.data : [];
+
+            let scanned = 0;
+            for (const tool of page) {
+                scanned += 1;
+                if (matchesFilters(tool)) matches.push(tool);
+                if (matches.length === limit) break;
+            }
+            hasMore = scanned < page.length || page.length === scanPageSize;
+            after = page[page.length - 1]?.id;
+        }
+        logger.info(`Listed ${matches.length} tools`);
+
+        // next_cursor is the last tool returned, so the following page resumes right after it
+        const payload = fitToResponseSize(matches.map(summarizeTool), (page, truncated) => ({
+            tools: page,
+            count: page.length,
+            next_cursor: truncated || hasMore ? (page[page.length - 1]?.id ?? null) : null,
+            ...(truncated && { truncated: true }),
+        }));
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify(payload),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed to list tools');
+    }
+}
+
+/**
+ * Tool definition for list_tools
+ */
+export const listToolsDefinition = {
+    name: 'list_tools',
+    description:
+        'List tools registered on the Letta server, optionally filtered by tags and a case-insensitive name substring. Returns a summary of each tool; a full page returns next_cursor for the following page. Use attach_tool to add one to an agent, or list_agent_tools for tools already attached.',
+    inputSchema: {
+        type: 'object',
+        properties: {
+            tags: {
+                type: 'array',
+                items: { type: 'string' },
+                description: 'Only return tools that have all of these tags',
+            },
+            name_filter: {
+                type: 'string',
+                description: 'Only return tools whose name contains this text (case-insensitive)',
+            },
+            after: {
+                type: 'string',
+                description:
+                    'Tool ID to start after (for pagination). Pass the next_cursor from a previous page.',
+            },
+            limit: {
+                type: 'integer',
+                description: `Maximum number of tools to return (default: ${DEFAULT_LIMIT})`,
+                minimum: 1,
+                default: DEFAULT_LIMIT,
+            },
+            bust_cache: bustCacheProperty,
+        },
+        required: [],
+    },
+};
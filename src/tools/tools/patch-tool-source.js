@@ -0,0 +1,155 @@
+import { createLogger } from '../../core/logger.js';
+import { applyLineEdits, applyUnifiedDiff } from './source-patch.js';
+import { httpError, invalidRequest } from '../../core/errors.js';
+
+const logger = createLogger('patch_tool_source');
+
+const countLines = (source) => (source ? source.replace(/\n$/, '').split('\n').length : 0);
+
+/**
+ * Tool handler for patching a tool's source code in place
+ */
+export async function handlePatchToolSource(server, args) {
+    if (!args?.tool_id) {
+        server.createErrorResponse('Missing required argument: tool_id');
+    }
+    const hasDiff = args.diff !== undefined;
+    const hasEdits = args.edits !== undefined;
+    if (hasDiff === hasEdits) {
+        server.createErrorResponse(
+            invalidRequest('diff', 'Invalid argument: provide exactly one of diff or edits'),
+        );
+    }
+    if (hasDiff && (typeof args.diff !== 'string' || !args.diff.trim())) {
+        server.createErrorResponse(
+            invalidRequest('diff', 'Invalid argument: diff must be a non-empty string'),
+        );
+    }
+    if (args.dry_run !== undefined && typeof args.dry_run !== 'boolean') {
+        server.createErrorResponse(
+            invalidRequest('dry_run', 'Invalid argument: dry_run must be a boolean'),
+        );
+    }
+
+    const toolId = encodeURIComponent(args.tool_id);
+    let tool;
+    try {
+        const headers = server.getApiHeaders();
+        const response = await server.api.get(`/tools/${toolId}`, { headers });
+        tool = response.data;
+    } catch (error) {
+        if (error.response && error.response.status === 404) {
+            server.createErrorResponse(httpError(404, `Tool not found: ${args.tool_id}`));
+        }
+        server.createErrorResponse(error, 'Failed to fetch tool source');
+    }
+
+    if (typeof tool?.source_code !== 'string') {
+        server.createErrorResponse(`Tool ${args.tool_id} has no source code to patch`);
+    }
+
+    let patched;
+    try {
+        patched = hasDiff
+            ? applyUnifiedDiff(tool.source_code, args.diff)
+            : applyLineEdits(tool.source_code, args.edits);
+    } catch (error) {
+        server.createErrorResponse(error.message);
+    }
+
+    const result = {
+        tool_id: tool.id,
+        name: tool.name,
+        changed: patched !== tool.source_code,
+        lines_before: countLines(tool.source_code),
+        lines_after: countLines(patched),
+    };
+
+    if (args.dry_run) {
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({ ...result, updated: false, source_code: patched }),
+                },
+            ],
+        };
+    }
+
+    try {
+        const headers = server.getApiHeaders();
+        if (result.changed) {
+            await server.api.patch(`/tools/${toolId}`, { source_code: patched }, { headers });
+            logger.info(`Patched source of tool ${args.tool_id}`);
+        }
+
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({ ...result, updated: result.changed }),
+                },
+            ],
+        };
+    } catch (error) {
+        logger.error('Error:', error.response?.data || error.message);
+        server.createErrorResponse(error, 'Failed to update tool source');
+    }
+}
+
+/**
+ * Tool definition for patch_tool_source
+ */
+export const patchToolSourceDefinition = {
+    name: 'patch_tool_source',
+    description:
+        "Change part of a tool's source code without re-uploading all of it. Fetches the current source, applies a unified diff or a list of line edits, and saves the result. The patch is rejected if it doesn't match the current source exactly (e.g. the source changed since it was read). Use get_tool_source to read the source first.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            tool_id: {
+                type: 'string',
+                description: 'ID of the tool to patch',
+            },
+            diff: {
+                type: 'string',
+                description:
+                    'Unified diff against the current source (hunks starting with "@@ -a,b +c,d @@"). Use this or edits.',
+            },
+            edits: {
+                type: 'array',
+                description:
+                    'Line edits against the current source. Use this or diff. Line numbers refer to the source before any edit is applied.',
+                items: {
+                    type: 'object',
+                    properties: {
+                        start_line: {
+                            type: 'integer',
+                            description: 'First line to replace (1-based)',
+                        },
+                        end_line: {
+                            type: 'integer',
+                            description:
+                                'Last line to replace (inclusive); start_line - 1 inserts before start_line',
+                        },
+                        old_text: {
+                            type: 'string',
+                            description: 'Current text of the lines, checked before replacing',
+                        },
+                        new_text: {
+                            type: 'string',
+                            description: 'Replacement text (empty string deletes the lines)',
+                        },
+                    },
+                    required: ['start_line', 'end_line', 'old_text', 'new_text'],
+                },
+            },
+            dry_run: {
+                type: 'boolean',
+                description: 'Return the patched source without saving it (default: false)',
+                default: false,
+            },
+        },
+        required: ['tool_id'],
+    },
+};
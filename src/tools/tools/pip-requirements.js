@@ -0,0 +1,53 @@
+import { invalidRequest } from '../../core/errors.js';
+/**
+ * Validation for the pip_requirements of Python tools.
+ *
+ * Letta stores each requirement as { name, version } and installs it as `name==version` (or just
+ * `name`), so only bare names and exact pins can be represented.
+ */
+
+// PEP 508 project name, optional extras, optional exact pin
+const PROJECT_NAME = '([A-Za-z0-9](?:[A-Za-z0-9._-]*[A-Za-z0-9])?)';
+const EXTRAS = '(\\[[A-Za-z0-9._,\\s-]+\\])?';
+const EXACT_PIN = '(?:==\\s*([A-Za-z0-9.*+!_-]+))?';
+const REQUIREMENT_PATTERN = new RegExp(`^${PROJECT_NAME}${EXTRAS}\\s*${EXACT_PIN}$`);
+
+const RANGE_OPERATOR = /(~=|!=|<=|>=|===|<|>)/;
+
+/**
+ * Parse pip requirement specifiers into Letta's { name, version } form
+ * @param {Array<string>} requirements - Specifiers such as "requests" or "pandas==2.2.0"
+ * @returns {Array<{name: string, version?: string}>}
+ * @throws {Error} If the list or any entry is malformed
+ */
+export function parsePipRequirements(requirements) {
+    if (!Array.isArray(requirements)) {
+        throw invalidRequest(
+            'pip_requirements',
+            'Invalid argument: pip_requirements must be an array of strings',
+        );
+    }
+
+    return requirements.map((entry) => {
+        if (typeof entry !== 'string' || !entry.trim()) {
+            throw invalidRequest(
+                'pip_requirements',
+                'Invalid argument: pip_requirements entries must be non-empty strings',
+            );
+        }
+        const specifier = entry.trim();
+        const match = REQUIREMENT_PATTERN.exec(specifier);
+        if (!match) {
+            const reason = RANGE_OPERATOR.test(specifier)
+                ? 'only exact pins (name==version) are supported'
+                : 'expected a package name, optionally pinned as name==version';
+            throw new Error(`Invalid pip requirement "${specifier}": ${reason}`);
+        }
+
+        const [, name, extras, version] = match;
+        return {
+            name: extras ? `${name}${extras.replace(/\s+/g, '')}` : name,
+            ...(version && { version }),
+        };
+    });
+}
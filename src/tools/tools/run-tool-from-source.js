@@ -0,0 +1,134 @@
+import { createLogger } from '../../core/logger.js';
+import { requestTimeout, timeoutSecsProperty } from '../../core/timeout.js';
+import { classifyError, invalidRequest } from '../../core/errors.js';
+import { scopeHeaders } from '../../core/scope.js';
+
+const logger = createLogger('run_tool_from_source');
+
+/**
+ * Tool handler for executing tool source code in the server's sandbox without saving it
+ */
+export async function handleRunToolFromSource(server, args) {
+    if (!args?.source_code || typeof args.source_code !== 'string') {
+        server.createErrorResponse('Missing required argument: source_code (must be a string)');
+    }
+    if (args.args !== undefined && (typeof args.args !== 'object' || Array.isArray(args.args))) {
+        server.createErrorResponse(
+            invalidRequest('args', 'Invalid argument: args must be an object'),
+        );
+    }
+    if (
+        args.env_vars !== undefined &&
+        (typeof args.env_vars !== 'object' ||
+            Array.isArray(args.env_vars) ||
+            Object.values(args.env_vars).some((value) => typeof value !== 'string'))
+    ) {
+        server.createErrorResponse(
+            invalidRequest(
+                'env_vars',
+                'Invalid argument: env_vars must map names to string values',
+            ),
+        );
+    }
+
+    const startedAt = Date.now();
+    // Kept for list_tool_runs; env var values are left out as they are often secrets
+    const recordRun = (outcome) =>
+        server.runHistory.record(
+            {
+                name: args.name ?? null,
+                source_type: args.source_type ?? null,
+                source_code: args.source_code,
+                args: args.args || {},
+                env_var_names: Object.keys(args.env_vars || {}),
+                started_at: new Date(startedAt).toISOString(),
+                duration_ms: Date.now() - startedAt,
+                ...outcome,
+            },
+            scopeHeaders(server.scope),
+        );
+
+    try {
+        const headers = server.getApiHeaders();
+        const body = {
+            source_code: args.source_code,
+            args: args.args || {},
+            env_vars: args.env_vars || {},
+        };
+        if (args.name) body.name = args.name;
+        if (args.source_type) body.source_type = args.source_type;
+
+        logger.info(`Running ${args.name || 'tool'} from source`);
+        const response = await server.api.post('/tools/run', body, {
+            headers,
+            ...requestTimeout(args),
+        });
+        const result = response.data || {};
+        const stdout = (result.stdout || []).join('');
+        const stderr = (result.stderr || []).join('');
+        recordRun({
+            status: result.status,
+            success: result.status === 'success',
+            tool_return: result.tool_return,
+            stdout,
+            stderr,
+        });
+
+        // A tool that raises still comes back as a 200 with status "error"; return its output
+        // rather than failing so the traceback in stderr is visible to the caller
+        return {
+            content: [
+                {
+                    type: 'text',
+                    text: JSON.stringify({
+                        status: result.status,
+                        tool_return: result.tool_return,
+                        stdout,
+                        stderr,
+                    }),
+                },
+            ],
+        };
+    } catch (error) {
+        recordRun({ status: 'failed', success: false, error: classifyError(error).message });
+        server.createErrorResponse(error, 'Failed to run tool from source');
+    }
+}
+
+/**
+ * Tool definition for run_tool_from_source
+ */
+export const runToolFromSourceDefinition = {
+    name: 'run_tool_from_source',
+    description:
+        "Execute tool source code in the Letta server's tool sandbox without saving it, returning the return value, stdout and stderr separately. A tool that raises returns status: \"error\" with its traceback in stderr. Sandbox limits (memory, E2B timeout) come from the server's sandbox configuration; timeout_secs bounds how long this call waits. Use upload_tool to save a working tool, and list_tool_runs to review recent runs.",
+    inputSchema: {
+        type: 'object',
+        properties: {
+            source_code: {
+                type: 'string',
+                description: 'Source code of the tool function',
+            },
+            args: {
+                type: 'object',
+                description: 'Arguments to call the function with',
+                additionalProperties: true,
+            },
+            env_vars: {
+                type: 'object',
+                description: 'Environment variables to set in the sandbox for this run',
+                additionalProperties: { type: 'string' },
+            },
+            name: {
+                type: 'string',
+                description: 'Function to run when the source defines several',
+            },
+            source_type: {
+                type: 'string',
+                description: 'Language of the source (default: python)',
+            },
+            timeout_secs: timeoutSecsProperty,
+        },
+        required: ['source_code'],
+    },
+};
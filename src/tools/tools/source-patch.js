@@ -0,0 +1,174 @@
+import { invalidRequest } from '../../core/errors.js';
+/**
+ * Apply incremental edits to a tool's source code.
+ *
+ * Both forms are strict: every context, removed or replaced line must match the current
+ * source exactly, so an edit made against an older version is rejected instead of being
+ * applied in the wrong place.
+ */
+
+const HUNK_HEADER = /^@@ -(\d+)(?:,(\d+))? \+(\d+)(?:,(\d+))? @@/;
+
+/**
+ * Split source into lines, remembering whether it ended with a newline
+ */
+function toLines(source) {
+    const lines = source.split('\n');
+    const trailingNewline = lines[lines.length - 1] === '';
+    if (trailingNewline) lines.pop();
+    return { lines, trailingNewline };
+}
+
+const fromLines = (lines, trailingNewline) => lines.join('\n') + (trailingNewline ? '\n' : '');
+
+const quote = (line) => (line === undefined ? 'end of file' : JSON.stringify(line));
+
+/**
+ * Parse the hunks of a unified diff
+ * @param {string} diff - Unified diff against a single file
+ * @returns {Array<{oldStart: number, lines: Array<{op: string, text: string}>}>}
+ */
+function parseUnifiedDiff(diff) {
+    const hunks = [];
+    let current = null;
+    for (const line of diff.replace(/\r\n/g, '\n').split('\n')) {
+        const header = HUNK_HEADER.exec(line);
+        if (header) {
+            current = { oldStart: Number(header[1]), lines: [] };
+            hunks.push(current);
+        } else if (!current) {
+            // File headers (---, +++, diff --git) and anything else before the first hunk
+            continue;
+        } else if (line.startsWith('\\')) {
+            // "\ No newline at end of file"; the source keeps its own trailing newline
+            continue;
+        } else if (line === '' || ' -+'.includes(line[0])) {
+            const op = line === '' ? ' ' : line[0];
+            current.lines.push({ op, text: line.slice(1) });
+        } else {
+            throw invalidRequest(
+                'diff',
+                `Invalid argument: diff has an unexpected line ${JSON.stringify(line)}`,
+            );
+        }
+    }
+    if (hunks.length === 0) {
+        throw invalidRequest(
+            'diff',
+            'Invalid argument: diff contains no hunks (expected "@@ -a,b +c,d @@")',
+        );
+    }
+    // A blank final line is the diff's own trailing newline, not an empty context line
+    for (const hunk of hunks) {
+        const last = hunk.lines[hunk.lines.length - 1];
+        if (last && last.op === ' ' && last.text === '' && hunk === hunks[hunks.length - 1]) {
+            hunk.lines.pop();
+        }
+    }
+    return hunks;
+}
+
+/**
+ * Apply a unified diff to source code
+ * @param {string} source - Current source
+ * @param {string} diff - Unified diff
+ * @returns {string} Patched source
+ * @throws {Error} If the diff is malformed or doesn't match the source
+ */
+export function applyUnifiedDiff(source, diff) {
+    const { lines, trailingNewline } = toLines(source);
+    const hunks = parseUnifiedDiff(diff);
+
+    const output = [];
+    let position = 0; // Index into the original lines
+    hunks.forEach((hunk, index) => {
+        // Pure insertions at the top of the file use a start line of 0
+        const start = Math.max(hunk.oldStart - 1, 0);
+        if (start < position) {
+            throw new Error(`Patch does not apply: hunk ${index + 1} overlaps the previous hunk`);
+        }
+        output.push(...lines.slice(position, start));
+        position = start;
+
+        for (const { op, text } of hunk.lines) {
+            if (op === '+') {
+                output.push(text);
+                continue;
+            }
+            if (lines[position] !== text) {
+                throw new Error(
+                    `Patch does not apply: hunk ${index + 1} expected ${quote(text)} at line ${position + 1} but found ${quote(lines[position])}. The source may have changed; fetch it again with get_tool_source.`,
+                );
+            }
+            if (op === ' ') output.push(text);
+            position += 1;
+        }
+    });
+    output.push(...lines.slice(position));
+    return fromLines(output, trailingNewline);
+}
+
+/**
+ * Apply line-range replacements to source code
+ * @param {string} source - Current source
+ * @param {Array<{start_line: number, end_line: number, old_text: string, new_text: string}>}
+ *   edits - 1-based inclusive ranges; end_line = start_line - 1 inserts before start_line
+ * @returns {string} Edited source
+ * @throws {Error} If an edit is malformed, overlaps another or doesn't match the source
+ */
+export function applyLineEdits(source, edits) {
+    if (!Array.isArray(edits) || edits.length === 0) {
+        throw invalidRequest('edits', 'Invalid argument: edits must be a non-empty array');
+    }
+    const { lines, trailingNewline } = toLines(source);
+
+    const sorted = edits
+        .map((edit, index) => {
+            const { start_line: start, end_line: end, old_text: oldText, new_text: newText } =
+                edit ?? {};
+            const validRange =
+                Number.isInteger(start) && Number.isInteger(end) && start >= 1 && end >= start - 1;
+            if (!validRange) {
+                throw invalidRequest(
+                    'edits',
+                    `Invalid argument: edits[${index}] needs integer start_line >= 1 and end_line >= start_line - 1`,
+                );
+            }
+            if (end > lines.length) {
+                throw new Error(
+                    `Patch does not apply: edits[${index}] ends at line ${end} but the source has ${lines.length} lines`,
+                );
+            }
+            if (typeof oldText !== 'string' || typeof newText !== 'string') {
+                throw invalidRequest(
+                    'edits',
+                    `Invalid argument: edits[${index}] needs old_text and new_text strings`,
+                );
+            }
+            return { index, start, end, oldText, newText };
+        })
+        .sort((a, b) => a.start - b.start || a.end - b.end);
+
+    for (let i = 1; i < sorted.length; i++) {
+        if (sorted[i].start <= sorted[i - 1].end) {
+            const [previous, next] = [sorted[i - 1].index, sorted[i].index];
+            throw invalidRequest(
+                'edits',
+                `Invalid argument: edits[${next}] overlaps edits[${previous}]`,
+            );
+        }
+    }
+
+    // Apply bottom-up so earlier line numbers stay valid
+    for (const { index, start, end, oldText, newText } of sorted.reverse()) {
+        const current = lines.slice(start - 1, end).join('\n');
+        if (current !== oldText.replace(/\n$/, '')) {
+            throw new Error(
+                `Patch does not apply: edits[${index}] expected lines ${start}-${end} to be ${JSON.stringify(oldText)} but found ${JSON.stringify(current)}. The source may have changed; fetch it again with get_tool_source.`,
+            );
+        }
+        const replacement = newText === '' ? [] : newText.replace(/\n$/, '').split('\n');
+        lines.splice(start - 1, end - start + 1, ...replacement);
+    }
+    return fromLines(lines, trailingNewline);
+}
@@ -0,0 +1,472 @@
+/**
+ * Local JSON schema generation for tool source code.
+ *
+ * Produces the `json_schema` object Letta stores for a tool ({ name, description, parameters })
+ * by reading the function signature and docstring/JSDoc, without a round trip to the server.
+ */
+
+const PYTHON_TYPES = {
+    str: 'string',
+    int: 'integer',
+    float: 'number',
+    bool: 'boolean',
+    list: 'array',
+    tuple: 'array',
+    set: 'array',
+    dict: 'object',
+    any: 'string',
+};
+
+const JS_TYPES = {
+    string: 'string',
+    number: 'number',
+    integer: 'integer',
+    int: 'integer',
+    boolean: 'boolean',
+    bool: 'boolean',
+    array: 'array',
+    object: 'object',
+    record: 'object',
+    map: 'object',
+    any: 'string',
+    '*': 'string',
+};
+
+// Function names are interpolated into the declaration patterns, so only identifiers are allowed
+const FUNCTION_NAME = /^[A-Za-z_$][\w$]*$/;
+
+const PYTHON_SECTIONS = /^(args|arguments|parameters|params|returns?|yields?|raises|examples?|notes?):\s*$/i;
+
+/**
+ * Error raised when the source can't be analyzed
+ */
+export class SchemaParseError extends Error {
+    constructor(message) {
+        super(message);
+        this.name = 'SchemaParseError';
+    }
+}
+
+/**
+ * Whether a function name is a plain identifier that can be looked up in the source
+ */
+export function isValidFunctionName(name) {
+    return typeof name === 'string' && FUNCTION_NAME.test(name);
+}
+
+/**
+ * Reject function names that aren't plain identifiers
+ */
+function checkFunctionName(functionName) {
+    if (functionName !== undefined && !isValidFunctionName(functionName)) {
+        throw new SchemaParseError(`Invalid function name: ${JSON.stringify(functionName)}`);
+    }
+}
+
+/**
+ * Split a string on a separator, ignoring separators nested in brackets or string literals
+ */
+function splitTopLevel(text, separator) {
+    const parts = [];
+    let depth = 0;
+    let quote = null;
+    let current = '';
+    for (let i = 0; i < text.length; i++) {
+        const ch = text[i];
+        if (quote) {
+            current += ch;
+            if (ch === '\\') {
+                current += text[++i] ?? '';
+            } else if (ch === quote) {
+                quote = null;
+            }
+            continue;
+        }
+        if (ch === '"' || ch === "'" || ch === '`') {
+            quote = ch;
+        } else if ('([{<'.includes(ch)) {
+            depth++;
+        } else if (')]}>'.includes(ch) && !(ch === '>' && text[i - 1] === '=')) {
+            depth--;
+        } else if (ch === separator && depth === 0) {
+            // Don't split comparison/arrow operators such as ==, != or =>
+            const operator = '=>'.includes(text[i + 1] ?? '') || '=!<>'.includes(text[i - 1]);
+            if (separator !== '=' || !operator) {
+                parts.push(current);
+                current = '';
+                continue;
+            }
+        }
+        current += ch;
+    }
+    parts.push(current);
+    return parts.map((part) => part.trim());
+}
+
+/**
+ * Return the index just past the parenthesis matching the one at openIndex
+ */
+function findClosingParen(source, openIndex) {
+    let depth = 0;
+    let quote = null;
+    for (let i = openIndex; i < source.length; i++) {
+        const ch = source[i];
+        if (quote) {
+            if (ch === '\\') i++;
+            else if (ch === quote) quote = null;
+            continue;
+        }
+        if (ch === '"' || ch === "'" || ch === '`') quote = ch;
+        else if (ch === '(') depth++;
+        else if (ch === ')' && --depth === 0) return i + 1;
+    }
+    throw new SchemaParseError('Unbalanced parentheses in function signature');
+}
+
+/**
+ * Parse a Python/JS literal default value into JSON, or undefined when it isn't a literal
+ */
+function parseDefault(raw) {
+    const value = raw.trim();
+    if (value === 'None' || value === 'null' || value === 'undefined') return null;
+    if (value === 'True' || value === 'true') return true;
+    if (value === 'False' || value === 'false') return false;
+    if (/^-?\d+(\.\d+)?([eE][+-]?\d+)?$/.test(value)) return Number(value);
+    const quoted = value.match(/^(['"`])([\s\S]*)\1$/);
+    if (quoted) return quoted[2];
+    try {
+        return JSON.parse(value.replace(/'/g, '"'));
+    } catch {
+        return undefined;
+    }
+}
+
+/**
+ * Map a Python type annotation to a JSON schema fragment
+ */
+function pythonTypeToSchema(annotation) {
+    let type = annotation.trim().replace(/^typing\./, '');
+
+    const union = splitTopLevel(type, '|').filter((part) => part !== 'None');
+    if (union.length === 1 && union[0] !== type) type = union[0];
+
+    const generic = type.match(/^(\w+)\[([\s\S]*)\]$/);
+    if (generic) {
+        const [, outer, inner] = generic;
+        const name = outer.toLowerCase();
+        if (name === 'optional') return pythonTypeToSchema(inner);
+        if (name === 'union') {
+            const members = splitTopLevel(inner, ',').filter((part) => part !== 'None');
+            return pythonTypeToSchema(members[0]);
+        }
+        if (name === 'literal') {
+            const values = splitTopLevel(inner, ',').map(parseDefault);
+            return { type: typeof values[0] === 'number' ? 'number' : 'string', enum: values };
+        }
+        if (['list', 'sequence', 'set', 'tuple', 'iterable'].includes(name)) {
+            return { type: 'array', items: pythonTypeToSchema(splitTopLevel(inner, ',')[0]) };
+        }
+        if (['dict', 'mapping'].includes(name)) return { type: 'object' };
+        throw new SchemaParseError(`Unsupported type annotation: ${annotation}`);
+    }
+
+    const mapped = PYTHON_TYPES[type.toLowerCase()];
+    if (!mapped) throw new SchemaParseError(`Unsupported type annotation: ${annotation}`);
+    return { type: mapped };
+}
+
+/**
+ * Map a JSDoc/TypeScript type expression to a JSON schema fragment
+ */
+function jsTypeToSchema(expression) {
+    let type = expression.trim().replace(/^\?|=$/g, '');
+
+    const union = splitTopLevel(type, '|').filter((part) => !['null', 'undefined'].includes(part));
+    if (union.length > 1 && union.every((part) => /^(['"]).*\1$/.test(part))) {
+        return { type: 'string', enum: union.map(parseDefault) };
+    }
+    if (union.length >= 1) type = union[0];
+
+    const arrayOf = type.match(/^Array\.?<([\s\S]+)>$/i) || type.match(/^([\s\S]+)\[\]$/);
+    if (arrayOf) return { type: 'array', items: jsTypeToSchema(arrayOf[1]) };
+    if (/^(Object|Record|Map)\.?</i.test(type) || type.startsWith('{')) return { type: 'object' };
+
+    const mapped = JS_TYPES[type.toLowerCase()];
+    if (!mapped) throw new SchemaParseError(`Unsupported type: ${expression}`);
+    return { type: mapped };
+}
+
+/**
+ * Infer a JSON schema type from a literal default value
+ */
+function schemaFromDefault(value) {
+    if (Array.isArray(value)) return { type: 'array' };
+    if (value === null || value === undefined) return { type: 'string' };
+    if (typeof value === 'number') return { type: Number.isInteger(value) ? 'integer' : 'number' };
+    if (typeof value === 'object') return { type: 'object' };
+    return { type: typeof value };
+}
+
+/**
+ * Strip common leading indentation from docstring lines
+ */
+function dedent(lines) {
+    const indents = lines.filter((line) => line.trim()).map((line) => line.match(/^\s*/)[0].length);
+    const min = indents.length ? Math.min(...indents) : 0;
+    return lines.map((line) => line.slice(min));
+}
+
+/**
+ * Parse a Google- or Sphinx-style Python docstring
+ */
+function parsePythonDocstring(docstring) {
+    // The first line sits right after the opening quotes, so it is left out of the dedent
+    const [first, ...rest] = docstring.split('\n');
+    const lines = [first.trim(), ...dedent(rest)];
+
+    const descriptionLines = [];
+    const params = {};
+    let section = null;
+    let current = null;
+
+    for (const line of lines) {
+        const trimmed = line.trim();
+        const sphinx = trimmed.match(/^:param\s+(?:(\S+)\s+)?(\w+):\s*(.*)$/);
+        if (sphinx) {
+            current = params[sphinx[2]] = { type: sphinx[1], description: sphinx[3] };
+            section = 'sphinx';
+            continue;
+        }
+        if (PYTHON_SECTIONS.test(trimmed)) {
+            section = trimmed.replace(/:$/, '').toLowerCase();
+            current = null;
+            continue;
+        }
+        if (/^:(returns?|rtype|raises)/.test(trimmed)) {
+            section = 'returns';
+            current = null;
+            continue;
+        }
+
+        if (!section) {
+            descriptionLines.push(trimmed);
+        } else if (['args', 'arguments', 'parameters', 'params'].includes(section)) {
+            const entry = trimmed.match(/^\*{0,2}(\w+)\s*(?:\(([^)]*)\))?\s*:\s*(.*)$/);
+            if (entry && !/^\s{8,}/.test(line)) {
+                current = params[entry[1]] = { type: entry[2], description: entry[3] };
+            } else if (current && trimmed) {
+                current.description = `${current.description} ${trimmed}`.trim();
+            }
+        } else if (section === 'sphinx' && current && trimmed) {
+            current.description = `${current.description} ${trimmed}`.trim();
+        }
+    }
+
+    // Use the first paragraph as the tool description
+    const paragraph = [];
+    for (const line of descriptionLines) {
+        if (!line && paragraph.length) break;
+        if (line) paragraph.push(line);
+    }
+    return { description: paragraph.join(' '), params };
+}
+
+/**
+ * Parse a JSDoc block into a description and per-parameter docs
+ */
+function parseJsDoc(block) {
+    const lines = block
+        .replace(/^\/\*\*|\*\/$/g, '')
+        .split('\n')
+        .map((line) => line.replace(/^\s*\*\s?/, '').trim());
+
+    const descriptionLines = [];
+    const params = {};
+    let current = null;
+    let inTags = false;
+
+    for (const line of lines) {
+        const param = line.match(
+            /^@(?:param|arg|argument)\s+(?:\{([^}]*)\}\s+)?(\[[^\]]+\]|[\w$.]+)\s*(?:-\s*)?(.*)$/,
+        );
+        if (param) {
+            inTags = true;
+            let name = param[2];
+            let defaultValue;
+            let optional = false;
+            if (name.startsWith('[')) {
+                optional = true;
+                [name, defaultValue] = name.slice(1, -1).split('=');
+            }
+            current = params[name.trim()] = {
+                type: param[1],
+                description: param[3],
+                optional,
+                default: defaultValue !== undefined ? parseDefault(defaultValue) : undefined,
+            };
+        } else if (line.startsWith('@')) {
+            inTags = true;
+            current = null;
+        } else if (!inTags) {
+            descriptionLines.push(line);
+        } else if (current && line) {
+            current.description = `${current.description} ${line}`.trim();
+        }
+    }
+
+    return { description: descriptionLines.filter(Boolean).join(' '), params };
+}
+
+function buildSchema(name, description, properties, required) {
+    return {
+        name,
+        description,
+        parameters: {
+            type: 'object',
+            properties,
+            required,
+        },
+    };
+}
+
+/**
+ * Generate a Letta json_schema from Python source
+ */
+export function generatePythonSchema(source, functionName) {
+    checkFunctionName(functionName);
+    const pattern = functionName
+        ? new RegExp(`^[ \\t]*(?:async\\s+)?def\\s+(${functionName})\\s*\\(`, 'm')
+        : /^(?:async\s+)?def\s+(\w+)\s*\(/m;
+    const match = pattern.exec(source);
+    if (!match) {
+        throw new SchemaParseError(
+            functionName
+                ? `Function '${functionName}' not found in source_code`
+                : 'No top-level function definition found in source_code',
+        );
+    }
+
+    const openIndex = match.index + match[0].length - 1;
+    const closeIndex = findClosingParen(source, openIndex);
+    const signature = source.slice(openIndex + 1, closeIndex - 1);
+
+    const rest = source.slice(closeIndex);
+    const bodyStart = rest.match(/^\s*(?:->\s*[^:]+)?:/);
+    if (!bodyStart) throw new SchemaParseError(`Malformed signature for '${match[1]}'`);
+    const docMatch = rest.slice(bodyStart[0].length).match(/^\s*[rRuU]?("""|''')([\s\S]*?)\1/);
+    const doc = parsePythonDocstring(docMatch ? docMatch[2] : '');
+
+    const properties = {};
+    const required = [];
+    for (const param of splitTopLevel(signature, ',')) {
+        if (!param || param === '*' || param === '/' || param.startsWith('*')) continue;
+        const [declaration, rawDefault] = splitTopLevel(param, '=');
+        const [name, annotation] = declaration.split(/:(.*)/s).map((part) => part?.trim());
+        if (name === 'self' || name === 'cls' || name === 'agent_state') continue;
+
+        const docs = doc.params[name] || {};
+        const typeSource = annotation || docs.type;
+        if (!typeSource) {
+            throw new SchemaParseError(`Parameter '${name}' has no type annotation`);
+        }
+
+        const property = pythonTypeToSchema(typeSource);
+        if (docs.description) property.description = docs.description;
+        if (rawDefault !== undefined) {
+            const value = parseDefault(rawDefault);
+            if (value !== undefined && value !== null) property.default = value;
+        } else {
+            required.push(name);
+        }
+        properties[name] = property;
+    }
+
+    return buildSchema(match[1], doc.description, properties, required);
+}
+
+/**
+ * Generate a Letta json_schema from JavaScript/TypeScript source
+ */
+export function generateJavaScriptSchema(source, functionName) {
+    checkFunctionName(functionName);
+    const name = functionName || '[A-Za-z_$][\\w$]*';
+    const patterns = [
+        new RegExp(`(?:export\\s+)?(?:default\\s+)?(?:async\\s+)?function\\s*\\*?\\s*(${name})\\s*\\(`),
+        new RegExp(
+            `(?:export\\s+)?(?:const|let|var)\\s+(${name})\\s*=\\s*(?:async\\s+)?(?:function\\s*[\\w$]*\\s*)?\\(`,
+        ),
+    ];
+    const match = patterns
+        .map((pattern) => pattern.exec(source))
+        .filter(Boolean)
+        .sort((a, b) => a.index - b.index)[0];
+    if (!match) {
+        throw new SchemaParseError(
+            functionName
+                ? `Function '${functionName}' not found in source_code`
+                : 'No function declaration found in source_code',
+        );
+    }
+
+    const openIndex = match.index + match[0].length - 1;
+    const closeIndex = findClosingParen(source, openIndex);
+    const signature = source.slice(openIndex + 1, closeIndex - 1);
+
+    // Use the JSDoc block that immediately precedes the declaration, if any
+    const preceding = source.slice(0, match.index);
+    const docMatch = preceding.match(/\/\*\*[\s\S]*?\*\/\s*$/);
+    const doc = docMatch ? parseJsDoc(docMatch[0].trim()) : { description: '', params: {} };
+
+    const properties = {};
+    const required = [];
+    for (const param of splitTopLevel(signature, ',')) {
+        if (!param || param.startsWith('...')) continue;
+        if (param.startsWith('{') || param.startsWith('[')) {
+            throw new SchemaParseError('Destructured parameters are not supported');
+        }
+        const [declaration, rawDefault] = splitTopLevel(param, '=');
+        const [rawName, annotation] = declaration.split(/:(.*)/s).map((part) => part?.trim());
+        const paramName = rawName.replace(/\?$/, '');
+        const docs = doc.params[paramName] || {};
+
+        const defaultValue = rawDefault !== undefined ? parseDefault(rawDefault) : docs.default;
+        let property;
+        if (docs.type || annotation) {
+            property = jsTypeToSchema(docs.type || annotation);
+        } else {
+            property = schemaFromDefault(defaultValue);
+        }
+        if (docs.description) property.description = docs.description;
+        if (defaultValue !== undefined && defaultValue !== null) property.default = defaultValue;
+
+        const optional = rawDefault !== undefined || docs.optional || rawName.endsWith('?');
+        if (!optional) required.push(paramName);
+        properties[paramName] = property;
+    }
+
+    return buildSchema(match[1], doc.description, properties, required);
+}
+
+/**
+ * Guess the source language from its contents
+ */
+export function detectSourceType(source) {
+    return /^(?:async\s+)?def\s+\w+\s*\(/m.test(source) ? 'python' : 'javascript';
+}
+
+/**
+ * Generate a Letta json_schema for the given source
+ * @param {string} source - Tool source code
+ * @param {Object} [options]
+ * @param {string} [options.sourceType] - 'python' or 'javascript' (detected when omitted)
+ * @param {string} [options.functionName] - Function to describe (first function when omitted)
+ * @returns {{name: string, description: string, parameters: Object}}
+ */
+export function generateSchemaFromSource(source, { sourceType, functionName } = {}) {
+    const language = sourceType || detectSourceType(source);
+    if (language === 'python') return generatePythonSchema(source, functionName);
+    if (language === 'javascript' || language === 'typescript') {
+        return generateJavaScriptSchema(source, functionName);
+    }
+    throw new SchemaParseError(`Unsupported source_type: ${language}`);
+}
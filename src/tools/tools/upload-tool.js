@@ -1,7 +1,32 @@
 import { createLogger } from '../../core/logger.js';
+import { parsePipRequirements } from './pip-requirements.js';
+import { generatePythonSchema } from './source-schema.js';
+import { idempotencyKeyProperty } from '../../core/idempotency.js';
+import { invalidRequest } from '../../core/errors.js';
 
 const logger = createLogger('upload_tool');
 
+/**
+ * Non-fatal problems with a derived schema that make a tool harder for agents to use
+ */
+function schemaWarnings(name, jsonSchema) {
+    const warnings = [];
+    if (jsonSchema.name !== name) {
+        warnings.push(
+            `Function '${jsonSchema.name}' does not match tool name '${name}'; Letta names tools after the function`,
+        );
+    }
+    if (!jsonSchema.description) {
+        warnings.push('Function has no docstring description');
+    }
+    for (const [param, property] of Object.entries(jsonSchema.parameters.properties)) {
+        if (!property.description) {
+            warnings.push(`Parameter '${param}' has no description in the docstring`);
+        }
+    }
+    return warnings;
+}
+
 /**
  * Tool handler for uploading a new tool to the Letta system
  */
@@ -18,6 +43,26 @@ export async function handleUploadTool(server, args) {
             throw new Error('Missing required argument: source_code (must be a string)');
         }
 
+        // Reject malformed specifiers before anything on the server is touched
+        const pipRequirements =
+            args.pip_requirements !== undefined
+                ? parsePipRequirements(args.pip_requirements)
+                : undefined;
+
+        // Derive the schema up front in validate mode so parse errors surface before any API call.
+        // This is a local preview only: the source is not compiled or run by Letta.
+        let jsonSchema;
+        if (args.validate) {
+            try {
+                jsonSchema = generatePythonSchema(args.source_code);
+            } catch (parseError) {
+                throw invalidRequest(
+                    'source_code',
+                    `Invalid argument: could not parse source_code: ${parseError.message}`,
+                );
+            }
+        }
+
         // Headers for API requests
         const headers = server.getApiHeaders();
 
@@ -44,6 +89,25 @@ export async function handleUploadTool(server, args) {
             }
         }
 
+        if (args.validate) {
+            return {
+                content: [
+                    {
+                        type: 'text',
+                        text: JSON.stringify({
+                            schema_preview: true,
+                            tool_name: args.name,
+                            category: category,
+                            json_schema: jsonSchema,
+                            warnings: schemaWarnings(args.name, jsonSchema),
+                            would_replace_tool_id: existingToolId,
+                            ...(pipRequirements && { pip_requirements: pipRequirements }),
+                        }),
+                    },
+                ],
+            };
+        }
+
         if (existingToolId) {
             try {
                 await server.api.delete(`/tools/${existingToolId}`, { headers });
@@ -61,6 +125,7 @@ export async function handleUploadTool(server, args) {
             description: args.description,
             tags: [category],
             source_type: 'python',
+            ...(pipRequirements && { pip_requirements: pipRequirements }),
         };
 
         // Create the tool
@@ -88,6 +153,7 @@ export async function handleUploadTool(server, args) {
                             agent_id: args.agent_id,
                             agent_name: agentName,
                             category: category,
+                            ...(pipRequirements && { pip_requirements: pipRequirements }),
                         }),
                     },
                 ],
@@ -102,6 +168,7 @@ export async function handleUploadTool(server, args) {
                             tool_id: toolId,
                             tool_name: args.name,
                             category: category,
+                            ...(pipRequirements && { pip_requirements: pipRequirements }),
                         }),
                     },
                 ],
@@ -118,7 +185,7 @@ export async function handleUploadTool(server, args) {
 export const uploadToolToolDefinition = {
     name: 'upload_tool',
     description:
-        'Upload a new tool to the Letta system. Use with attach_tool to add it to agents, or list_agent_tools to verify attachment.',
+        'Upload a new tool to the Letta system. Pass validate: true to preview the schema derived locally from the signature and docstring without creating anything; the preview does not prove Letta will accept or run the source. Use with attach_tool to add it to agents, or list_agent_tools to verify attachment.',
     inputSchema: {
         type: 'object',
         properties: {
@@ -143,6 +210,19 @@ export const uploadToolToolDefinition = {
                 type: 'string',
                 description: 'Optional agent ID to attach the tool to after creation',
             },
+            validate: {
+                type: 'boolean',
+                description:
+                    'Return a local preview of the json_schema derived from source_code, with any warnings, without creating the tool. The source is not compiled or run, so this does not guarantee Letta accepts it (default: false)',
+                default: false,
+            },
+            pip_requirements: {
+                type: 'array',
+                items: { type: 'string' },
+                description:
+                    'Optional Python packages the tool needs, as names or exact pins (e.g., ["requests", "pandas==2.2.0"])',
+            },
+            idempotency_key: idempotencyKeyProperty,
         },
         required: ['name', 'description', 'source_code'],
     },
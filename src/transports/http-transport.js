@@ -4,6 +4,10 @@ import { randomUUID } from 'crypto';
 import { StreamableHTTPServerTransport } from '@modelcontextprotocol/sdk/server/streamableHttp.js';
 import { isInitializeRequest } from '@modelcontextprotocol/sdk/types.js';
 import { createLogger } from '../core/logger.js';
+import { isOriginAllowed, readAllowedOrigins } from '../core/origins.js';
+import { rateLimitMiddleware, readRateLimit } from '../core/rate-limit.js';
+import { bearerAuthMiddleware, readAuthToken } from '../core/inbound-auth.js';
+import { drainInFlight } from '../core/shutdown.js';
 
 /**
  * A simple in-memory implementation of the EventStore interface for recovery
@@ -90,17 +94,19 @@ export async function runHTTP(server) {
         const transports = {};
 
         // Security: Validate Origin header to prevent DNS rebinding attacks
+        const allowedOrigins = readAllowedOrigins(process.env);
+        if (allowedOrigins === null) {
+            logger.warn(
+                'LETTA_ALLOWED_ORIGINS is "*": accepting requests from ANY origin. Do not run this way in production.',
+            );
+        } else if (!process.env.LETTA_ALLOWED_ORIGINS) {
+            logger.warn(
+                `LETTA_ALLOWED_ORIGINS is not set; only the built-in default origins are allowed (${allowedOrigins.join(', ')})`,
+            );
+        }
         app.use((req, res, next) => {
             const origin = req.headers.origin;
-            const allowedOrigins = [
-                'http://localhost',
-                'http://127.0.0.1',
-                'http://192.168.50.90',
-                'https://letta.oculair.ca',
-                'https://letta2.oculair.ca',
-            ];
-
-            if (origin && !allowedOrigins.some((allowed) => origin.startsWith(allowed))) {
+            if (origin && !isOriginAllowed(origin, allowedOrigins)) {
                 logger.warn(`Blocked request from unauthorized origin: ${origin}`);
                 return res.status(403).json({
                     jsonrpc: '2.0',
@@ -117,13 +123,8 @@ export async function runHTTP(server) {
         // Middleware
         app.use(
             cors({
-                origin: [
-                    'http://localhost',
-                    'http://127.0.0.1',
-                    'http://192.168.50.90',
-                    'https://letta.oculair.ca',
-                    'https://letta2.oculair.ca',
-                ],
+                origin: (origin, callback) =>
+                    callback(null, !origin || isOriginAllowed(origin, allowedOrigins)),
                 credentials: true,
             }),
         );
@@ -136,6 +137,15 @@ export async function runHTTP(server) {
             next();
         });
 
+        // Per-IP rate limit on the MCP endpoint (LETTA_RATE_LIMIT per LETTA_RATE_WINDOW_SECS),
+        // ahead of auth so requests with a wrong token count too
+        const rateLimit = readRateLimit(process.env);
+        app.use('/mcp', rateLimitMiddleware(rateLimit, logger));
+
+        // Optional bearer-token auth on the MCP endpoint (LETTA_MCP_AUTH_TOKEN)
+        const authToken = readAuthToken(process.env);
+        app.use('/mcp', bearerAuthMiddleware(authToken, logger));
+
         // Protocol version validation middleware
         app.use('/mcp', (req, res, next) => {
             // Skip validation for initialization requests
@@ -306,12 +316,21 @@ export async function runHTTP(server) {
                 uptime: process.uptime(),
                 timestamp: new Date().toISOString(),
                 security: {
-                    origin_validation: true,
+                    origin_validation: allowedOrigins !== null,
+                    bearer_auth: Boolean(authToken),
+                    rate_limit: rateLimit.limit
+                        ? { limit: rateLimit.limit, window_secs: rateLimit.windowSecs }
+                        : null,
                     localhost_binding: true,
                 },
             });
         });
 
+        // Prometheus metrics: per-tool call counts by outcome and call durations
+        app.get('/metrics', (req, res) => {
+            res.type('text/plain; version=0.0.4').send(server.metrics.render());
+        });
+
         // Start server - bind to all interfaces for Docker container access
         const PORT = process.env.PORT || 3001;
         const HOST = '0.0.0.0'; // Docker containers need to bind to all interfaces
@@ -320,15 +339,37 @@ export async function runHTTP(server) {
             logger.info(`Letta MCP HTTP server is running on ${HOST}:${PORT}`);
             logger.info(`MCP endpoint: http://localhost:${PORT}/mcp`);
             logger.info(`Health check: http://localhost:${PORT}/health`);
+            logger.info(`Metrics: http://localhost:${PORT}/metrics`);
             logger.info('Protocol version: 2025-06-18');
-            logger.info('Security: Origin validation enabled, DNS rebinding protection active');
+            logger.info(
+                allowedOrigins === null
+                    ? 'Security: Origin validation DISABLED (LETTA_ALLOWED_ORIGINS="*")'
+                    : `Security: Origin validation enabled for ${allowedOrigins.join(', ')}`,
+            );
             logger.info(`API credentials: ${server.apiBase ? 'Available' : 'Not available'}`);
+            if (!authToken) {
+                logger.warn('LETTA_MCP_AUTH_TOKEN is not set: /mcp accepts unauthenticated requests');
+            }
         });
 
-        // Graceful shutdown
+        // Graceful shutdown: stop accepting connections, let running tool calls finish (up to
+        // LETTA_SHUTDOWN_TIMEOUT_SECS), then close the sessions. A second signal exits at once.
+        let shuttingDown = false;
         const shutdownHandler = async () => {
+            if (shuttingDown) {
+                logger.warn('Shutdown already in progress; exiting immediately');
+                if (process.env.NODE_ENV !== 'test') {
+                    process.exit(1);
+                }
+                return;
+            }
+            shuttingDown = true;
             logger.info('Shutting down HTTP server...');
             httpServer.close();
+            httpServer.closeIdleConnections?.();
+            if (server.inFlight.size > 0) {
+                await drainInFlight(server, server.shutdownOptions, logger);
+            }
 
             // Clean up all transports
             for (const [sessionId, transport] of Object.entries(transports)) {
@@ -1,5 +1,6 @@
 import { runStdio } from './stdio-transport.js';
 import { runSSE } from './sse-transport.js';
 import { runHTTP } from './http-transport.js';
+import { runUDS } from './uds-transport.js';
 
-export { runStdio, runSSE, runHTTP };
+export { runStdio, runSSE, runHTTP, runUDS };
@@ -1,6 +1,9 @@
 import express from 'express';
 import { SSEServerTransport } from '@modelcontextprotocol/sdk/server/sse.js';
 import { createLogger } from '../core/logger.js';
+import { bearerAuthMiddleware, readAuthToken } from '../core/inbound-auth.js';
+import { rateLimitMiddleware, readRateLimit } from '../core/rate-limit.js';
+import { drainInFlight } from '../core/shutdown.js';
 
 /**
  * Run the server using SSE transport
@@ -107,6 +110,13 @@ export async function runSSE(server) {
             }
         };
 
+        // Per-IP rate limit on the MCP endpoints, ahead of auth so wrong tokens count too
+        app.use(['/sse', '/message'], rateLimitMiddleware(readRateLimit(process.env), logger));
+
+        // Optional bearer-token auth on the MCP endpoints (LETTA_MCP_AUTH_TOKEN)
+        const requireAuth = bearerAuthMiddleware(readAuthToken(process.env), logger);
+        app.use(['/sse', '/message'], requireAuth);
+
         app.get('/sse', async (req, res) => {
             const clientIp = req.headers['x-forwarded-for'] || req.socket.remoteAddress;
             const wasDisconnected = !isConnected && transport !== undefined;
@@ -258,6 +268,11 @@ export async function runSSE(server) {
                 clearInterval(pingInterval);
             }
 
+            // Let running tool calls finish while their connections are still open
+            if (server.inFlight.size > 0) {
+                await drainInFlight(server, server.shutdownOptions, logger);
+            }
+
             // Clean up all active connections
             logger.info(`Cleaning up ${activeConnections.size} active connections`);
             for (const [clientId] of activeConnections.entries()) {
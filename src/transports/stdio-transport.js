@@ -1,5 +1,6 @@
 import { StdioServerTransport } from '@modelcontextprotocol/sdk/server/stdio.js';
 import { createLogger } from '../core/logger.js';
+import { drainInFlight } from '../core/shutdown.js';
 
 /**
  * Run the server using stdio transport
@@ -13,6 +14,10 @@ export async function runStdio(server) {
         logger.info('Letta MCP server running on stdio');
 
         const cleanup = async () => {
+            // Let running tool calls finish before the transport goes away
+            if (server.inFlight.size > 0) {
+                await drainInFlight(server, server.shutdownOptions, logger);
+            }
             await server.server.close();
             process.exit(0);
         };
@@ -0,0 +1,116 @@
+import net from 'node:net';
+import fs from 'node:fs/promises';
+import { StdioServerTransport } from '@modelcontextprotocol/sdk/server/stdio.js';
+import { createLogger } from '../core/logger.js';
+import { drainInFlight } from '../core/shutdown.js';
+
+/**
+ * Make sure the socket path is free to bind. A leftover socket from a previous run is
+ * removed; a socket another process is listening on, or any other file, is an error.
+ * @param {string} socketPath
+ */
+export async function prepareSocketPath(socketPath) {
+    let stats;
+    try {
+        stats = await fs.lstat(socketPath);
+    } catch (error) {
+        if (error.code === 'ENOENT') return;
+        throw error;
+    }
+    if (!stats.isSocket()) {
+        throw new Error(`LETTA_SOCKET_PATH ${socketPath} exists and is not a socket`);
+    }
+
+    const inUse = await new Promise((resolve) => {
+        const probe = net.connect(socketPath);
+        probe.once('connect', () => {
+            probe.destroy();
+            resolve(true);
+        });
+        probe.once('error', () => resolve(false));
+    });
+    if (inUse) {
+        throw new Error(`Socket ${socketPath} is already in use by another process`);
+    }
+    await fs.unlink(socketPath);
+}
+
+/**
+ * Run the server on a Unix domain socket, speaking newline-delimited JSON-RPC (the same
+ * framing as stdio) to one client at a time
+ * @param {Object} server - The LettaServer instance
+ */
+export async function runUDS(server) {
+    const logger = createLogger('uds-transport');
+    try {
+        const socketPath = process.env.LETTA_SOCKET_PATH;
+        if (!socketPath) {
+            throw new Error('Missing required environment variable: LETTA_SOCKET_PATH');
+        }
+        await prepareSocketPath(socketPath);
+
+        let activeTransport = null;
+        const socketServer = net.createServer(async (socket) => {
+            if (activeTransport) {
+                logger.warn('Rejected connection: a client is already connected');
+                socket.end();
+                return;
+            }
+
+            const transport = new StdioServerTransport(socket, socket);
+            activeTransport = transport;
+            socket.on('close', async () => {
+                if (activeTransport !== transport) return;
+                activeTransport = null;
+                logger.info('Client disconnected');
+                await transport.close?.();
+            });
+            socket.on('error', (error) => logger.warn(`Socket error: ${error.message}`));
+
+            try {
+                await server.server.connect(transport);
+                logger.info('Client connected');
+            } catch (error) {
+                logger.error('Failed to connect client:', error);
+                socket.destroy();
+            }
+        });
+
+        await new Promise((resolve, reject) => {
+            socketServer.once('error', reject);
+            socketServer.listen(socketPath, resolve);
+        });
+        logger.info(`Letta MCP server listening on unix socket ${socketPath}`);
+
+        // Graceful shutdown: stop accepting clients, let running tool calls finish, then
+        // remove the socket file
+        let shuttingDown = false;
+        const cleanup = async () => {
+            if (shuttingDown) return;
+            shuttingDown = true;
+            logger.info('Shutting down unix socket server...');
+            socketServer.close();
+            if (server.inFlight.size > 0) {
+                await drainInFlight(server, server.shutdownOptions, logger);
+            }
+            await activeTransport?.close?.();
+            await fs.unlink(socketPath).catch(() => {});
+            await server.server.close();
+            if (process.env.NODE_ENV !== 'test') {
+                process.exit(0);
+            }
+        };
+
+        process.on('SIGINT', cleanup);
+        process.on('SIGTERM', cleanup);
+
+        // Return the socket server for testing
+        return socketServer;
+    } catch (error) {
+        logger.error('Failed to start unix socket server:', error);
+        if (process.env.NODE_ENV !== 'test') {
+            process.exit(1);
+        }
+        throw error;
+    }
+}